@@ -0,0 +1,210 @@
+//! The `audios.rs` the firmware includes.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::{Clip, ClipFormat};
+
+/// Types every generated file starts with.
+const PRELUDE: &str = r#"/// How a clip is stored in flash.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClipFormat {
+    /// A WAV file, for `ClipStream`.
+    Wav,
+    /// An MP3 file, for `Mp3Stream`.
+    Mp3,
+}
+
+/// Frames of a clip played over and over, `end` excluded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LoopRegion {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// What the build script read from a clip.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClipInfo {
+    pub format: ClipFormat,
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub frames: u32,
+    pub duration_ms: u32,
+    /// Played over and over until the clip is released.
+    pub loop_region: Option<LoopRegion>,
+}
+"#;
+
+pub fn render(dir: &Path, clips: &[Clip]) -> String {
+    let mut code = String::new();
+    let out = &mut code;
+    let _ = writeln!(
+        out,
+        "// Generated by audio-assets from `{}`.",
+        dir.display()
+    );
+    let _ = writeln!(
+        out,
+        "// Do not edit: add, remove or replace the files there instead.\n"
+    );
+    out.push_str(PRELUDE);
+
+    let _ = writeln!(out, "\n/// The clips in `{}`.", dir.display());
+    let _ = writeln!(out, "#[derive(Clone, Copy, Debug, PartialEq, Eq)]");
+    let _ = writeln!(out, "pub enum AudioClip {{");
+    for clip in clips {
+        let _ = writeln!(out, "    /// `{}`", file_name(&clip.source));
+        let _ = writeln!(out, "    {},", clip.variant);
+    }
+    let _ = writeln!(out, "    /// Nothing to play.");
+    let _ = writeln!(out, "    None,");
+    let _ = writeln!(out, "}}\n");
+
+    let _ = writeln!(out, "impl AudioClip {{");
+    let _ = writeln!(
+        out,
+        "    /// Every clip, in file name order (without `None`)."
+    );
+    let _ = writeln!(out, "    pub const ALL: [AudioClip; {}] = [", clips.len());
+    for clip in clips {
+        let _ = writeln!(out, "        AudioClip::{},", clip.variant);
+    }
+    let _ = writeln!(out, "    ];\n");
+
+    let _ = writeln!(out, "    /// The file stem, in snake_case.");
+    let _ = writeln!(out, "    pub const fn name(self) -> &'static str {{");
+    let _ = writeln!(out, "        match self {{");
+    for clip in clips {
+        let _ = writeln!(
+            out,
+            "            AudioClip::{} => {:?},",
+            clip.variant, clip.name
+        );
+    }
+    let _ = writeln!(out, "            AudioClip::None => \"none\",");
+    let _ = writeln!(out, "        }}\n    }}\n");
+
+    let _ = writeln!(
+        out,
+        "    /// The clip called `name` (see [`AudioClip::name`])."
+    );
+    let _ = writeln!(
+        out,
+        "    pub fn from_name(name: &str) -> Option<AudioClip> {{"
+    );
+    let _ = writeln!(
+        out,
+        "        AudioClip::ALL.into_iter().find(|clip| clip.name() == name)"
+    );
+    let _ = writeln!(out, "    }}\n");
+
+    let _ = writeln!(out, "    /// The file as it is stored in flash.");
+    let _ = writeln!(out, "    pub fn data(self) -> &'static [u8] {{");
+    let _ = writeln!(out, "        match self {{");
+    for clip in clips {
+        let _ = writeln!(
+            out,
+            "            AudioClip::{} => &{},",
+            clip.variant, clip.constant
+        );
+    }
+    let _ = writeln!(out, "            AudioClip::None => &[],");
+    let _ = writeln!(out, "        }}\n    }}\n");
+
+    let _ = writeln!(
+        out,
+        "    /// Format and length of the clip; `None` for `AudioClip::None`."
+    );
+    let _ = writeln!(out, "    pub const fn info(self) -> Option<ClipInfo> {{");
+    let _ = writeln!(out, "        match self {{");
+    for clip in clips {
+        let info = &clip.info;
+        let format = match info.format {
+            ClipFormat::Wav => "Wav",
+            ClipFormat::Mp3 => "Mp3",
+        };
+        let _ = writeln!(
+            out,
+            "            AudioClip::{} => Some(ClipInfo {{",
+            clip.variant
+        );
+        let _ = writeln!(out, "                format: ClipFormat::{format},");
+        let _ = writeln!(out, "                sample_rate: {},", info.sample_rate);
+        let _ = writeln!(out, "                channels: {},", info.channels);
+        let _ = writeln!(out, "                frames: {},", info.frames);
+        let _ = writeln!(out, "                duration_ms: {},", info.duration_ms());
+        match info.loop_region {
+            Some(region) => {
+                let _ = writeln!(
+                    out,
+                    "                loop_region: Some(LoopRegion {{ start: {}, end: {} }}),",
+                    region.start, region.end
+                );
+            }
+            None => {
+                let _ = writeln!(out, "                loop_region: None,");
+            }
+        }
+        let _ = writeln!(out, "            }}),");
+    }
+    let _ = writeln!(out, "            AudioClip::None => None,");
+    let _ = writeln!(out, "        }}\n    }}\n}}");
+
+    for clip in clips {
+        let _ = writeln!(out, "\n/// `{}`", file_name(&clip.source));
+        let _ = writeln!(
+            out,
+            "pub static {}: [u8; {}] = *include_bytes!({:?});",
+            clip.constant,
+            clip.len,
+            clip.embedded.display().to_string()
+        );
+    }
+    code
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ClipInfo, LoopRegion};
+
+    #[test]
+    fn renders_a_variant_and_a_static_per_clip() {
+        let clip = Clip {
+            name: "fairy_song_1".into(),
+            variant: "FairySong1".into(),
+            constant: "FAIRY_SONG_1".into(),
+            source: "/assets/Fairy Song 1.wav".into(),
+            embedded: "/out/fairy_song_1.wav".into(),
+            len: 1044,
+            info: ClipInfo {
+                format: ClipFormat::Wav,
+                sample_rate: 11025,
+                channels: 1,
+                frames: 2205,
+                loop_region: Some(LoopRegion {
+                    start: 100,
+                    end: 2000,
+                }),
+            },
+        };
+        let code = render(Path::new("/assets"), &[clip]);
+        assert!(code.contains("    /// `Fairy Song 1.wav`\n    FairySong1,\n    /// Nothing"));
+        assert!(code
+            .contains("pub const ALL: [AudioClip; 1] = [\n        AudioClip::FairySong1,\n    ];"));
+        assert!(code.contains("AudioClip::FairySong1 => \"fairy_song_1\","));
+        assert!(code.contains("AudioClip::FairySong1 => &FAIRY_SONG_1,"));
+        assert!(code.contains("                duration_ms: 200,\n"));
+        assert!(code.contains("loop_region: Some(LoopRegion { start: 100, end: 2000 }),"));
+        assert!(code.contains(
+            "pub static FAIRY_SONG_1: [u8; 1044] = *include_bytes!(\"/out/fairy_song_1.wav\");"
+        ));
+    }
+}
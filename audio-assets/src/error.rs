@@ -0,0 +1,53 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Why a clip could not be embedded. Every variant names the file, so the
+/// build fails with a message that points at it.
+#[derive(Debug)]
+pub enum Error {
+    /// The directory or a file could not be read, or an output not written.
+    Io { path: PathBuf, error: io::Error },
+    /// A `.wav` file that does not parse, or that the players cannot decode.
+    Wav {
+        path: PathBuf,
+        error: wav_parser::Error,
+    },
+    /// A `.mp3` file without a single decodable frame.
+    Mp3 {
+        path: PathBuf,
+        error: mp3_decoder::Error,
+    },
+    /// Neither `.wav` nor `.mp3`.
+    UnknownFormat(PathBuf),
+    /// Two files map to the same clip name, or a file is named `none`.
+    Name { path: PathBuf, name: String },
+    /// Loop points outside their clip, on an MP3 clip, or for a clip that
+    /// is not there (the path is then the assets directory).
+    Loop { path: PathBuf, reason: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, error } => write!(f, "{}: {error}", path.display()),
+            Error::Wav { path, error } => write!(f, "{}: {error}", path.display()),
+            Error::Mp3 { path, error } => write!(f, "{}: {error}", path.display()),
+            Error::UnknownFormat(path) => {
+                write!(
+                    f,
+                    "{}: only .wav and .mp3 clips are supported",
+                    path.display()
+                )
+            }
+            Error::Name { path, name } => write!(
+                f,
+                "{}: the clip name `{name}` is taken (by another file or by `AudioClip::None`)",
+                path.display()
+            ),
+            Error::Loop { path, reason } => write!(f, "{}: {reason}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,314 @@
+//! Build-time pipeline for the audio clips embedded in the players' flash.
+//!
+//! A `build.rs` points [`Assets`] at a directory of `.wav` and `.mp3` files.
+//! Every file is parsed and decoded on the build machine, so a broken or
+//! unsupported clip fails the build instead of the first playback. WAV clips
+//! can be transcoded to the I2S rate and to IMA ADPCM on the way. The result
+//! is an `audios.rs` in `OUT_DIR` with the `AudioClip` enum, one `static` per
+//! clip and what the players need to know about it:
+//!
+//! ```no_run
+//! // build.rs
+//! audio_assets::Assets::new("src/audios")
+//!     .transcode(audio_assets::Transcode {
+//!         sample_rate: 11025,
+//!         mono: true,
+//!         encoding: audio_assets::Encoding::ImaAdpcm { block_align: 256 },
+//!     })
+//!     .generate()
+//!     .unwrap_or_else(|err| panic!("{err}"));
+//! ```
+//!
+//! ```ignore
+//! // src/audios.rs
+//! include!(concat!(env!("OUT_DIR"), "/audios.rs"));
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+mod codegen;
+mod error;
+mod name;
+mod transcode;
+
+pub use audio_pipeline::LoopRegion;
+pub use error::Error;
+
+/// How a clip is stored in flash.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClipFormat {
+    /// A WAV file, played through `ClipStream`.
+    Wav,
+    /// An MP3 file, played through `Mp3Stream`.
+    Mp3,
+}
+
+/// What the build script learned about a clip.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClipInfo {
+    pub format: ClipFormat,
+    pub sample_rate: u32,
+    pub channels: u16,
+    /// Frames the players get out of the clip.
+    pub frames: u32,
+    /// Frames played over and over until the player releases the clip: the
+    /// first forward loop of the `smpl` chunk, or [`Assets::loop_ms`].
+    pub loop_region: Option<LoopRegion>,
+}
+
+impl ClipInfo {
+    /// Playback time in milliseconds.
+    pub fn duration_ms(&self) -> u32 {
+        (self.frames as u64 * 1000 / self.sample_rate as u64) as u32
+    }
+}
+
+/// One embedded clip.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Clip {
+    /// The file stem in snake_case, e.g. `fairy_song_1`.
+    pub name: String,
+    /// The `AudioClip` variant, e.g. `FairySong1`.
+    pub variant: String,
+    /// The `static` holding the bytes, e.g. `FAIRY_SONG_1`.
+    pub constant: String,
+    /// The file in the assets directory.
+    pub source: PathBuf,
+    /// The file that ends up in flash: `source`, or its transcoded copy.
+    pub embedded: PathBuf,
+    /// Size of `embedded` in bytes.
+    pub len: usize,
+    pub info: ClipInfo,
+}
+
+/// Sample encoding of transcoded clips.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    /// 16-bit PCM.
+    Pcm16,
+    /// IMA ADPCM, a quarter of the size of 16-bit PCM. `block_align` is in
+    /// bytes, 256 per channel is usual.
+    ImaAdpcm { block_align: u16 },
+}
+
+/// What WAV clips are converted to before they are embedded. MP3 clips are
+/// kept as they are: they are smaller than any PCM or ADPCM copy, and the
+/// players decode them at run time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Transcode {
+    /// Usually the I2S rate, so nothing is resampled on the device.
+    pub sample_rate: u32,
+    /// Mix stereo clips down to one channel.
+    pub mono: bool,
+    pub encoding: Encoding,
+}
+
+/// The clips of one directory.
+#[derive(Clone, Debug)]
+pub struct Assets {
+    dir: PathBuf,
+    transcode: Option<Transcode>,
+    /// Loop points set in the build script: clip name, start and end in ms.
+    loops: Vec<(String, u32, u32)>,
+}
+
+impl Assets {
+    /// Clips in `dir`, relative to the crate being built. Every `.wav` and
+    /// `.mp3` file is a clip; hidden files are skipped, anything else is an
+    /// error.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            transcode: None,
+            loops: Vec::new(),
+        }
+    }
+
+    /// Loops the WAV clip `name` (see [`Clip::name`]) from `start_ms` to
+    /// `end_ms`, in place of the loop of its `smpl` chunk.
+    pub fn loop_ms(mut self, name: &str, start_ms: u32, end_ms: u32) -> Self {
+        self.loops.push((name.to_string(), start_ms, end_ms));
+        self
+    }
+
+    /// Converts every WAV clip before embedding it.
+    pub fn transcode(mut self, transcode: Transcode) -> Self {
+        self.transcode = Some(transcode);
+        self
+    }
+
+    /// Writes `audios.rs` (and transcoded clips) to the `OUT_DIR` of the
+    /// build script, and asks Cargo to run it again when a clip changes.
+    pub fn generate(&self) -> Result<Vec<Clip>, Error> {
+        let out_dir = std::env::var_os("OUT_DIR").expect("generate() runs in a build script");
+        let clips = self.write_to(Path::new(&out_dir))?;
+        println!("cargo:rerun-if-changed={}", self.dir.display());
+        for clip in &clips {
+            println!("cargo:rerun-if-changed={}", clip.source.display());
+        }
+        Ok(clips)
+    }
+
+    /// Writes `audios.rs` (and transcoded clips) to `out_dir` and returns the
+    /// clips, in file name order.
+    pub fn write_to(&self, out_dir: &Path) -> Result<Vec<Clip>, Error> {
+        let io = |path: &Path| {
+            let path = path.to_path_buf();
+            move |error| Error::Io { path, error }
+        };
+        let dir = fs::canonicalize(&self.dir).map_err(io(&self.dir))?;
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(&dir).map_err(io(&dir))? {
+            let path = entry.map_err(io(&dir))?.path();
+            let hidden = path
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+            if path.is_file() && !hidden {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut clips: Vec<Clip> = Vec::new();
+        for path in paths {
+            let clip = self.load(&path, out_dir)?;
+            if clip.name == "none" || clips.iter().any(|other| other.name == clip.name) {
+                return Err(Error::Name {
+                    path,
+                    name: clip.name,
+                });
+            }
+            clips.push(clip);
+        }
+        if let Some((name, _, _)) = self
+            .loops
+            .iter()
+            .find(|(name, _, _)| !clips.iter().any(|clip| &clip.name == name))
+        {
+            return Err(Error::Loop {
+                path: dir,
+                reason: format!("there is no clip `{name}` to loop"),
+            });
+        }
+
+        let code = codegen::render(&dir, &clips);
+        let target = out_dir.join("audios.rs");
+        fs::write(&target, code).map_err(io(&target))?;
+        Ok(clips)
+    }
+
+    fn load(&self, path: &Path, out_dir: &Path) -> Result<Clip, Error> {
+        let bytes = fs::read(path).map_err(|error| Error::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let name = name::snake_case(&stem);
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_ascii_lowercase());
+
+        let (embedded, len, info) = match extension.as_deref() {
+            Some("wav") => {
+                let wav_error = |error| Error::Wav {
+                    path: path.to_path_buf(),
+                    error,
+                };
+                let wav = wav_parser::parse(&bytes).map_err(wav_error)?;
+                transcode::check_wav(&wav).map_err(wav_error)?;
+                let (embedded, len, mut info) = match self.transcode {
+                    None => (path.to_path_buf(), bytes.len(), wav_info(&wav)),
+                    Some(target) => {
+                        let file = transcode::transcode_wav(&wav, &target).map_err(wav_error)?;
+                        let info = wav_info(&wav_parser::parse(&file).map_err(wav_error)?);
+                        let embedded = out_dir.join(format!("{name}.wav"));
+                        fs::write(&embedded, &file).map_err(|error| Error::Io {
+                            path: embedded.clone(),
+                            error,
+                        })?;
+                        (embedded, file.len(), info)
+                    }
+                };
+                // Loop points follow the clip to its new rate
+                let scale = |frame: u32| {
+                    (frame as u64 * info.sample_rate as u64 / wav.format.sample_rate as u64) as u32
+                };
+                info.loop_region = LoopRegion::from_wav(&wav).map(|region| LoopRegion {
+                    start: scale(region.start),
+                    end: scale(region.end),
+                });
+                if let Some(region) = self.annotated_loop(&name, info.sample_rate) {
+                    info.loop_region = Some(region);
+                }
+                if let Some(region) = info.loop_region {
+                    if region.start >= region.end || region.end > info.frames {
+                        return Err(Error::Loop {
+                            path: path.to_path_buf(),
+                            reason: format!(
+                                "the loop {}..{} is not within the {} frames of the clip",
+                                region.start, region.end, info.frames
+                            ),
+                        });
+                    }
+                }
+                (embedded, len, info)
+            }
+            Some("mp3") => {
+                let mp3 = transcode::measure_mp3(&bytes).map_err(|error| Error::Mp3 {
+                    path: path.to_path_buf(),
+                    error,
+                })?;
+                let info = ClipInfo {
+                    format: ClipFormat::Mp3,
+                    sample_rate: mp3.sample_rate,
+                    channels: mp3.channels,
+                    frames: mp3.frames,
+                    loop_region: None,
+                };
+                if self.annotated_loop(&name, info.sample_rate).is_some() {
+                    return Err(Error::Loop {
+                        path: path.to_path_buf(),
+                        reason: "only WAV clips loop".to_string(),
+                    });
+                }
+                (path.to_path_buf(), bytes.len(), info)
+            }
+            _ => return Err(Error::UnknownFormat(path.to_path_buf())),
+        };
+
+        Ok(Clip {
+            variant: name::variant(&name),
+            constant: name::constant(&name),
+            name,
+            source: path.to_path_buf(),
+            embedded,
+            len,
+            info,
+        })
+    }
+
+    /// The loop set for clip `name` with [`Assets::loop_ms`], in frames at
+    /// `sample_rate`.
+    fn annotated_loop(&self, name: &str, sample_rate: u32) -> Option<LoopRegion> {
+        let frame = |ms: u32| (ms as u64 * sample_rate as u64 / 1000) as u32;
+        self.loops
+            .iter()
+            .rev()
+            .find(|(clip, _, _)| clip == name)
+            .map(|&(_, start_ms, end_ms)| LoopRegion {
+                start: frame(start_ms),
+                end: frame(end_ms),
+            })
+    }
+}
+
+fn wav_info(wav: &wav_parser::Wav) -> ClipInfo {
+    ClipInfo {
+        format: ClipFormat::Wav,
+        sample_rate: wav.format.sample_rate,
+        channels: wav.format.channels,
+        frames: wav.frames(),
+        loop_region: None,
+    }
+}
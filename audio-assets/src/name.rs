@@ -0,0 +1,65 @@
+//! Rust identifiers for clip file names.
+
+/// The file stem as a snake_case name: `Fairy Song-1` becomes `fairy_song_1`.
+/// Names that would start with a digit get a `clip_` prefix.
+pub fn snake_case(stem: &str) -> String {
+    let mut name = String::new();
+    for c in stem.chars() {
+        if c.is_ascii_alphanumeric() {
+            name.push(c.to_ascii_lowercase());
+        } else if !name.is_empty() && !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+    while name.ends_with('_') {
+        name.pop();
+    }
+    if name.is_empty() {
+        name.push_str("clip");
+    } else if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name.insert_str(0, "clip_");
+    }
+    name
+}
+
+/// `fairy_song_1` as an enum variant: `FairySong1`.
+pub fn variant(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// `fairy_song_1` as a `static`: `FAIRY_SONG_1`.
+pub fn constant(name: &str) -> String {
+    name.to_ascii_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_stems_become_identifiers() {
+        assert_eq!(snake_case("fairy_caution"), "fairy_caution");
+        assert_eq!(snake_case("Fairy Song-1"), "fairy_song_1");
+        assert_eq!(
+            snake_case("__Free_Test_Data_100KB_MP3__"),
+            "free_test_data_100kb_mp3"
+        );
+        assert_eq!(snake_case("8bit"), "clip_8bit");
+        assert_eq!(snake_case("---"), "clip");
+    }
+
+    #[test]
+    fn names_become_variants_and_statics() {
+        assert_eq!(variant("fairy_song_1"), "FairySong1");
+        assert_eq!(variant("test_16bits_8000hz_mono"), "Test16bits8000hzMono");
+        assert_eq!(constant("fairy_song_1"), "FAIRY_SONG_1");
+    }
+}
@@ -0,0 +1,105 @@
+//! Decoding clips on the build machine, to measure them and to re-encode
+//! them for the target.
+
+use audio_pipeline::{ClipStream, Frame, OutputFormat, Quality};
+use mp3_decoder::{Decoder, MAX_SAMPLES_PER_FRAME};
+use wav_parser::{Format, Wav};
+
+use crate::{Encoding, Transcode};
+
+/// What a decoded MP3 clip holds.
+pub struct Mp3Summary {
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub frames: u32,
+}
+
+/// Decodes a whole MP3 clip, the only way to be sure it plays and to count
+/// its samples.
+pub fn measure_mp3(data: &[u8]) -> Result<Mp3Summary, mp3_decoder::Error> {
+    let (_, info) = mp3_decoder::find_frame(data)?;
+    let mut decoder = Box::new(Decoder::new());
+    let mut pcm = [0i16; MAX_SAMPLES_PER_FRAME];
+    let mut offset = 0;
+    let mut frames = 0;
+    while let Ok(frame) = decoder.decode_frame(&data[offset..], &mut pcm) {
+        offset += frame.consumed;
+        frames += frame.samples as u32;
+    }
+    Ok(Mp3Summary {
+        sample_rate: info.sample_rate,
+        channels: info.channels as u16,
+        frames,
+    })
+}
+
+/// Checks that the players can decode `wav`: the same stream they build.
+pub fn check_wav(wav: &Wav) -> Result<(), wav_parser::Error> {
+    ClipStream::new(
+        wav,
+        OutputFormat::STEREO_16,
+        wav.format.sample_rate,
+        Quality::Linear,
+    )
+    .map(drop)
+}
+
+/// Re-encodes a WAV clip for `target` into a new WAV file.
+pub fn transcode_wav(wav: &Wav, target: &Transcode) -> Result<Vec<u8>, wav_parser::Error> {
+    let mut stream = ClipStream::new(
+        wav,
+        OutputFormat::STEREO_16,
+        target.sample_rate,
+        Quality::Sinc,
+    )?;
+    let frames = drain(|out| stream.fill(out));
+    Ok(encode(&frames, target))
+}
+
+/// Runs a stream's `fill` to the end and reads back its 16-bit stereo frames.
+fn drain(mut fill: impl FnMut(&mut [u8]) -> usize) -> Vec<Frame> {
+    let mut bytes = Vec::new();
+    let mut buffer = [0u8; 4096];
+    loop {
+        let written = fill(&mut buffer);
+        bytes.extend_from_slice(&buffer[..written]);
+        if written < buffer.len() {
+            break;
+        }
+    }
+    bytes
+        .chunks_exact(4)
+        .map(|frame| {
+            [
+                i16::from_le_bytes([frame[0], frame[1]]),
+                i16::from_le_bytes([frame[2], frame[3]]),
+            ]
+        })
+        .collect()
+}
+
+fn encode(frames: &[Frame], target: &Transcode) -> Vec<u8> {
+    let samples: Vec<i16> = if target.mono {
+        frames
+            .iter()
+            .map(|&[left, right]| ((left as i32 + right as i32) / 2) as i16)
+            .collect()
+    } else {
+        frames.iter().flatten().copied().collect()
+    };
+    let channels = if target.mono { 1 } else { 2 };
+
+    match target.encoding {
+        Encoding::Pcm16 => {
+            let format = Format::pcm(channels, target.sample_rate, 16);
+            let mut file = wav_parser::write_header(&format, samples.len() as u32 * 2).to_vec();
+            for sample in samples {
+                file.extend_from_slice(&sample.to_le_bytes());
+            }
+            file
+        }
+        Encoding::ImaAdpcm { block_align } => {
+            ima_adpcm::encode_pcm(&samples, channels, target.sample_rate, block_align)
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//! Runs the pipeline over copies of the repository's clips and compiles the
+//! generated code the way a `no_std` firmware crate would.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use audio_assets::{Assets, ClipFormat, Encoding, Error, LoopRegion, Transcode};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../wav-parser/tests/fixtures");
+const MP3: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../wav-hex-player/src/audios/Free_Test_Data_100KB_MP3.mp3"
+);
+
+/// A fresh directory under the test's target dir.
+fn scratch(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// An assets directory with an 8-bit mono clip, a 16-bit stereo clip and an
+/// MP3, plus an output directory.
+fn assets(name: &str) -> (PathBuf, PathBuf) {
+    let dir = scratch(name);
+    let assets = dir.join("audios");
+    let out = dir.join("out");
+    fs::create_dir_all(&assets).unwrap();
+    fs::create_dir_all(&out).unwrap();
+    fs::copy(
+        Path::new(FIXTURES).join("pcm8_mono_8000.wav"),
+        assets.join("wav_data.wav"),
+    )
+    .unwrap();
+    fs::copy(
+        Path::new(FIXTURES).join("pcm16_stereo_44100_list.wav"),
+        assets.join("Fairy Song-1.WAV"),
+    )
+    .unwrap();
+    fs::copy(MP3, assets.join("song.mp3")).unwrap();
+    fs::write(assets.join(".DS_Store"), b"not a clip").unwrap();
+    (assets, out)
+}
+
+/// Compiles `audios.rs` as part of a `no_std` library.
+fn compile(out: &Path) {
+    let lib = out.join("lib.rs");
+    fs::write(
+        &lib,
+        "#![no_std]\n#![deny(warnings)]\npub mod audios { include!(\"audios.rs\"); }\n",
+    )
+    .unwrap();
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+    let output = Command::new(rustc)
+        .args([
+            "--edition",
+            "2021",
+            "--crate-type",
+            "lib",
+            "--emit",
+            "metadata",
+        ])
+        .arg("--out-dir")
+        .arg(out)
+        .arg(&lib)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn embeds_clips_as_they_are() {
+    let (assets, out) = assets("as_they_are");
+    let clips = Assets::new(&assets).write_to(&out).unwrap();
+
+    let names: Vec<_> = clips.iter().map(|clip| clip.name.as_str()).collect();
+    assert_eq!(names, ["fairy_song_1", "song", "wav_data"]);
+    assert_eq!(clips[0].variant, "FairySong1");
+    assert_eq!(
+        clips[0].embedded,
+        assets.join("Fairy Song-1.WAV").canonicalize().unwrap()
+    );
+
+    let wav = &clips[2].info;
+    assert_eq!(wav.format, ClipFormat::Wav);
+    assert_eq!(
+        (wav.sample_rate, wav.channels, wav.frames),
+        (8000, 1, 18854)
+    );
+    assert_eq!(wav.duration_ms(), 2356);
+
+    // 154 frames of 1152 samples
+    let mp3 = &clips[1].info;
+    assert_eq!(mp3.format, ClipFormat::Mp3);
+    assert_eq!((mp3.sample_rate, mp3.frames), (44100, 177408));
+
+    let code = fs::read_to_string(out.join("audios.rs")).unwrap();
+    assert!(code.contains("pub static WAV_DATA: [u8; 18898]"));
+    compile(&out);
+}
+
+#[test]
+fn transcodes_wav_clips_to_adpcm() {
+    let (assets, out) = assets("adpcm");
+    let clips = Assets::new(&assets)
+        .transcode(Transcode {
+            sample_rate: 11025,
+            mono: true,
+            encoding: Encoding::ImaAdpcm { block_align: 256 },
+        })
+        .write_to(&out)
+        .unwrap();
+
+    let wav_data = &clips[2];
+    assert_eq!(wav_data.embedded, out.join("wav_data.wav"));
+    let file = fs::read(&wav_data.embedded).unwrap();
+    assert_eq!(file.len(), wav_data.len);
+    let wav = wav_parser::parse(&file).unwrap();
+    assert_eq!(wav.format.codec.tag(), 0x11);
+    assert_eq!((wav.format.sample_rate, wav.format.channels), (11025, 1));
+    // Resampled from 8 kHz: 18854 * 11025 / 8000 frames, and the last
+    // ADPCM block padded
+    assert!((25982..25982 + 8).contains(&wav_data.info.frames));
+    assert!((2356..=2357).contains(&wav_data.info.duration_ms()));
+    // Twice the samples at 4 bits instead of 8
+    assert!(file.len() < 18898 * 3 / 4);
+
+    // MP3 clips stay as they are
+    assert_eq!(
+        clips[1].embedded,
+        assets.join("song.mp3").canonicalize().unwrap()
+    );
+    compile(&out);
+}
+
+#[test]
+fn transcodes_to_pcm() {
+    let (assets, out) = assets("pcm");
+    let clips = Assets::new(&assets)
+        .transcode(Transcode {
+            sample_rate: 44100,
+            mono: false,
+            encoding: Encoding::Pcm16,
+        })
+        .write_to(&out)
+        .unwrap();
+    let wav = wav_parser::parse(&fs::read(&clips[0].embedded).unwrap())
+        .unwrap()
+        .format;
+    assert_eq!(wav, wav_parser::Format::pcm(2, 44100, 16));
+}
+
+#[test]
+fn rejects_what_cannot_play() {
+    let (assets, out) = assets("broken");
+    fs::write(assets.join("broken.wav"), b"RIFF\0\0\0\0AVI ").unwrap();
+    let err = Assets::new(&assets).write_to(&out).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Wav {
+            error: wav_parser::Error::NotWave,
+            ..
+        }
+    ));
+    assert!(err.to_string().contains("broken.wav"));
+
+    fs::remove_file(assets.join("broken.wav")).unwrap();
+    fs::write(assets.join("notes.txt"), b"").unwrap();
+    assert!(matches!(
+        Assets::new(&assets).write_to(&out),
+        Err(Error::UnknownFormat(_))
+    ));
+
+    fs::remove_file(assets.join("notes.txt")).unwrap();
+    fs::copy(assets.join("wav_data.wav"), assets.join("song.wav")).unwrap();
+    assert!(matches!(
+        Assets::new(&assets).write_to(&out),
+        Err(Error::Name { name, .. }) if name == "song"
+    ));
+}
+
+#[test]
+fn loops_follow_the_smpl_chunk_or_the_build_script() {
+    let (assets, out) = assets("loops");
+    fs::copy(
+        Path::new(FIXTURES).join("pcm16_mono_11025_loop.wav"),
+        assets.join("hum.wav"),
+    )
+    .unwrap();
+
+    // Frames 100 to 999 of the `smpl` chunk, end included
+    let clips = Assets::new(&assets).write_to(&out).unwrap();
+    let hum = clips.iter().find(|clip| clip.name == "hum").unwrap();
+    assert_eq!(
+        hum.info.loop_region,
+        Some(LoopRegion {
+            start: 100,
+            end: 1000
+        })
+    );
+    assert!(clips
+        .iter()
+        .filter(|clip| clip.name != "hum")
+        .all(|clip| clip.info.loop_region.is_none()));
+    let code = fs::read_to_string(out.join("audios.rs")).unwrap();
+    assert!(code.contains("loop_region: Some(LoopRegion { start: 100, end: 1000 }),"));
+    compile(&out);
+
+    // Scaled to the transcoded rate; the build script has the last word
+    let clips = Assets::new(&assets)
+        .transcode(Transcode {
+            sample_rate: 22050,
+            mono: true,
+            encoding: Encoding::Pcm16,
+        })
+        .loop_ms("wav_data", 500, 1500)
+        .write_to(&out)
+        .unwrap();
+    let regions: Vec<_> = clips.iter().map(|clip| clip.info.loop_region).collect();
+    assert_eq!(
+        regions,
+        [
+            None,
+            Some(LoopRegion {
+                start: 200,
+                end: 2000
+            }),
+            None,
+            Some(LoopRegion {
+                start: 11025,
+                end: 33075
+            }),
+        ]
+    );
+    compile(&out);
+}
+
+#[test]
+fn rejects_loops_that_cannot_play() {
+    let (assets, out) = assets("bad_loops");
+    let err = Assets::new(&assets)
+        .loop_ms("wav_data", 2000, 3000)
+        .write_to(&out)
+        .unwrap_err();
+    assert!(matches!(err, Error::Loop { .. }));
+    assert!(err.to_string().contains("wav_data.wav"));
+
+    assert!(matches!(
+        Assets::new(&assets).loop_ms("song", 0, 100).write_to(&out),
+        Err(Error::Loop { .. })
+    ));
+    let err = Assets::new(&assets)
+        .loop_ms("rain", 0, 100)
+        .write_to(&out)
+        .unwrap_err();
+    assert!(err.to_string().contains("no clip `rain`"), "{err}");
+}
@@ -0,0 +1,421 @@
+use wav_parser::{Codec, Error, Format};
+
+use crate::Frame;
+
+/// Width of one sample slot in the DMA buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SampleWidth {
+    /// Signed 16-bit little-endian (`DataFormat::Data16Channel16`).
+    Bits16,
+    /// Signed 32-bit little-endian, also used for 24-bit data left-justified
+    /// in a 32-bit slot (`DataFormat::Data32Channel32` / `Data32Channel24`).
+    Bits32,
+}
+
+impl SampleWidth {
+    pub fn bytes(self) -> usize {
+        match self {
+            SampleWidth::Bits16 => 2,
+            SampleWidth::Bits32 => 4,
+        }
+    }
+}
+
+/// Frame layout the I2S peripheral was configured for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutputFormat {
+    pub width: SampleWidth,
+    pub channels: u16,
+}
+
+impl OutputFormat {
+    /// Philips I2S with `DataFormat::Data16Channel16`: interleaved L/R, 16 bits each.
+    pub const STEREO_16: Self = Self {
+        width: SampleWidth::Bits16,
+        channels: 2,
+    };
+
+    /// Philips I2S with `DataFormat::Data32Channel32`.
+    pub const STEREO_32: Self = Self {
+        width: SampleWidth::Bits32,
+        channels: 2,
+    };
+
+    /// Bytes per output frame.
+    pub fn frame_size(&self) -> usize {
+        self.width.bytes() * self.channels as usize
+    }
+
+    /// The WAV format describing this layout at `sample_rate`, e.g. to save
+    /// what would have been sent to the I2S.
+    pub fn wav_format(&self, sample_rate: u32) -> Format {
+        Format::pcm(self.channels, sample_rate, self.width.bytes() as u16 * 8)
+    }
+}
+
+/// Sample encodings a WAV `data` chunk can hold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Encoding {
+    U8,
+    I16,
+    I24,
+    I32,
+    F32,
+    F64,
+}
+
+impl Encoding {
+    fn of(format: &Format) -> Result<Self, Error> {
+        match (format.codec, format.bits_per_sample) {
+            (Codec::Pcm, 8) => Ok(Encoding::U8),
+            (Codec::Pcm, 16) => Ok(Encoding::I16),
+            (Codec::Pcm, 24) => Ok(Encoding::I24),
+            (Codec::Pcm, 32) => Ok(Encoding::I32),
+            (Codec::IeeeFloat, 32) => Ok(Encoding::F32),
+            (Codec::IeeeFloat, 64) => Ok(Encoding::F64),
+            _ => Err(Error::UnsupportedFormat(
+                "no converter for this sample encoding",
+            )),
+        }
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Encoding::U8 => 1,
+            Encoding::I16 => 2,
+            Encoding::I24 => 3,
+            Encoding::I32 | Encoding::F32 => 4,
+            Encoding::F64 => 8,
+        }
+    }
+
+    /// Reads one sample scaled to the full `i32` range.
+    fn read(self, b: &[u8]) -> i32 {
+        match self {
+            // 8-bit WAV is unsigned with silence at 0x80
+            Encoding::U8 => (b[0] as i32 - 128) << 24,
+            Encoding::I16 => (i16::from_le_bytes([b[0], b[1]]) as i32) << 16,
+            Encoding::I24 => i32::from_le_bytes([0, b[0], b[1], b[2]]),
+            Encoding::I32 => i32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+            // `as` saturates, so +1.0 and out-of-range values clip cleanly
+            Encoding::F32 => {
+                (f32::from_le_bytes([b[0], b[1], b[2], b[3]]) * 2_147_483_648.0) as i32
+            }
+            Encoding::F64 => {
+                let mut raw = [0u8; 8];
+                raw.copy_from_slice(&b[..8]);
+                (f64::from_le_bytes(raw) * 2_147_483_648.0) as i32
+            }
+        }
+    }
+}
+
+fn write_sample(width: SampleWidth, sample: i32, out: &mut [u8]) {
+    match width {
+        SampleWidth::Bits16 => out[..2].copy_from_slice(&((sample >> 16) as i16).to_le_bytes()),
+        SampleWidth::Bits32 => out[..4].copy_from_slice(&sample.to_le_bytes()),
+    }
+}
+
+/// Writes stereo frames into a DMA buffer laid out as `output`; returns the
+/// number of bytes written (whole output frames only).
+///
+/// Both channels are averaged for a mono output and repeated for any output
+/// channel past the second.
+pub fn encode(frames: &[Frame], output: OutputFormat, out: &mut [u8]) -> usize {
+    let dst_frame = output.frame_size();
+    let width = output.width;
+    let count = frames.len().min(out.len() / dst_frame);
+
+    for (frame, dst) in frames.iter().zip(out.chunks_exact_mut(dst_frame)) {
+        if output.channels == 1 {
+            let mono = (frame[0] as i32 + frame[1] as i32) / 2;
+            write_sample(width, mono << 16, dst);
+            continue;
+        }
+        for (channel, slot) in dst.chunks_exact_mut(width.bytes()).enumerate() {
+            write_sample(width, (frame[channel.min(1)] as i32) << 16, slot);
+        }
+    }
+    count * dst_frame
+}
+
+/// How much of the input and output a [`Converter`] call used.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Progress {
+    /// Source bytes read (always whole source frames).
+    pub consumed: usize,
+    /// Output written: DMA buffer bytes for [`Converter::convert`], frames
+    /// for [`Converter::decode`].
+    pub written: usize,
+}
+
+/// Turns clip samples, as described by their WAV header, into the frame
+/// layout the I2S peripheral expects.
+///
+/// Mono sources are duplicated on every output channel, multi-channel sources
+/// played on a mono output are averaged, and extra source channels beyond the
+/// output's are dropped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Converter {
+    encoding: Encoding,
+    source_channels: usize,
+    output: OutputFormat,
+}
+
+impl Converter {
+    pub fn new(source: &Format, output: OutputFormat) -> Result<Self, Error> {
+        if output.channels == 0 {
+            return Err(Error::UnsupportedFormat(
+                "output needs at least one channel",
+            ));
+        }
+        Ok(Self {
+            encoding: Encoding::of(source)?,
+            source_channels: source.channels as usize,
+            output,
+        })
+    }
+
+    pub fn output(&self) -> OutputFormat {
+        self.output
+    }
+
+    /// Bytes per source frame.
+    pub fn source_frame_size(&self) -> usize {
+        self.encoding.size() * self.source_channels
+    }
+
+    /// Source bytes that convert into at most `output_len` bytes of output.
+    pub fn input_len_for(&self, output_len: usize) -> usize {
+        output_len / self.output.frame_size() * self.source_frame_size()
+    }
+
+    /// Converts as many whole frames as fit in both buffers.
+    ///
+    /// A partial frame at the end of `input` is left unconsumed so the caller
+    /// can prepend it to the next chunk.
+    pub fn convert(&self, input: &[u8], output: &mut [u8]) -> Progress {
+        let src_frame = self.source_frame_size();
+        let dst_frame = self.output.frame_size();
+        let frames = (input.len() / src_frame).min(output.len() / dst_frame);
+        let size = self.encoding.size();
+        let width = self.output.width;
+        let dst_channels = self.output.channels as usize;
+
+        for (src, dst) in input
+            .chunks_exact(src_frame)
+            .zip(output.chunks_exact_mut(dst_frame))
+            .take(frames)
+        {
+            if dst_channels == 1 && self.source_channels > 1 {
+                let sum: i64 = src
+                    .chunks_exact(size)
+                    .map(|s| self.encoding.read(s) as i64)
+                    .sum();
+                write_sample(width, (sum / self.source_channels as i64) as i32, dst);
+                continue;
+            }
+            for (channel, out) in dst.chunks_exact_mut(width.bytes()).enumerate() {
+                let at = channel.min(self.source_channels - 1) * size;
+                write_sample(width, self.encoding.read(&src[at..at + size]), out);
+            }
+        }
+
+        Progress {
+            consumed: frames * src_frame,
+            written: frames * dst_frame,
+        }
+    }
+
+    /// Converts as many whole frames as fit into 16-bit stereo [`Frame`]s,
+    /// for stages (resampling, mixing...) that work on samples rather than on
+    /// the DMA layout.
+    pub fn decode(&self, input: &[u8], output: &mut [Frame]) -> Progress {
+        let src_frame = self.source_frame_size();
+        let frames = (input.len() / src_frame).min(output.len());
+        let size = self.encoding.size();
+        let right = self.source_channels.min(2) - 1;
+
+        for (src, dst) in input.chunks_exact(src_frame).zip(output.iter_mut()) {
+            let left = self.encoding.read(&src[..size]);
+            let right = self.encoding.read(&src[right * size..(right + 1) * size]);
+            *dst = [(left >> 16) as i16, (right >> 16) as i16];
+        }
+
+        Progress {
+            consumed: frames * src_frame,
+            written: frames,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(codec: Codec, channels: u16, bits: u16) -> Format {
+        Format {
+            codec,
+            channels,
+            sample_rate: 8000,
+            byte_rate: 8000 * (channels * bits / 8) as u32,
+            block_align: channels * bits / 8,
+            bits_per_sample: bits,
+            valid_bits: bits,
+            channel_mask: 0,
+        }
+    }
+
+    fn i16_at(bytes: &[u8], index: usize) -> i16 {
+        i16::from_le_bytes([bytes[index * 2], bytes[index * 2 + 1]])
+    }
+
+    #[test]
+    fn unsigned_8_bit_mono_to_16_bit_stereo() {
+        let conv = Converter::new(&format(Codec::Pcm, 1, 8), OutputFormat::STEREO_16).unwrap();
+        let mut out = [0xAAu8; 12];
+        let progress = conv.convert(&[0x80, 0xFF, 0x00], &mut out);
+        assert_eq!(
+            progress,
+            Progress {
+                consumed: 3,
+                written: 12
+            }
+        );
+        let samples: [i16; 6] = core::array::from_fn(|i| i16_at(&out, i));
+        assert_eq!(samples, [0, 0, 127 << 8, 127 << 8, -32768, -32768]);
+    }
+
+    #[test]
+    fn sixteen_bit_stereo_is_copied() {
+        let conv = Converter::new(&format(Codec::Pcm, 2, 16), OutputFormat::STEREO_16).unwrap();
+        let input = [0x34, 0x12, 0xCC, 0xED, 0x01, 0x00, 0xFF, 0xFF];
+        let mut out = [0u8; 8];
+        conv.convert(&input, &mut out);
+        assert_eq!(out, input);
+    }
+
+    #[test]
+    fn twenty_four_bit_keeps_the_top_bits() {
+        let conv = Converter::new(&format(Codec::Pcm, 1, 24), OutputFormat::STEREO_16).unwrap();
+        let mut out = [0u8; 4];
+        conv.convert(&[0xFF, 0x34, 0x92], &mut out);
+        assert_eq!(i16_at(&out, 0), 0x9234u16 as i16);
+
+        let conv = Converter::new(&format(Codec::Pcm, 1, 24), OutputFormat::STEREO_32).unwrap();
+        let mut out = [0u8; 8];
+        conv.convert(&[0xFF, 0x34, 0x12], &mut out);
+        assert_eq!(&out[..4], &[0x00, 0xFF, 0x34, 0x12]);
+        assert_eq!(&out[4..], &[0x00, 0xFF, 0x34, 0x12]);
+    }
+
+    #[test]
+    fn thirty_two_bit_and_float_sources() {
+        let conv = Converter::new(&format(Codec::Pcm, 1, 32), OutputFormat::STEREO_16).unwrap();
+        let mut out = [0u8; 4];
+        conv.convert(&0x4000_1234i32.to_le_bytes(), &mut out);
+        assert_eq!(i16_at(&out, 0), 0x4000);
+
+        let conv =
+            Converter::new(&format(Codec::IeeeFloat, 1, 32), OutputFormat::STEREO_16).unwrap();
+        let mut input = [0u8; 12];
+        input[..4].copy_from_slice(&0.5f32.to_le_bytes());
+        input[4..8].copy_from_slice(&(-1.0f32).to_le_bytes());
+        input[8..].copy_from_slice(&1.5f32.to_le_bytes());
+        let mut out = [0u8; 12];
+        conv.convert(&input, &mut out);
+        assert_eq!(i16_at(&out, 0), 16384);
+        assert_eq!(i16_at(&out, 2), -32768);
+        assert_eq!(i16_at(&out, 4), 32767);
+    }
+
+    #[test]
+    fn stereo_to_mono_averages() {
+        let mono = OutputFormat {
+            width: SampleWidth::Bits16,
+            channels: 1,
+        };
+        let conv = Converter::new(&format(Codec::Pcm, 2, 16), mono).unwrap();
+        let mut input = [0u8; 4];
+        input[..2].copy_from_slice(&1000i16.to_le_bytes());
+        input[2..].copy_from_slice(&(-3000i16).to_le_bytes());
+        let mut out = [0u8; 2];
+        conv.convert(&input, &mut out);
+        assert_eq!(i16_at(&out, 0), -1000);
+    }
+
+    #[test]
+    fn stops_at_whole_frames() {
+        let conv = Converter::new(&format(Codec::Pcm, 2, 16), OutputFormat::STEREO_16).unwrap();
+        let mut out = [0u8; 64];
+        // 2.5 source frames: the half frame waits for the next chunk
+        let progress = conv.convert(&[1; 10], &mut out);
+        assert_eq!(
+            progress,
+            Progress {
+                consumed: 8,
+                written: 8
+            }
+        );
+
+        // Output full after one frame
+        let progress = conv.convert(&[1; 10], &mut out[..7]);
+        assert_eq!(
+            progress,
+            Progress {
+                consumed: 4,
+                written: 4
+            }
+        );
+    }
+
+    #[test]
+    fn input_len_matches_output_room() {
+        let conv = Converter::new(&format(Codec::Pcm, 1, 8), OutputFormat::STEREO_16).unwrap();
+        assert_eq!(conv.input_len_for(4096), 1024);
+        assert_eq!(conv.input_len_for(4095), 1023);
+    }
+
+    #[test]
+    fn decodes_into_stereo_frames() {
+        let conv = Converter::new(&format(Codec::Pcm, 1, 8), OutputFormat::STEREO_16).unwrap();
+        let mut frames = [[1i16; 2]; 4];
+        let progress = conv.decode(&[0x80, 0xC0], &mut frames);
+        assert_eq!(
+            progress,
+            Progress {
+                consumed: 2,
+                written: 2
+            }
+        );
+        assert_eq!(frames[..3], [[0, 0], [16384, 16384], [1, 1]]);
+
+        let conv = Converter::new(&format(Codec::Pcm, 2, 16), OutputFormat::STEREO_16).unwrap();
+        let progress = conv.decode(&[0x01, 0x00, 0xFF, 0xFF, 0x02], &mut frames);
+        assert_eq!(
+            progress,
+            Progress {
+                consumed: 4,
+                written: 1
+            }
+        );
+        assert_eq!(frames[0], [1, -1]);
+    }
+
+    #[test]
+    fn encodes_frames_for_the_dma_buffer() {
+        let frames = [[0x1234, -2], [100, 300]];
+        let mut out = [0u8; 10];
+        assert_eq!(encode(&frames, OutputFormat::STEREO_16, &mut out), 8);
+        assert_eq!(&out[..4], &[0x34, 0x12, 0xFE, 0xFF]);
+
+        let mono = OutputFormat {
+            width: SampleWidth::Bits32,
+            channels: 1,
+        };
+        let mut out = [0u8; 8];
+        assert_eq!(encode(&frames, mono, &mut out), 8);
+        assert_eq!(&out[4..], &(200i32 << 16).to_le_bytes());
+    }
+}
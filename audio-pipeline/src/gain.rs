@@ -0,0 +1,370 @@
+//! Software volume for the DMA buffer: a master volume, soft mute, and fades
+//! on start, stop and pause, all reached through short per-frame ramps so the
+//! amplifier never sees a step.
+
+use crate::convert::{OutputFormat, SampleWidth};
+
+/// The loudest volume: full scale.
+pub const MAX_VOLUME: u8 = 100;
+
+/// How long every ramp takes, whatever its size.
+pub const RAMP_MS: u32 = 20;
+
+/// A gain of 1.0. Gains never go above it, so applying one cannot clip.
+pub(crate) const UNITY: i32 = 1 << 16;
+
+/// Most channels a frame can have, as in the DMA ring.
+const MAX_CHANNELS: usize = 8;
+
+/// The gain stage of the audio task, applied to encoded output frames.
+///
+/// It starts faded out: call [`Gain::fade_in`] when a clip starts.
+#[derive(Clone, Debug)]
+pub struct Gain {
+    volume: u8,
+    muted: bool,
+    /// Faded in: the output follows the volume.
+    open: bool,
+    level: Ramp,
+    /// The last frame written, and the frames left of its decay to silence.
+    last: [i32; MAX_CHANNELS],
+    tail: u32,
+}
+
+impl Gain {
+    /// A faded-out gain stage at `volume` (0 to [`MAX_VOLUME`]) for output at
+    /// `sample_rate`.
+    pub fn new(sample_rate: u32, volume: u8) -> Self {
+        Self {
+            volume: volume.min(MAX_VOLUME),
+            muted: false,
+            open: false,
+            level: Ramp::new(0, sample_rate),
+            last: [0; MAX_CHANNELS],
+            tail: 0,
+        }
+    }
+
+    pub fn volume(&self) -> u8 {
+        self.volume
+    }
+
+    /// Ramps to `volume`, 0 to [`MAX_VOLUME`]. The level goes with the square
+    /// of the volume, close to how loud it sounds: 50 is about -12 dB.
+    pub fn set_volume(&mut self, volume: u8) {
+        self.volume = volume.min(MAX_VOLUME);
+        self.retarget();
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted
+    }
+
+    /// Ramps to silence, or back to the volume, which is kept.
+    pub fn set_muted(&mut self, muted: bool) {
+        self.muted = muted;
+        self.retarget();
+    }
+
+    /// Ramps up from silence, at the start of a clip or on resume.
+    pub fn fade_in(&mut self) {
+        self.open = true;
+        self.tail = 0;
+        self.retarget();
+    }
+
+    /// Ramps down to silence, before a stop, pause or jump. Wait for
+    /// [`Gain::is_silent`] before cutting the audio.
+    pub fn fade_out(&mut self) {
+        self.open = false;
+        self.retarget();
+    }
+
+    /// Nothing gets through any more: the audio can be cut without a click.
+    pub fn is_silent(&self) -> bool {
+        self.level.is_settled() && self.level.level() == 0
+    }
+
+    /// Applies the gain to the whole `output` frames of `buffer`.
+    pub fn apply(&mut self, buffer: &mut [u8], output: OutputFormat) {
+        let frame_size = output.frame_size();
+        let channels = (output.channels as usize).min(MAX_CHANNELS);
+        for frame in buffer.chunks_exact_mut(frame_size) {
+            let level = self.level.next();
+            for (c, sample) in frame.chunks_exact_mut(output.width.bytes()).enumerate() {
+                let scaled = match output.width {
+                    SampleWidth::Bits16 => {
+                        let value = i16::from_le_bytes([sample[0], sample[1]]) as i32;
+                        let scaled = (value * level) >> 16;
+                        sample.copy_from_slice(&(scaled as i16).to_le_bytes());
+                        scaled << 16
+                    }
+                    SampleWidth::Bits32 => {
+                        let value =
+                            i32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]);
+                        let scaled = ((value as i64 * level as i64) >> 16) as i32;
+                        sample.copy_from_slice(&scaled.to_le_bytes());
+                        scaled
+                    }
+                };
+                if c < channels {
+                    self.last[c] = scaled;
+                }
+            }
+        }
+        self.tail = self.level.frames;
+    }
+
+    /// Once the source has ended, writes whole frames decaying from the last
+    /// one to silence, so a clip that stops on a loud sample does not pop.
+    /// Returns the bytes written: 0 once the tail is done.
+    pub fn tail(&mut self, buffer: &mut [u8], output: OutputFormat) -> usize {
+        let frame_size = output.frame_size();
+        let channels = (output.channels as usize).min(MAX_CHANNELS);
+        let mut written = 0;
+        for frame in buffer.chunks_exact_mut(frame_size) {
+            if self.tail == 0 {
+                break;
+            }
+            self.tail -= 1;
+            let level = (self.tail as i64 * UNITY as i64 / self.level.frames as i64) as i32;
+            for (c, sample) in frame.chunks_exact_mut(output.width.bytes()).enumerate() {
+                let value = ((self.last[c.min(channels - 1)] as i64 * level as i64) >> 16) as i32;
+                match output.width {
+                    SampleWidth::Bits16 => {
+                        sample.copy_from_slice(&((value >> 16) as i16).to_le_bytes())
+                    }
+                    SampleWidth::Bits32 => sample.copy_from_slice(&value.to_le_bytes()),
+                }
+            }
+            written += frame_size;
+        }
+        if self.tail == 0 {
+            self.last = [0; MAX_CHANNELS];
+        }
+        written
+    }
+
+    /// Frames of the tail still to come.
+    pub(crate) fn tail_left(&self) -> u32 {
+        self.tail
+    }
+
+    /// Starts a ramp from the current level to what the settings ask for.
+    fn retarget(&mut self) {
+        self.level.set(if self.open && !self.muted {
+            volume_level(self.volume)
+        } else {
+            0
+        });
+    }
+}
+
+/// The gain of `volume`, 0 to [`MAX_VOLUME`]: its square, where `UNITY` is 1.0.
+pub(crate) fn volume_level(volume: u8) -> i32 {
+    let volume = volume.min(MAX_VOLUME) as i32;
+    UNITY * volume * volume / (MAX_VOLUME as i32 * MAX_VOLUME as i32)
+}
+
+/// A gain moving in a straight line to its target over [`RAMP_MS`].
+#[derive(Clone, Debug)]
+pub(crate) struct Ramp {
+    level: i32,
+    /// The ramp goes from `start` to `target`, with `remaining` frames to go.
+    start: i32,
+    target: i32,
+    remaining: u32,
+    /// Frames of a whole ramp.
+    pub(crate) frames: u32,
+}
+
+impl Ramp {
+    /// A settled ramp at `level`, for output at `sample_rate`.
+    pub(crate) fn new(level: i32, sample_rate: u32) -> Self {
+        Self {
+            level,
+            start: level,
+            target: level,
+            remaining: 0,
+            frames: (sample_rate * RAMP_MS / 1000).max(1),
+        }
+    }
+
+    pub(crate) fn level(&self) -> i32 {
+        self.level
+    }
+
+    pub(crate) fn is_settled(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// Heads for `target` from the current level. A ramp already heading
+    /// there goes on as it was.
+    pub(crate) fn set(&mut self, target: i32) {
+        if target == self.target {
+            return;
+        }
+        self.start = self.level;
+        self.target = target;
+        self.remaining = self.frames;
+    }
+
+    /// The level for this frame, then one frame along, landing exactly on
+    /// the target.
+    pub(crate) fn next(&mut self) -> i32 {
+        let level = self.level;
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            let left = (self.target - self.start) as i64 * self.remaining as i64;
+            self.level = self.target - (left / self.frames as i64) as i32;
+        }
+        level
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    fn frames(samples: &[i16]) -> Vec<u8> {
+        samples
+            .iter()
+            .flat_map(|&sample| [sample, sample])
+            .flat_map(i16::to_le_bytes)
+            .collect()
+    }
+
+    fn left(buffer: &[u8]) -> Vec<i16> {
+        buffer
+            .chunks_exact(4)
+            .map(|frame| i16::from_le_bytes([frame[0], frame[1]]))
+            .collect()
+    }
+
+    /// 1 kHz output: every ramp is 20 frames.
+    fn faded_in(volume: u8) -> Gain {
+        let mut gain = Gain::new(1000, volume);
+        gain.fade_in();
+        let mut settle = frames(&[0; 20]);
+        gain.apply(&mut settle, OutputFormat::STEREO_16);
+        gain
+    }
+
+    #[test]
+    fn fade_in_ramps_up_smoothly_to_full_scale() {
+        let mut gain = Gain::new(1000, MAX_VOLUME);
+        gain.fade_in();
+        let mut buffer = frames(&[i16::MAX; 30]);
+        gain.apply(&mut buffer, OutputFormat::STEREO_16);
+        let out = left(&buffer);
+
+        assert_eq!(out[0], 0);
+        assert!(out.windows(2).all(|w| w[0] <= w[1]));
+        assert!(out.windows(2).all(|w| w[1] - w[0] <= i16::MAX / 20 + 1));
+        assert_eq!(out[20..], [i16::MAX; 10]);
+        // Both channels get the same gain
+        assert!(buffer.chunks_exact(4).all(|f| f[..2] == f[2..]));
+    }
+
+    #[test]
+    fn never_amplifies_or_clips() {
+        for volume in [0, 1, 37, 50, 99, MAX_VOLUME, 255] {
+            let mut gain = faded_in(volume);
+            let samples = [i16::MIN, i16::MAX, -1, 1, 12345];
+            let mut buffer = frames(&samples);
+            gain.apply(&mut buffer, OutputFormat::STEREO_16);
+            for (out, sample) in left(&buffer).into_iter().zip(samples) {
+                assert!(
+                    out.unsigned_abs() <= sample.unsigned_abs(),
+                    "{volume}: {out}"
+                );
+                assert!(out == 0 || out.signum() == sample.signum());
+            }
+        }
+    }
+
+    #[test]
+    fn volume_follows_a_square_law() {
+        let mut gain = faded_in(50);
+        let mut buffer = frames(&[16000]);
+        gain.apply(&mut buffer, OutputFormat::STEREO_16);
+        assert_eq!(left(&buffer), [4000]);
+
+        gain.set_volume(0);
+        let mut buffer = frames(&[16000; 25]);
+        gain.apply(&mut buffer, OutputFormat::STEREO_16);
+        let out = left(&buffer);
+        assert!(out.windows(2).all(|w| w[0] >= w[1]));
+        assert_eq!(out[20..], [0; 5]);
+    }
+
+    #[test]
+    fn mute_keeps_the_volume() {
+        let mut gain = faded_in(80);
+        gain.set_muted(true);
+        let mut buffer = frames(&[10000; 20]);
+        gain.apply(&mut buffer, OutputFormat::STEREO_16);
+        assert!(gain.is_silent());
+        assert_eq!(gain.volume(), 80);
+
+        gain.set_muted(false);
+        let mut buffer = frames(&[10000; 21]);
+        gain.apply(&mut buffer, OutputFormat::STEREO_16);
+        // 0.8² of full scale, give or take the fixed-point rounding
+        assert!((6399..=6400).contains(&left(&buffer)[20]));
+    }
+
+    #[test]
+    fn fade_out_ends_in_silence() {
+        let mut gain = faded_in(MAX_VOLUME);
+        gain.fade_out();
+        assert!(!gain.is_silent());
+        let mut buffer = frames(&[-20000; 19]);
+        gain.apply(&mut buffer, OutputFormat::STEREO_16);
+        assert!(!gain.is_silent());
+        let mut buffer = frames(&[-20000; 5]);
+        gain.apply(&mut buffer, OutputFormat::STEREO_16);
+        assert!(gain.is_silent());
+        assert_eq!(left(&buffer)[1..], [0; 4]);
+    }
+
+    #[test]
+    fn tail_decays_from_the_last_frame() {
+        let mut gain = faded_in(MAX_VOLUME);
+        let mut buffer = frames(&[8000, 30000]);
+        gain.apply(&mut buffer, OutputFormat::STEREO_16);
+
+        // Split over two buffers, and nothing more once it is done
+        let mut first = std::vec![0xAA; 10 * 4 + 3];
+        assert_eq!(gain.tail(&mut first, OutputFormat::STEREO_16), 40);
+        let mut second = std::vec![0xAA; 40 * 4];
+        assert_eq!(gain.tail(&mut second, OutputFormat::STEREO_16), 40);
+        assert_eq!(gain.tail(&mut second, OutputFormat::STEREO_16), 0);
+
+        let mut out = left(&first[..40]);
+        out.extend(left(&second[..40]));
+        assert!((28499..=28500).contains(&out[0]), "{}", out[0]);
+        assert!(out.windows(2).all(|w| w[0] > w[1]));
+        assert_eq!(out[19], 0);
+    }
+
+    #[test]
+    fn works_on_32_bit_frames() {
+        let mut gain = Gain::new(1000, 50);
+        gain.fade_in();
+        let mut buffer: Vec<u8> = [i32::MAX, i32::MIN]
+            .repeat(21)
+            .into_iter()
+            .flat_map(i32::to_le_bytes)
+            .collect();
+        gain.apply(&mut buffer, OutputFormat::STEREO_32);
+        let last = &buffer[20 * 8..];
+        let left = i32::from_le_bytes([last[0], last[1], last[2], last[3]]);
+        let right = i32::from_le_bytes([last[4], last[5], last[6], last[7]]);
+        assert_eq!(left, i32::MAX / 4);
+        assert_eq!(right, i32::MIN / 4);
+    }
+}
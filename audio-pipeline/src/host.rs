@@ -0,0 +1,221 @@
+//! Sinks that capture the output on a PC, so the playback path can be checked
+//! in `cargo test` or listened to without a board.
+
+use core::convert::Infallible;
+use std::fs::File;
+use std::io::{self, BufWriter, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::vec::Vec;
+
+use crate::convert::{OutputFormat, SampleWidth};
+use crate::ring::DmaRing;
+use crate::sink::AudioSink;
+use crate::Frame;
+
+/// Keeps every buffer written to it.
+#[derive(Clone, Debug)]
+pub struct MemorySink {
+    format: OutputFormat,
+    sample_rate: u32,
+    bytes: Vec<u8>,
+    writes: Vec<usize>,
+}
+
+impl MemorySink {
+    pub fn new(format: OutputFormat, sample_rate: u32) -> Self {
+        Self {
+            format,
+            sample_rate,
+            bytes: Vec::new(),
+            writes: Vec::new(),
+        }
+    }
+
+    /// Everything written, padding included.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Length of each buffer, in write order.
+    pub fn writes(&self) -> &[usize] {
+        &self.writes
+    }
+
+    /// The output read back as 16-bit stereo frames (top 16 bits of 32-bit
+    /// slots, mono duplicated).
+    pub fn frames(&self) -> Vec<Frame> {
+        let width = self.format.width.bytes();
+        let sample = |bytes: &[u8]| match self.format.width {
+            SampleWidth::Bits16 => i16::from_le_bytes([bytes[0], bytes[1]]),
+            SampleWidth::Bits32 => i16::from_le_bytes([bytes[2], bytes[3]]),
+        };
+        self.bytes
+            .chunks_exact(self.format.frame_size())
+            .map(|frame| {
+                let right = width * (self.format.channels.min(2) as usize - 1);
+                [sample(frame), sample(&frame[right..])]
+            })
+            .collect()
+    }
+
+    /// The output as a complete WAV file.
+    pub fn to_wav(&self) -> Vec<u8> {
+        let format = self.format.wav_format(self.sample_rate);
+        let mut wav = wav_parser::write_header(&format, self.bytes.len() as u32).to_vec();
+        wav.extend_from_slice(&self.bytes);
+        wav
+    }
+}
+
+impl AudioSink for MemorySink {
+    type Error = Infallible;
+
+    fn write(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+        self.bytes.extend_from_slice(buffer);
+        self.writes.push(buffer.len());
+        Ok(())
+    }
+}
+
+/// Streams the output into a WAV file. The header is rewritten with the
+/// final length by [`WavFileSink::finish`].
+pub struct WavFileSink {
+    file: BufWriter<File>,
+    format: OutputFormat,
+    sample_rate: u32,
+    data_len: u32,
+}
+
+impl WavFileSink {
+    pub fn create(
+        path: impl AsRef<Path>,
+        format: OutputFormat,
+        sample_rate: u32,
+    ) -> io::Result<Self> {
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(&wav_parser::write_header(
+            &format.wav_format(sample_rate),
+            0,
+        ))?;
+        Ok(Self {
+            file,
+            format,
+            sample_rate,
+            data_len: 0,
+        })
+    }
+
+    /// Patches the header and closes the file.
+    pub fn finish(mut self) -> io::Result<()> {
+        let format = self.format.wav_format(self.sample_rate);
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file
+            .write_all(&wav_parser::write_header(&format, self.data_len))?;
+        self.file.flush()
+    }
+}
+
+impl AudioSink for WavFileSink {
+    type Error = io::Error;
+
+    fn write(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+        self.file.write_all(buffer)?;
+        self.data_len += buffer.len() as u32;
+        Ok(())
+    }
+}
+
+/// The I2S of a [`MemoryRing`] ran out of data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Underrun;
+
+/// A circular DMA buffer played in memory. Time passes one push at a time:
+/// after each push the "I2S" plays `step` bytes, so a producer that pushes
+/// less than `step` per call falls behind and underruns. The underrun is
+/// reported by the next push.
+#[derive(Clone, Debug)]
+pub struct MemoryRing {
+    ring: Vec<u8>,
+    /// Length of a DMA descriptor: a push never crosses one.
+    segment: usize,
+    step: usize,
+    /// Next byte the producer writes, and how many bytes before it are
+    /// waiting to be played.
+    write: usize,
+    queued: usize,
+    /// The I2S underran since the last push.
+    late: bool,
+    played: Vec<u8>,
+}
+
+impl MemoryRing {
+    /// A ring of `len` bytes that starts out full of silence, like a DMA
+    /// buffer that was zeroed before the transfer started.
+    pub fn new(len: usize, segment: usize, step: usize) -> Self {
+        assert!(step > 0 && segment > 0 && len % segment == 0);
+        Self {
+            ring: std::vec![0; len],
+            segment,
+            step,
+            write: 0,
+            queued: len,
+            late: false,
+            played: Vec::new(),
+        }
+    }
+
+    /// Everything the I2S has played.
+    pub fn played(&self) -> &[u8] {
+        &self.played
+    }
+
+    /// Plays `len` bytes: the queued ones, then (an underrun) whatever the
+    /// ring still holds after them. Returns whether it underran.
+    fn advance(&mut self, len: usize) -> bool {
+        let size = self.ring.len();
+        let queued = len.min(self.queued);
+        let read = (self.write + size - self.queued) % size;
+        for i in 0..queued {
+            self.played.push(self.ring[(read + i) % size]);
+        }
+        self.queued -= queued;
+
+        // Old data plays from the write position on; new data goes after it
+        let stale = len - queued;
+        for _ in 0..stale {
+            self.played.push(self.ring[self.write]);
+            self.write = (self.write + 1) % size;
+        }
+        stale > 0
+    }
+}
+
+impl DmaRing for MemoryRing {
+    type Error = Underrun;
+
+    async fn push_with(
+        &mut self,
+        fill: impl FnOnce(&mut [u8]) -> usize,
+    ) -> Result<usize, Underrun> {
+        if self.late {
+            self.late = false;
+            return Err(Underrun);
+        }
+        // Waiting for the I2S to free some of the ring
+        while self.queued == self.ring.len() {
+            self.advance(self.step);
+        }
+        let free = self.ring.len() - self.queued;
+        let len = free.min(self.segment - self.write % self.segment);
+        let written = fill(&mut self.ring[self.write..self.write + len]);
+        self.write = (self.write + written) % self.ring.len();
+        self.queued += written;
+        // Time passes until the next push
+        self.late = self.advance(self.step);
+        Ok(written)
+    }
+
+    fn is_underrun(_: &Underrun) -> bool {
+        true
+    }
+}
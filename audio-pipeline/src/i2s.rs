@@ -0,0 +1,49 @@
+//! The [`AudioSink`] and [`DmaRing`] of the real hardware.
+
+use esp_hal::dma::DmaError;
+use esp_hal::i2s::master::asynch::I2sWriteDmaTransferAsync;
+use esp_hal::i2s::master::{Error, I2sTx};
+use esp_hal::Blocking;
+
+use crate::ring::DmaRing;
+use crate::sink::AudioSink;
+
+/// Sends each buffer with a blocking DMA transfer on an I2S transmitter.
+pub struct I2sSink<'a, 'd> {
+    tx: &'a mut I2sTx<'d, Blocking>,
+}
+
+impl<'a, 'd> I2sSink<'a, 'd> {
+    pub fn new(tx: &'a mut I2sTx<'d, Blocking>) -> Self {
+        Self { tx }
+    }
+}
+
+impl AudioSink for I2sSink<'_, '_> {
+    type Error = Error;
+
+    fn write(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+        // Waiting (rather than dropping the transfer) reports descriptor errors
+        self.tx.write_dma(&buffer)?.wait()?;
+        Ok(())
+    }
+}
+
+/// A circular transfer from `write_dma_circular_async`: the I2S loops over
+/// the buffer until the transfer is dropped.
+impl<BUF> DmaRing for I2sWriteDmaTransferAsync<'_, BUF> {
+    type Error = Error;
+
+    async fn push_with(&mut self, fill: impl FnOnce(&mut [u8]) -> usize) -> Result<usize, Error> {
+        // The inherent push_with waits the same way but drops the error, so a
+        // late producer would never hear about it
+        self.available().await?;
+        I2sWriteDmaTransferAsync::push_with(self, fill).await
+    }
+
+    fn is_underrun(error: &Error) -> bool {
+        // Every descriptor went back to the CPU: the I2S replayed old data.
+        // The EOF that reported it is cleared, so the next push goes on
+        matches!(error, Error::DmaError(DmaError::Late))
+    }
+}
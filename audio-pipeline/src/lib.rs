@@ -0,0 +1,68 @@
+//! Sample processing between the clip data and the I2S DMA buffer.
+//!
+//! Everything here is plain `no_std` code over byte and sample slices, so it
+//! runs the same on the ESP32-C3 and in `cargo test` on the host.
+
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod convert;
+pub mod gain;
+#[cfg(feature = "std")]
+pub mod host;
+#[cfg(feature = "esp32c3")]
+pub mod i2s;
+pub mod meter;
+pub mod mixer;
+pub mod mp3;
+pub mod player;
+pub mod playlist;
+pub mod protect;
+pub mod resample;
+pub mod ring;
+mod rng;
+#[cfg(feature = "sdmmc")]
+pub mod sd;
+pub mod sink;
+pub mod speech;
+pub mod spl;
+pub mod stream;
+pub mod synth;
+pub mod tune;
+
+pub use convert::{encode, Converter, OutputFormat, Progress, SampleWidth};
+pub use gain::{Gain, MAX_VOLUME, RAMP_MS};
+#[cfg(feature = "std")]
+pub use host::{MemoryRing, MemorySink, Underrun, WavFileSink};
+#[cfg(feature = "esp32c3")]
+pub use i2s::I2sSink;
+pub use meter::{LedBar, LevelMeter, Levels, SILENCE_DB, VU_THRESHOLDS};
+pub use mixer::{Mixer, Priority, VoiceId, DUCK_VOLUME};
+pub use mp3::Mp3Stream;
+pub use player::{Command, Event, Player, Position, State, PROGRESS_MS};
+pub use playlist::{Mode, Playlist, HISTORY, SAVED_HEADER};
+pub use protect::{
+    Biquad, FilterChain, Limiter, DC_BLOCKER_HZ, MAX_LOOKAHEAD, MAX_SECTIONS, MAX_SHELF_DB,
+};
+pub use resample::{Quality, Resampler};
+pub use ring::{DmaRing, RingStats, RingWriter};
+#[cfg(feature = "sdmmc")]
+pub use sd::{DirEvent, DirPlayer, FileStream, Skip, WavRecorder};
+pub use sink::{play, AudioSink, PcmSource, Played, RawSource};
+pub use speech::{Number, Part, Sentence, Speech, Word, CROSSFADE_MS, MAX_WORDS};
+pub use spl::{
+    adc_sample, AWeighting, SoundEvent, SoundMeter, BLOCK_MS, CLAP_DB, FAST_MS,
+    INMP441_SENSITIVITY, LOUD_DB,
+};
+pub use stream::{ClipStream, LoopRegion};
+pub use synth::{Envelope, Synth, Waveform};
+pub use tune::{Timbre, Tune};
+
+/// A note as `buzzer-music` plays it, for [`Tune`].
+pub use rtttl::Tone;
+
+/// One stereo sample pair, left then right: the working format between
+/// decoding and the final [`encode`] into the DMA buffer.
+pub type Frame = [i16; 2];
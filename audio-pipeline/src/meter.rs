@@ -0,0 +1,296 @@
+//! Output levels for a VU meter: the RMS and peak of every block sent to the
+//! I2S, smoothed like a needle, and a bar of LEDs lit from them with a peak
+//! hold, like `simple-voltmeter`'s.
+
+use crate::convert::{OutputFormat, SampleWidth};
+
+/// Level of silence, in tenths of dB below full scale: the floor of 16-bit
+/// audio.
+pub const SILENCE_DB: i16 = -960;
+
+/// Thresholds of a six-LED bar (three green, two yellow, one red), in tenths
+/// of dB below full scale.
+pub const VU_THRESHOLDS: [i16; 6] = [-400, -300, -200, -120, -60, -10];
+
+/// Smoothed levels over every channel, full scale being `i16::MAX`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Levels {
+    pub rms: u16,
+    pub peak: u16,
+}
+
+impl Levels {
+    pub const SILENT: Self = Self { rms: 0, peak: 0 };
+
+    /// In tenths of dB below full scale, [`SILENCE_DB`] for silence.
+    pub fn rms_db(&self) -> i16 {
+        to_db(self.rms)
+    }
+
+    pub fn peak_db(&self) -> i16 {
+        to_db(self.peak)
+    }
+}
+
+/// Measures blocks of output. The RMS rises over `attack_ms` and falls over
+/// `release_ms` (time constants); the peak jumps up at once and falls over
+/// `release_ms`.
+#[derive(Clone, Debug)]
+pub struct LevelMeter {
+    sample_rate: u32,
+    attack_ms: u32,
+    release_ms: u32,
+    rms: f32,
+    peak: f32,
+}
+
+impl LevelMeter {
+    pub fn new(sample_rate: u32, attack_ms: u32, release_ms: u32) -> Self {
+        Self {
+            sample_rate: sample_rate.max(1),
+            attack_ms,
+            release_ms,
+            rms: 0.0,
+            peak: 0.0,
+        }
+    }
+
+    pub fn levels(&self) -> Levels {
+        Levels {
+            rms: self.rms as u16,
+            peak: self.peak as u16,
+        }
+    }
+
+    /// Back to silence at once, when the output stops.
+    pub fn reset(&mut self) {
+        self.rms = 0.0;
+        self.peak = 0.0;
+    }
+
+    /// Measures one block of `buffer` in the `output` layout and returns the
+    /// smoothed levels after it.
+    pub fn measure(&mut self, buffer: &[u8], output: OutputFormat) -> Levels {
+        let width = output.width.bytes();
+        let mut squares = 0u64;
+        let mut peak = 0u16;
+        let mut count = 0u64;
+        for sample in buffer.chunks_exact(width) {
+            let value = match output.width {
+                SampleWidth::Bits16 => i16::from_le_bytes([sample[0], sample[1]]),
+                SampleWidth::Bits32 => i16::from_le_bytes([sample[2], sample[3]]),
+            };
+            squares += (value as i64 * value as i64) as u64;
+            peak = peak.max(value.unsigned_abs());
+            count += 1;
+        }
+        if count == 0 {
+            return self.levels();
+        }
+
+        let frames = count / output.channels.max(1) as u64;
+        let ms = frames as f32 * 1000.0 / self.sample_rate as f32;
+        let rms = libm::sqrtf((squares / count) as f32);
+        let time = if rms > self.rms {
+            self.attack_ms
+        } else {
+            self.release_ms
+        };
+        self.rms = follow(self.rms, rms, ms, time);
+        self.peak = if peak as f32 >= self.peak {
+            peak as f32
+        } else {
+            follow(self.peak, peak as f32, ms, self.release_ms)
+        };
+        self.levels()
+    }
+}
+
+/// A bar of `N` LEDs: LED `i` lights once the RMS is above `thresholds[i]`
+/// (tenths of dB below full scale, lowest first). The highest LED the peak
+/// reached stays lit for `hold_ms`, or until a higher peak.
+#[derive(Clone, Debug)]
+pub struct LedBar<const N: usize> {
+    thresholds: [i16; N],
+    hold_ms: u32,
+    /// LEDs the held peak lights, and for how much longer.
+    held: usize,
+    hold_left: u32,
+}
+
+impl<const N: usize> LedBar<N> {
+    pub fn new(thresholds: [i16; N], hold_ms: u32) -> Self {
+        Self {
+            thresholds,
+            hold_ms,
+            held: 0,
+            hold_left: 0,
+        }
+    }
+
+    /// The LEDs to light for `levels`, `elapsed_ms` after the last update.
+    pub fn update(&mut self, levels: Levels, elapsed_ms: u32) -> [bool; N] {
+        let lit = self.count_above(levels.rms_db());
+        let peak = self.count_above(levels.peak_db());
+        self.hold_left = self.hold_left.saturating_sub(elapsed_ms);
+        if peak >= self.held || self.hold_left == 0 {
+            self.held = peak;
+            self.hold_left = self.hold_ms;
+        }
+        core::array::from_fn(|i| i < lit || i + 1 == self.held)
+    }
+
+    fn count_above(&self, db: i16) -> usize {
+        self.thresholds.iter().filter(|&&t| db > t).count()
+    }
+}
+
+/// One step of a one-pole filter from `current` towards `target`, `ms` long,
+/// with a time constant of `time_ms`.
+fn follow(current: f32, target: f32, ms: f32, time_ms: u32) -> f32 {
+    if time_ms == 0 {
+        return target;
+    }
+    let keep = libm::expf(-ms / time_ms as f32);
+    target + (current - target) * keep
+}
+
+fn to_db(level: u16) -> i16 {
+    if level == 0 {
+        return SILENCE_DB;
+    }
+    let db = 200.0 * libm::log10f(level as f32 / i16::MAX as f32);
+    (libm::roundf(db) as i16).max(SILENCE_DB)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::encode;
+    use crate::Frame;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    const RATE: u32 = 10_000;
+
+    /// `ms` of a 16-bit stereo sine peaking at `peak`, as the I2S takes it.
+    fn sine(peak: f32, ms: u32, output: OutputFormat) -> Vec<u8> {
+        let frames: Vec<Frame> = (0..RATE * ms / 1000)
+            .map(|i| {
+                let s = peak * libm::sinf(2.0 * core::f32::consts::PI * i as f32 / 20.0);
+                [s as i16, s as i16]
+            })
+            .collect();
+        let mut bytes = std::vec![0; frames.len() * output.frame_size()];
+        encode(&frames, output, &mut bytes);
+        bytes
+    }
+
+    #[test]
+    fn full_scale_levels_in_db() {
+        let mut meter = LevelMeter::new(RATE, 0, 0);
+        let levels = meter.measure(
+            &sine(32767.0, 100, OutputFormat::STEREO_16),
+            OutputFormat::STEREO_16,
+        );
+        // A sine's RMS is 3 dB below its peak
+        assert!(levels.peak >= 32760, "{levels:?}");
+        assert!((levels.rms_db() + 30).abs() <= 1, "{}", levels.rms_db());
+        assert!(levels.peak_db().abs() <= 1);
+
+        let quiet = meter.measure(
+            &sine(327.67, 100, OutputFormat::STEREO_32),
+            OutputFormat::STEREO_32,
+        );
+        assert!((quiet.rms_db() + 430).abs() <= 2, "{}", quiet.rms_db());
+        assert_eq!(Levels::SILENT.rms_db(), SILENCE_DB);
+        assert_eq!(
+            meter.measure(&[0; 400], OutputFormat::STEREO_16),
+            Levels::SILENT
+        );
+    }
+
+    #[test]
+    fn rms_rises_with_the_attack_and_falls_with_the_release() {
+        let output = OutputFormat::STEREO_16;
+        let mut meter = LevelMeter::new(RATE, 10, 300);
+        let loud = sine(20000.0, 10, output);
+        let target = 20000.0 / 2f32.sqrt();
+
+        // One time constant: 63% of the way
+        let first = meter.measure(&loud, output);
+        assert!(
+            (first.rms as f32 / target - 0.632).abs() < 0.01,
+            "{first:?}"
+        );
+        assert_eq!(first.peak, 20000);
+        for _ in 0..10 {
+            meter.measure(&loud, output);
+        }
+        assert!((meter.levels().rms as f32 - target).abs() < 5.0);
+
+        // Silence for 300 ms: 37% left, of the RMS and of the peak
+        let silence = std::vec![0; 4 * 300 * RATE as usize / 1000];
+        let after = meter.measure(&silence, output);
+        assert!(
+            (after.rms as f32 / target - 0.368).abs() < 0.01,
+            "{after:?}"
+        );
+        assert!(
+            (after.peak as f32 / 20000.0 - 0.368).abs() < 0.01,
+            "{after:?}"
+        );
+
+        // Measured in small blocks, the result is the same
+        let mut split = LevelMeter::new(RATE, 10, 300);
+        for block in loud.chunks(40) {
+            split.measure(block, output);
+        }
+        assert!((split.levels().rms as i32 - first.rms as i32).abs() <= 2);
+        meter.reset();
+        assert_eq!(meter.levels(), Levels::SILENT);
+    }
+
+    #[test]
+    fn led_bar_follows_the_rms_and_holds_the_peak() {
+        let mut bar = LedBar::new(VU_THRESHOLDS, 500);
+        let at = |db: f32| (32767.0 * libm::powf(10.0, db / 20.0)) as u16;
+
+        assert_eq!(bar.update(Levels::SILENT, 0), [false; 6]);
+        // -25 dB RMS lights two green LEDs; the -5 dB peak the yellow one
+        // above -6 dB
+        let loud = Levels {
+            rms: at(-25.0),
+            peak: at(-5.0),
+        };
+        assert_eq!(
+            bar.update(loud, 50),
+            [true, true, false, false, true, false]
+        );
+        let quiet = Levels {
+            rms: at(-35.0),
+            peak: at(-30.0),
+        };
+        assert_eq!(
+            bar.update(quiet, 250),
+            [true, false, false, false, true, false]
+        );
+        // The hold runs out: the new peak is held in turn
+        assert_eq!(
+            bar.update(quiet, 250),
+            [true, false, false, false, false, false]
+        );
+        assert_eq!(
+            bar.update(Levels::SILENT, 50),
+            [true, false, false, false, false, false]
+        );
+        assert_eq!(bar.update(Levels::SILENT, 500), [false; 6]);
+        // Clipping lights the whole bar
+        let clipping = Levels {
+            rms: 32767,
+            peak: 32767,
+        };
+        assert_eq!(bar.update(clipping, 50), [true; 6]);
+    }
+}
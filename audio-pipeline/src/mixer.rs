@@ -0,0 +1,399 @@
+//! Several clips at once: the mixer pulls every voice into a scratch buffer,
+//! applies its [`Gain`], and sums them with saturation. While an alert plays
+//! the other voices are ducked, so the alert is heard over the music.
+
+use crate::convert::{OutputFormat, SampleWidth};
+use crate::gain::{volume_level, Gain, Ramp, UNITY};
+use crate::sink::PcmSource;
+
+/// Frames mixed per step. The scratch and sum buffers live on the stack.
+const BLOCK: usize = 32;
+
+/// Most channels a frame can have, as in the DMA ring.
+const MAX_CHANNELS: usize = 8;
+
+/// The volume the other voices drop to while an alert plays, unless
+/// [`Mixer::set_duck_volume`] says otherwise: about -20 dB.
+pub const DUCK_VOLUME: u8 = 30;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Priority {
+    /// Music and other background sounds, ducked under alerts.
+    Normal,
+    /// Ducks every `Normal` voice while it plays.
+    Alert,
+}
+
+/// A voice of a [`Mixer`]. The slot is reused once the voice has finished.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VoiceId(usize);
+
+struct Voice<S> {
+    source: S,
+    gain: Gain,
+    priority: Priority,
+    /// Fading out to stay silent where it is.
+    paused: bool,
+    /// Fading out to be removed.
+    stopping: bool,
+    /// The source ran out; its tail is decaying.
+    ended: bool,
+    /// Nothing more to play: waiting for [`Mixer::take_finished`].
+    done: bool,
+}
+
+impl<S: PcmSource> Voice<S> {
+    /// Silent and not going anywhere: skipped by the mix.
+    fn is_held(&self) -> bool {
+        self.done || (self.paused && self.gain.is_silent())
+    }
+
+    /// Fills `scratch` with whole frames and returns the bytes written.
+    fn fill(&mut self, scratch: &mut [u8], output: OutputFormat) -> usize {
+        let mut written = 0;
+        if !self.ended {
+            written = self.source.fill(scratch);
+            self.ended = written < scratch.len();
+            self.gain.apply(&mut scratch[..written], output);
+        }
+        if self.ended {
+            written += self.gain.tail(&mut scratch[written..], output);
+        }
+        let decayed = self.ended && self.gain.tail_left() == 0;
+        if decayed || (self.stopping && self.gain.is_silent()) {
+            self.done = true;
+        }
+        written
+    }
+}
+
+/// Sums up to `N` voices of 16-bit output frames.
+pub struct Mixer<S, const N: usize> {
+    voices: [Option<Voice<S>>; N],
+    output: OutputFormat,
+    sample_rate: u32,
+    duck_volume: u8,
+    /// Gain of the `Normal` voices: 1.0, or the duck volume under an alert.
+    duck: Ramp,
+}
+
+impl<S: PcmSource, const N: usize> Mixer<S, N> {
+    /// A mixer of `output` frames at `sample_rate`, which sets how long the
+    /// fades and the ducking take. Only 16-bit output is mixed.
+    pub fn new(output: OutputFormat, sample_rate: u32) -> Self {
+        assert!(
+            output.width == SampleWidth::Bits16 && output.channels as usize <= MAX_CHANNELS,
+            "the mixer takes 16-bit frames of up to {MAX_CHANNELS} channels"
+        );
+        Self {
+            voices: core::array::from_fn(|_| None),
+            output,
+            sample_rate,
+            duck_volume: DUCK_VOLUME,
+            duck: Ramp::new(UNITY, sample_rate),
+        }
+    }
+
+    /// The volume, 0 to [`MAX_VOLUME`](crate::MAX_VOLUME), of the `Normal`
+    /// voices while an alert plays.
+    pub fn set_duck_volume(&mut self, volume: u8) {
+        self.duck_volume = volume;
+    }
+
+    /// Starts `source` at `volume`, fading in. Gives it back if all `N`
+    /// voices are taken.
+    pub fn add(&mut self, source: S, volume: u8, priority: Priority) -> Result<VoiceId, S> {
+        let Some(slot) = self.voices.iter().position(Option::is_none) else {
+            return Err(source);
+        };
+        let mut gain = Gain::new(self.sample_rate, volume);
+        gain.fade_in();
+        self.voices[slot] = Some(Voice {
+            source,
+            gain,
+            priority,
+            paused: false,
+            stopping: false,
+            ended: false,
+            done: false,
+        });
+        Ok(VoiceId(slot))
+    }
+
+    pub fn source_mut(&mut self, id: VoiceId) -> Option<&mut S> {
+        self.voice_mut(id).map(|voice| &mut voice.source)
+    }
+
+    /// The gain of a voice, for its volume and mute.
+    pub fn gain_mut(&mut self, id: VoiceId) -> Option<&mut Gain> {
+        self.voice_mut(id).map(|voice| &mut voice.gain)
+    }
+
+    /// Fades the voice out and holds it where it is.
+    pub fn pause(&mut self, id: VoiceId) {
+        if let Some(voice) = self.voice_mut(id) {
+            voice.paused = true;
+            voice.gain.fade_out();
+        }
+    }
+
+    pub fn resume(&mut self, id: VoiceId) {
+        if let Some(voice) = self.voice_mut(id) {
+            if !voice.stopping {
+                voice.paused = false;
+                voice.gain.fade_in();
+            }
+        }
+    }
+
+    /// Fades the voice out, then finishes it.
+    pub fn stop(&mut self, id: VoiceId) {
+        if let Some(voice) = self.voice_mut(id) {
+            voice.stopping = true;
+            voice.gain.fade_out();
+        }
+    }
+
+    /// Whether the voice makes no sound: a pause or stop has faded out.
+    pub fn is_silent(&self, id: VoiceId) -> bool {
+        self.voices[id.0]
+            .as_ref()
+            .is_none_or(|voice| voice.gain.is_silent())
+    }
+
+    /// Some voice still has something to play. When none has, [`fill`]
+    /// writes nothing.
+    ///
+    /// [`fill`]: PcmSource::fill
+    pub fn is_active(&self) -> bool {
+        self.voices.iter().flatten().any(|voice| !voice.is_held())
+    }
+
+    /// Takes a voice that has played to its end or finished its stop, so
+    /// the slot can be reused.
+    pub fn take_finished(&mut self) -> Option<(VoiceId, S)> {
+        let slot = self
+            .voices
+            .iter()
+            .position(|voice| voice.as_ref().is_some_and(|voice| voice.done))?;
+        let voice = self.voices[slot].take()?;
+        Some((VoiceId(slot), voice.source))
+    }
+
+    fn voice_mut(&mut self, id: VoiceId) -> Option<&mut Voice<S>> {
+        self.voices[id.0].as_mut().filter(|voice| !voice.done)
+    }
+}
+
+impl<S: PcmSource, const N: usize> PcmSource for Mixer<S, N> {
+    /// Mixes whole frames into `out`. Less than `out.len()` means every
+    /// voice has ended or is held.
+    fn fill(&mut self, out: &mut [u8]) -> usize {
+        let frame_size = self.output.frame_size();
+        let channels = self.output.channels as usize;
+        let mut written = 0;
+
+        loop {
+            let frames = ((out.len() - written) / frame_size).min(BLOCK);
+            if frames == 0 || !self.is_active() {
+                return written;
+            }
+
+            let alert = self
+                .voices
+                .iter()
+                .flatten()
+                .any(|voice| voice.priority == Priority::Alert && !voice.is_held());
+            self.duck.set(if alert {
+                volume_level(self.duck_volume)
+            } else {
+                UNITY
+            });
+
+            // The alerts, and the voices that get ducked
+            let mut alerts = [0i32; BLOCK * MAX_CHANNELS];
+            let mut normal = [0i32; BLOCK * MAX_CHANNELS];
+            let mut scratch = [0u8; BLOCK * MAX_CHANNELS * 2];
+            let mut produced = 0;
+            for voice in self.voices.iter_mut().flatten() {
+                if voice.is_held() {
+                    continue;
+                }
+                let scratch = &mut scratch[..frames * frame_size];
+                let filled = voice.fill(scratch, self.output) / frame_size;
+                produced = produced.max(filled);
+                let sum = match voice.priority {
+                    Priority::Alert => &mut alerts,
+                    Priority::Normal => &mut normal,
+                };
+                for (sum, sample) in sum
+                    .iter_mut()
+                    .zip(scratch[..filled * frame_size].chunks_exact(2))
+                {
+                    *sum += i16::from_le_bytes([sample[0], sample[1]]) as i32;
+                }
+            }
+
+            let out = &mut out[written..written + produced * frame_size];
+            for (f, frame) in out.chunks_exact_mut(frame_size).enumerate() {
+                let duck = self.duck.next() as i64;
+                for (c, sample) in frame.chunks_exact_mut(2).enumerate() {
+                    let i = f * channels + c;
+                    let mixed = alerts[i] as i64 + ((normal[i] as i64 * duck) >> 16);
+                    let mixed = mixed.clamp(i16::MIN as i64, i16::MAX as i64) as i16;
+                    sample.copy_from_slice(&mixed.to_le_bytes());
+                }
+            }
+            written += produced * frame_size;
+            if produced < frames {
+                return written;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sink::RawSource;
+
+    extern crate std;
+    use crate::MAX_VOLUME;
+    use std::vec::Vec;
+
+    /// 1 kHz, so fades and ducking take 20 frames.
+    const RATE: u32 = 1000;
+
+    fn constant(value: i16, frames: usize) -> Vec<u8> {
+        [value, value]
+            .repeat(frames)
+            .into_iter()
+            .flat_map(i16::to_le_bytes)
+            .collect()
+    }
+
+    fn left(buffer: &[u8]) -> Vec<i16> {
+        buffer
+            .chunks_exact(4)
+            .map(|frame| i16::from_le_bytes([frame[0], frame[1]]))
+            .collect()
+    }
+
+    fn mix<S: PcmSource, const N: usize>(mixer: &mut Mixer<S, N>, frames: usize) -> Vec<i16> {
+        let mut out = std::vec![0u8; frames * 4];
+        let written = mixer.fill(&mut out);
+        left(&out[..written])
+    }
+
+    #[test]
+    fn sums_voices_after_their_fade_in() {
+        let a = constant(1000, 100);
+        let b = constant(-300, 100);
+        let mut mixer: Mixer<RawSource, 2> = Mixer::new(OutputFormat::STEREO_16, RATE);
+        mixer
+            .add(RawSource(&a), MAX_VOLUME, Priority::Normal)
+            .unwrap();
+        mixer.add(RawSource(&b), 50, Priority::Normal).unwrap();
+
+        let out = mix(&mut mixer, 30);
+        assert_eq!(out[0], 0);
+        // 1000 + 0.25 × -300
+        assert_eq!(out[20..], [925; 10]);
+    }
+
+    #[test]
+    fn saturates_instead_of_wrapping() {
+        let loud = constant(30000, 100);
+        let mut mixer: Mixer<RawSource, 3> = Mixer::new(OutputFormat::STEREO_16, RATE);
+        for _ in 0..3 {
+            mixer
+                .add(RawSource(&loud), MAX_VOLUME, Priority::Normal)
+                .unwrap();
+        }
+        let out = mix(&mut mixer, 40);
+        assert!(out.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(out[39], i16::MAX);
+    }
+
+    #[test]
+    fn alerts_duck_the_other_voices_until_they_end() {
+        let music = constant(10000, 200);
+        let alert = constant(2000, 60);
+        let mut mixer: Mixer<RawSource, 2> = Mixer::new(OutputFormat::STEREO_16, RATE);
+        mixer
+            .add(RawSource(&music), MAX_VOLUME, Priority::Normal)
+            .unwrap();
+        assert_eq!(mix(&mut mixer, 20).len(), 20);
+
+        let id = mixer
+            .add(RawSource(&alert), MAX_VOLUME, Priority::Alert)
+            .unwrap();
+        let out = mix(&mut mixer, 80);
+        // The music ramps down to 0.3² (899 once rounded) as the alert ramps up
+        assert_eq!(out[0], 10000);
+        assert!(out[..20].windows(2).all(|w| w[0] >= w[1]));
+        assert_eq!(out[20..60], [899 + 2000; 40]);
+
+        // The alert and its tail are over: the music comes back
+        assert_eq!(mixer.take_finished().map(|(done, _)| done), Some(id));
+        assert!(mixer.take_finished().is_none());
+        let out = mix(&mut mixer, 40);
+        assert!(out.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(out[39], 10000);
+    }
+
+    #[test]
+    fn ends_when_every_voice_has_ended() {
+        let short = constant(100, 10);
+        let long = constant(100, 50);
+        let mut mixer: Mixer<RawSource, 2> = Mixer::new(OutputFormat::STEREO_16, RATE);
+        let a = mixer
+            .add(RawSource(&short), MAX_VOLUME, Priority::Normal)
+            .unwrap();
+        let b = mixer
+            .add(RawSource(&long), MAX_VOLUME, Priority::Normal)
+            .unwrap();
+
+        // 50 frames, then the 20-frame tail of the longer voice
+        assert_eq!(mix(&mut mixer, 200).len(), 70);
+        assert!(!mixer.is_active());
+        let mut finished = [
+            mixer.take_finished().unwrap().0,
+            mixer.take_finished().unwrap().0,
+        ];
+        finished.sort_by_key(|id| id.0);
+        assert_eq!(finished, [a, b]);
+        assert_eq!(mix(&mut mixer, 10).len(), 0);
+    }
+
+    #[test]
+    fn pause_and_stop_fade_out_first() {
+        let music = constant(8000, 1000);
+        let mut mixer: Mixer<RawSource, 1> = Mixer::new(OutputFormat::STEREO_16, RATE);
+        let id = mixer
+            .add(RawSource(&music), MAX_VOLUME, Priority::Normal)
+            .unwrap();
+        mix(&mut mixer, 20);
+
+        // Mixed a block at a time: the fade ends in the first one
+        mixer.pause(id);
+        assert!(!mixer.is_silent(id));
+        let out = mix(&mut mixer, 100);
+        assert_eq!(out.len(), BLOCK);
+        assert!(out.windows(2).all(|w| w[0] >= w[1]));
+        assert_eq!(out[20..], [0; BLOCK - 20]);
+        assert!(mixer.is_silent(id));
+        assert!(!mixer.is_active());
+
+        mixer.resume(id);
+        assert_eq!(mix(&mut mixer, 30)[29], 8000);
+        mixer.stop(id);
+        assert_eq!(mix(&mut mixer, 100).len(), BLOCK);
+        assert_eq!(mixer.take_finished().map(|(done, _)| done), Some(id));
+        let again = mixer.add(RawSource(&music), MAX_VOLUME, Priority::Normal);
+        assert_eq!(again.ok(), Some(id));
+        assert!(mixer
+            .add(RawSource(&music), MAX_VOLUME, Priority::Normal)
+            .is_err());
+    }
+}
@@ -0,0 +1,260 @@
+use mp3_decoder::{find_frame, Decoder, Error, MAX_SAMPLES_PER_FRAME};
+
+use crate::convert::{encode, OutputFormat};
+use crate::resample::{Quality, Resampler};
+use crate::Frame;
+
+/// Frames resampled per step, as in [`ClipStream`](crate::ClipStream).
+const BLOCK: usize = 64;
+
+/// Plays an MP3 clip into DMA-sized buffers at a fixed output rate: decode a
+/// frame at a time, resample, then encode to the I2S layout.
+///
+/// The [`Decoder`] is borrowed because it is big (about 22 KiB): keep it in
+/// a `static` rather than on a task's stack.
+pub struct Mp3Stream<'a> {
+    data: &'a [u8],
+    /// Start of the next undecoded MP3 frame in `data`.
+    offset: usize,
+    decoder: &'a mut Decoder,
+    output: OutputFormat,
+    resampler: Resampler,
+    /// Interleaved samples of the last decoded frame.
+    pcm: [i16; MAX_SAMPLES_PER_FRAME],
+    channels: usize,
+    /// Frames of `pcm` the resampler has not taken yet.
+    pending_start: usize,
+    pending_end: usize,
+    sample_rate: u32,
+    /// Frames decoded since the start of the clip.
+    position: u64,
+}
+
+impl<'a> Mp3Stream<'a> {
+    /// Starts a clip. The sample rate is read from its first frame.
+    pub fn new(
+        data: &'a [u8],
+        decoder: &'a mut Decoder,
+        output: OutputFormat,
+        output_rate: u32,
+        quality: Quality,
+    ) -> Result<Self, Error> {
+        let (_, info) = find_frame(data)?;
+        decoder.reset();
+        Ok(Self {
+            data,
+            offset: 0,
+            decoder,
+            output,
+            resampler: Resampler::new(info.sample_rate, output_rate, quality),
+            pcm: [0; MAX_SAMPLES_PER_FRAME],
+            channels: info.channels as usize,
+            pending_start: 0,
+            pending_end: 0,
+            sample_rate: info.sample_rate,
+            position: 0,
+        })
+    }
+
+    /// Fills `out` with whole output frames and returns the bytes written.
+    /// Less than `out.len()` means the clip has ended; 0 means nothing is left.
+    pub fn fill(&mut self, out: &mut [u8]) -> usize {
+        let frame_size = self.output.frame_size();
+        let mut written = 0;
+        let mut block = [[0i16; 2]; BLOCK];
+
+        loop {
+            let room = ((out.len() - written) / frame_size).min(BLOCK);
+            if room == 0 {
+                return written;
+            }
+
+            if self.pending_start == self.pending_end {
+                self.decode_next();
+            }
+
+            let produced = if self.pending_start < self.pending_end {
+                let mut pending = [[0i16; 2]; BLOCK];
+                let count = (self.pending_end - self.pending_start).min(BLOCK);
+                for (i, frame) in pending[..count].iter_mut().enumerate() {
+                    *frame = self.frame(self.pending_start + i);
+                }
+                let (consumed, produced) = self
+                    .resampler
+                    .process(&pending[..count], &mut block[..room]);
+                self.pending_start += consumed;
+                produced
+            } else {
+                match self.resampler.flush(&mut block[..room]) {
+                    0 => return written,
+                    produced => produced,
+                }
+            };
+            written += encode(&block[..produced], self.output, &mut out[written..]);
+        }
+    }
+
+    /// Milliseconds into the clip of the next frame to play. The length of
+    /// an MP3 clip is only known once it is decoded: the build script's
+    /// `ClipInfo` has it.
+    pub fn position_ms(&self) -> u32 {
+        let pending = (self.pending_end - self.pending_start) as u64;
+        let frame = self.position.saturating_sub(pending);
+        (frame * 1000 / self.sample_rate as u64) as u32
+    }
+
+    /// Starts the clip again from the first frame.
+    pub fn rewind(&mut self) {
+        self.offset = 0;
+        self.position = 0;
+        self.pending_start = 0;
+        self.pending_end = 0;
+        self.decoder.reset();
+        self.resampler.reset();
+    }
+
+    /// Ends the stream and gives the decoder back for the next clip.
+    pub fn into_decoder(self) -> &'a mut Decoder {
+        self.decoder
+    }
+
+    /// Jumps `ms` milliseconds into the clip, to the MP3 frame holding that
+    /// point, or to its end. The frames after it may need bits of the skipped
+    /// ones and decode to nothing until the bit reservoir fills again.
+    pub fn seek(&mut self, ms: u32) {
+        self.rewind();
+        let Ok((mut offset, mut info)) = find_frame(self.data) else {
+            return;
+        };
+        let target = ms as u64 * info.sample_rate as u64 / 1000;
+        let mut position = 0;
+        while position + info.samples as u64 <= target {
+            // The next header after this one; checked against the one after
+            match find_frame(&self.data[offset + 1..]) {
+                Ok((skip, next)) => {
+                    offset += 1 + skip;
+                    position += info.samples as u64;
+                    info = next;
+                }
+                Err(_) => {
+                    offset = self.data.len();
+                    break;
+                }
+            }
+        }
+        self.offset = offset;
+        self.position = position;
+    }
+
+    /// Decodes MP3 frames until one yields samples or the clip ends. Frames
+    /// waiting for the bit reservoir yield none, a damaged tail ends the clip.
+    fn decode_next(&mut self) {
+        self.pending_start = 0;
+        self.pending_end = 0;
+        while let Ok(frame) = self
+            .decoder
+            .decode_frame(&self.data[self.offset..], &mut self.pcm)
+        {
+            self.offset += frame.consumed;
+            if frame.samples > 0 {
+                self.channels = frame.info.channels as usize;
+                self.pending_end = frame.samples;
+                self.position += frame.samples as u64;
+                return;
+            }
+        }
+        self.offset = self.data.len();
+    }
+
+    /// Frame `index` of `pcm`, mono duplicated on both channels.
+    fn frame(&self, index: usize) -> Frame {
+        match self.channels {
+            1 => [self.pcm[index]; 2],
+            _ => [self.pcm[2 * index], self.pcm[2 * index + 1]],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    const MP3: &[u8] =
+        include_bytes!("../../wav-hex-player/src/audios/Free_Test_Data_100KB_MP3.mp3");
+
+    fn play(stream: &mut Mp3Stream, chunk: usize) -> Vec<u8> {
+        let mut all = Vec::new();
+        let mut buf = std::vec![0u8; chunk];
+        loop {
+            let n = stream.fill(&mut buf);
+            all.extend_from_slice(&buf[..n]);
+            if n < chunk {
+                return all;
+            }
+        }
+    }
+
+    #[test]
+    fn decodes_and_resamples_the_clip() {
+        let mut decoder = Decoder::new();
+        let mut stream = Mp3Stream::new(
+            MP3,
+            &mut decoder,
+            OutputFormat::STEREO_16,
+            11025,
+            Quality::Linear,
+        )
+        .unwrap();
+        // 154 frames of 1152 samples at 44.1 kHz, a quarter of them at 11025 Hz
+        let bytes = play(&mut stream, 4092);
+        let frames = bytes.len() / 4;
+        assert!((44352..=44353).contains(&frames), "{frames} frames");
+        assert_eq!(stream.position_ms(), 177408 * 1000 / 44100);
+        assert!(bytes.iter().any(|&byte| byte != 0));
+
+        stream.rewind();
+        assert_eq!(play(&mut stream, 1000), bytes);
+    }
+
+    #[test]
+    fn rejects_data_without_frames() {
+        let mut decoder = Decoder::new();
+        let result = Mp3Stream::new(
+            &[0; 1000],
+            &mut decoder,
+            OutputFormat::STEREO_16,
+            11025,
+            Quality::Linear,
+        );
+        assert!(matches!(result, Err(Error::NoFrame)));
+    }
+
+    #[test]
+    fn seek_starts_at_an_mp3_frame() {
+        let mut decoder = Decoder::new();
+        let mut stream = Mp3Stream::new(
+            MP3,
+            &mut decoder,
+            OutputFormat::STEREO_16,
+            11025,
+            Quality::Linear,
+        )
+        .unwrap();
+        let all = play(&mut stream, 4092).len() / 4;
+
+        // 1 s is in frame 38 (of 1152 samples at 44.1 kHz, 288 output frames
+        // each); the first one after the jump lacks its reservoir bits
+        stream.seek(1000);
+        assert_eq!(stream.position_ms(), 992);
+        let skipped = all - play(&mut stream, 4092).len() / 4;
+        assert!(
+            [38 * 288, 39 * 288].contains(&skipped),
+            "{skipped} frames skipped"
+        );
+        stream.seek(60_000);
+        assert_eq!(stream.fill(&mut [0u8; 64]), 0);
+    }
+}
@@ -0,0 +1,530 @@
+//! The playback state machine of the audio tasks: other tasks send
+//! [`Command`]s, the player moves between [`State`]s and reports every change
+//! as an [`Event`]. It knows nothing of the audio itself, so the task opens,
+//! drops and seeks its sources as the events say.
+
+use crate::gain::MAX_VOLUME;
+
+/// Default interval of the [`Event::Progress`] reports, in milliseconds.
+pub const PROGRESS_MS: u32 = 1000;
+
+/// What other tasks ask of the player. `C` identifies a clip, like the
+/// generated `AudioClip` enum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command<C> {
+    /// Play a clip now, replacing the current one. The queue is kept.
+    Play(C),
+    /// Stop and clear the queue.
+    Stop,
+    Pause,
+    Resume,
+    /// Jump to a position of the current clip, in milliseconds.
+    Seek(u32),
+    /// Set the master volume, 0 to [`MAX_VOLUME`].
+    SetVolume(u8),
+    /// Silence the output, or bring it back, keeping the volume.
+    SetMuted(bool),
+    /// Play a clip after the current one and the queued ones, or now if
+    /// nothing plays.
+    Enqueue(C),
+    /// Play a clip over the current one, which is ducked meanwhile. Alerts
+    /// do not change the state.
+    Alert(C),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum State<C> {
+    #[default]
+    Idle,
+    Playing(C),
+    Paused(C),
+}
+
+impl<C: Copy> State<C> {
+    /// The clip being played or paused.
+    pub fn clip(&self) -> Option<C> {
+        match *self {
+            State::Idle => None,
+            State::Playing(clip) | State::Paused(clip) => Some(clip),
+        }
+    }
+}
+
+/// How far into its clip the player is, in milliseconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Position {
+    pub ms: u32,
+    /// The length of the clip, 0 when the source does not know it.
+    pub duration_ms: u32,
+}
+
+/// A change of the player, published for the other tasks and acted on by the
+/// audio task.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event<C> {
+    /// A clip starts from the beginning: open a new source.
+    Started(C),
+    Paused(C),
+    Resumed(C),
+    /// The current clip jumped to a position, in milliseconds.
+    Seeked(C, u32),
+    /// Where the playing clip is, every [`Player::set_progress_interval`]
+    /// milliseconds of it, and at its end just before [`Event::Finished`].
+    Progress(C, Position),
+    /// A clip played to its end.
+    Finished(C),
+    /// Playback stopped before the end: drop the source.
+    Stopped(C),
+    VolumeChanged(u8),
+    MuteChanged(bool),
+    Enqueued(C),
+    /// The queue was full: the clip was dropped.
+    QueueFull(C),
+    /// An alert starts over the current clip.
+    Alert(C),
+    /// An alert has played, or could not be played. Published by the audio
+    /// task, which is the one that knows.
+    AlertFinished(C),
+}
+
+/// The state machine, with a queue of up to `N` clips to play next.
+#[derive(Clone, Debug)]
+pub struct Player<C, const N: usize> {
+    state: State<C>,
+    volume: u8,
+    muted: bool,
+    queue: [Option<C>; N],
+    /// Index of the next queued clip, and how many there are.
+    head: usize,
+    len: usize,
+    /// Where the current clip is, as the audio task last said.
+    position: Option<Position>,
+    progress_interval: u32,
+    /// The interval of the last [`Event::Progress`], counted from the start.
+    reported: Option<u32>,
+}
+
+impl<C: Copy, const N: usize> Default for Player<C, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Copy, const N: usize> Player<C, N> {
+    /// An idle player at full volume.
+    pub const fn new() -> Self {
+        Self {
+            state: State::Idle,
+            volume: MAX_VOLUME,
+            muted: false,
+            queue: [None; N],
+            head: 0,
+            len: 0,
+            position: None,
+            progress_interval: PROGRESS_MS,
+            reported: None,
+        }
+    }
+
+    pub fn state(&self) -> State<C> {
+        self.state
+    }
+
+    pub fn volume(&self) -> u8 {
+        self.volume
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted
+    }
+
+    /// Where the current clip is, once the audio task has reported it.
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    /// Reports progress every `ms` milliseconds of the clip; 0 turns the
+    /// reports off.
+    pub fn set_progress_interval(&mut self, ms: u32) {
+        self.progress_interval = ms;
+    }
+
+    /// Clips waiting to be played, next first.
+    pub fn queue(&self) -> impl Iterator<Item = C> + '_ {
+        (0..self.len).filter_map(|i| self.queue[(self.head + i) % N])
+    }
+
+    /// Applies `command` and passes the events it causes to `emit`. Commands
+    /// that change nothing (pausing while idle, say) emit nothing.
+    pub fn handle(&mut self, command: Command<C>, emit: &mut impl FnMut(Event<C>)) {
+        match (command, self.state) {
+            (Command::Play(clip), _) => self.start(clip, emit),
+            (Command::Stop, state) => {
+                self.head = 0;
+                self.len = 0;
+                if let Some(clip) = state.clip() {
+                    self.state = State::Idle;
+                    self.position = None;
+                    emit(Event::Stopped(clip));
+                }
+            }
+            (Command::Pause, State::Playing(clip)) => {
+                self.state = State::Paused(clip);
+                emit(Event::Paused(clip));
+            }
+            (Command::Resume, State::Paused(clip)) => {
+                self.state = State::Playing(clip);
+                emit(Event::Resumed(clip));
+            }
+            (Command::Pause | Command::Resume, _) => {}
+            (Command::Seek(ms), state) => {
+                if let Some(clip) = state.clip() {
+                    // Seeks past the end land there
+                    if let Some(position) = &mut self.position {
+                        position.ms = match position.duration_ms {
+                            0 => ms,
+                            duration_ms => ms.min(duration_ms),
+                        };
+                    }
+                    self.reported = None;
+                    emit(Event::Seeked(clip, ms));
+                }
+            }
+            (Command::SetVolume(volume), _) => {
+                let volume = volume.min(MAX_VOLUME);
+                if volume != self.volume {
+                    self.volume = volume;
+                    emit(Event::VolumeChanged(volume));
+                }
+            }
+            (Command::SetMuted(muted), _) => {
+                if muted != self.muted {
+                    self.muted = muted;
+                    emit(Event::MuteChanged(muted));
+                }
+            }
+            (Command::Alert(clip), _) => emit(Event::Alert(clip)),
+            (Command::Enqueue(clip), State::Idle) => self.start(clip, emit),
+            (Command::Enqueue(clip), _) => {
+                if self.len == N {
+                    emit(Event::QueueFull(clip));
+                } else {
+                    self.queue[(self.head + self.len) % N] = Some(clip);
+                    self.len += 1;
+                    emit(Event::Enqueued(clip));
+                }
+            }
+        }
+    }
+
+    /// The audio task says where the playing clip is: reports it when it
+    /// has moved into another interval since the last report (or back, after
+    /// a seek or a loop).
+    pub fn progress(&mut self, position: Position, emit: &mut impl FnMut(Event<C>)) {
+        let State::Playing(clip) = self.state else {
+            return;
+        };
+        self.position = Some(position);
+        if self.progress_interval == 0 {
+            return;
+        }
+        let interval = position.ms / self.progress_interval;
+        if self.reported != Some(interval) {
+            self.reported = Some(interval);
+            emit(Event::Progress(clip, position));
+        }
+    }
+
+    /// The audio task ran out of samples for the current clip: starts the
+    /// next queued one, if any.
+    pub fn finished(&mut self, emit: &mut impl FnMut(Event<C>)) {
+        let Some(clip) = self.state.clip() else {
+            return;
+        };
+        if let (Some(position), true) = (self.position, self.progress_interval > 0) {
+            let end = position.duration_ms.max(position.ms);
+            emit(Event::Progress(
+                clip,
+                Position {
+                    ms: end,
+                    duration_ms: position.duration_ms,
+                },
+            ));
+        }
+        self.position = None;
+        emit(Event::Finished(clip));
+        match self.dequeue() {
+            Some(next) => self.start(next, emit),
+            None => self.state = State::Idle,
+        }
+    }
+
+    fn start(&mut self, clip: C, emit: &mut impl FnMut(Event<C>)) {
+        self.state = State::Playing(clip);
+        self.position = None;
+        self.reported = None;
+        emit(Event::Started(clip));
+    }
+
+    fn dequeue(&mut self) -> Option<C> {
+        if self.len == 0 {
+            return None;
+        }
+        let clip = self.queue[self.head].take();
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        clip
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Clip {
+        Song,
+        Caution,
+        Chime,
+    }
+
+    /// Applies `commands` in order and returns all the events.
+    fn run(player: &mut Player<Clip, 2>, commands: &[Command<Clip>]) -> Vec<Event<Clip>> {
+        let mut events = Vec::new();
+        for &command in commands {
+            player.handle(command, &mut |event| events.push(event));
+        }
+        events
+    }
+
+    fn finish(player: &mut Player<Clip, 2>) -> Vec<Event<Clip>> {
+        let mut events = Vec::new();
+        player.finished(&mut |event| events.push(event));
+        events
+    }
+
+    #[test]
+    fn plays_pauses_and_resumes() {
+        let mut player = Player::new();
+        assert_eq!(
+            run(
+                &mut player,
+                &[Command::Play(Clip::Song), Command::Pause, Command::Pause]
+            ),
+            [Event::Started(Clip::Song), Event::Paused(Clip::Song)]
+        );
+        assert_eq!(player.state(), State::Paused(Clip::Song));
+
+        assert_eq!(
+            run(&mut player, &[Command::Seek(1500), Command::Resume]),
+            [Event::Seeked(Clip::Song, 1500), Event::Resumed(Clip::Song)]
+        );
+        assert_eq!(player.state(), State::Playing(Clip::Song));
+        assert_eq!(finish(&mut player), [Event::Finished(Clip::Song)]);
+        assert_eq!(player.state(), State::Idle);
+    }
+
+    #[test]
+    fn idle_player_ignores_transport_commands() {
+        let mut player = Player::new();
+        let events = run(
+            &mut player,
+            &[
+                Command::Pause,
+                Command::Resume,
+                Command::Seek(10),
+                Command::Stop,
+            ],
+        );
+        assert_eq!(events, []);
+        assert_eq!(finish(&mut player), []);
+        assert_eq!(player.state(), State::Idle);
+    }
+
+    #[test]
+    fn play_replaces_the_current_clip_and_keeps_the_queue() {
+        let mut player = Player::new();
+        let events = run(
+            &mut player,
+            &[
+                Command::Play(Clip::Song),
+                Command::Enqueue(Clip::Chime),
+                Command::Play(Clip::Caution),
+            ],
+        );
+        assert_eq!(
+            events,
+            [
+                Event::Started(Clip::Song),
+                Event::Enqueued(Clip::Chime),
+                Event::Started(Clip::Caution)
+            ]
+        );
+        assert_eq!(
+            finish(&mut player),
+            [Event::Finished(Clip::Caution), Event::Started(Clip::Chime)]
+        );
+    }
+
+    #[test]
+    fn queue_plays_in_order_and_drops_what_does_not_fit() {
+        let mut player = Player::new();
+        let events = run(
+            &mut player,
+            &[
+                Command::Enqueue(Clip::Song),
+                Command::Enqueue(Clip::Chime),
+                Command::Enqueue(Clip::Caution),
+                Command::Enqueue(Clip::Song),
+            ],
+        );
+        // Idle: the first one plays right away
+        assert_eq!(
+            events,
+            [
+                Event::Started(Clip::Song),
+                Event::Enqueued(Clip::Chime),
+                Event::Enqueued(Clip::Caution),
+                Event::QueueFull(Clip::Song)
+            ]
+        );
+        assert_eq!(
+            player.queue().collect::<Vec<_>>(),
+            [Clip::Chime, Clip::Caution]
+        );
+
+        let mut played = Vec::new();
+        while let State::Playing(clip) = player.state() {
+            played.push(clip);
+            finish(&mut player);
+        }
+        assert_eq!(played, [Clip::Song, Clip::Chime, Clip::Caution]);
+    }
+
+    #[test]
+    fn alerts_leave_the_state_alone() {
+        let mut player = Player::new();
+        let events = run(
+            &mut player,
+            &[
+                Command::Alert(Clip::Caution),
+                Command::Play(Clip::Song),
+                Command::Alert(Clip::Caution),
+            ],
+        );
+        assert_eq!(
+            events,
+            [
+                Event::Alert(Clip::Caution),
+                Event::Started(Clip::Song),
+                Event::Alert(Clip::Caution)
+            ]
+        );
+        assert_eq!(player.state(), State::Playing(Clip::Song));
+    }
+
+    #[test]
+    fn stop_clears_the_queue() {
+        let mut player = Player::new();
+        let events = run(
+            &mut player,
+            &[
+                Command::Play(Clip::Song),
+                Command::Enqueue(Clip::Chime),
+                Command::Pause,
+                Command::Stop,
+            ],
+        );
+        assert_eq!(events.last(), Some(&Event::Stopped(Clip::Song)));
+        assert_eq!(player.state(), State::Idle);
+        assert_eq!(player.queue().count(), 0);
+        assert_eq!(finish(&mut player), []);
+    }
+
+    #[test]
+    fn volume_and_mute_only_report_changes() {
+        let mut player = Player::new();
+        assert_eq!(player.volume(), MAX_VOLUME);
+        let events = run(
+            &mut player,
+            &[
+                Command::SetVolume(40),
+                Command::SetVolume(40),
+                Command::SetVolume(250),
+                Command::SetMuted(true),
+                Command::SetMuted(true),
+            ],
+        );
+        assert_eq!(
+            events,
+            [
+                Event::VolumeChanged(40),
+                Event::VolumeChanged(MAX_VOLUME),
+                Event::MuteChanged(true)
+            ]
+        );
+        assert!(player.is_muted());
+        assert_eq!(player.state(), State::Idle);
+    }
+
+    fn report(player: &mut Player<Clip, 2>, ms: u32) -> Vec<Event<Clip>> {
+        let mut events = Vec::new();
+        let position = Position {
+            ms,
+            duration_ms: 5000,
+        };
+        player.progress(position, &mut |event| events.push(event));
+        events
+    }
+
+    #[test]
+    fn reports_progress_once_per_interval() {
+        let mut player = Player::new();
+        assert_eq!(report(&mut player, 0), []);
+        run(&mut player, &[Command::Play(Clip::Song)]);
+        assert_eq!(player.position(), None);
+
+        let at = |ms| {
+            Event::Progress(
+                Clip::Song,
+                Position {
+                    ms,
+                    duration_ms: 5000,
+                },
+            )
+        };
+        let reports: Vec<_> = (0..25).flat_map(|i| report(&mut player, i * 100)).collect();
+        assert_eq!(reports, [at(0), at(1000), at(2000)]);
+        assert_eq!(player.position().unwrap().ms, 2400);
+
+        // A seek is reported at once, back or forth
+        run(&mut player, &[Command::Seek(600)]);
+        assert_eq!(player.position().unwrap().ms, 600);
+        assert_eq!(report(&mut player, 650), [at(650)]);
+        assert_eq!(report(&mut player, 700), []);
+
+        // Nothing while paused; the end before `Finished`
+        run(&mut player, &[Command::Pause]);
+        assert_eq!(report(&mut player, 3000), []);
+        run(&mut player, &[Command::Resume]);
+        assert_eq!(finish(&mut player), [at(5000), Event::Finished(Clip::Song)]);
+        assert_eq!(player.position(), None);
+    }
+
+    #[test]
+    fn progress_interval_is_configurable() {
+        let mut player = Player::new();
+        player.set_progress_interval(250);
+        run(&mut player, &[Command::Play(Clip::Song)]);
+        let reports = (0..10).flat_map(|i| report(&mut player, i * 100)).count();
+        assert_eq!(reports, 4);
+
+        player.set_progress_interval(0);
+        assert_eq!(report(&mut player, 4000), []);
+        assert_eq!(player.position().unwrap().ms, 4000);
+        assert_eq!(finish(&mut player), [Event::Finished(Clip::Song)]);
+    }
+}
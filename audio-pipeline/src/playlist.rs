@@ -0,0 +1,431 @@
+//! The order songs play in. A [`Playlist`] deals in track indices
+//! `0..len`, so the caller maps them to its own clips, and takes its
+//! randomness as a seed: it runs and is tested on the host like the
+//! [`Player`](crate::Player).
+
+use crate::rng::XorShift;
+
+/// Tracks remembered for [`Playlist::previous`].
+pub const HISTORY: usize = 8;
+
+/// Bytes [`Playlist::save`] writes besides the order: the mode, the track
+/// count, the position and a checksum.
+pub const SAVED_HEADER: usize = 4;
+
+/// Added to the checksum, so zeroed memory does not pass for a playlist.
+const CHECKSUM_SEED: u8 = 0x5a;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Every track once, in order, then stop.
+    Ordered,
+    /// The current track over and over. Skipping still moves on.
+    RepeatOne,
+    /// Every track in order, then again from the first.
+    RepeatAll,
+    /// Every track once in a random order, then again in a new one. A track
+    /// never plays twice in a row, not even across two rounds.
+    #[default]
+    Shuffle,
+}
+
+impl Mode {
+    fn from_u8(value: u8) -> Option<Self> {
+        Some(match value {
+            0 => Mode::Ordered,
+            1 => Mode::RepeatOne,
+            2 => Mode::RepeatAll,
+            3 => Mode::Shuffle,
+            _ => return None,
+        })
+    }
+}
+
+/// Up to `N` tracks (255 at most) in the order the [`Mode`] gives, with the
+/// tracks played before the current one.
+#[derive(Clone, Debug)]
+pub struct Playlist<const N: usize> {
+    mode: Mode,
+    len: usize,
+    /// The tracks of the current round. Shuffled in [`Mode::Shuffle`], in
+    /// order otherwise.
+    order: [u8; N],
+    /// Index in `order` of the newest track reached; `len` once an
+    /// [`Mode::Ordered`] list has ended.
+    position: usize,
+    current: Option<u8>,
+    /// Tracks played before the current one, oldest first.
+    history: Stack,
+    /// Tracks [`Playlist::previous`] stepped back from, next on top.
+    ahead: Stack,
+    rng: XorShift,
+}
+
+impl<const N: usize> Playlist<N> {
+    /// A playlist of `len` tracks, at its first one. `seed` drives the
+    /// shuffles: take it from a hardware RNG.
+    pub fn new(len: usize, mode: Mode, seed: u32) -> Self {
+        assert!(len <= N && N <= 255, "too many tracks");
+        let mut playlist = Self {
+            mode,
+            len,
+            order: [0; N],
+            position: 0,
+            current: None,
+            history: Stack::new(),
+            ahead: Stack::new(),
+            rng: XorShift::new(seed),
+        };
+        playlist.reorder();
+        if mode == Mode::Shuffle {
+            playlist.shuffle(None);
+        }
+        playlist.current = playlist.order[..len].first().copied();
+        playlist
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Switches modes from the current track. Shuffling starts a new round
+    /// with it; leaving shuffle goes on in order from it. An ordered list
+    /// that has ended starts over.
+    pub fn set_mode(&mut self, mode: Mode) {
+        if mode == self.mode {
+            return;
+        }
+        self.mode = mode;
+        self.ahead = Stack::new();
+        self.reorder();
+        if mode == Mode::Shuffle {
+            self.shuffle(None);
+        }
+        match self.current {
+            Some(track) => {
+                let at = self.order[..self.len].iter().position(|&t| t == track);
+                self.position = at.unwrap_or(0);
+                if mode == Mode::Shuffle {
+                    // It is not played again before the others
+                    self.order.swap(0, self.position);
+                    self.position = 0;
+                }
+            }
+            None => {
+                self.position = 0;
+                self.current = self.order[..self.len].first().copied();
+            }
+        }
+    }
+
+    /// The track to play, or `None` once an ordered list has ended.
+    pub fn current(&self) -> Option<usize> {
+        self.current.map(usize::from)
+    }
+
+    /// The current track played to its end: moves to the one after it, which
+    /// is the same one in [`Mode::RepeatOne`].
+    pub fn finished(&mut self) -> Option<usize> {
+        if self.mode == Mode::RepeatOne {
+            return self.current();
+        }
+        self.skip()
+    }
+
+    /// Moves to the next track: back to where [`previous`](Self::previous)
+    /// started if it was used, on through the order otherwise.
+    pub fn skip(&mut self) -> Option<usize> {
+        let next = match self.ahead.pop() {
+            Some(track) => Some(track),
+            None => self.advance(),
+        };
+        if let Some(track) = self.current {
+            self.history.push(track);
+        }
+        self.current = next;
+        self.current()
+    }
+
+    /// Moves back to the track played before the current one. Returns `None`
+    /// and stays put when the history is used up.
+    pub fn previous(&mut self) -> Option<usize> {
+        let track = self.history.pop()?;
+        if let Some(current) = self.current {
+            self.ahead.push(current);
+        }
+        self.current = Some(track);
+        self.current()
+    }
+
+    /// Tracks played before the current one, latest first.
+    pub fn history(&self) -> impl Iterator<Item = usize> + '_ {
+        self.history.iter().rev().map(usize::from)
+    }
+
+    /// Writes the mode, the order and the position into `out`, which needs
+    /// [`SAVED_HEADER`] + [`len`](Self::len) bytes, and returns the bytes
+    /// written. The history is not kept, and a step back is forgotten:
+    /// [`restore`](Self::restore) resumes at the newest track reached.
+    pub fn save(&self, out: &mut [u8]) -> usize {
+        let size = SAVED_HEADER + self.len;
+        let out = &mut out[..size];
+        out[0] = self.mode as u8;
+        out[1] = self.len as u8;
+        out[2] = self.position as u8;
+        out[SAVED_HEADER..].copy_from_slice(&self.order[..self.len]);
+        out[3] = checksum(out);
+        size
+    }
+
+    /// The playlist `save` wrote into `saved`, if that holds one of `len`
+    /// tracks: bytes from memory that was never written, or a list saved
+    /// before the tracks changed, give `None`. `seed` drives the shuffles
+    /// from there on.
+    pub fn restore(saved: &[u8], len: usize, seed: u32) -> Option<Self> {
+        if len == 0 || len > N || saved.len() < SAVED_HEADER + len {
+            return None;
+        }
+        let saved = &saved[..SAVED_HEADER + len];
+        let mode = Mode::from_u8(saved[0])?;
+        let position = usize::from(saved[2]);
+        if usize::from(saved[1]) != len || position > len || saved[3] != checksum(saved) {
+            return None;
+        }
+
+        let mut playlist = Self::new(len, mode, seed);
+        let mut seen = [false; N];
+        for (slot, &track) in playlist.order.iter_mut().zip(&saved[SAVED_HEADER..]) {
+            if usize::from(track) >= len || core::mem::replace(&mut seen[usize::from(track)], true)
+            {
+                return None;
+            }
+            *slot = track;
+        }
+        playlist.position = position;
+        playlist.current = playlist.order[..len].get(position).copied();
+        Some(playlist)
+    }
+
+    /// The next track of the order, starting a new round if the mode
+    /// goes on after the last one.
+    fn advance(&mut self) -> Option<u8> {
+        if self.position >= self.len {
+            return None;
+        }
+        self.position += 1;
+        if self.position == self.len {
+            match self.mode {
+                Mode::Ordered => return None,
+                Mode::Shuffle => self.shuffle(self.order.get(self.len - 1).copied()),
+                Mode::RepeatOne | Mode::RepeatAll => {}
+            }
+            self.position = 0;
+        }
+        Some(self.order[self.position])
+    }
+
+    fn reorder(&mut self) {
+        for (track, slot) in self.order.iter_mut().enumerate() {
+            *slot = track as u8;
+        }
+    }
+
+    /// Fisher–Yates over the tracks, keeping `last` off the first place so
+    /// it does not play twice in a row.
+    fn shuffle(&mut self, last: Option<u8>) {
+        let order = &mut self.order[..self.len];
+        for i in (1..order.len()).rev() {
+            let j = self.rng.below(i as u32 + 1) as usize;
+            order.swap(i, j);
+        }
+        if order.len() > 1 && order.first().copied() == last {
+            let j = 1 + self.rng.below(order.len() as u32 - 1) as usize;
+            order.swap(0, j);
+        }
+    }
+}
+
+fn checksum(saved: &[u8]) -> u8 {
+    saved
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != 3)
+        .fold(CHECKSUM_SEED, |sum, (_, &byte)| {
+            sum.rotate_left(1).wrapping_add(byte)
+        })
+}
+
+/// The last [`HISTORY`] tracks pushed; the oldest is dropped when full.
+#[derive(Clone, Debug)]
+struct Stack {
+    tracks: [u8; HISTORY],
+    len: usize,
+}
+
+impl Stack {
+    const fn new() -> Self {
+        Self {
+            tracks: [0; HISTORY],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, track: u8) {
+        if self.len == HISTORY {
+            self.tracks.copy_within(1.., 0);
+            self.len -= 1;
+        }
+        self.tracks[self.len] = track;
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<u8> {
+        self.len = self.len.checked_sub(1)?;
+        Some(self.tracks[self.len])
+    }
+
+    fn iter(&self) -> impl DoubleEndedIterator<Item = u8> + '_ {
+        self.tracks[..self.len].iter().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    fn take<const N: usize>(playlist: &mut Playlist<N>, count: usize) -> Vec<usize> {
+        let mut played: Vec<usize> = playlist.current().into_iter().collect();
+        played.extend((1..count).map_while(|_| playlist.finished()));
+        played
+    }
+
+    #[test]
+    fn ordered_plays_once_and_repeat_all_wraps() {
+        let mut ordered: Playlist<8> = Playlist::new(3, Mode::Ordered, 1);
+        assert_eq!(take(&mut ordered, 10), [0, 1, 2]);
+        assert_eq!(ordered.current(), None);
+        assert_eq!(ordered.finished(), None);
+
+        let mut all: Playlist<8> = Playlist::new(3, Mode::RepeatAll, 1);
+        assert_eq!(take(&mut all, 7), [0, 1, 2, 0, 1, 2, 0]);
+    }
+
+    #[test]
+    fn repeat_one_repeats_until_skipped() {
+        let mut playlist: Playlist<8> = Playlist::new(3, Mode::RepeatOne, 1);
+        assert_eq!(take(&mut playlist, 3), [0, 0, 0]);
+        assert_eq!(playlist.skip(), Some(1));
+        assert_eq!(playlist.finished(), Some(1));
+    }
+
+    #[test]
+    fn shuffle_plays_every_track_once_per_round_and_never_twice_in_a_row() {
+        for seed in 0..200 {
+            let mut playlist: Playlist<8> = Playlist::new(5, Mode::Shuffle, seed);
+            let played = take(&mut playlist, 50);
+            for round in played.chunks(5) {
+                let mut sorted = round.to_vec();
+                sorted.sort();
+                assert_eq!(sorted, [0, 1, 2, 3, 4], "seed {seed}");
+            }
+            assert!(played.windows(2).all(|w| w[0] != w[1]), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn seeds_give_different_orders() {
+        let orders: Vec<Vec<usize>> = (1..6)
+            .map(|seed| take(&mut Playlist::<8>::new(8, Mode::Shuffle, seed), 8))
+            .collect();
+        assert!(orders.windows(2).any(|w| w[0] != w[1]));
+        assert!(orders
+            .iter()
+            .any(|order| *order != [0, 1, 2, 3, 4, 5, 6, 7]));
+    }
+
+    #[test]
+    fn previous_walks_the_history_and_skip_comes_back() {
+        let mut playlist: Playlist<8> = Playlist::new(4, Mode::RepeatAll, 1);
+        assert_eq!(take(&mut playlist, 3), [0, 1, 2]);
+        assert_eq!(playlist.history().collect::<Vec<_>>(), [1, 0]);
+
+        assert_eq!(playlist.previous(), Some(1));
+        assert_eq!(playlist.previous(), Some(0));
+        assert_eq!(playlist.previous(), None);
+        assert_eq!(playlist.current(), Some(0));
+
+        assert_eq!(playlist.skip(), Some(1));
+        assert_eq!(playlist.skip(), Some(2));
+        assert_eq!(playlist.skip(), Some(3));
+    }
+
+    #[test]
+    fn history_keeps_the_latest_tracks() {
+        let mut playlist: Playlist<16> = Playlist::new(16, Mode::Ordered, 1);
+        take(&mut playlist, 12);
+        assert_eq!(playlist.current(), Some(11));
+        assert_eq!(
+            playlist.history().collect::<Vec<_>>(),
+            [10, 9, 8, 7, 6, 5, 4, 3]
+        );
+    }
+
+    #[test]
+    fn switching_to_shuffle_starts_a_round_at_the_current_track() {
+        let mut playlist: Playlist<8> = Playlist::new(6, Mode::Ordered, 7);
+        take(&mut playlist, 3);
+        playlist.set_mode(Mode::Shuffle);
+        assert_eq!(playlist.current(), Some(2));
+
+        let mut round = take(&mut playlist, 6);
+        round.sort();
+        assert_eq!(round, [0, 1, 2, 3, 4, 5]);
+
+        playlist.set_mode(Mode::RepeatAll);
+        let from = playlist.current().unwrap();
+        assert_eq!(playlist.finished(), Some((from + 1) % 6));
+    }
+
+    #[test]
+    fn a_saved_playlist_resumes_where_it_was() {
+        let mut playlist: Playlist<8> = Playlist::new(6, Mode::Shuffle, 42);
+        take(&mut playlist, 3);
+        let mut saved = [0u8; SAVED_HEADER + 8];
+        let size = playlist.save(&mut saved);
+        assert_eq!(size, SAVED_HEADER + 6);
+
+        let mut restored = Playlist::<8>::restore(&saved, 6, 1).unwrap();
+        assert_eq!(restored.mode(), Mode::Shuffle);
+        assert_eq!(restored.current(), playlist.current());
+        // The rest of the round is the same
+        for _ in 0..2 {
+            assert_eq!(restored.finished(), playlist.finished());
+        }
+    }
+
+    #[test]
+    fn restore_rejects_what_it_did_not_save() {
+        // Persistent memory after the first boot
+        assert!(Playlist::<8>::restore(&[0; 12], 6, 1).is_none());
+
+        let mut saved = [0u8; SAVED_HEADER + 8];
+        Playlist::<8>::new(6, Mode::RepeatAll, 1).save(&mut saved);
+        // Songs were added since
+        assert!(Playlist::<8>::restore(&saved, 7, 1).is_none());
+        // A flipped bit
+        saved[SAVED_HEADER + 2] ^= 0x01;
+        assert!(Playlist::<8>::restore(&saved, 6, 1).is_none());
+        assert!(Playlist::<8>::restore(&[], 6, 1).is_none());
+    }
+}
@@ -0,0 +1,675 @@
+//! Speaker protection for the DMA buffer: a chain of biquads that removes DC
+//! and the lows a small speaker cannot play (and can shape the tone with
+//! shelves), then a look-ahead peak limiter that keeps the output under a
+//! ceiling. The coefficients are worked out once in floating point; the
+//! samples go through integer arithmetic only.
+
+use core::f64::consts::PI;
+
+use crate::convert::{OutputFormat, SampleWidth};
+use crate::gain::UNITY;
+
+/// Sections a [`FilterChain`] holds.
+pub const MAX_SECTIONS: usize = 4;
+
+/// Longest look-ahead of a [`Limiter`], in frames: 11.6 ms at 11025 Hz.
+pub const MAX_LOOKAHEAD: usize = 128;
+
+/// Corner of [`FilterChain::dc_blocker`], well below anything audible.
+pub const DC_BLOCKER_HZ: u32 = 5;
+
+/// Shelf gains are clamped to ±12 dB.
+pub const MAX_SHELF_DB: i8 = 12;
+
+/// Channels filtered and limited: the I2S frames are stereo. Further slots
+/// are left as they are.
+const CHANNELS: usize = 2;
+
+/// Decibels per halving of the gain, 20 log10(2), in Q16.
+const DB_PER_OCTAVE: i64 = 394_567;
+
+/// Fractional bits of the biquad coefficients: a range of ±8, enough for
+/// the boost of a 12 dB shelf.
+const COEFF_BITS: u32 = 28;
+
+/// `y = b0 x + b1 x[-1] + b2 x[-2] - a1 y[-1] - a2 y[-2]`, with coefficients
+/// in Q28 and `a0` divided out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Biquad {
+    b0: i32,
+    b1: i32,
+    b2: i32,
+    a1: i32,
+    a2: i32,
+}
+
+impl Biquad {
+    /// Quantizes the coefficients of `b(z) / a(z)`.
+    fn from_coefficients(b: [f64; 3], a: [f64; 3]) -> Self {
+        let q = |value: f64| libm::round(value / a[0] * (1u32 << COEFF_BITS) as f64) as i32;
+        Self {
+            b0: q(b[0]),
+            b1: q(b[1]),
+            b2: q(b[2]),
+            a1: q(a[1]),
+            a2: q(a[2]),
+        }
+    }
+
+    /// A first-order high-pass with its pole just inside the unit circle:
+    /// `(1 - z^-1) / (1 - r z^-1)`.
+    pub fn dc_blocker(sample_rate: u32, hz: u32) -> Self {
+        let r = 1.0 - 2.0 * PI * hz as f64 / sample_rate.max(1) as f64;
+        Self::from_coefficients([1.0, -1.0, 0.0], [1.0, -r, 0.0])
+    }
+
+    /// A second-order Butterworth high-pass: -3 dB at `hz`, 12 dB per octave
+    /// below it.
+    pub fn high_pass(sample_rate: u32, hz: u32) -> Self {
+        let (cos, alpha) = corner(sample_rate, hz, core::f64::consts::FRAC_1_SQRT_2);
+        let b = [(1.0 + cos) / 2.0, -(1.0 + cos), (1.0 + cos) / 2.0];
+        Self::from_coefficients(b, [1.0 + alpha, -2.0 * cos, 1.0 - alpha])
+    }
+
+    /// Lifts or cuts everything below `hz` by `db` dB.
+    pub fn low_shelf(sample_rate: u32, hz: u32, db: i8) -> Self {
+        let (a, cos, beta) = shelf(sample_rate, hz, db);
+        let b = [
+            a * ((a + 1.0) - (a - 1.0) * cos + beta),
+            2.0 * a * ((a - 1.0) - (a + 1.0) * cos),
+            a * ((a + 1.0) - (a - 1.0) * cos - beta),
+        ];
+        let den = [
+            (a + 1.0) + (a - 1.0) * cos + beta,
+            -2.0 * ((a - 1.0) + (a + 1.0) * cos),
+            (a + 1.0) + (a - 1.0) * cos - beta,
+        ];
+        Self::from_coefficients(b, den)
+    }
+
+    /// Lifts or cuts everything above `hz` by `db` dB.
+    pub fn high_shelf(sample_rate: u32, hz: u32, db: i8) -> Self {
+        let (a, cos, beta) = shelf(sample_rate, hz, db);
+        let b = [
+            a * ((a + 1.0) + (a - 1.0) * cos + beta),
+            -2.0 * a * ((a - 1.0) + (a + 1.0) * cos),
+            a * ((a + 1.0) + (a - 1.0) * cos - beta),
+        ];
+        let den = [
+            (a + 1.0) - (a - 1.0) * cos + beta,
+            2.0 * ((a - 1.0) - (a + 1.0) * cos),
+            (a + 1.0) - (a - 1.0) * cos - beta,
+        ];
+        Self::from_coefficients(b, den)
+    }
+
+    /// Gain at `theta` radians per sample, from the quantized coefficients.
+    fn response(&self, theta: f64) -> f64 {
+        let unit = (1u32 << COEFF_BITS) as f64;
+        let [b0, b1, b2, a1, a2] =
+            [self.b0, self.b1, self.b2, self.a1, self.a2].map(|c| c as f64 / unit);
+        let (cos1, sin1) = (libm::cos(theta), libm::sin(theta));
+        let (cos2, sin2) = (libm::cos(2.0 * theta), libm::sin(2.0 * theta));
+        let num = libm::hypot(b0 + b1 * cos1 + b2 * cos2, -(b1 * sin1 + b2 * sin2));
+        let den = libm::hypot(1.0 + a1 * cos1 + a2 * cos2, -(a1 * sin1 + a2 * sin2));
+        num / den
+    }
+}
+
+/// Cosine and `alpha` of the cookbook designs for a corner at `hz`, kept
+/// below Nyquist.
+fn corner(sample_rate: u32, hz: u32, q: f64) -> (f64, f64) {
+    let rate = sample_rate.max(1) as f64;
+    let w0 = 2.0 * PI * (hz as f64).clamp(1.0, 0.45 * rate) / rate;
+    (libm::cos(w0), libm::sin(w0) / (2.0 * q))
+}
+
+/// Amplitude, cosine and `2 sqrt(A) alpha` of a shelf with a slope of 1.
+fn shelf(sample_rate: u32, hz: u32, db: i8) -> (f64, f64, f64) {
+    let db = db.clamp(-MAX_SHELF_DB, MAX_SHELF_DB) as f64;
+    let a = libm::pow(10.0, db / 40.0);
+    let (cos, alpha) = corner(sample_rate, hz, core::f64::consts::FRAC_1_SQRT_2);
+    (a, cos, 2.0 * libm::sqrt(a) * alpha)
+}
+
+/// What a [`Biquad`] remembers of one channel. The bits shifted out of the
+/// last output are added back into the next one, so the rounding noise does
+/// not pile up at the low corners.
+#[derive(Clone, Copy, Debug, Default)]
+struct History {
+    x1: i32,
+    x2: i32,
+    y1: i32,
+    y2: i32,
+    error: i64,
+}
+
+/// Biquads in series, applied to encoded output frames like
+/// [`Gain`](crate::Gain). Sections are added in the order they run:
+///
+/// ```
+/// # use audio_pipeline::FilterChain;
+/// let chain = FilterChain::new(11025).dc_blocker().high_pass(150).low_shelf(300, 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FilterChain {
+    sample_rate: u32,
+    sections: [Biquad; MAX_SECTIONS],
+    len: usize,
+    history: [[History; CHANNELS]; MAX_SECTIONS],
+}
+
+impl FilterChain {
+    /// A chain with no sections, which changes nothing.
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            sample_rate: sample_rate.max(1),
+            sections: [Biquad::default(); MAX_SECTIONS],
+            len: 0,
+            history: [[History::default(); CHANNELS]; MAX_SECTIONS],
+        }
+    }
+
+    /// Adds `section` at the end. Past [`MAX_SECTIONS`] it is left out.
+    pub fn section(mut self, section: Biquad) -> Self {
+        if self.len < MAX_SECTIONS {
+            self.sections[self.len] = section;
+            self.len += 1;
+        }
+        self
+    }
+
+    /// Removes the DC offset, with a corner at [`DC_BLOCKER_HZ`].
+    pub fn dc_blocker(self) -> Self {
+        let section = Biquad::dc_blocker(self.sample_rate, DC_BLOCKER_HZ);
+        self.section(section)
+    }
+
+    /// Cuts what lies below `hz`, 12 dB per octave. Add it twice for 24.
+    pub fn high_pass(self, hz: u32) -> Self {
+        let section = Biquad::high_pass(self.sample_rate, hz);
+        self.section(section)
+    }
+
+    /// Lifts or cuts the lows below `hz` by `db` dB.
+    pub fn low_shelf(self, hz: u32, db: i8) -> Self {
+        let section = Biquad::low_shelf(self.sample_rate, hz, db);
+        self.section(section)
+    }
+
+    /// Lifts or cuts the highs above `hz` by `db` dB.
+    pub fn high_shelf(self, hz: u32, db: i8) -> Self {
+        let section = Biquad::high_shelf(self.sample_rate, hz, db);
+        self.section(section)
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Gain of the chain at `hz`, in dB.
+    pub fn gain_db(&self, hz: f32) -> f32 {
+        let theta = 2.0 * PI * hz as f64 / self.sample_rate as f64;
+        let gain: f64 = self.sections[..self.len]
+            .iter()
+            .map(|section| section.response(theta))
+            .product();
+        (20.0 * libm::log10(gain)) as f32
+    }
+
+    /// Forgets the past samples, before audio that does not follow on.
+    pub fn reset(&mut self) {
+        self.history = [[History::default(); CHANNELS]; MAX_SECTIONS];
+    }
+
+    /// Filters the whole `output` frames of `buffer`.
+    pub fn apply(&mut self, buffer: &mut [u8], output: OutputFormat) {
+        if self.len == 0 {
+            return;
+        }
+        for frame in buffer.chunks_exact_mut(output.frame_size()) {
+            for (c, sample) in frame
+                .chunks_exact_mut(output.width.bytes())
+                .take(CHANNELS)
+                .enumerate()
+            {
+                let mut x = read(sample, output.width) >> 8;
+                for (section, history) in self.sections[..self.len]
+                    .iter()
+                    .zip(self.history.iter_mut())
+                {
+                    x = next(section, &mut history[c], x);
+                }
+                write(sample, output.width, x as i64 * 256);
+            }
+        }
+    }
+}
+
+/// One sample through one section. Samples carry 24 bits, so the
+/// accumulator has room for the gain of any section.
+fn next(section: &Biquad, history: &mut History, x: i32) -> i32 {
+    let acc = section.b0 as i64 * x as i64
+        + section.b1 as i64 * history.x1 as i64
+        + section.b2 as i64 * history.x2 as i64
+        - section.a1 as i64 * history.y1 as i64
+        - section.a2 as i64 * history.y2 as i64
+        + history.error;
+    let y = (acc >> COEFF_BITS).clamp(-(1 << 30), (1 << 30) - 1);
+    history.error = acc - (y << COEFF_BITS);
+    history.x2 = history.x1;
+    history.x1 = x;
+    history.y2 = history.y1;
+    history.y1 = y as i32;
+    y as i32
+}
+
+/// The sample as a 32-bit value.
+fn read(sample: &[u8], width: SampleWidth) -> i32 {
+    match width {
+        SampleWidth::Bits16 => (i16::from_le_bytes([sample[0], sample[1]]) as i32) << 16,
+        SampleWidth::Bits32 => i32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]),
+    }
+}
+
+/// `log2` of a Q16 gain in `1..=UNITY`, in Q16: one bit of the fraction per
+/// squaring.
+fn log2_q16(gain: u32) -> i32 {
+    let shift = gain.leading_zeros() as i32 - 15;
+    let mut mantissa = (gain as u64) << shift;
+    let mut log = -shift << 16;
+    for bit in (0..16).rev() {
+        mantissa = (mantissa * mantissa) >> 16;
+        if mantissa >= 2 << 16 {
+            mantissa >>= 1;
+            log += 1 << bit;
+        }
+    }
+    log
+}
+
+/// Writes a 32-bit value, clipped to the sample width.
+fn write(sample: &mut [u8], width: SampleWidth, value: i64) {
+    let value = value.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+    match width {
+        SampleWidth::Bits16 => sample.copy_from_slice(&((value >> 16) as i16).to_le_bytes()),
+        SampleWidth::Bits32 => sample.copy_from_slice(&value.to_le_bytes()),
+    }
+}
+
+/// A peak limiter that sees `lookahead` frames ahead: the output is delayed
+/// by that much, and the gain is already down when a peak comes out, so the
+/// output never goes over the ceiling and nothing is clipped. Both channels
+/// get the same gain, so the stereo image does not move.
+///
+/// The gain needed by each frame is held over the look-ahead, recovers
+/// towards 1.0 with the release time, then is averaged over the look-ahead,
+/// so it ramps down smoothly instead of stepping.
+#[derive(Clone, Debug)]
+pub struct Limiter {
+    /// The ceiling as a 32-bit sample.
+    ceiling: i32,
+    lookahead: usize,
+    /// Q16 step of the release towards [`UNITY`].
+    release: i64,
+    /// Frames in, so the held gains know when they leave the window.
+    count: u32,
+    /// Frames of audio still in the delay, for [`Limiter::flush`].
+    pending: usize,
+    delay: [[i32; CHANNELS]; MAX_LOOKAHEAD],
+    /// Gains needed by the frames of the window, rising from the front, the
+    /// smallest at the front, with the frame each belongs to.
+    held: [(u32, i32); MAX_LOOKAHEAD],
+    held_start: usize,
+    held_len: usize,
+    /// The released gain, and its last `lookahead` values and their sum.
+    gain: i32,
+    smooth: [i32; MAX_LOOKAHEAD],
+    sum: i64,
+}
+
+impl Limiter {
+    /// A limiter with its ceiling at `ceiling_db` dBFS, looking
+    /// `lookahead_ms` ahead (up to [`MAX_LOOKAHEAD`] frames) and recovering
+    /// over about `release_ms`.
+    pub fn new(sample_rate: u32, ceiling_db: i8, lookahead_ms: u32, release_ms: u32) -> Self {
+        let sample_rate = sample_rate.max(1);
+        let ceiling = libm::pow(10.0, ceiling_db.min(0) as f64 / 20.0) * i32::MAX as f64;
+        let lookahead = (lookahead_ms as u64 * sample_rate as u64 / 1000) as usize;
+        let release_frames = (release_ms as f64 * sample_rate as f64 / 1000.0).max(1.0);
+        let mut limiter = Self {
+            ceiling: ceiling as i32,
+            lookahead: lookahead.clamp(1, MAX_LOOKAHEAD),
+            release: ((1.0 - libm::exp(-1.0 / release_frames)) * UNITY as f64) as i64,
+            count: 0,
+            pending: 0,
+            delay: [[0; CHANNELS]; MAX_LOOKAHEAD],
+            held: [(0, UNITY); MAX_LOOKAHEAD],
+            held_start: 0,
+            held_len: 0,
+            gain: UNITY,
+            smooth: [UNITY; MAX_LOOKAHEAD],
+            sum: 0,
+        };
+        limiter.reset();
+        limiter
+    }
+
+    /// Frames the output is behind the input.
+    pub fn latency(&self) -> usize {
+        self.lookahead - 1
+    }
+
+    /// The gain applied to the last frame out, in dB: 0 while nothing is
+    /// limited. Worked out in fixed point, only when asked for.
+    pub fn reduction_db(&self) -> i8 {
+        let gain = (self.sum / self.lookahead as i64).clamp(1, UNITY as i64) as u32;
+        let db = (log2_q16(gain) as i64 * DB_PER_OCTAVE + (1 << 31)) >> 32;
+        db as i8
+    }
+
+    /// Whether every frame in has come back out, so [`Limiter::reset`]
+    /// drops nothing.
+    pub fn is_drained(&self) -> bool {
+        self.pending == 0
+    }
+
+    /// Empties the delay and lets go of the gain, before audio that does not
+    /// follow on. [`Limiter::flush`] it first, or its last frames are lost.
+    pub fn reset(&mut self) {
+        self.count = 0;
+        self.pending = 0;
+        self.delay = [[0; CHANNELS]; MAX_LOOKAHEAD];
+        self.held_start = 0;
+        self.held_len = 0;
+        self.gain = UNITY;
+        self.smooth = [UNITY; MAX_LOOKAHEAD];
+        self.sum = UNITY as i64 * self.lookahead as i64;
+    }
+
+    /// Limits the whole `output` frames of `buffer`, which come out
+    /// [`Limiter::latency`] frames late.
+    pub fn apply(&mut self, buffer: &mut [u8], output: OutputFormat) {
+        let width = output.width.bytes();
+        for frame in buffer.chunks_exact_mut(output.frame_size()) {
+            let mut input = [0i32; CHANNELS];
+            for (value, sample) in input.iter_mut().zip(frame.chunks_exact(width)) {
+                *value = read(sample, output.width);
+            }
+            let (delayed, gain) = self.next(input);
+            for (value, sample) in delayed.iter().zip(frame.chunks_exact_mut(width)) {
+                write(sample, output.width, (*value as i64 * gain as i64) >> 16);
+            }
+            self.pending = (self.pending + 1).min(self.latency());
+        }
+    }
+
+    /// Writes the frames still in the delay into `buffer` as whole `output`
+    /// frames, once the input has stopped, and returns the bytes written: 0
+    /// once drained.
+    pub fn flush(&mut self, buffer: &mut [u8], output: OutputFormat) -> usize {
+        let width = output.width.bytes();
+        let mut written = 0;
+        for frame in buffer.chunks_exact_mut(output.frame_size()) {
+            if self.pending == 0 {
+                break;
+            }
+            frame.fill(0);
+            let (delayed, gain) = self.next([0; CHANNELS]);
+            for (value, sample) in delayed.iter().zip(frame.chunks_exact_mut(width)) {
+                write(sample, output.width, (*value as i64 * gain as i64) >> 16);
+            }
+            self.pending -= 1;
+            written += frame.len();
+        }
+        written
+    }
+
+    /// Takes a frame in, and gives back the one leaving the delay with its
+    /// Q16 gain.
+    fn next(&mut self, input: [i32; CHANNELS]) -> ([i32; CHANNELS], i32) {
+        let n = self.lookahead;
+        let t = self.count;
+        self.count = self.count.wrapping_add(1);
+
+        // The gain that brings this frame down to the ceiling
+        let peak = input.iter().map(|x| x.unsigned_abs()).max().unwrap_or(0);
+        let needed = if peak <= self.ceiling as u32 {
+            UNITY
+        } else {
+            (self.ceiling as i64 * UNITY as i64 / peak as i64) as i32
+        };
+
+        // Smallest gain of the window: drop what has left it and what the
+        // new one undercuts
+        if self.held_len > 0 && t.wrapping_sub(self.held[self.held_start].0) >= n as u32 {
+            self.held_start = (self.held_start + 1) % n;
+            self.held_len -= 1;
+        }
+        while self.held_len > 0 {
+            let back = (self.held_start + self.held_len - 1) % n;
+            if self.held[back].1 < needed {
+                break;
+            }
+            self.held_len -= 1;
+        }
+        self.held[(self.held_start + self.held_len) % n] = (t, needed);
+        self.held_len += 1;
+        let held = self.held[self.held_start].1;
+
+        // Recover towards 1.0, never above the held gain
+        let rise = ((UNITY - self.gain) as i64 * self.release + 0xFFFF) >> 16;
+        self.gain = (self.gain + rise as i32).min(held);
+
+        // Averaged over the window, so it is down by the time the frame
+        // that needed it leaves the delay
+        let slot = t as usize % n;
+        self.sum += self.gain as i64 - self.smooth[slot] as i64;
+        self.smooth[slot] = self.gain;
+        self.delay[slot] = input;
+        let delayed = self.delay[(slot + 1) % n];
+        (delayed, (self.sum / n as i64) as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::encode;
+    use crate::gain::{Gain, MAX_VOLUME};
+    use crate::Frame;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    fn sine(hz: f64, rate: u32, amplitude: f64, frames: usize) -> Vec<Frame> {
+        (0..frames)
+            .map(|i| {
+                let x = amplitude * libm::sin(2.0 * PI * hz * i as f64 / rate as f64);
+                [x as i16; 2]
+            })
+            .collect()
+    }
+
+    fn bytes(frames: &[Frame]) -> Vec<u8> {
+        let mut out = std::vec![0u8; frames.len() * 4];
+        encode(frames, OutputFormat::STEREO_16, &mut out);
+        out
+    }
+
+    fn left(bytes: &[u8]) -> Vec<i32> {
+        bytes
+            .chunks_exact(4)
+            .map(|frame| i16::from_le_bytes([frame[0], frame[1]]) as i32)
+            .collect()
+    }
+
+    /// Level of a sine after `chain`, in dB, once the filters have settled.
+    fn measured_db(chain: &mut FilterChain, hz: f64) -> f64 {
+        let rate = chain.sample_rate();
+        let mut buffer = bytes(&sine(hz, rate, 8000.0, rate as usize));
+        chain.reset();
+        chain.apply(&mut buffer, OutputFormat::STEREO_16);
+        let tail = &left(&buffer)[rate as usize / 2..];
+        let rms = |samples: &[i32]| {
+            let sum: f64 = samples.iter().map(|&x| (x as f64) * (x as f64)).sum();
+            libm::sqrt(sum / samples.len() as f64)
+        };
+        20.0 * libm::log10(rms(tail) / (8000.0 / libm::sqrt(2.0)))
+    }
+
+    #[test]
+    fn high_pass_cuts_the_lows_and_keeps_the_rest() {
+        let mut chain = FilterChain::new(11025).dc_blocker().high_pass(150);
+        assert!((chain.gain_db(150.0) + 3.0).abs() < 0.2);
+        assert!(chain.gain_db(37.5) < -23.0, "{}", chain.gain_db(37.5));
+        assert!(chain.gain_db(1000.0).abs() < 0.2);
+        assert!(chain.gain_db(4000.0).abs() < 0.1);
+
+        // The integer path follows the designed response
+        for hz in [50.0, 150.0, 400.0, 1000.0, 3000.0] {
+            let expected = chain.gain_db(hz as f32) as f64;
+            let measured = measured_db(&mut chain, hz);
+            assert!(
+                (measured - expected).abs() < 0.3,
+                "{hz} Hz: {measured} dB, designed {expected} dB"
+            );
+        }
+    }
+
+    #[test]
+    fn dc_blocker_removes_an_offset() {
+        // 8-bit clips decoded around the wrong middle sit on an offset
+        let mut frames = sine(440.0, 8000, 3000.0, 8000);
+        for frame in &mut frames {
+            frame[0] += 4000;
+            frame[1] += 4000;
+        }
+        let mut buffer = bytes(&frames);
+        let mut chain = FilterChain::new(8000).dc_blocker();
+        chain.apply(&mut buffer, OutputFormat::STEREO_16);
+        let tail = &left(&buffer)[4000..];
+        let mean = tail.iter().sum::<i32>() / tail.len() as i32;
+        assert!(mean.abs() < 20, "offset {mean}");
+        assert!(chain.gain_db(440.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn shelves_lift_and_cut_their_side() {
+        let chain = FilterChain::new(44100)
+            .low_shelf(200, 6)
+            .high_shelf(5000, -4);
+        assert!((chain.gain_db(30.0) - 6.0).abs() < 0.2);
+        assert!(chain.gain_db(1500.0).abs() < 0.6);
+        assert!((chain.gain_db(18000.0) + 4.0).abs() < 0.3);
+
+        // Clamped to 12 dB, and a chain holds MAX_SECTIONS
+        let loud = FilterChain::new(44100).low_shelf(200, 30);
+        assert!((loud.gain_db(20.0) - 12.0).abs() < 0.2);
+        let mut long = FilterChain::new(44100);
+        for _ in 0..6 {
+            long = long.high_pass(100);
+        }
+        assert_eq!(long.len, MAX_SECTIONS);
+        assert!((measured_db(&mut long, 1000.0) - long.gain_db(1000.0) as f64).abs() < 0.3);
+    }
+
+    #[test]
+    fn limiter_keeps_peaks_under_the_ceiling() {
+        let rate = 11025;
+        let mut limiter = Limiter::new(rate, -6, 5, 50);
+        let latency = limiter.latency();
+        assert_eq!(latency, 54);
+        let ceiling = (32767.0 * libm::pow(10.0, -0.3)) as i32;
+
+        // Quiet, a loud burst with a sharp start, then quiet again
+        let mut frames = sine(300.0, rate, 4000.0, 2000);
+        frames.extend(sine(300.0, rate, 30000.0, 2000));
+        frames.extend(sine(300.0, rate, 4000.0, 6000));
+        frames[3000] = [i16::MAX, i16::MIN];
+        let mut buffer = bytes(&frames);
+        for chunk in buffer.chunks_mut(4 * 100) {
+            limiter.apply(chunk, OutputFormat::STEREO_16);
+        }
+        // Negative samples round down, one step further out
+        let out = left(&buffer);
+        assert!(out.iter().all(|x| x.abs() <= ceiling + 1));
+        assert!(out[..latency].iter().all(|&x| x == 0));
+
+        // Delayed, and untouched where it was quiet
+        let input: Vec<i32> = frames.iter().map(|frame| frame[0] as i32).collect();
+        assert_eq!(out[latency..latency + 1900], input[..1900]);
+        assert_eq!(out[latency + 9000..], input[9000..frames.len() - latency]);
+        // The burst is brought down, not clipped: it keeps its shape
+        let peak = out[2500..4000].iter().map(|x| x.abs()).max().unwrap();
+        assert!(peak > ceiling * 9 / 10, "peak {peak}");
+        assert!(limiter.reduction_db() == 0);
+    }
+
+    #[test]
+    fn limiter_gain_ramps_instead_of_stepping() {
+        let rate = 8000;
+        let mut limiter = Limiter::new(rate, -6, 8, 100);
+        let mut frames = std::vec![[1000i16; 2]; 400];
+        frames.extend(std::vec![[-32000i16; 2]; 400]);
+        let mut buffer = bytes(&frames);
+        limiter.apply(&mut buffer, OutputFormat::STEREO_16);
+        assert!((-6..=-5).contains(&limiter.reduction_db()));
+
+        // The gain falls over the look-ahead before the step comes out
+        let latency = limiter.latency();
+        let out = left(&buffer);
+        assert_eq!(out[400 - 1], 1000);
+        let ramp = &out[400..400 + latency];
+        assert!(ramp.windows(2).all(|pair| pair[1] <= pair[0]));
+        assert!(ramp[latency - 1] < 600, "{}", ramp[latency - 1]);
+        let ceiling = (32767.0 * libm::pow(10.0, -0.3)) as i32;
+        assert!(out.iter().all(|x| x.abs() <= ceiling + 1));
+    }
+
+    #[test]
+    fn fixed_point_log_follows_libm() {
+        for gain in [
+            1,
+            7,
+            1000,
+            UNITY as u32 / 10,
+            UNITY as u32 / 2,
+            50_000,
+            UNITY as u32,
+        ] {
+            let expected = libm::log2(gain as f64 / UNITY as f64) * UNITY as f64;
+            let log = log2_q16(gain) as f64;
+            assert!((log - expected).abs() < 4.0, "{gain}: {log}, {expected}");
+        }
+    }
+
+    #[test]
+    fn limiter_flush_lets_a_fade_out_reach_zero() {
+        let rate = 11025;
+        let mut gain = Gain::new(rate, MAX_VOLUME);
+        let mut limiter = Limiter::new(rate, -6, 5, 80);
+        let mut buffer = bytes(&std::vec![[30000i16; 2]; 530]);
+        let (playing, fading) = buffer.split_at_mut(4 * 300);
+        gain.fade_in();
+        gain.apply(playing, OutputFormat::STEREO_16);
+        gain.fade_out();
+        gain.apply(fading, OutputFormat::STEREO_16);
+        assert!(gain.is_silent());
+        limiter.apply(&mut buffer, OutputFormat::STEREO_16);
+
+        // The end of the fade is still in the delay
+        let latency = limiter.latency();
+        assert!(!limiter.is_drained());
+        assert!(left(&buffer)[529] > 2000);
+
+        let mut tail = std::vec![0xAAu8; 4 * (latency + 10)];
+        let written = limiter.flush(&mut tail, OutputFormat::STEREO_16);
+        assert_eq!(written, 4 * latency);
+        assert!(limiter.is_drained());
+        assert_eq!(limiter.flush(&mut tail, OutputFormat::STEREO_16), 0);
+        let out = left(&tail[..written]);
+        assert!(out.windows(2).all(|pair| pair[1] <= pair[0]));
+        assert_eq!(out[latency - 1], 0);
+    }
+}
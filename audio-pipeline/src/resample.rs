@@ -0,0 +1,381 @@
+use crate::Frame;
+
+/// Input frames on each side of the interpolated position used by
+/// [`Quality::Sinc`].
+const HALF_TAPS: usize = 16;
+const TAPS: usize = 2 * HALF_TAPS;
+/// Sub-sample positions stored in the filter table; positions in between are
+/// interpolated from the two nearest rows.
+const PHASES: usize = 32;
+/// 1.0 in the Q14 filter table.
+const ONE: i16 = 1 << 14;
+
+/// Interpolation used by a [`Resampler`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Quality {
+    /// Straight line between the two nearest input frames. Cheap, but dulls
+    /// the highs and lets aliases through when downsampling.
+    Linear,
+    /// Blackman-windowed sinc over 32 input frames, low-passed below the
+    /// lower of the two Nyquist frequencies.
+    Sinc,
+}
+
+/// Streaming sample-rate converter for stereo frames.
+///
+/// Input is accepted in chunks of any size; the converter keeps only the last
+/// few input frames between calls, so memory use is fixed (about 2 KiB for
+/// the filter table) whatever the clip length. Positions are tracked as exact
+/// integer fractions of the two rates, so long clips do not drift.
+///
+/// Output is delayed by [`Resampler::latency`] input frames; call
+/// [`Resampler::flush`] after the last chunk to play them out.
+#[derive(Clone, Debug)]
+pub struct Resampler {
+    quality: Quality,
+    /// Input rate: how far the position moves per output frame.
+    step: u32,
+    /// Output rate: the denominator of `phase`.
+    den: u32,
+    /// Position between `window[HALF_TAPS - 1]` and `window[HALF_TAPS]`, in
+    /// 1/`den` input frames. `phase >= den` means more input is needed.
+    phase: u32,
+    /// The last `TAPS` input frames, oldest first.
+    window: [Frame; TAPS],
+    /// Silent frames already fed by `flush`.
+    flushed: usize,
+    /// Q14 filter coefficients, one row per phase (plus the closing row).
+    /// Q14 rather than Q15 so the centre tap of 1.0 still fits in an `i16`.
+    table: [[i16; TAPS]; PHASES + 1],
+}
+
+impl Resampler {
+    pub fn new(input_rate: u32, output_rate: u32, quality: Quality) -> Self {
+        assert!(
+            input_rate > 0 && output_rate > 0,
+            "sample rates must be non-zero"
+        );
+        let divisor = gcd(input_rate, output_rate);
+        let mut resampler = Self {
+            quality,
+            step: input_rate / divisor,
+            den: output_rate / divisor,
+            phase: 0,
+            window: [[0; 2]; TAPS],
+            flushed: 0,
+            table: [[0; TAPS]; PHASES + 1],
+        };
+        if quality == Quality::Sinc {
+            // Keep 10% of the band as transition when the output is the lower rate
+            let cutoff = if output_rate < input_rate {
+                0.9 * output_rate as f64 / input_rate as f64
+            } else {
+                1.0
+            };
+            fill_table(&mut resampler.table, cutoff);
+        }
+        resampler.reset();
+        resampler
+    }
+
+    /// Forgets all buffered input, ready for a new clip.
+    pub fn reset(&mut self) {
+        self.window = [[0; 2]; TAPS];
+        self.flushed = 0;
+        // Preload so that the first output frame lands exactly on input frame 0
+        self.phase = (HALF_TAPS as u32 + 1) * self.den;
+    }
+
+    /// Input frames buffered before the first output frame comes out.
+    pub fn latency(&self) -> usize {
+        if self.is_passthrough() {
+            0
+        } else {
+            HALF_TAPS + 1
+        }
+    }
+
+    /// `true` when the rates are equal and frames are copied without
+    /// filtering or delay.
+    pub fn is_passthrough(&self) -> bool {
+        self.step == self.den
+    }
+
+    /// Resamples from `input` into `output` until one of them runs out.
+    ///
+    /// Returns `(frames consumed, frames produced)`; unconsumed input must be
+    /// passed again on the next call.
+    pub fn process(&mut self, input: &[Frame], output: &mut [Frame]) -> (usize, usize) {
+        if self.is_passthrough() {
+            let n = input.len().min(output.len());
+            output[..n].copy_from_slice(&input[..n]);
+            return (n, n);
+        }
+
+        let mut consumed = 0;
+        let mut produced = 0;
+        loop {
+            while self.phase >= self.den {
+                let Some(&frame) = input.get(consumed) else {
+                    return (consumed, produced);
+                };
+                self.window.copy_within(1.., 0);
+                self.window[TAPS - 1] = frame;
+                self.phase -= self.den;
+                consumed += 1;
+            }
+            let Some(out) = output.get_mut(produced) else {
+                return (consumed, produced);
+            };
+            *out = self.interpolate();
+            produced += 1;
+            self.phase += self.step;
+        }
+    }
+
+    /// Feeds silence after the last chunk so the buffered input comes out.
+    /// Call until it returns 0.
+    pub fn flush(&mut self, output: &mut [Frame]) -> usize {
+        if self.is_passthrough() {
+            return 0;
+        }
+        const SILENCE: [Frame; HALF_TAPS] = [[0; 2]; HALF_TAPS];
+        let (consumed, produced) = self.process(&SILENCE[self.flushed..], output);
+        self.flushed += consumed;
+        produced
+    }
+
+    fn interpolate(&self) -> Frame {
+        match self.quality {
+            Quality::Linear => {
+                let frac = ((self.phase as u64) << 16) / self.den as u64;
+                let a = self.window[HALF_TAPS - 1];
+                let b = self.window[HALF_TAPS];
+                let lerp = |a: i16, b: i16| {
+                    (a as i32 + (((b as i32 - a as i32) as i64 * frac as i64) >> 16) as i32) as i16
+                };
+                [lerp(a[0], b[0]), lerp(a[1], b[1])]
+            }
+            Quality::Sinc => {
+                let scaled = self.phase as u64 * PHASES as u64;
+                let row = (scaled / self.den as u64) as usize;
+                let frac = (((scaled % self.den as u64) << 15) / self.den as u64) as i32;
+                let (lo, hi) = (&self.table[row], &self.table[row + 1]);
+
+                let mut acc = [0i64; 2];
+                for (k, frame) in self.window.iter().enumerate() {
+                    let c = lo[k] as i32 + (((hi[k] as i32 - lo[k] as i32) * frac) >> 15);
+                    acc[0] += c as i64 * frame[0] as i64;
+                    acc[1] += c as i64 * frame[1] as i64;
+                }
+                acc.map(|a| ((a + (1 << 13)) >> 14).clamp(i16::MIN as i64, i16::MAX as i64) as i16)
+            }
+        }
+    }
+}
+
+/// Fills the polyphase table: row `p` holds the taps for an output position
+/// `p / PHASES` of the way from `window[HALF_TAPS - 1]` to `window[HALF_TAPS]`.
+fn fill_table(table: &mut [[i16; TAPS]; PHASES + 1], cutoff: f64) {
+    use core::f64::consts::PI;
+
+    for (p, row) in table.iter_mut().enumerate() {
+        let mut taps = [0f64; TAPS];
+        for (k, tap) in taps.iter_mut().enumerate() {
+            let x = k as f64 - (HALF_TAPS - 1) as f64 - p as f64 / PHASES as f64;
+            let t = PI * cutoff * x;
+            let sinc = if t.abs() < 1e-9 {
+                1.0
+            } else {
+                libm::sin(t) / t
+            };
+            let w = x / HALF_TAPS as f64;
+            let blackman = 0.42 + 0.5 * libm::cos(PI * w) + 0.08 * libm::cos(2.0 * PI * w);
+            *tap = sinc * blackman;
+        }
+
+        // Unity gain at DC, rounding error folded into the largest tap
+        let sum: f64 = taps.iter().sum();
+        let mut total = 0i32;
+        for (tap, out) in taps.iter().zip(row.iter_mut()) {
+            *out = libm::round(tap * ONE as f64 / sum) as i16;
+            total += *out as i32;
+        }
+        let center = (HALF_TAPS - 1) + (p * 2 >= PHASES) as usize;
+        row[center] += (ONE as i32 - total) as i16;
+    }
+}
+
+fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    fn sine(rate: u32, freq: f64, amplitude: f64, frames: usize) -> Vec<Frame> {
+        (0..frames)
+            .map(|i| {
+                let s =
+                    amplitude * (2.0 * core::f64::consts::PI * freq * i as f64 / rate as f64).sin();
+                [s.round() as i16, (-s).round() as i16]
+            })
+            .collect()
+    }
+
+    /// Resamples a whole signal, feeding it in uneven chunks.
+    fn run(resampler: &mut Resampler, input: &[Frame]) -> Vec<Frame> {
+        let mut output = Vec::new();
+        let mut out = [[0i16; 2]; 37];
+        let mut offset = 0;
+        let mut chunk = 1;
+        while offset < input.len() {
+            let end = (offset + chunk).min(input.len());
+            let (consumed, produced) = resampler.process(&input[offset..end], &mut out);
+            output.extend_from_slice(&out[..produced]);
+            offset += consumed;
+            chunk = chunk * 3 % 101 + 1;
+        }
+        loop {
+            let produced = resampler.flush(&mut out);
+            if produced == 0 {
+                break;
+            }
+            output.extend_from_slice(&out[..produced]);
+        }
+        output
+    }
+
+    /// Frequency from the rising zero crossings of the left channel.
+    fn measure_frequency(signal: &[Frame], rate: u32) -> f64 {
+        let mut crossings = Vec::new();
+        for i in 1..signal.len() {
+            let (a, b) = (signal[i - 1][0] as f64, signal[i][0] as f64);
+            if a < 0.0 && b >= 0.0 {
+                crossings.push(i as f64 - 1.0 + a / (a - b));
+            }
+        }
+        let cycles = crossings.len() - 1;
+        cycles as f64 * rate as f64 / (crossings[cycles] - crossings[0])
+    }
+
+    fn rms(signal: &[Frame]) -> f64 {
+        let sum: f64 = signal.iter().map(|f| (f[0] as f64).powi(2)).sum();
+        (sum / signal.len() as f64).sqrt()
+    }
+
+    #[test]
+    fn keeps_the_pitch_of_a_sine() {
+        for quality in [Quality::Linear, Quality::Sinc] {
+            for (from, to, freq) in [
+                (8000, 11025, 440.0),
+                (11025, 8000, 1000.0),
+                (16000, 11025, 2000.0),
+                (44100, 11025, 1500.0),
+                (22050, 48000, 5000.0),
+            ] {
+                let input = sine(from, freq, 12000.0, from as usize / 2);
+                let output = run(&mut Resampler::new(from, to, quality), &input);
+
+                let expected_len = input.len() as f64 * to as f64 / from as f64;
+                assert!((output.len() as f64 - expected_len).abs() < 2.0);
+
+                // Skip the filter's fade in and out
+                let steady = &output[64..output.len() - 64];
+                let measured = measure_frequency(steady, to);
+                assert!(
+                    (measured - freq).abs() < freq * 0.001,
+                    "{quality:?} {from}->{to}: {measured} Hz instead of {freq} Hz"
+                );
+                let gain = rms(steady) / rms(&input);
+                let max_loss = if quality == Quality::Linear {
+                    0.8
+                } else {
+                    0.97
+                };
+                assert!(
+                    gain > max_loss && gain < 1.03,
+                    "{quality:?} {from}->{to}: gain {gain}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn output_is_aligned_with_the_input() {
+        let input = sine(8000, 200.0, 10000.0, 400);
+        let output = run(&mut Resampler::new(8000, 16000, Quality::Sinc), &input);
+        for i in 0..390 {
+            let diff = (output[2 * i][0] - input[i][0]).abs();
+            assert!(
+                diff <= 4,
+                "frame {i}: {} vs {}",
+                output[2 * i][0],
+                input[i][0]
+            );
+        }
+    }
+
+    #[test]
+    fn equal_rates_pass_frames_through() {
+        let input = sine(11025, 700.0, 20000.0, 300);
+        for quality in [Quality::Linear, Quality::Sinc] {
+            let mut resampler = Resampler::new(11025, 11025, quality);
+            assert!(resampler.is_passthrough());
+            assert_eq!(run(&mut resampler, &input), input);
+        }
+    }
+
+    #[test]
+    fn sinc_rejects_what_would_alias() {
+        // 7 kHz does not exist at 11025 Hz: it must be filtered, not folded to 4025 Hz
+        let input = sine(22050, 7000.0, 12000.0, 11025);
+        let linear = run(&mut Resampler::new(22050, 11025, Quality::Linear), &input);
+        let sinc = run(&mut Resampler::new(22050, 11025, Quality::Sinc), &input);
+
+        let linear_level = rms(&linear[64..linear.len() - 64]) / rms(&input);
+        let sinc_level = rms(&sinc[64..sinc.len() - 64]) / rms(&input);
+        assert!(linear_level > 0.3, "linear alias level {linear_level}");
+        assert!(sinc_level < 0.01, "sinc alias level {sinc_level}");
+    }
+
+    #[test]
+    fn full_scale_input_does_not_wrap() {
+        let square: Vec<Frame> = (0..2048)
+            .map(|i| {
+                if i / 64 % 2 == 0 {
+                    [i16::MAX; 2]
+                } else {
+                    [i16::MIN; 2]
+                }
+            })
+            .collect();
+        let output = run(&mut Resampler::new(8000, 11025, Quality::Sinc), &square);
+        for (j, frame) in output.iter().enumerate() {
+            let source = j as f64 * 8000.0 / 11025.0;
+            let edge = (source / 64.0).round() * 64.0;
+            if (source - edge).abs() < 2.0 || source > 2040.0 {
+                continue;
+            }
+            // Ringing overshoots are clipped instead of flipping sign
+            let high = (source as usize) / 64 % 2 == 0;
+            assert_eq!(frame[0] > 0, high, "frame {j}: {}", frame[0]);
+        }
+    }
+
+    #[test]
+    fn reset_restarts_cleanly() {
+        let input = sine(8000, 440.0, 8000.0, 500);
+        let mut resampler = Resampler::new(8000, 11025, Quality::Sinc);
+        let first = run(&mut resampler, &input);
+        resampler.reset();
+        assert_eq!(run(&mut resampler, &input), first);
+    }
+}
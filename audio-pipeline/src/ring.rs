@@ -0,0 +1,330 @@
+//! Gapless streaming into a circular DMA buffer: the I2S plays the ring
+//! without stopping while the audio task refills the part it has played.
+
+use core::future::Future;
+
+use crate::sink::PcmSource;
+
+/// Largest output frame a [`RingWriter`] carries across a segment boundary:
+/// 8 channels of 32 bits.
+const MAX_FRAME: usize = 32;
+
+/// The producer end of a ring the I2S plays continuously.
+pub trait DmaRing {
+    type Error;
+
+    /// Waits until the I2S has played part of the ring, then lets `fill`
+    /// write into the free bytes and returns what it wrote. `fill` may get
+    /// only part of the free space (up to the end of a DMA descriptor or the
+    /// wrap), and nothing is lost if it writes less.
+    fn push_with(
+        &mut self,
+        fill: impl FnOnce(&mut [u8]) -> usize,
+    ) -> impl Future<Output = Result<usize, Self::Error>>;
+
+    /// Whether `error` means the I2S caught up with the producer: it played
+    /// old data, but the ring keeps going.
+    fn is_underrun(error: &Self::Error) -> bool;
+}
+
+/// What a [`RingWriter`] has pushed since it was created.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RingStats {
+    /// Audio bytes from the sources.
+    pub audio: u64,
+    /// Silence pushed while there was nothing to play.
+    pub silence: u64,
+    /// Times the I2S ran out of data.
+    pub underruns: u32,
+}
+
+/// Streams sources into a [`DmaRing`] back to back, so one clip follows the
+/// previous one without a gap, and fills the ring with silence in between.
+pub struct RingWriter<R> {
+    ring: R,
+    frame_size: usize,
+    /// The frame that did not fit before the end of the last segment, and how
+    /// much of it is still to go.
+    frame: [u8; MAX_FRAME],
+    frame_start: usize,
+    stats: RingStats,
+}
+
+impl<R: DmaRing> RingWriter<R> {
+    /// A writer of `frame_size`-byte frames (see
+    /// [`OutputFormat::frame_size`](crate::OutputFormat::frame_size)).
+    pub fn new(ring: R, frame_size: usize) -> Self {
+        assert!(
+            (1..=MAX_FRAME).contains(&frame_size),
+            "frames of 1 to {MAX_FRAME} bytes"
+        );
+        Self {
+            ring,
+            frame_size,
+            frame: [0; MAX_FRAME],
+            frame_start: frame_size,
+            stats: RingStats::default(),
+        }
+    }
+
+    pub fn stats(&self) -> RingStats {
+        self.stats
+    }
+
+    pub fn ring(&self) -> &R {
+        &self.ring
+    }
+
+    /// Pushes `source` into the ring until it ends, and returns the audio
+    /// bytes it gave. The last of them are still queued when this returns:
+    /// keep pushing (another clip or [`RingWriter::silence`]) so they play.
+    pub async fn play(&mut self, source: &mut impl PcmSource) -> Result<u64, R::Error> {
+        let start = self.stats.audio;
+        while self.push(source).await? {}
+        Ok(self.stats.audio - start)
+    }
+
+    /// Pushes the next part of `source` into the free part of the ring, once,
+    /// and returns whether there is more to push. Between pushes the caller
+    /// may drop `source` for another one: a frame split across the end of a
+    /// segment is finished first, so the ring stays frame-aligned.
+    pub async fn push(&mut self, source: &mut impl PcmSource) -> Result<bool, R::Error> {
+        let mut ended = false;
+        let written = self.push_from(source, &mut ended).await?;
+        self.stats.audio += written as u64;
+        Ok(!ended || self.frame_start < self.frame_size)
+    }
+
+    /// Pushes silence into the free part of the ring, once. Looping on it
+    /// keeps the ring from replaying old audio while nothing plays.
+    pub async fn silence(&mut self) -> Result<usize, R::Error> {
+        let written = self.push_from(&mut Silence, &mut false).await?;
+        self.stats.silence += written as u64;
+        Ok(written)
+    }
+
+    /// Pushes silence until an error other than an underrun. Meant to be
+    /// raced against whatever starts the next clip.
+    pub async fn idle(&mut self) -> R::Error {
+        loop {
+            if let Err(error) = self.silence().await {
+                return error;
+            }
+        }
+    }
+
+    /// One push from `source`; an underrun is counted and writes nothing.
+    async fn push_from(
+        &mut self,
+        source: &mut impl PcmSource,
+        ended: &mut bool,
+    ) -> Result<usize, R::Error> {
+        let Self {
+            ring,
+            frame_size,
+            frame,
+            frame_start,
+            stats,
+        } = self;
+        let pushed = ring
+            .push_with(|out| fill(source, out, &mut frame[..*frame_size], frame_start, ended))
+            .await;
+        match pushed {
+            Ok(written) => Ok(written),
+            Err(error) if R::is_underrun(&error) => {
+                stats.underruns += 1;
+                Ok(0)
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// Endless silence, for the ring between clips.
+struct Silence;
+
+impl PcmSource for Silence {
+    fn fill(&mut self, out: &mut [u8]) -> usize {
+        out.fill(0);
+        out.len()
+    }
+}
+
+/// Fills one segment of the ring: the rest of a split frame first, then
+/// whole frames straight from the source, then the start of a frame that
+/// crosses the end of the segment.
+fn fill(
+    source: &mut impl PcmSource,
+    out: &mut [u8],
+    frame: &mut [u8],
+    frame_start: &mut usize,
+    ended: &mut bool,
+) -> usize {
+    let frame_size = frame.len();
+    let rest = &frame[*frame_start..];
+    let mut written = rest.len().min(out.len());
+    out[..written].copy_from_slice(&rest[..written]);
+    *frame_start += written;
+    if *frame_start < frame_size || *ended {
+        return written;
+    }
+
+    let whole = (out.len() - written) / frame_size * frame_size;
+    if whole > 0 {
+        let filled = source.fill(&mut out[written..written + whole]);
+        written += filled;
+        if filled < whole {
+            *ended = true;
+            return written;
+        }
+    }
+
+    let split = out.len() - written;
+    if split > 0 {
+        if source.fill(frame) < frame_size {
+            *ended = true;
+        } else {
+            out[written..].copy_from_slice(&frame[..split]);
+            *frame_start = split;
+            written += split;
+        }
+    }
+    written
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sink::RawSource;
+
+    extern crate std;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+    use std::vec::Vec;
+
+    /// A ring whose I2S side is driven by the test: `free` bytes can be
+    /// pushed, in segments of at most `segment` bytes.
+    struct TestRing {
+        pushed: Vec<u8>,
+        segment: usize,
+        /// Underruns to report before the next pushes succeed.
+        late: u32,
+    }
+
+    impl DmaRing for TestRing {
+        type Error = bool;
+
+        async fn push_with(
+            &mut self,
+            fill: impl FnOnce(&mut [u8]) -> usize,
+        ) -> Result<usize, bool> {
+            if self.late > 0 {
+                self.late -= 1;
+                return Err(true);
+            }
+            let mut segment = std::vec![0xEEu8; self.segment];
+            let written = fill(&mut segment);
+            self.pushed.extend_from_slice(&segment[..written]);
+            Ok(written)
+        }
+
+        fn is_underrun(error: &bool) -> bool {
+            *error
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut context = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    fn writer(segment: usize, frame_size: usize) -> RingWriter<TestRing> {
+        let ring = TestRing {
+            pushed: Vec::new(),
+            segment,
+            late: 0,
+        };
+        RingWriter::new(ring, frame_size)
+    }
+
+    #[test]
+    fn frames_cross_segment_boundaries() {
+        let data: Vec<u8> = (0..=255).collect();
+        // 8-byte frames in 12-byte segments
+        let mut writer = writer(12, 8);
+        let bytes = block_on(writer.play(&mut RawSource(&data[..240]))).unwrap();
+        assert_eq!(bytes, 240);
+        assert_eq!(writer.ring().pushed, data[..240]);
+        assert_eq!(writer.stats().audio, 240);
+    }
+
+    #[test]
+    fn clips_follow_each_other_without_a_gap() {
+        let mut writer = writer(10, 4);
+        // The second clip starts right after the last frame of the first
+        block_on(writer.play(&mut RawSource(&[1; 12]))).unwrap();
+        block_on(writer.play(&mut RawSource(&[2; 8]))).unwrap();
+        let mut expected = std::vec![1; 12];
+        expected.extend_from_slice(&[2; 8]);
+        assert_eq!(writer.ring().pushed, expected);
+
+        assert_eq!(block_on(writer.silence()), Ok(10));
+        assert_eq!(writer.ring().pushed[20..], [0; 10]);
+        assert_eq!(writer.stats().silence, 10);
+    }
+
+    #[test]
+    fn a_source_dropped_mid_frame_keeps_the_ring_aligned() {
+        // The first push ends in the middle of the third 4-byte frame
+        let mut writer = writer(10, 4);
+        assert_eq!(block_on(writer.push(&mut RawSource(&[5; 40]))), Ok(true));
+        assert_eq!(block_on(writer.silence()), Ok(10));
+        let mut expected = std::vec![5; 12];
+        expected.extend_from_slice(&[0; 8]);
+        assert_eq!(writer.ring().pushed, expected);
+        assert_eq!(writer.stats().audio, 10);
+    }
+
+    #[test]
+    fn counts_underruns_and_keeps_going() {
+        let mut writer = writer(16, 4);
+        writer.ring.late = 2;
+        block_on(writer.play(&mut RawSource(&[3; 40]))).unwrap();
+        assert_eq!(writer.ring().pushed, [3; 40]);
+        assert_eq!(writer.stats().underruns, 2);
+
+        writer.ring.late = 1;
+        assert_eq!(block_on(writer.silence()), Ok(0));
+        assert_eq!(writer.stats().underruns, 3);
+    }
+
+    #[test]
+    fn other_errors_stop_the_writer() {
+        struct Broken;
+        impl DmaRing for Broken {
+            type Error = &'static str;
+
+            async fn push_with(
+                &mut self,
+                _: impl FnOnce(&mut [u8]) -> usize,
+            ) -> Result<usize, Self::Error> {
+                Err("descriptor error")
+            }
+
+            fn is_underrun(_: &Self::Error) -> bool {
+                false
+            }
+        }
+        let mut writer = RingWriter::new(Broken, 4);
+        assert_eq!(
+            block_on(writer.play(&mut RawSource(&[0; 8]))),
+            Err("descriptor error")
+        );
+        assert_eq!(block_on(writer.idle()), "descriptor error");
+    }
+}
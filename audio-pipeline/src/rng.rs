@@ -0,0 +1,26 @@
+//! A small pseudo-random generator, for shuffles and noise.
+
+/// Marsaglia's xorshift32: plenty for shuffling songs, or for noise.
+#[derive(Clone, Debug)]
+pub(crate) struct XorShift(u32);
+
+impl XorShift {
+    pub(crate) fn new(seed: u32) -> Self {
+        // Zero would stay zero forever
+        Self(if seed == 0 { 0x9e37_79b9 } else { seed })
+    }
+
+    pub(crate) fn next(&mut self) -> u32 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.0 = x;
+        x
+    }
+
+    /// A number in `0..n`.
+    pub(crate) fn below(&mut self, n: u32) -> u32 {
+        ((u64::from(self.next()) * u64::from(n)) >> 32) as u32
+    }
+}
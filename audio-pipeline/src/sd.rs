@@ -0,0 +1,515 @@
+//! WAV files played straight from a FAT volume opened with `embedded-sdmmc`:
+//! a [`FileStream`] reads one file a block at a time, a [`DirPlayer`] plays
+//! every file of a directory back to back, and a [`WavRecorder`] writes one.
+
+use core::fmt::Debug;
+
+use embedded_sdmmc::{BlockDevice, DirEntry, Directory, File, Mode, ShortFileName, TimeSource};
+use wav_parser::{write_header, Format, Header, HEADER_LEN};
+
+use crate::convert::{encode, Converter, OutputFormat};
+use crate::resample::{Quality, Resampler};
+use crate::sink::PcmSource;
+use crate::Frame;
+
+/// Bytes read from the card at a time: one block, which also gathers the
+/// chunks of the WAV header that playback needs.
+const READ: usize = 512;
+
+/// Frames decoded or resampled per step, as in [`ClipStream`](crate::ClipStream).
+const BLOCK: usize = 64;
+
+type SdError<D> = embedded_sdmmc::Error<<D as BlockDevice>::Error>;
+
+/// Why a file of the directory was not played.
+#[derive(Clone, Debug)]
+pub enum Skip<E: Debug> {
+    /// The name does not end in `.WAV`.
+    NotWav,
+    /// A WAV file whose header can't be read or whose format can't be played.
+    Wav(wav_parser::Error),
+    /// The card or the file system failed.
+    Io(embedded_sdmmc::Error<E>),
+}
+
+/// What a [`DirPlayer`] is doing, for the log.
+#[derive(Clone, Debug)]
+pub enum DirEvent<E: Debug> {
+    Started(ShortFileName),
+    Finished(ShortFileName),
+    Skipped(ShortFileName, Skip<E>),
+    /// Reading failed partway through a file: the rest of it is dropped.
+    Cut(ShortFileName, embedded_sdmmc::Error<E>),
+    /// No file is left to play. Holds the error if the directory itself could
+    /// not be read.
+    Ended(Option<embedded_sdmmc::Error<E>>),
+}
+
+/// Plays the `data` chunk of a WAV file into DMA-sized buffers at a fixed
+/// output rate, like a [`ClipStream`](crate::ClipStream) reading the card
+/// instead of flash. PCM and float files only: IMA ADPCM is [`Skip`]ped.
+pub struct FileStream<
+    'a,
+    D: BlockDevice,
+    T: TimeSource,
+    const MAX_DIRS: usize,
+    const MAX_FILES: usize,
+    const MAX_VOLUMES: usize,
+> {
+    file: File<'a, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES>,
+    /// Bytes of the `data` chunk not read from the card yet.
+    remaining: usize,
+    converter: Converter,
+    output: OutputFormat,
+    resampler: Resampler,
+    /// Bytes read but not decoded yet, a partial frame among them.
+    raw: [u8; READ],
+    raw_start: usize,
+    raw_end: usize,
+    /// Decoded frames the resampler has not taken yet.
+    pending: [Frame; BLOCK],
+    pending_start: usize,
+    pending_end: usize,
+    /// The read that ended the stream early.
+    error: Option<SdError<D>>,
+}
+
+impl<'a, D, T, const MAX_DIRS: usize, const MAX_FILES: usize, const MAX_VOLUMES: usize>
+    FileStream<'a, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES>
+where
+    D: BlockDevice,
+    T: TimeSource,
+{
+    /// Reads the header of `file` and gets ready to play its audio, wherever
+    /// in the file it starts.
+    pub fn new(
+        file: File<'a, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES>,
+        output: OutputFormat,
+        output_rate: u32,
+        quality: Quality,
+    ) -> Result<Self, Skip<D::Error>> {
+        let mut raw = [0; READ];
+        let header = Self::read_header(&file, &mut raw)?;
+        let converter = Converter::new(&header.format, output).map_err(Skip::Wav)?;
+        file.seek_from_start(header.data_offset as u32)
+            .map_err(Skip::Io)?;
+
+        let in_file = (file.length() as usize).saturating_sub(header.data_offset);
+        Ok(Self {
+            file,
+            remaining: header.data_len.min(in_file),
+            converter,
+            output,
+            resampler: Resampler::new(header.format.sample_rate, output_rate, quality),
+            raw,
+            raw_start: 0,
+            raw_end: 0,
+            pending: [[0; 2]; BLOCK],
+            pending_start: 0,
+            pending_end: 0,
+            error: None,
+        })
+    }
+
+    /// Walks the chunks of `file` up to `data`, seeking past the ones playback
+    /// does not need, like a `LIST` of tags or an embedded picture. The RIFF
+    /// header, the first `fmt ` and `fact` and the `data` header are gathered
+    /// in `raw` for [`wav_parser::parse_header`].
+    fn read_header(
+        file: &File<'a, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES>,
+        raw: &mut [u8; READ],
+    ) -> Result<Header, Skip<D::Error>> {
+        let mut len = file.read(&mut raw[..12]).map_err(Skip::Io)?;
+        if len < 12 {
+            return wav_parser::parse_header(&raw[..len]).map_err(Skip::Wav);
+        }
+        // Where the next chunk starts in the file
+        let mut offset = 12;
+        // The first `fmt ` and `fact`
+        let mut gathered = [false; 2];
+        loop {
+            let read = file.read(&mut raw[len..len + 8]).map_err(Skip::Io)?;
+            if read < 8 {
+                len += read;
+                break;
+            }
+            let id = [raw[len], raw[len + 1], raw[len + 2], raw[len + 3]];
+            let size = u32::from_le_bytes([raw[len + 4], raw[len + 5], raw[len + 6], raw[len + 7]]);
+            if &id == b"data" {
+                let mut header = wav_parser::parse_header(&raw[..len + 8]).map_err(Skip::Wav)?;
+                header.data_offset = offset as usize + 8;
+                return Ok(header);
+            }
+
+            let padded = u64::from(size) + u64::from(size & 1);
+            let kind = match &id {
+                b"fmt " => Some(0),
+                b"fact" => Some(1),
+                _ => None,
+            };
+            match kind {
+                // Leaving room for the header of the next chunk
+                Some(kind) if !gathered[kind] && padded <= (READ - len - 16) as u64 => {
+                    gathered[kind] = true;
+                    let body = len + 8..len + 8 + padded as usize;
+                    let read = file.read(&mut raw[body.clone()]).map_err(Skip::Io)?;
+                    len += 8 + read;
+                    if read < body.len() {
+                        break;
+                    }
+                }
+                _ => {
+                    let next = offset + 8 + padded;
+                    if next > u64::from(file.length()) {
+                        return Err(Skip::Wav(wav_parser::Error::TruncatedChunk {
+                            id,
+                            offset: offset as usize,
+                        }));
+                    }
+                    file.seek_from_start(next as u32).map_err(Skip::Io)?;
+                }
+            }
+            offset += 8 + padded;
+        }
+        // Not a WAV file, or one without audio: the parser says which
+        wav_parser::parse_header(&raw[..len]).map_err(Skip::Wav)
+    }
+
+    /// Fills `out` with whole output frames and returns the bytes written.
+    /// Less than `out.len()` means the file has ended, or a read failed:
+    /// see [`take_error`](Self::take_error).
+    pub fn fill(&mut self, out: &mut [u8]) -> usize {
+        let output = self.output;
+        let frame_size = output.frame_size();
+        let mut written = 0;
+        let mut block = [[0i16; 2]; BLOCK];
+
+        loop {
+            let room = ((out.len() - written) / frame_size).min(BLOCK);
+            if room == 0 {
+                return written;
+            }
+
+            if self.pending_start == self.pending_end {
+                self.decode_next();
+            }
+
+            let produced = if self.pending_start < self.pending_end {
+                let pending = &self.pending[self.pending_start..self.pending_end];
+                let (consumed, produced) = self.resampler.process(pending, &mut block[..room]);
+                self.pending_start += consumed;
+                produced
+            } else {
+                match self.resampler.flush(&mut block[..room]) {
+                    0 => return written,
+                    produced => produced,
+                }
+            };
+            written += encode(&block[..produced], output, &mut out[written..]);
+        }
+    }
+
+    /// The read error that cut the file short, if one did.
+    pub fn take_error(&mut self) -> Option<SdError<D>> {
+        self.error.take()
+    }
+
+    /// Decodes the next [`BLOCK`] frames into `pending`, reading the card
+    /// when less than a frame is left.
+    fn decode_next(&mut self) {
+        if self.raw_end - self.raw_start < self.converter.source_frame_size() {
+            self.read_more();
+        }
+        let progress = self
+            .converter
+            .decode(&self.raw[self.raw_start..self.raw_end], &mut self.pending);
+        self.raw_start += progress.consumed;
+        self.pending_start = 0;
+        self.pending_end = progress.written;
+    }
+
+    fn read_more(&mut self) {
+        self.raw.copy_within(self.raw_start..self.raw_end, 0);
+        self.raw_end -= self.raw_start;
+        self.raw_start = 0;
+
+        let wanted = (READ - self.raw_end).min(self.remaining);
+        if wanted == 0 {
+            return;
+        }
+        match self
+            .file
+            .read(&mut self.raw[self.raw_end..self.raw_end + wanted])
+        {
+            // The file is shorter than its FAT entry says
+            Ok(0) => self.remaining = 0,
+            Ok(read) => {
+                self.raw_end += read;
+                self.remaining -= read;
+            }
+            Err(err) => {
+                self.remaining = 0;
+                self.error = Some(err);
+            }
+        }
+    }
+}
+
+impl<D, T, const MAX_DIRS: usize, const MAX_FILES: usize, const MAX_VOLUMES: usize> PcmSource
+    for FileStream<'_, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES>
+where
+    D: BlockDevice,
+    T: TimeSource,
+{
+    fn fill(&mut self, out: &mut [u8]) -> usize {
+        FileStream::fill(self, out)
+    }
+}
+
+/// Plays every WAV file of a directory once, in name order. A file that ends
+/// partway through a buffer is followed by the next one in the same buffer,
+/// so there is no gap between them. Other files are skipped, and every step
+/// is reported to `on_event`.
+pub struct DirPlayer<
+    'a,
+    D: BlockDevice,
+    T: TimeSource,
+    const MAX_DIRS: usize,
+    const MAX_FILES: usize,
+    const MAX_VOLUMES: usize,
+    F,
+> {
+    dir: &'a Directory<'a, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES>,
+    output: OutputFormat,
+    output_rate: u32,
+    quality: Quality,
+    /// The last file tried: the next one is the first name after it.
+    last: Option<ShortFileName>,
+    stream: Option<FileStream<'a, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES>>,
+    ended: bool,
+    on_event: F,
+}
+
+impl<'a, D, T, const MAX_DIRS: usize, const MAX_FILES: usize, const MAX_VOLUMES: usize, F>
+    DirPlayer<'a, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES, F>
+where
+    D: BlockDevice,
+    T: TimeSource,
+    F: FnMut(DirEvent<D::Error>),
+{
+    pub fn new(
+        dir: &'a Directory<'a, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES>,
+        output: OutputFormat,
+        output_rate: u32,
+        quality: Quality,
+        on_event: F,
+    ) -> Self {
+        Self {
+            dir,
+            output,
+            output_rate,
+            quality,
+            last: None,
+            stream: None,
+            ended: false,
+            on_event,
+        }
+    }
+
+    /// The file playing, or the last one tried.
+    pub fn current(&self) -> Option<&ShortFileName> {
+        self.last.as_ref()
+    }
+
+    /// Opens the next file that plays, reporting the ones skipped on the way.
+    fn open_next(&mut self) -> bool {
+        loop {
+            let entry = match next_entry(self.dir, self.last.as_ref()) {
+                Ok(Some(entry)) => entry,
+                Ok(None) => return self.end(None),
+                Err(err) => return self.end(Some(err)),
+            };
+            let name = entry.name;
+            self.last = Some(name.clone());
+            if name.extension() != b"WAV" {
+                (self.on_event)(DirEvent::Skipped(name, Skip::NotWav));
+                continue;
+            }
+
+            let opened = self
+                .dir
+                .open_file_in_dir(&name, Mode::ReadOnly)
+                .map_err(Skip::Io)
+                .and_then(|file| {
+                    FileStream::new(file, self.output, self.output_rate, self.quality)
+                });
+            match opened {
+                Ok(stream) => {
+                    self.stream = Some(stream);
+                    (self.on_event)(DirEvent::Started(name));
+                    return true;
+                }
+                Err(skip) => (self.on_event)(DirEvent::Skipped(name, skip)),
+            }
+        }
+    }
+
+    fn end(&mut self, error: Option<SdError<D>>) -> bool {
+        self.ended = true;
+        (self.on_event)(DirEvent::Ended(error));
+        false
+    }
+}
+
+impl<D, T, const MAX_DIRS: usize, const MAX_FILES: usize, const MAX_VOLUMES: usize, F> PcmSource
+    for DirPlayer<'_, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES, F>
+where
+    D: BlockDevice,
+    T: TimeSource,
+    F: FnMut(DirEvent<D::Error>),
+{
+    fn fill(&mut self, out: &mut [u8]) -> usize {
+        let frame_size = self.output.frame_size();
+        let mut written = 0;
+        while out.len() - written >= frame_size && !self.ended {
+            let Some(stream) = &mut self.stream else {
+                self.open_next();
+                continue;
+            };
+            written += stream.fill(&mut out[written..]);
+            if out.len() - written < frame_size {
+                break;
+            }
+
+            // The file has ended: the next one goes on in the same buffer.
+            // Dropping the stream closes the file.
+            let error = stream.take_error();
+            self.stream = None;
+            let name = self.last.clone().expect("a file was opened");
+            (self.on_event)(match error {
+                Some(err) => DirEvent::Cut(name, err),
+                None => DirEvent::Finished(name),
+            });
+        }
+        written
+    }
+}
+
+/// The first file of `dir` named after `last`, in byte order.
+fn next_entry<D, T, const MAX_DIRS: usize, const MAX_FILES: usize, const MAX_VOLUMES: usize>(
+    dir: &Directory<'_, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES>,
+    last: Option<&ShortFileName>,
+) -> Result<Option<DirEntry>, SdError<D>>
+where
+    D: BlockDevice,
+    T: TimeSource,
+{
+    let mut next: Option<DirEntry> = None;
+    dir.iterate_dir(|entry| {
+        let attributes = entry.attributes;
+        if attributes.is_directory() || attributes.is_volume() || attributes.is_hidden() {
+            return;
+        }
+        let after_last = last.is_none_or(|last| key(&entry.name) > key(last));
+        let before_next = next
+            .as_ref()
+            .is_none_or(|next| key(&entry.name) < key(&next.name));
+        if after_last && before_next {
+            next = Some(entry.clone());
+        }
+    })?;
+    Ok(next)
+}
+
+fn key(name: &ShortFileName) -> (&[u8], &[u8]) {
+    (name.base_name(), name.extension())
+}
+
+/// Writes PCM to a WAV file as it comes, for recording straight to the card.
+///
+/// The header goes first with a length of zero, since the length is only
+/// known at the end: [`finish`](Self::finish) writes it again with the real
+/// sizes. [`sync`](Self::sync) does the same halfway, so a recording cut by a
+/// reset still plays up to the last sync.
+pub struct WavRecorder<
+    'a,
+    D: BlockDevice,
+    T: TimeSource,
+    const MAX_DIRS: usize,
+    const MAX_FILES: usize,
+    const MAX_VOLUMES: usize,
+> {
+    file: File<'a, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES>,
+    format: Format,
+    /// Bytes of audio written after the header.
+    data_len: u32,
+}
+
+impl<'a, D, T, const MAX_DIRS: usize, const MAX_FILES: usize, const MAX_VOLUMES: usize>
+    WavRecorder<'a, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES>
+where
+    D: BlockDevice,
+    T: TimeSource,
+{
+    /// Starts a recording in `format` in an empty `file`, opened with
+    /// [`Mode::ReadWriteCreateOrTruncate`].
+    pub fn new(
+        file: File<'a, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES>,
+        format: Format,
+    ) -> Result<Self, SdError<D>> {
+        file.write(&write_header(&format, 0))?;
+        Ok(Self {
+            file,
+            format,
+            data_len: 0,
+        })
+    }
+
+    /// Bytes of audio recorded so far.
+    pub fn data_len(&self) -> u32 {
+        self.data_len
+    }
+
+    /// Appends the whole frames at the start of `pcm`, which is in the
+    /// recording's format, and returns the bytes taken. Less than the whole
+    /// frames means the file is full: the sizes of a WAV file are 32 bits, so
+    /// it holds a little under 4 GiB of audio.
+    pub fn write(&mut self, pcm: &[u8]) -> Result<usize, SdError<D>> {
+        let frame_size = self.format.block_align.max(1) as usize;
+        let room = (u32::MAX - HEADER_LEN as u32 - 1 - self.data_len) as usize;
+        let len = pcm.len().min(room) / frame_size * frame_size;
+        self.file.write(&pcm[..len])?;
+        self.data_len += len as u32;
+        Ok(len)
+    }
+
+    /// Writes the sizes recorded so far into the header and flushes the file,
+    /// then goes on at the end.
+    pub fn sync(&mut self) -> Result<(), SdError<D>> {
+        self.write_sizes()?;
+        self.file.flush()?;
+        self.file.seek_from_end(0)
+    }
+
+    /// Writes the final sizes and closes the file. Returns the bytes of
+    /// audio recorded.
+    pub fn finish(self) -> Result<u32, SdError<D>> {
+        // Chunks are padded to an even size, which only an odd number of
+        // 8-bit mono frames needs
+        if self.data_len % 2 == 1 {
+            self.file.write(&[0])?;
+        }
+        self.write_sizes()?;
+        self.file.close()?;
+        Ok(self.data_len)
+    }
+
+    fn write_sizes(&self) -> Result<(), SdError<D>> {
+        let mut header = write_header(&self.format, self.data_len);
+        // The RIFF size counts the pad byte too
+        let riff_len = self.file.length() - 8;
+        header[4..8].copy_from_slice(&riff_len.to_le_bytes());
+        self.file.seek_from_start(0)?;
+        self.file.write(&header)
+    }
+}
@@ -0,0 +1,150 @@
+use crate::mp3::Mp3Stream;
+use crate::stream::ClipStream;
+
+/// Somewhere to send DMA-sized buffers of output frames: the I2S peripheral on
+/// the ESP32-C3, memory or a WAV file on the host.
+pub trait AudioSink {
+    type Error;
+
+    /// Plays one full buffer, returning once the sink can take the next one.
+    fn write(&mut self, buffer: &[u8]) -> Result<(), Self::Error>;
+}
+
+impl<S: AudioSink + ?Sized> AudioSink for &mut S {
+    type Error = S::Error;
+
+    fn write(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+        (**self).write(buffer)
+    }
+}
+
+/// Audio already in the output frame layout, produced a buffer at a time.
+pub trait PcmSource {
+    /// Fills the start of `out` and returns the bytes written. Less than
+    /// `out.len()` means the source has ended; 0 means nothing is left.
+    fn fill(&mut self, out: &mut [u8]) -> usize;
+}
+
+impl PcmSource for ClipStream<'_> {
+    fn fill(&mut self, out: &mut [u8]) -> usize {
+        ClipStream::fill(self, out)
+    }
+}
+
+impl PcmSource for Mp3Stream<'_> {
+    fn fill(&mut self, out: &mut [u8]) -> usize {
+        Mp3Stream::fill(self, out)
+    }
+}
+
+/// Bytes sent as they are, for data that is already in the output layout.
+#[derive(Clone, Copy, Debug)]
+pub struct RawSource<'a>(pub &'a [u8]);
+
+impl PcmSource for RawSource<'_> {
+    fn fill(&mut self, out: &mut [u8]) -> usize {
+        let len = out.len().min(self.0.len());
+        out[..len].copy_from_slice(&self.0[..len]);
+        self.0 = &self.0[len..];
+        len
+    }
+}
+
+/// What a [`play`] call sent to the sink.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Played {
+    /// Buffers written, the last one zero-padded.
+    pub buffers: usize,
+    /// Audio bytes written, not counting the padding.
+    pub bytes: usize,
+}
+
+/// Plays `source` to the end through `buffer`: every write is a full
+/// `buffer`, and the tail of the last one is silence.
+pub fn play<S: AudioSink>(
+    source: &mut impl PcmSource,
+    buffer: &mut [u8],
+    sink: &mut S,
+) -> Result<Played, S::Error> {
+    let mut played = Played::default();
+    loop {
+        let written = source.fill(buffer);
+        if written == 0 {
+            return Ok(played);
+        }
+        // Zero-pad so the end of the previous buffer is not played again
+        buffer[written..].fill(0);
+        sink.write(buffer)?;
+
+        played.buffers += 1;
+        played.bytes += written;
+        if written < buffer.len() {
+            return Ok(played);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Remembers the first byte and length of every buffer.
+    #[derive(Default)]
+    struct Recorder {
+        writes: [(u8, usize); 4],
+        count: usize,
+    }
+
+    impl AudioSink for Recorder {
+        type Error = &'static str;
+
+        fn write(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+            let slot = self.writes.get_mut(self.count).ok_or("too many writes")?;
+            *slot = (buffer[0], buffer.len());
+            self.count += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn plays_whole_buffers_and_pads_the_last() {
+        let data = [7u8; 10];
+        let mut buffer = [0xAAu8; 4];
+        let mut sink = Recorder::default();
+        let played = play(&mut RawSource(&data), &mut buffer, &mut sink).unwrap();
+
+        assert_eq!(
+            played,
+            Played {
+                buffers: 3,
+                bytes: 10
+            }
+        );
+        assert_eq!(sink.writes[..3], [(7, 4), (7, 4), (7, 4)]);
+        assert_eq!(buffer, [7, 7, 0, 0]);
+    }
+
+    #[test]
+    fn exact_fit_needs_no_padding_buffer() {
+        let mut sink = Recorder::default();
+        let played = play(&mut RawSource(&[1; 8]), &mut [0u8; 4], &mut sink).unwrap();
+        assert_eq!(
+            played,
+            Played {
+                buffers: 2,
+                bytes: 8
+            }
+        );
+
+        let played = play(&mut RawSource(&[]), &mut [0u8; 4], &mut sink).unwrap();
+        assert_eq!(played, Played::default());
+    }
+
+    #[test]
+    fn sink_errors_stop_playback() {
+        let mut sink = Recorder::default();
+        let result = play(&mut RawSource(&[1; 40]), &mut [0u8; 4], &mut sink);
+        assert_eq!(result, Err("too many writes"));
+        assert_eq!(sink.count, 4);
+    }
+}
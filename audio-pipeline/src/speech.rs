@@ -0,0 +1,655 @@
+//! Spoken prompts put together from recorded words: a phrase template and a
+//! number become a [`Sentence`] ("moisture", "is", "twenty", "three",
+//! "percent"), and [`Speech`] plays the clip of each word in turn with a short
+//! crossfade between them.
+
+use crate::convert::{encode, OutputFormat};
+use crate::sink::PcmSource;
+use crate::Frame;
+
+/// Words a sentence can hold: enough for any `i32` and a template around it.
+pub const MAX_WORDS: usize = 32;
+
+/// Default overlap between two words.
+pub const CROSSFADE_MS: u32 = 15;
+
+/// Longest crossfade, in frames: 23 ms at 11 025 Hz.
+const MAX_FADE: usize = 256;
+
+/// Frames read from a word's clip at a time.
+const CHUNK: usize = 64;
+
+/// A word with a clip of its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Word {
+    Zero,
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Eleven,
+    Twelve,
+    Thirteen,
+    Fourteen,
+    Fifteen,
+    Sixteen,
+    Seventeen,
+    Eighteen,
+    Nineteen,
+    Twenty,
+    Thirty,
+    Forty,
+    Fifty,
+    Sixty,
+    Seventy,
+    Eighty,
+    Ninety,
+    Hundred,
+    Thousand,
+    Million,
+    Billion,
+    Minus,
+    Point,
+    Degrees,
+    Percent,
+    Soil,
+    Moisture,
+    Temperature,
+    Light,
+    Is,
+}
+
+const UNITS: [Word; 20] = [
+    Word::Zero,
+    Word::One,
+    Word::Two,
+    Word::Three,
+    Word::Four,
+    Word::Five,
+    Word::Six,
+    Word::Seven,
+    Word::Eight,
+    Word::Nine,
+    Word::Ten,
+    Word::Eleven,
+    Word::Twelve,
+    Word::Thirteen,
+    Word::Fourteen,
+    Word::Fifteen,
+    Word::Sixteen,
+    Word::Seventeen,
+    Word::Eighteen,
+    Word::Nineteen,
+];
+
+const TENS: [Word; 8] = [
+    Word::Twenty,
+    Word::Thirty,
+    Word::Forty,
+    Word::Fifty,
+    Word::Sixty,
+    Word::Seventy,
+    Word::Eighty,
+    Word::Ninety,
+];
+
+impl Word {
+    /// Every word, in declaration order: the clips to record.
+    pub const ALL: [Word; 41] = [
+        Word::Zero,
+        Word::One,
+        Word::Two,
+        Word::Three,
+        Word::Four,
+        Word::Five,
+        Word::Six,
+        Word::Seven,
+        Word::Eight,
+        Word::Nine,
+        Word::Ten,
+        Word::Eleven,
+        Word::Twelve,
+        Word::Thirteen,
+        Word::Fourteen,
+        Word::Fifteen,
+        Word::Sixteen,
+        Word::Seventeen,
+        Word::Eighteen,
+        Word::Nineteen,
+        Word::Twenty,
+        Word::Thirty,
+        Word::Forty,
+        Word::Fifty,
+        Word::Sixty,
+        Word::Seventy,
+        Word::Eighty,
+        Word::Ninety,
+        Word::Hundred,
+        Word::Thousand,
+        Word::Million,
+        Word::Billion,
+        Word::Minus,
+        Word::Point,
+        Word::Degrees,
+        Word::Percent,
+        Word::Soil,
+        Word::Moisture,
+        Word::Temperature,
+        Word::Light,
+        Word::Is,
+    ];
+
+    /// The word in lower case: `speech_<name>` is the clip to record for it.
+    pub fn name(self) -> &'static str {
+        match self {
+            Word::Zero => "zero",
+            Word::One => "one",
+            Word::Two => "two",
+            Word::Three => "three",
+            Word::Four => "four",
+            Word::Five => "five",
+            Word::Six => "six",
+            Word::Seven => "seven",
+            Word::Eight => "eight",
+            Word::Nine => "nine",
+            Word::Ten => "ten",
+            Word::Eleven => "eleven",
+            Word::Twelve => "twelve",
+            Word::Thirteen => "thirteen",
+            Word::Fourteen => "fourteen",
+            Word::Fifteen => "fifteen",
+            Word::Sixteen => "sixteen",
+            Word::Seventeen => "seventeen",
+            Word::Eighteen => "eighteen",
+            Word::Nineteen => "nineteen",
+            Word::Twenty => "twenty",
+            Word::Thirty => "thirty",
+            Word::Forty => "forty",
+            Word::Fifty => "fifty",
+            Word::Sixty => "sixty",
+            Word::Seventy => "seventy",
+            Word::Eighty => "eighty",
+            Word::Ninety => "ninety",
+            Word::Hundred => "hundred",
+            Word::Thousand => "thousand",
+            Word::Million => "million",
+            Word::Billion => "billion",
+            Word::Minus => "minus",
+            Word::Point => "point",
+            Word::Degrees => "degrees",
+            Word::Percent => "percent",
+            Word::Soil => "soil",
+            Word::Moisture => "moisture",
+            Word::Temperature => "temperature",
+            Word::Light => "light",
+            Word::Is => "is",
+        }
+    }
+}
+
+/// A piece of a phrase template.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    Word(Word),
+    /// Where the number is spoken.
+    Number,
+}
+
+/// A number to speak: `value` with its last `decimals` digits after the
+/// point, so `Number::new(235, 1)` is "twenty three point five".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Number {
+    pub value: i32,
+    pub decimals: u8,
+}
+
+impl Number {
+    pub const fn new(value: i32, decimals: u8) -> Self {
+        Self { value, decimals }
+    }
+
+    /// `value` rounded to `decimals` digits after the point, like a
+    /// temperature from `ntn-resistor-temp`.
+    pub fn from_f64(value: f64, decimals: u8) -> Self {
+        let scaled = value * libm::pow(10.0, decimals as f64);
+        Self::new(libm::round(scaled) as i32, decimals)
+    }
+}
+
+impl From<i32> for Number {
+    fn from(value: i32) -> Self {
+        Self::new(value, 0)
+    }
+}
+
+/// The words of a prompt, in the order they are spoken.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sentence {
+    words: [Word; MAX_WORDS],
+    len: usize,
+}
+
+impl Sentence {
+    pub const fn new() -> Self {
+        Self {
+            words: [Word::Zero; MAX_WORDS],
+            len: 0,
+        }
+    }
+
+    /// `template` with `number` spelled out in place of every `Part::Number`.
+    /// Words past `MAX_WORDS` are dropped.
+    pub fn build(template: &[Part], number: impl Into<Number>) -> Self {
+        let number = number.into();
+        let mut sentence = Self::new();
+        for part in template {
+            match *part {
+                Part::Word(word) => sentence.push(word),
+                Part::Number => sentence.push_number(number),
+            }
+        }
+        sentence
+    }
+
+    pub fn words(&self) -> &[Word] {
+        &self.words[..self.len]
+    }
+
+    pub fn push(&mut self, word: Word) {
+        if self.len < MAX_WORDS {
+            self.words[self.len] = word;
+            self.len += 1;
+        }
+    }
+
+    /// Spells `number` out: "minus", the whole part in American English
+    /// (no "and"), then "point" and the decimals one digit at a time.
+    pub fn push_number(&mut self, number: Number) {
+        if number.value < 0 {
+            self.push(Word::Minus);
+        }
+        let magnitude = number.value.unsigned_abs();
+        let scale = 10u32.pow(number.decimals.min(9) as u32);
+        let (whole, fraction) = (magnitude / scale, magnitude % scale);
+
+        if whole == 0 {
+            self.push(Word::Zero);
+        }
+        for (unit, word) in [
+            (1_000_000_000, Word::Billion),
+            (1_000_000, Word::Million),
+            (1000, Word::Thousand),
+        ] {
+            let group = whole / unit % 1000;
+            if group > 0 {
+                self.push_hundreds(group);
+                self.push(word);
+            }
+        }
+        self.push_hundreds(whole % 1000);
+
+        if number.decimals > 0 {
+            self.push(Word::Point);
+            let mut digits = scale / 10;
+            while digits > 0 {
+                self.push(UNITS[(fraction / digits % 10) as usize]);
+                digits /= 10;
+            }
+        }
+    }
+
+    /// 0 to 999, nothing for 0.
+    fn push_hundreds(&mut self, n: u32) {
+        if n >= 100 {
+            self.push(UNITS[(n / 100) as usize]);
+            self.push(Word::Hundred);
+        }
+        let rest = n % 100;
+        if rest >= 20 {
+            self.push(TENS[(rest / 10 - 2) as usize]);
+            if rest % 10 > 0 {
+                self.push(UNITS[(rest % 10) as usize]);
+            }
+        } else if rest > 0 {
+            self.push(UNITS[rest as usize]);
+        }
+    }
+}
+
+impl Default for Sentence {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Plays a [`Sentence`] as 16-bit stereo frames: the clip `open` gives for
+/// each word, the end of one fading out while the next fades in. A word
+/// without a clip is skipped.
+///
+/// The last frames of each clip are read ahead, so any `PcmSource` works
+/// without knowing its length.
+pub struct Speech<S> {
+    sentence: Sentence,
+    next_word: usize,
+    open: fn(Word) -> Option<S>,
+    current: Option<S>,
+    /// The current clip has no frames left to read.
+    ended: bool,
+    /// Bytes read from the current clip and not yet taken.
+    chunk: [u8; CHUNK * 4],
+    chunk_pos: usize,
+    chunk_len: usize,
+    /// Frames read ahead: the tail to fade out when the clip ends.
+    ahead: [Frame; MAX_FADE + 1],
+    ahead_start: usize,
+    ahead_len: usize,
+    fade: usize,
+    /// Frames of the crossfade under way, and how many are left.
+    blend_len: usize,
+    blend_left: usize,
+}
+
+impl<S: PcmSource> Speech<S> {
+    /// `sentence` at `sample_rate`, with a crossfade of [`CROSSFADE_MS`].
+    pub fn new(sentence: Sentence, open: fn(Word) -> Option<S>, sample_rate: u32) -> Self {
+        let mut speech = Self {
+            sentence,
+            next_word: 0,
+            open,
+            current: None,
+            ended: true,
+            chunk: [0; CHUNK * 4],
+            chunk_pos: 0,
+            chunk_len: 0,
+            ahead: [[0; 2]; MAX_FADE + 1],
+            ahead_start: 0,
+            ahead_len: 0,
+            fade: 0,
+            blend_len: 0,
+            blend_left: 0,
+        };
+        speech.set_crossfade(CROSSFADE_MS, sample_rate);
+        speech
+    }
+
+    /// Overlap between two words, up to about 23 ms at 11 025 Hz. 0 plays
+    /// them back to back.
+    pub fn set_crossfade(&mut self, ms: u32, sample_rate: u32) {
+        let frames = (sample_rate as u64 * ms as u64 / 1000) as usize;
+        self.fade = frames.min(MAX_FADE);
+    }
+
+    pub fn sentence(&self) -> &Sentence {
+        &self.sentence
+    }
+
+    /// The next frame, or `None` once every word has been spoken.
+    fn next_frame(&mut self) -> Option<Frame> {
+        loop {
+            if self.blend_left > 0 {
+                let old = self.pop_ahead().unwrap_or([0; 2]);
+                let new = self.read().unwrap_or([0; 2]);
+                let i = (self.blend_len - self.blend_left) as i32;
+                let len = self.blend_len as i32;
+                self.blend_left -= 1;
+                let mix =
+                    |old: i16, new: i16| ((old as i32 * (len - i) + new as i32 * i) / len) as i16;
+                return Some([mix(old[0], new[0]), mix(old[1], new[1])]);
+            }
+
+            // Keep the tail of the clip read ahead, and one frame more to
+            // know whether it has ended
+            while self.ahead_len <= self.fade {
+                match self.read() {
+                    Some(frame) => self.push_ahead(frame),
+                    None => break,
+                }
+            }
+            if !self.ended {
+                return self.pop_ahead();
+            }
+
+            // The clip has ended: on to the next word that has one
+            if self.open_next() {
+                self.blend_len = self.ahead_len;
+                self.blend_left = self.ahead_len;
+                continue;
+            }
+            return self.pop_ahead();
+        }
+    }
+
+    /// Opens the clip of the next word that has one.
+    fn open_next(&mut self) -> bool {
+        while let Some(&word) = self.sentence.words().get(self.next_word) {
+            self.next_word += 1;
+            if let Some(source) = (self.open)(word) {
+                self.current = Some(source);
+                self.ended = false;
+                return true;
+            }
+        }
+        self.current = None;
+        false
+    }
+
+    /// The next frame of the current clip.
+    fn read(&mut self) -> Option<Frame> {
+        if self.chunk_pos + 4 > self.chunk_len {
+            let source = self.current.as_mut().filter(|_| !self.ended)?;
+            self.chunk_len = source.fill(&mut self.chunk) / 4 * 4;
+            self.chunk_pos = 0;
+            if self.chunk_len == 0 {
+                self.ended = true;
+                return None;
+            }
+        }
+        let b = &self.chunk[self.chunk_pos..self.chunk_pos + 4];
+        self.chunk_pos += 4;
+        Some([
+            i16::from_le_bytes([b[0], b[1]]),
+            i16::from_le_bytes([b[2], b[3]]),
+        ])
+    }
+
+    fn push_ahead(&mut self, frame: Frame) {
+        self.ahead[(self.ahead_start + self.ahead_len) % (MAX_FADE + 1)] = frame;
+        self.ahead_len += 1;
+    }
+
+    fn pop_ahead(&mut self) -> Option<Frame> {
+        if self.ahead_len == 0 {
+            return None;
+        }
+        let frame = self.ahead[self.ahead_start];
+        self.ahead_start = (self.ahead_start + 1) % (MAX_FADE + 1);
+        self.ahead_len -= 1;
+        Some(frame)
+    }
+}
+
+impl<S: PcmSource> PcmSource for Speech<S> {
+    fn fill(&mut self, out: &mut [u8]) -> usize {
+        let output = OutputFormat::STEREO_16;
+        let mut written = 0;
+        let mut block = [[0i16; 2]; CHUNK];
+        loop {
+            let room = ((out.len() - written) / output.frame_size()).min(CHUNK);
+            let mut produced = 0;
+            while produced < room {
+                match self.next_frame() {
+                    Some(frame) => block[produced] = frame,
+                    None => break,
+                }
+                produced += 1;
+            }
+            written += encode(&block[..produced], output, &mut out[written..]);
+            if produced < CHUNK {
+                return written;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sink::RawSource;
+    use Word::*;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    fn spell(value: i32, decimals: u8) -> Vec<Word> {
+        let mut sentence = Sentence::new();
+        sentence.push_number(Number::new(value, decimals));
+        sentence.words().to_vec()
+    }
+
+    #[test]
+    fn every_word_is_listed_once() {
+        assert!(Word::ALL
+            .iter()
+            .enumerate()
+            .all(|(i, &word)| word as usize == i));
+        assert_eq!(Word::ALL.len(), Is as usize + 1);
+        let names: std::collections::BTreeSet<_> = Word::ALL.iter().map(|w| w.name()).collect();
+        assert_eq!(names.len(), Word::ALL.len());
+    }
+
+    #[test]
+    fn numbers_are_spelled_out() {
+        assert_eq!(spell(0, 0), [Zero]);
+        assert_eq!(spell(7, 0), [Seven]);
+        assert_eq!(spell(13, 0), [Thirteen]);
+        assert_eq!(spell(20, 0), [Twenty]);
+        assert_eq!(spell(23, 0), [Twenty, Three]);
+        assert_eq!(spell(100, 0), [One, Hundred]);
+        assert_eq!(spell(105, 0), [One, Hundred, Five]);
+        assert_eq!(spell(342, 0), [Three, Hundred, Forty, Two]);
+        assert_eq!(spell(2024, 0), [Two, Thousand, Twenty, Four]);
+        assert_eq!(spell(1_000_000, 0), [One, Million]);
+        assert_eq!(spell(-5, 0), [Minus, Five]);
+        assert_eq!(
+            spell(i32::MIN, 0),
+            [
+                Minus, Two, Billion, One, Hundred, Forty, Seven, Million, Four, Hundred, Eighty,
+                Three, Thousand, Six, Hundred, Forty, Eight
+            ]
+        );
+    }
+
+    #[test]
+    fn decimals_are_read_digit_by_digit() {
+        assert_eq!(spell(235, 1), [Twenty, Three, Point, Five]);
+        assert_eq!(spell(5, 2), [Zero, Point, Zero, Five]);
+        assert_eq!(spell(-1250, 2), [Minus, Twelve, Point, Five, Zero]);
+        // A temperature from the thermistor, rounded
+        assert_eq!(Number::from_f64(23.46, 1), Number::new(235, 1));
+        assert_eq!(Number::from_f64(-0.04, 1), Number::new(0, 1));
+    }
+
+    #[test]
+    fn templates_put_the_number_in_place() {
+        let moisture = [
+            Part::Word(Soil),
+            Part::Word(Moisture),
+            Part::Word(Is),
+            Part::Number,
+            Part::Word(Percent),
+        ];
+        assert_eq!(
+            Sentence::build(&moisture, 42).words(),
+            [Soil, Moisture, Is, Forty, Two, Percent]
+        );
+        let temperature = [Part::Word(Temperature), Part::Number, Part::Word(Degrees)];
+        assert_eq!(
+            Sentence::build(&temperature, Number::from_f64(21.0, 0)).words(),
+            [Temperature, Twenty, One, Degrees]
+        );
+
+        // Too long: cut at MAX_WORDS
+        let mut long = Sentence::new();
+        for _ in 0..MAX_WORDS + 5 {
+            long.push(Light);
+        }
+        assert_eq!(long.words().len(), MAX_WORDS);
+        assert_eq!(Sentence::build(&[], 3).words(), []);
+    }
+
+    /// 400 frames of a constant level for each word, none for `Is`.
+    fn clip(word: Word) -> Option<RawSource<'static>> {
+        static ONE: [u8; 1600] = constant(1000);
+        static TWO: [u8; 1600] = constant(3000);
+        match word {
+            One => Some(RawSource(&ONE)),
+            Two => Some(RawSource(&TWO)),
+            _ => None,
+        }
+    }
+
+    const fn constant(level: i16) -> [u8; 1600] {
+        let bytes = level.to_le_bytes();
+        let mut out = [0; 1600];
+        let mut i = 0;
+        while i < 1600 {
+            out[i] = bytes[i % 2];
+            i += 1;
+        }
+        out
+    }
+
+    fn left(speech: &mut Speech<RawSource<'static>>) -> Vec<i16> {
+        let mut out = [0u8; 8000];
+        let written = speech.fill(&mut out);
+        out[..written]
+            .chunks_exact(4)
+            .map(|frame| i16::from_le_bytes([frame[0], frame[1]]))
+            .collect()
+    }
+
+    #[test]
+    fn words_crossfade_into_each_other() {
+        let mut sentence = Sentence::new();
+        for word in [One, Is, Two] {
+            sentence.push(word);
+        }
+        // 10 ms at 10 kHz: 100 frames of overlap, and `Is` is skipped
+        let mut speech = Speech::new(sentence, clip, 10_000);
+        speech.set_crossfade(10, 10_000);
+        let frames = left(&mut speech);
+        assert_eq!(frames.len(), 800 - 100);
+
+        assert!(frames[..300].iter().all(|&s| s == 1000));
+        // A linear ramp from one level to the other
+        for (i, &s) in frames[300..400].iter().enumerate() {
+            assert_eq!(s as i32, 1000 + 2000 * i as i32 / 100, "{i}");
+        }
+        assert!(frames[400..].iter().all(|&s| s == 3000));
+        assert_eq!(left(&mut speech), []);
+    }
+
+    #[test]
+    fn without_crossfade_words_follow_each_other() {
+        let mut sentence = Sentence::new();
+        for word in [Two, One, Five] {
+            sentence.push(word);
+        }
+        let mut speech = Speech::new(sentence, clip, 10_000);
+        speech.set_crossfade(0, 10_000);
+        let frames = left(&mut speech);
+        assert_eq!(frames.len(), 800);
+        assert!(frames[..400].iter().all(|&s| s == 3000));
+        assert!(frames[400..].iter().all(|&s| s == 1000));
+
+        // Nothing to say, nothing played
+        let mut silent = Speech::new(Sentence::build(&[Part::Word(Is)], 0), clip, 10_000);
+        assert_eq!(left(&mut silent), []);
+    }
+}
@@ -0,0 +1,578 @@
+//! Sound level meter for a microphone: the A-weighted level in dB SPL with
+//! the Fast time weighting, Leq over fixed windows, and loud sounds and claps
+//! reported as events.
+//!
+//! Samples come in as `i32` at full scale, like the 24-bit words of an
+//! INMP441 in 32-bit I2S slots. [`adc_sample`] scales an ADC reading the same
+//! way. Levels are in tenths of dB, like the rest of the crate.
+
+use core::f64::consts::PI;
+
+/// Sensitivity of an INMP441: a 94 dB SPL tone peaks 26 dB below full scale.
+/// In tenths of dB, like every level here.
+pub const INMP441_SENSITIVITY: i16 = -260;
+
+/// Time constant of the Fast time weighting of sound level meters.
+pub const FAST_MS: u32 = 125;
+
+/// Level above which a sound is `Loud` by default: 85 dB(A), where hearing
+/// protection starts.
+pub const LOUD_DB: i16 = 850;
+
+/// Level a clap has to reach by default, in tenths of dB(A) SPL.
+pub const CLAP_DB: i16 = 650;
+
+/// Blocks the levels are checked in: Leq windows are rounded to it.
+pub const BLOCK_MS: u32 = 5;
+
+/// A `Loud` sound is `Quiet` again 3 dB below the threshold, so a level near
+/// it does not flicker.
+const HYSTERESIS_DB: i16 = 30;
+
+/// A clap rises at least 15 dB above the background, then falls 10 dB from
+/// its peak within 100 ms. Another one can follow 150 ms later.
+const CLAP_RISE: f32 = 31.62;
+const CLAP_FALL: f32 = 0.1;
+const CLAP_MAX_MS: u32 = 100;
+const CLAP_DEAF_MS: u32 = 150;
+
+/// Time constants of the background level claps stand out from: it rises
+/// slowly, so a clap does not raise it, and falls quickly after a loud sound.
+const BACKGROUND_RISE_MS: u32 = 1000;
+const BACKGROUND_FALL_MS: u32 = 100;
+
+/// Corner frequencies of the A-weighting curve (IEC 61672), in Hz.
+const A_POLES: [f64; 4] = [20.598_997, 107.652_65, 737.862_23, 12_194.217];
+
+/// What a [`SoundMeter`] noticed. Levels are in tenths of dB(A) SPL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoundEvent {
+    /// The equivalent level of the window lasting `window_ms` that just ended.
+    Leq { window_ms: u32, db: i16 },
+    /// The Fast level went above the loud threshold.
+    Loud { db: i16 },
+    /// The Fast level fell back below the loud threshold, `ms` after `Loud`.
+    Quiet { ms: u32 },
+    /// A short, sharp sound: a clap, a knock, a snap. `db` is its peak.
+    Clap { db: i16 },
+}
+
+/// The A-weighting filter: the analog curve through the bilinear transform,
+/// as six first-order sections, with 0 dB at 1 kHz. Corners below 40% of
+/// the sample rate are prewarped, so they land on the right frequency.
+#[derive(Clone, Debug)]
+pub struct AWeighting {
+    sections: [Section; 6],
+    gain: f32,
+    sample_rate: u32,
+}
+
+/// `y = b0 x + b1 x[-1] - a1 y[-1]`.
+#[derive(Clone, Copy, Debug, Default)]
+struct Section {
+    b0: f32,
+    b1: f32,
+    a1: f32,
+    x1: f32,
+    y1: f32,
+}
+
+impl Section {
+    /// `s / (s + w)` for a corner at `hz`.
+    fn high_pass(hz: f64, sample_rate: f64) -> Self {
+        let (k, w) = bilinear(hz, sample_rate);
+        Self {
+            b0: (k / (k + w)) as f32,
+            b1: (-k / (k + w)) as f32,
+            a1: ((w - k) / (k + w)) as f32,
+            ..Self::default()
+        }
+    }
+
+    /// `w / (s + w)` for a corner at `hz`.
+    fn low_pass(hz: f64, sample_rate: f64) -> Self {
+        let (k, w) = bilinear(hz, sample_rate);
+        Self {
+            b0: (w / (k + w)) as f32,
+            b1: (w / (k + w)) as f32,
+            a1: ((w - k) / (k + w)) as f32,
+            ..Self::default()
+        }
+    }
+
+    fn next(&mut self, x: f32) -> f32 {
+        let y = self.b0 * x + self.b1 * self.x1 - self.a1 * self.y1;
+        self.x1 = x;
+        self.y1 = y;
+        y
+    }
+
+    /// Gain at `theta` radians per sample.
+    fn response(&self, theta: f64) -> f64 {
+        let (cos, sin) = (libm::cos(theta), libm::sin(theta));
+        let (b0, b1, a1) = (self.b0 as f64, self.b1 as f64, self.a1 as f64);
+        let num = libm::hypot(b0 + b1 * cos, -b1 * sin);
+        let den = libm::hypot(1.0 + a1 * cos, -a1 * sin);
+        num / den
+    }
+}
+
+/// `2 fs` and the angular frequency of a corner at `hz`, prewarped when it
+/// is low enough.
+fn bilinear(hz: f64, sample_rate: f64) -> (f64, f64) {
+    let k = 2.0 * sample_rate;
+    let w = if hz < 0.4 * sample_rate {
+        k * libm::tan(PI * hz / sample_rate)
+    } else {
+        2.0 * PI * hz
+    };
+    (k, w)
+}
+
+impl AWeighting {
+    pub fn new(sample_rate: u32) -> Self {
+        let sample_rate = sample_rate.max(1);
+        let rate = sample_rate as f64;
+        let [f1, f2, f3, f4] = A_POLES;
+        let mut filter = Self {
+            sections: [
+                Section::high_pass(f1, rate),
+                Section::high_pass(f1, rate),
+                Section::high_pass(f2, rate),
+                Section::high_pass(f3, rate),
+                Section::low_pass(f4, rate),
+                Section::low_pass(f4, rate),
+            ],
+            gain: 1.0,
+            sample_rate,
+        };
+        let at_1k = filter.response(1000.0);
+        filter.gain = (1.0 / at_1k) as f32;
+        filter
+    }
+
+    /// Gain of the filter at `hz`, in dB.
+    pub fn gain_db(&self, hz: f32) -> f32 {
+        let gain = self.response(hz as f64) * self.gain as f64;
+        (20.0 * libm::log10(gain)) as f32
+    }
+
+    pub fn next(&mut self, x: f32) -> f32 {
+        let y = self
+            .sections
+            .iter_mut()
+            .fold(x, |x, section| section.next(x));
+        y * self.gain
+    }
+
+    fn response(&self, hz: f64) -> f64 {
+        let theta = 2.0 * PI * hz / self.sample_rate as f64;
+        self.sections
+            .iter()
+            .map(|section| section.response(theta))
+            .product()
+    }
+}
+
+/// Where the clap detector is.
+#[derive(Clone, Copy, Debug)]
+enum Clap {
+    Listening,
+    /// Risen above the background `blocks` ago, `peak` the loudest block.
+    Burst {
+        peak: f32,
+        blocks: u32,
+    },
+    /// Too long for a clap: waits for the background to catch up.
+    Sustained,
+    /// Just heard one: ignores the next `blocks`, the echo of the room.
+    Deaf {
+        blocks: u32,
+    },
+}
+
+/// One Leq window: the sum of the squares and the blocks it lasted so far.
+#[derive(Clone, Copy, Debug)]
+struct Window {
+    ms: u32,
+    blocks: u32,
+    count: u32,
+    sum: f64,
+}
+
+/// Measures a stream of microphone samples in blocks of [`BLOCK_MS`], over
+/// `W` Leq windows.
+///
+/// Mean squares are relative to full scale, so a full-scale sine is 0.5; the
+/// sensitivity turns them into dB SPL.
+#[derive(Clone, Debug)]
+pub struct SoundMeter<const W: usize> {
+    weighting: AWeighting,
+    sample_rate: u32,
+    /// dB SPL of a full-scale sine.
+    full_scale_db: f32,
+    /// A-weighted mean square with the Fast time weighting.
+    fast: f32,
+    fast_keep: f32,
+    block_len: u32,
+    block_count: u32,
+    block_sum: f32,
+    windows: [Window; W],
+    loud_db: i16,
+    /// Blocks since `Loud`, while it is.
+    loud_blocks: Option<u32>,
+    clap_db: i16,
+    clap: Clap,
+    background: f32,
+    background_rise: f32,
+    background_fall: f32,
+}
+
+impl<const W: usize> SoundMeter<W> {
+    /// A meter for a microphone peaking `sensitivity` tenths of dB below full
+    /// scale with a 94 dB SPL tone (see [`INMP441_SENSITIVITY`]). Each of
+    /// `windows_ms` gets a `Leq` event every time it ends.
+    pub fn new(sample_rate: u32, sensitivity: i16, windows_ms: [u32; W]) -> Self {
+        let sample_rate = sample_rate.max(1);
+        let block_len = (sample_rate * BLOCK_MS / 1000).max(1);
+        let blocks_per_s = sample_rate as f32 / block_len as f32;
+        Self {
+            weighting: AWeighting::new(sample_rate),
+            sample_rate,
+            full_scale_db: 94.0 - sensitivity as f32 / 10.0,
+            fast: 0.0,
+            fast_keep: libm::expf(-1000.0 / (FAST_MS as f32 * sample_rate as f32)),
+            block_len,
+            block_count: 0,
+            block_sum: 0.0,
+            windows: windows_ms.map(|ms| Window {
+                ms,
+                blocks: ms.div_ceil(BLOCK_MS).max(1),
+                count: 0,
+                sum: 0.0,
+            }),
+            loud_db: LOUD_DB,
+            loud_blocks: None,
+            clap_db: CLAP_DB,
+            clap: Clap::Listening,
+            background: 0.0,
+            background_rise: libm::expf(-1000.0 / (BACKGROUND_RISE_MS as f32 * blocks_per_s)),
+            background_fall: libm::expf(-1000.0 / (BACKGROUND_FALL_MS as f32 * blocks_per_s)),
+        }
+    }
+
+    /// Level above which a sound is `Loud`, in tenths of dB(A) SPL.
+    pub fn set_loud_threshold(&mut self, db: i16) {
+        self.loud_db = db;
+    }
+
+    /// Level a clap has to reach, in tenths of dB(A) SPL.
+    pub fn set_clap_threshold(&mut self, db: i16) {
+        self.clap_db = db;
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// The A-weighted level with the Fast time weighting, in tenths of dB
+    /// SPL.
+    pub fn level(&self) -> i16 {
+        self.to_db(self.fast)
+    }
+
+    /// Measures `samples` and reports what happened in them through `emit`.
+    /// Blocks go on across calls, so any block size gives the same events.
+    pub fn process(&mut self, samples: &[i32], emit: &mut impl FnMut(SoundEvent)) {
+        for &sample in samples {
+            let x = self.weighting.next(sample as f32 / 2_147_483_648.0);
+            let square = x * x;
+            self.fast = square + (self.fast - square) * self.fast_keep;
+            self.block_sum += square;
+            self.block_count += 1;
+            if self.block_count == self.block_len {
+                let mean = self.block_sum / self.block_len as f32;
+                self.block_count = 0;
+                self.block_sum = 0.0;
+                self.end_block(mean, emit);
+            }
+        }
+    }
+
+    fn end_block(&mut self, mean: f32, emit: &mut impl FnMut(SoundEvent)) {
+        for window in &mut self.windows {
+            window.sum += mean as f64;
+            window.count += 1;
+            if window.count == window.blocks {
+                let leq = (window.sum / window.blocks as f64) as f32;
+                window.count = 0;
+                window.sum = 0.0;
+                emit(SoundEvent::Leq {
+                    window_ms: window.ms,
+                    db: to_db(leq, self.full_scale_db),
+                });
+            }
+        }
+
+        let level = self.level();
+        match self.loud_blocks {
+            None if level >= self.loud_db => {
+                self.loud_blocks = Some(0);
+                emit(SoundEvent::Loud { db: level });
+            }
+            Some(blocks) if level < self.loud_db - HYSTERESIS_DB => {
+                self.loud_blocks = None;
+                emit(SoundEvent::Quiet {
+                    ms: (blocks + 1) * BLOCK_MS,
+                });
+            }
+            Some(blocks) => self.loud_blocks = Some(blocks + 1),
+            None => {}
+        }
+
+        self.detect_clap(mean, emit);
+    }
+
+    fn detect_clap(&mut self, mean: f32, emit: &mut impl FnMut(SoundEvent)) {
+        let mut follow = false;
+        self.clap = match self.clap {
+            Clap::Listening => {
+                if mean > self.background * CLAP_RISE && self.to_db(mean) >= self.clap_db {
+                    Clap::Burst {
+                        peak: mean,
+                        blocks: 1,
+                    }
+                } else {
+                    follow = true;
+                    Clap::Listening
+                }
+            }
+            Clap::Burst { peak, blocks } => {
+                let peak = peak.max(mean);
+                if mean < peak * CLAP_FALL {
+                    emit(SoundEvent::Clap {
+                        db: self.to_db(peak),
+                    });
+                    Clap::Deaf {
+                        blocks: CLAP_DEAF_MS / BLOCK_MS,
+                    }
+                } else if blocks * BLOCK_MS >= CLAP_MAX_MS {
+                    follow = true;
+                    Clap::Sustained
+                } else {
+                    Clap::Burst {
+                        peak,
+                        blocks: blocks + 1,
+                    }
+                }
+            }
+            Clap::Sustained => {
+                follow = true;
+                if mean > self.background * CLAP_RISE {
+                    Clap::Sustained
+                } else {
+                    Clap::Listening
+                }
+            }
+            Clap::Deaf { blocks: 0 } => Clap::Listening,
+            Clap::Deaf { blocks } => Clap::Deaf { blocks: blocks - 1 },
+        };
+        if follow {
+            let keep = if mean > self.background {
+                self.background_rise
+            } else {
+                self.background_fall
+            };
+            self.background = mean + (self.background - mean) * keep;
+        }
+    }
+
+    fn to_db(&self, mean_square: f32) -> i16 {
+        to_db(mean_square, self.full_scale_db)
+    }
+}
+
+/// A mean square relative to full scale in tenths of dB SPL, never below 0.
+fn to_db(mean_square: f32, full_scale_db: f32) -> i16 {
+    if mean_square <= 0.0 {
+        return 0;
+    }
+    let db = 10.0 * libm::log10f(2.0 * mean_square) + full_scale_db;
+    libm::roundf(db * 10.0).clamp(0.0, i16::MAX as f32) as i16
+}
+
+/// A 12-bit ADC reading of a microphone biased at mid-scale, as a full-scale
+/// `i32` sample. The A-weighting removes what is left of the bias.
+pub fn adc_sample(raw: u16) -> i32 {
+    (raw.min(4095) as i32 - 2048) << 20
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::XorShift;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    const RATE: u32 = 16_000;
+
+    /// Peak of a tone at `db` SPL on an INMP441, as a fraction of full scale.
+    fn amplitude(db: f32) -> f32 {
+        libm::powf(10.0, (db - 94.0 + INMP441_SENSITIVITY as f32 / 10.0) / 20.0)
+    }
+
+    fn tone(hz: f32, db: f32, ms: u32) -> Vec<i32> {
+        let peak = amplitude(db) * i32::MAX as f32;
+        (0..RATE * ms / 1000)
+            .map(|i| {
+                let phase = 2.0 * core::f32::consts::PI * hz * i as f32 / RATE as f32;
+                (peak * libm::sinf(phase)) as i32
+            })
+            .collect()
+    }
+
+    /// White noise with the mean square of a sine at `db` SPL.
+    fn noise(rng: &mut XorShift, db: f32, ms: u32) -> Vec<i32> {
+        // A uniform noise's RMS is its peak over sqrt(3), a sine's over sqrt(2)
+        let peak = amplitude(db) * libm::sqrtf(1.5) * i32::MAX as f32;
+        (0..RATE * ms / 1000)
+            .map(|_| {
+                let x = rng.next() as f32 / u32::MAX as f32 * 2.0 - 1.0;
+                (peak * x) as i32
+            })
+            .collect()
+    }
+
+    fn events<const W: usize>(meter: &mut SoundMeter<W>, samples: &[i32]) -> Vec<SoundEvent> {
+        let mut events = Vec::new();
+        meter.process(samples, &mut |event| events.push(event));
+        events
+    }
+
+    #[test]
+    fn a_weighting_follows_the_standard_curve() {
+        // IEC 61672 values, at the two rates the projects use
+        let curve = [
+            (31.5, -39.4),
+            (100.0, -19.1),
+            (1000.0, 0.0),
+            (2000.0, 1.2),
+            (4000.0, 1.0),
+        ];
+        for rate in [16_000, 48_000] {
+            let filter = AWeighting::new(rate);
+            for (hz, db) in curve {
+                let gain = filter.gain_db(hz);
+                assert!((gain - db).abs() < 0.6, "{hz} Hz at {rate}: {gain}");
+            }
+        }
+    }
+
+    #[test]
+    fn a_calibration_tone_reads_94_db() {
+        let mut meter = SoundMeter::new(RATE, INMP441_SENSITIVITY, []);
+        meter.process(&tone(1000.0, 94.0, 1000), &mut |_| {});
+        assert!((meter.level() - 940).abs() <= 3, "{}", meter.level());
+
+        // The same tone at 100 Hz is 19 dB quieter to the ear
+        let mut meter = SoundMeter::new(RATE, INMP441_SENSITIVITY, []);
+        meter.process(&tone(100.0, 94.0, 1000), &mut |_| {});
+        assert!((meter.level() - 749).abs() <= 5, "{}", meter.level());
+
+        // An ADC reading at mid-scale is silence
+        assert_eq!(adc_sample(2048), 0);
+        assert_eq!(adc_sample(4095), 2047 << 20);
+    }
+
+    #[test]
+    fn leq_of_each_window() {
+        let mut meter = SoundMeter::new(RATE, INMP441_SENSITIVITY, [1000, 2000]);
+        meter.set_loud_threshold(i16::MAX);
+        let mut samples = tone(1000.0, 80.0, 1000);
+        samples.extend(tone(1000.0, 60.0, 1000));
+
+        // In odd blocks, to show they do not matter
+        let mut events = Vec::new();
+        for block in samples.chunks(333) {
+            meter.process(block, &mut |event| events.push(event));
+        }
+        let leqs: Vec<(u32, i16)> = events
+            .iter()
+            .filter_map(|event| match *event {
+                SoundEvent::Leq { window_ms, db } => Some((window_ms, db)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(leqs.len(), 3, "{events:?}");
+        assert_eq!(leqs[0].0, 1000);
+        assert!((leqs[0].1 - 800).abs() <= 3, "{leqs:?}");
+        assert_eq!(leqs[1].0, 1000);
+        assert!((leqs[1].1 - 600).abs() <= 5, "{leqs:?}");
+        // Energy, not decibels, is averaged: 20 dB quieter halves it
+        assert_eq!(leqs[2].0, 2000);
+        assert!((leqs[2].1 - 770).abs() <= 3, "{leqs:?}");
+    }
+
+    #[test]
+    fn loud_then_quiet_with_hysteresis() {
+        let mut meter = SoundMeter::new(RATE, INMP441_SENSITIVITY, []);
+        meter.set_clap_threshold(i16::MAX);
+        let mut samples = tone(1000.0, 70.0, 500);
+        samples.extend(tone(1000.0, 90.0, 1000));
+        // Just below the threshold, above the hysteresis: still loud
+        samples.extend(tone(1000.0, 84.0, 500));
+        samples.extend(tone(1000.0, 70.0, 500));
+
+        let events = events(&mut meter, &samples);
+        assert_eq!(events.len(), 2, "{events:?}");
+        let SoundEvent::Loud { db } = events[0] else {
+            panic!("{events:?}");
+        };
+        assert!((850..=900).contains(&db), "{db}");
+        let SoundEvent::Quiet { ms } = events[1] else {
+            panic!("{events:?}");
+        };
+        // Loud from the rise to the 70 dB tone, plus the Fast decay
+        assert!((1450..1650).contains(&ms), "{ms}");
+    }
+
+    #[test]
+    fn a_clap_stands_out_from_the_background() {
+        let mut rng = XorShift::new(7);
+        let mut meter = SoundMeter::new(RATE, INMP441_SENSITIVITY, []);
+        meter.set_loud_threshold(i16::MAX);
+        let mut samples = noise(&mut rng, 45.0, 1000);
+        samples.extend(noise(&mut rng, 95.0, 10));
+        samples.extend(noise(&mut rng, 45.0, 1000));
+        // Two claps in quick succession
+        samples.extend(noise(&mut rng, 90.0, 15));
+        samples.extend(noise(&mut rng, 45.0, 300));
+        samples.extend(noise(&mut rng, 90.0, 15));
+        samples.extend(noise(&mut rng, 45.0, 500));
+
+        let events = events(&mut meter, &samples);
+        assert_eq!(events.len(), 3, "{events:?}");
+        for event in &events {
+            let SoundEvent::Clap { db } = *event else {
+                panic!("{events:?}");
+            };
+            assert!(db > 800, "{events:?}");
+        }
+    }
+
+    #[test]
+    fn sustained_or_quiet_sounds_are_no_claps() {
+        let mut rng = XorShift::new(7);
+        let mut meter = SoundMeter::new(RATE, INMP441_SENSITIVITY, []);
+        meter.set_loud_threshold(i16::MAX);
+        let mut samples = noise(&mut rng, 45.0, 1000);
+        // A door left to bang for half a second
+        samples.extend(noise(&mut rng, 95.0, 500));
+        samples.extend(noise(&mut rng, 45.0, 1000));
+        // A sharp sound below the clap threshold
+        samples.extend(noise(&mut rng, 60.0, 10));
+        samples.extend(noise(&mut rng, 45.0, 500));
+        assert_eq!(events(&mut meter, &samples), []);
+    }
+}
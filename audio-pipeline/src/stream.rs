@@ -0,0 +1,512 @@
+use wav_parser::{Codec, Error, LoopKind, Wav};
+
+use crate::convert::{encode, Converter, OutputFormat};
+use crate::resample::{Quality, Resampler};
+use crate::Frame;
+
+/// Frames decoded or resampled per step. Small enough to live on the stack of
+/// an embassy task.
+const BLOCK: usize = 64;
+
+/// Turns the clip's `data` chunk into [`Frame`]s.
+enum Source {
+    Pcm(Converter),
+    /// IMA ADPCM, decoded block by block.
+    Adpcm(ima_adpcm::Decoder),
+}
+
+/// Frames of a clip played over and over, `end` excluded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LoopRegion {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl LoopRegion {
+    /// The first forward loop of the clip's `smpl` chunk. Ping-pong and
+    /// backward loops play once, as if they were not there.
+    pub fn from_wav(wav: &Wav) -> Option<Self> {
+        let sample_loop = wav
+            .sampler?
+            .loops()
+            .find(|sample_loop| sample_loop.kind == LoopKind::Forward)?;
+        Some(Self {
+            start: sample_loop.start,
+            end: sample_loop.end.saturating_add(1),
+        })
+    }
+}
+
+/// Plays a parsed WAV clip into DMA-sized buffers at a fixed output rate:
+/// decode to [`Frame`]s, resample, then encode to the I2S layout.
+///
+/// With a [`LoopRegion`] it goes back to the loop start every time it reaches
+/// the loop end, before resampling, so the wrap is seamless. Once released it
+/// plays on past the loop end to the end of the clip.
+pub struct ClipStream<'a> {
+    data: &'a [u8],
+    /// Next unread byte of `data`.
+    offset: usize,
+    /// Clip frame that `offset` decodes next.
+    position: u32,
+    source: Source,
+    /// Frames in the clip.
+    frames: u32,
+    looping: Option<LoopRegion>,
+    /// Clip frames decoded since the start, to release after a duration.
+    played: u64,
+    release_at: Option<u64>,
+    sample_rate: u32,
+    output: OutputFormat,
+    resampler: Resampler,
+    /// Decoded frames the resampler has not taken yet.
+    pending: [Frame; BLOCK],
+    pending_start: usize,
+    pending_end: usize,
+}
+
+impl<'a> ClipStream<'a> {
+    pub fn new(
+        wav: &Wav<'a>,
+        output: OutputFormat,
+        output_rate: u32,
+        quality: Quality,
+    ) -> Result<Self, Error> {
+        let source = match wav.format.codec {
+            Codec::ImaAdpcm { .. } => Source::Adpcm(ima_adpcm::Decoder::new(&wav.format)?),
+            _ => Source::Pcm(Converter::new(&wav.format, output)?),
+        };
+        Ok(Self {
+            data: wav.data,
+            offset: 0,
+            position: 0,
+            source,
+            frames: wav.frames(),
+            looping: None,
+            played: 0,
+            release_at: None,
+            sample_rate: wav.format.sample_rate,
+            output,
+            resampler: Resampler::new(wav.format.sample_rate, output_rate, quality),
+            pending: [[0; 2]; BLOCK],
+            pending_start: 0,
+            pending_end: 0,
+        })
+    }
+
+    /// Fills `out` with whole output frames and returns the bytes written.
+    /// Less than `out.len()` means the clip has ended; 0 means nothing is left.
+    pub fn fill(&mut self, out: &mut [u8]) -> usize {
+        let output = self.output;
+        let frame_size = output.frame_size();
+        let mut written = 0;
+        let mut block = [[0i16; 2]; BLOCK];
+
+        loop {
+            let room = ((out.len() - written) / frame_size).min(BLOCK);
+            if room == 0 {
+                return written;
+            }
+
+            if self.pending_start == self.pending_end {
+                self.decode_next();
+            }
+
+            let produced = if self.pending_start < self.pending_end {
+                let pending = &self.pending[self.pending_start..self.pending_end];
+                let (consumed, produced) = self.resampler.process(pending, &mut block[..room]);
+                self.pending_start += consumed;
+                produced
+            } else {
+                match self.resampler.flush(&mut block[..room]) {
+                    0 => return written,
+                    produced => produced,
+                }
+            };
+            written += encode(&block[..produced], output, &mut out[written..]);
+        }
+    }
+
+    /// Loops `region` until [`ClipStream::release`]. A region the clip has
+    /// already played past, or an empty one, is never reached.
+    pub fn set_loop(&mut self, region: LoopRegion) {
+        self.looping = (region.start < region.end).then_some(region);
+    }
+
+    /// Plays the current pass of the loop to its end, then the rest of the
+    /// clip.
+    pub fn release(&mut self) {
+        self.looping = None;
+        self.release_at = None;
+    }
+
+    /// Releases the loop once `ms` milliseconds of the clip have played,
+    /// counted from the start.
+    pub fn release_after(&mut self, ms: u32) {
+        self.release_at = Some(ms as u64 * self.sample_rate as u64 / 1000);
+    }
+
+    /// Whether the clip still goes back to a loop start.
+    pub fn is_looping(&self) -> bool {
+        self.looping.is_some()
+    }
+
+    /// Milliseconds into the clip of the next frame to play. It moves back
+    /// at every wrap of a loop.
+    pub fn position_ms(&self) -> u32 {
+        let pending = (self.pending_end - self.pending_start) as u32;
+        let frame = self.position.saturating_sub(pending);
+        (frame as u64 * 1000 / self.sample_rate as u64) as u32
+    }
+
+    /// Length of the clip in milliseconds, from its frames and rate.
+    pub fn duration_ms(&self) -> u32 {
+        (self.frames as u64 * 1000 / self.sample_rate as u64) as u32
+    }
+
+    /// Starts the clip again from the first frame. The loop is kept.
+    pub fn rewind(&mut self) {
+        self.offset = 0;
+        self.position = 0;
+        self.played = 0;
+        self.pending_start = 0;
+        self.pending_end = 0;
+        self.resampler.reset();
+        if let Source::Adpcm(decoder) = &mut self.source {
+            decoder.reset();
+        }
+    }
+
+    /// Jumps `ms` milliseconds into the clip, or to its end. ADPCM clips
+    /// restart at the block holding that point.
+    pub fn seek(&mut self, ms: u32) {
+        self.rewind();
+        let frame = (ms as u64 * self.sample_rate as u64 / 1000) as usize;
+        let (offset, frame) = match &self.source {
+            Source::Pcm(converter) => (frame.saturating_mul(converter.source_frame_size()), frame),
+            Source::Adpcm(decoder) => {
+                let block = frame / decoder.samples_per_block();
+                (
+                    decoder.block_offset(frame),
+                    block * decoder.samples_per_block(),
+                )
+            }
+        };
+        self.offset = offset.min(self.data.len());
+        self.position = frame.min(u32::MAX as usize) as u32;
+    }
+
+    /// Moves the decoder to clip frame `frame`. ADPCM restarts at its block
+    /// and decodes up to it.
+    fn jump(&mut self, frame: u32) {
+        match &mut self.source {
+            Source::Pcm(converter) => {
+                let offset = (frame as usize).saturating_mul(converter.source_frame_size());
+                self.offset = offset.min(self.data.len());
+            }
+            Source::Adpcm(decoder) => {
+                decoder.reset();
+                self.offset = decoder.block_offset(frame as usize).min(self.data.len());
+                let channels = decoder.channels();
+                let mut skip = frame as usize % decoder.samples_per_block();
+                let mut samples = [0i16; 2 * BLOCK];
+                while skip > 0 {
+                    let count = skip.min(BLOCK);
+                    let (consumed, written) =
+                        decoder.decode(&self.data[self.offset..], &mut samples[..count * channels]);
+                    self.offset += consumed;
+                    if written == 0 {
+                        break;
+                    }
+                    skip -= written;
+                }
+            }
+        }
+        self.position = frame;
+    }
+
+    /// Decodes the next [`BLOCK`] frames into `pending`, up to the loop end.
+    fn decode_next(&mut self) {
+        if self.release_at.is_some_and(|at| self.played >= at) {
+            self.release();
+        }
+        if let Some(region) = self.looping {
+            if self.position == region.end {
+                self.jump(region.start);
+            }
+        }
+        let count = match self.looping {
+            Some(region) if self.position < region.end => {
+                (region.end - self.position).min(BLOCK as u32) as usize
+            }
+            _ => BLOCK,
+        };
+
+        let data = &self.data[self.offset..];
+        let (consumed, written) = match &mut self.source {
+            Source::Pcm(converter) => {
+                let progress = converter.decode(data, &mut self.pending[..count]);
+                (progress.consumed, progress.written)
+            }
+            Source::Adpcm(decoder) => {
+                let channels = decoder.channels();
+                let mut samples = [0i16; 2 * BLOCK];
+                let (consumed, written) = decoder.decode(data, &mut samples[..count * channels]);
+                // Mono is duplicated on both channels
+                for (frame, samples) in self.pending[..written]
+                    .iter_mut()
+                    .zip(samples.chunks_exact(channels))
+                {
+                    *frame = [samples[0], samples[channels - 1]];
+                }
+                (consumed, written)
+            }
+        };
+        self.offset += consumed;
+        self.position += written as u32;
+        self.played += written as u64;
+        self.pending_start = 0;
+        self.pending_end = written;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    /// 16-bit mono WAV of a rising ramp.
+    fn ramp_wav(rate: u32, frames: u16) -> Vec<u8> {
+        let data_len = frames as u32 * 2;
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&rate.to_le_bytes());
+        wav.extend_from_slice(&(rate * 2).to_le_bytes());
+        wav.extend_from_slice(&2u16.to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&data_len.to_le_bytes());
+        for i in 0..frames {
+            wav.extend_from_slice(&(i as i16 * 10).to_le_bytes());
+        }
+        wav
+    }
+
+    fn play(stream: &mut ClipStream, chunk: usize) -> Vec<u8> {
+        let mut all = Vec::new();
+        let mut buf = std::vec![0u8; chunk];
+        loop {
+            let n = stream.fill(&mut buf);
+            all.extend_from_slice(&buf[..n]);
+            if n < chunk {
+                return all;
+            }
+        }
+    }
+
+    #[test]
+    fn same_rate_stream_matches_plain_conversion() {
+        let bytes = ramp_wav(11025, 500);
+        let wav = wav_parser::parse(&bytes).unwrap();
+        let mut stream =
+            ClipStream::new(&wav, OutputFormat::STEREO_16, 11025, Quality::Sinc).unwrap();
+        let streamed = play(&mut stream, 100);
+
+        let mut expected = std::vec![0u8; 2000];
+        let conv = Converter::new(&wav.format, OutputFormat::STEREO_16).unwrap();
+        conv.convert(wav.data, &mut expected);
+        assert_eq!(streamed, expected);
+    }
+
+    #[test]
+    fn stream_length_follows_the_rate_ratio() {
+        let bytes = ramp_wav(8000, 800);
+        let wav = wav_parser::parse(&bytes).unwrap();
+        let mut stream =
+            ClipStream::new(&wav, OutputFormat::STEREO_16, 11025, Quality::Linear).unwrap();
+        // 800 frames at 8 kHz = 100 ms = 1102.5 frames at 11025 Hz
+        let frames = play(&mut stream, 4092).len() / 4;
+        assert!((1102..=1103).contains(&frames), "{frames} frames");
+        assert_eq!(stream.fill(&mut [0u8; 64]), 0);
+
+        stream.rewind();
+        assert_eq!(play(&mut stream, 256).len() / 4, frames);
+    }
+
+    #[test]
+    fn decodes_ima_adpcm_clips() {
+        // A square wave the coder follows closely after a few steps
+        let pcm: Vec<i16> = (0..1010)
+            .map(|i| if i / 50 % 2 == 0 { 4000 } else { -4000 })
+            .collect();
+        let bytes = ima_adpcm::encode_pcm(&pcm, 1, 11025, 256);
+        let wav = wav_parser::parse(&bytes).unwrap();
+        let mut stream =
+            ClipStream::new(&wav, OutputFormat::STEREO_16, 11025, Quality::Sinc).unwrap();
+        let streamed = play(&mut stream, 100);
+        assert_eq!(streamed.len(), 1010 * 4);
+
+        let left: Vec<i16> = streamed
+            .chunks_exact(4)
+            .map(|frame| i16::from_le_bytes([frame[0], frame[1]]))
+            .collect();
+        assert!(streamed
+            .chunks_exact(4)
+            .all(|frame| frame[..2] == frame[2..]));
+        assert!((left[40] - 4000).abs() < 200, "{}", left[40]);
+        assert!((left[90] + 4000).abs() < 200, "{}", left[90]);
+
+        stream.rewind();
+        assert_eq!(play(&mut stream, 256), streamed);
+    }
+
+    #[test]
+    fn position_follows_the_frames_played() {
+        let bytes = ramp_wav(1000, 500);
+        let wav = wav_parser::parse(&bytes).unwrap();
+        let mut stream =
+            ClipStream::new(&wav, OutputFormat::STEREO_16, 1000, Quality::Linear).unwrap();
+        assert_eq!((stream.position_ms(), stream.duration_ms()), (0, 500));
+
+        // Within a block of the frames handed out
+        let mut buf = [0u8; 4 * 200];
+        stream.fill(&mut buf);
+        assert!((200..200 + BLOCK as u32).contains(&stream.position_ms()));
+        stream.seek(320);
+        assert_eq!(stream.position_ms(), 320);
+        play(&mut stream, 64);
+        assert_eq!(stream.position_ms(), 500);
+    }
+
+    #[test]
+    fn seek_skips_to_the_frame_of_a_time() {
+        let bytes = ramp_wav(1000, 500);
+        let wav = wav_parser::parse(&bytes).unwrap();
+        let mut stream =
+            ClipStream::new(&wav, OutputFormat::STEREO_16, 1000, Quality::Linear).unwrap();
+        let all = play(&mut stream, 64);
+
+        // 1 kHz: one frame per millisecond
+        stream.seek(120);
+        assert_eq!(play(&mut stream, 64), all[120 * 4..]);
+        stream.seek(10_000);
+        assert_eq!(stream.fill(&mut [0u8; 64]), 0);
+    }
+
+    /// Left samples of a 16-bit stereo stream.
+    fn left(bytes: &[u8]) -> Vec<i16> {
+        bytes
+            .chunks_exact(4)
+            .map(|frame| i16::from_le_bytes([frame[0], frame[1]]))
+            .collect()
+    }
+
+    #[test]
+    fn loops_wrap_on_the_exact_frame() {
+        let bytes = ramp_wav(1000, 100);
+        let wav = wav_parser::parse(&bytes).unwrap();
+        let mut stream =
+            ClipStream::new(&wav, OutputFormat::STEREO_16, 1000, Quality::Linear).unwrap();
+        stream.set_loop(LoopRegion { start: 20, end: 60 });
+
+        // Buffers that do not line up with the loop
+        let mut buf = [0u8; 4 * 37];
+        let mut played = Vec::new();
+        for _ in 0..10 {
+            assert_eq!(stream.fill(&mut buf), buf.len());
+            played.extend(left(&buf));
+        }
+        let expected: Vec<i16> = (0..60)
+            .chain((0..).flat_map(|_| 20..60))
+            .take(played.len())
+            .map(|i| i * 10)
+            .collect();
+        assert_eq!(played, expected);
+        assert!(stream.is_looping());
+
+        // The pass under way ends at the loop end, then the tail plays
+        stream.release();
+        let tail = left(&play(&mut stream, 64));
+        let at = played.len() as i16;
+        let pass = (60 - 20 - (at - 60) % 40) as usize;
+        assert_eq!(tail.len(), pass + 40);
+        assert_eq!(tail[pass - 1], 590);
+        assert_eq!(
+            tail[pass..],
+            (60..100).map(|i| i * 10).collect::<Vec<_>>()[..]
+        );
+    }
+
+    #[test]
+    fn releases_after_a_duration() {
+        let bytes = ramp_wav(1000, 100);
+        let wav = wav_parser::parse(&bytes).unwrap();
+        let mut stream =
+            ClipStream::new(&wav, OutputFormat::STEREO_16, 1000, Quality::Linear).unwrap();
+        stream.set_loop(LoopRegion {
+            start: 50,
+            end: 100,
+        });
+        stream.release_after(1000);
+        // Released within a block of a second, then to the end of the pass
+        let frames = play(&mut stream, 256).len() / 4;
+        assert!((1000..1000 + BLOCK + 50).contains(&frames), "{frames}");
+        assert!(!stream.is_looping());
+    }
+
+    #[test]
+    fn adpcm_loops_decode_from_inside_a_block() {
+        let pcm: Vec<i16> = (0..1200).map(|i| ((i * 37) % 2000 - 1000) as i16).collect();
+        let bytes = ima_adpcm::encode_pcm(&pcm, 1, 8000, 256);
+        let wav = wav_parser::parse(&bytes).unwrap();
+        let mut stream =
+            ClipStream::new(&wav, OutputFormat::STEREO_16, 8000, Quality::Linear).unwrap();
+        let once = left(&play(&mut stream, 400));
+        assert!(once.len() >= 1200);
+
+        // The loop starts in the second block (505 frames each)
+        stream.rewind();
+        stream.set_loop(LoopRegion {
+            start: 600,
+            end: 1100,
+        });
+        let mut buf = [0u8; 4 * 2100];
+        assert_eq!(stream.fill(&mut buf), buf.len());
+        let looped = left(&buf);
+        assert_eq!(looped[..1100], once[..1100]);
+        assert_eq!(looped[1100..1600], once[600..1100]);
+        assert_eq!(looped[1600..], once[600..1100]);
+    }
+
+    #[test]
+    fn reads_the_forward_loop_of_the_smpl_chunk() {
+        let mut bytes = ramp_wav(8000, 10);
+        let mut smpl = [0u8; 36 + 24];
+        smpl[28] = 1;
+        smpl[36 + 8] = 2;
+        smpl[36 + 12] = 7;
+        bytes.extend_from_slice(b"smpl");
+        bytes.extend_from_slice(&(smpl.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&smpl);
+        let riff = bytes.len() as u32 - 8;
+        bytes[4..8].copy_from_slice(&riff.to_le_bytes());
+
+        let wav = wav_parser::parse(&bytes).unwrap();
+        assert_eq!(
+            LoopRegion::from_wav(&wav),
+            Some(LoopRegion { start: 2, end: 8 })
+        );
+        let plain = ramp_wav(8000, 10);
+        assert_eq!(
+            LoopRegion::from_wav(&wav_parser::parse(&plain).unwrap()),
+            None
+        );
+    }
+}
@@ -0,0 +1,559 @@
+//! Tones made on the fly instead of read from a clip: an oscillator (sine,
+//! triangle, saw, square or noise) shaped by an ADSR envelope, with optional
+//! frequency sweeps, rendered as 16-bit stereo frames for the I2S.
+
+use core::f64::consts::PI;
+
+use crate::convert::{encode, OutputFormat};
+use crate::gain::UNITY;
+use crate::rng::XorShift;
+use crate::sink::PcmSource;
+use crate::Frame;
+
+/// Entries of the sine table over one cycle.
+const TABLE: usize = 256;
+
+/// One cycle of a full-scale sine, with the first entry again at the end so
+/// interpolation never wraps.
+static SINE: [i16; TABLE + 1] = sine_table();
+
+/// Rows of the pink noise generator: each one changes half as often as the
+/// one before, which gives about 3 dB less per octave over 8 octaves.
+const PINK_ROWS: usize = 8;
+
+/// Frames rendered per step of [`Synth::fill`].
+const BLOCK: usize = 64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Waveform {
+    Sine,
+    /// Rises for half a cycle, falls for the other half.
+    Triangle,
+    /// Rises over the whole cycle, then drops back.
+    Saw,
+    /// High for `duty` percent of the cycle: 50 is a plain square wave.
+    Square {
+        duty: u8,
+    },
+    /// Every frequency at the same level, like a detuned radio.
+    WhiteNoise,
+    /// Lower frequencies louder, like rain or a waterfall.
+    PinkNoise,
+}
+
+/// How the level of a note moves: up over `attack_ms`, down to `sustain`
+/// percent of the peak over `decay_ms`, held there until the note is released,
+/// then down to silence over `release_ms`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Envelope {
+    pub attack_ms: u32,
+    pub decay_ms: u32,
+    pub sustain: u8,
+    pub release_ms: u32,
+}
+
+impl Envelope {
+    /// Full level as soon as the note starts, silent as soon as it ends.
+    pub const FLAT: Self = Self::new(0, 0, 100, 0);
+
+    pub const fn new(attack_ms: u32, decay_ms: u32, sustain: u8, release_ms: u32) -> Self {
+        Self {
+            attack_ms,
+            decay_ms,
+            sustain,
+            release_ms,
+        }
+    }
+}
+
+impl Default for Envelope {
+    /// A few milliseconds of attack and release, just enough not to click.
+    fn default() -> Self {
+        Self::new(5, 0, 100, 5)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Stage {
+    Attack,
+    Decay,
+    Sustain,
+    /// Down to silence from the level the note was released at.
+    Release(i32),
+    Off,
+}
+
+/// One voice of synthesised sound. It is silent until [`Synth::note_on`] and
+/// ends once a released note has faded out, so as a [`PcmSource`] it plays one
+/// note.
+#[derive(Clone, Debug)]
+pub struct Synth {
+    waveform: Waveform,
+    sample_rate: u32,
+    /// Position in the cycle, a whole cycle being 2³².
+    phase: u32,
+    /// Phase added per frame: the frequency.
+    step: u32,
+    /// A sweep goes from `sweep_from` to `sweep_to` over `sweep_frames`,
+    /// `sweep_left` of them still to go.
+    sweep_from: u32,
+    sweep_to: u32,
+    sweep_frames: u32,
+    sweep_left: u32,
+    amplitude: i16,
+    envelope: Envelope,
+    stage: Stage,
+    /// Frames into the current stage.
+    position: u32,
+    /// Envelope level of the last frame, where [`UNITY`] is the peak.
+    level: i32,
+    noise: XorShift,
+    pink: [i32; PINK_ROWS],
+    /// Counts frames so each pink row is updated at its own rate.
+    pink_counter: u32,
+}
+
+impl Synth {
+    /// A silent voice at full scale with the [`Envelope::default`] envelope,
+    /// rendering at `sample_rate`.
+    pub fn new(waveform: Waveform, sample_rate: u32) -> Self {
+        let mut noise = XorShift::new(0);
+        Self {
+            waveform,
+            sample_rate: sample_rate.max(1),
+            phase: 0,
+            step: 0,
+            sweep_from: 0,
+            sweep_to: 0,
+            sweep_frames: 0,
+            sweep_left: 0,
+            amplitude: i16::MAX,
+            envelope: Envelope::default(),
+            stage: Stage::Off,
+            position: 0,
+            level: 0,
+            pink: core::array::from_fn(|_| white(&mut noise) / (PINK_ROWS as i32 + 1)),
+            noise,
+            pink_counter: 0,
+        }
+    }
+
+    pub fn waveform(&self) -> Waveform {
+        self.waveform
+    }
+
+    pub fn set_waveform(&mut self, waveform: Waveform) {
+        self.waveform = waveform;
+    }
+
+    /// The frequency right now, in Hz.
+    pub fn frequency(&self) -> f32 {
+        (self.step as f64 * self.sample_rate as f64 / 4_294_967_296.0) as f32
+    }
+
+    /// Jumps to `hz`, ending any sweep. Up to half the sample rate.
+    pub fn set_frequency(&mut self, hz: f32) {
+        self.step = self.step_of(hz);
+        self.sweep_left = 0;
+    }
+
+    /// Glides in a straight line from the current frequency to `hz` over
+    /// `ms`.
+    pub fn sweep(&mut self, hz: f32, ms: u32) {
+        self.sweep_from = self.step;
+        self.sweep_to = self.step_of(hz);
+        self.sweep_frames = self.frames(ms).max(1);
+        self.sweep_left = self.sweep_frames;
+    }
+
+    /// Peak sample value, before the envelope. Negative values count as their
+    /// magnitude.
+    pub fn set_amplitude(&mut self, peak: i16) {
+        self.amplitude = peak.saturating_abs();
+    }
+
+    /// Used from the next [`Synth::note_on`].
+    pub fn set_envelope(&mut self, envelope: Envelope) {
+        self.envelope = envelope;
+    }
+
+    /// Starts a note from the top of the cycle and the start of the attack.
+    pub fn note_on(&mut self) {
+        self.phase = 0;
+        self.stage = Stage::Attack;
+        self.position = 0;
+        self.level = 0;
+    }
+
+    /// Releases the note: it fades out over the release time, then ends.
+    pub fn note_off(&mut self) {
+        if !matches!(self.stage, Stage::Release(_) | Stage::Off) {
+            self.stage = Stage::Release(self.level);
+            self.position = 0;
+        }
+    }
+
+    /// A note is playing or fading out.
+    pub fn is_active(&self) -> bool {
+        self.stage != Stage::Off
+    }
+
+    /// The next sample, 0 once the note has ended.
+    pub fn next_sample(&mut self) -> i16 {
+        let level = self.advance_envelope();
+        let wave = self.oscillate();
+        self.advance_phase();
+        let sample = (wave * self.amplitude as i32) >> 15;
+        ((sample as i64 * level as i64) >> 16) as i16
+    }
+
+    /// Fills `frames` with the same sample on both channels and returns how
+    /// many it wrote: fewer than asked once the note has ended.
+    pub fn render(&mut self, frames: &mut [Frame]) -> usize {
+        for (i, frame) in frames.iter_mut().enumerate() {
+            if !self.is_active() {
+                return i;
+            }
+            let sample = self.next_sample();
+            *frame = [sample, sample];
+        }
+        frames.len()
+    }
+
+    /// The frames of `ms` at the sample rate.
+    fn frames(&self, ms: u32) -> u32 {
+        (self.sample_rate as u64 * ms as u64 / 1000) as u32
+    }
+
+    fn step_of(&self, hz: f32) -> u32 {
+        let hz = (hz as f64).clamp(0.0, self.sample_rate as f64 / 2.0);
+        (hz * 4_294_967_296.0 / self.sample_rate as f64) as u32
+    }
+
+    /// The waveform at the current phase, full scale.
+    fn oscillate(&mut self) -> i32 {
+        let phase = self.phase;
+        match self.waveform {
+            Waveform::Sine => sine(phase),
+            Waveform::Triangle => {
+                // A quarter of a cycle on, so it starts at zero going up
+                let t = (phase.wrapping_add(1 << 30) >> 15) as i32;
+                let rising = if t < 1 << 16 { t } else { (1 << 17) - 1 - t };
+                (rising - (1 << 15)).max(-i16::MAX as i32)
+            }
+            Waveform::Saw => ((phase >> 16) as i32 - (1 << 15)).max(-i16::MAX as i32),
+            Waveform::Square { duty } => {
+                let high = (u64::from(duty.min(100)) << 32) / 100;
+                if u64::from(phase) < high {
+                    i16::MAX as i32
+                } else {
+                    -i16::MAX as i32
+                }
+            }
+            Waveform::WhiteNoise => white(&mut self.noise),
+            Waveform::PinkNoise => {
+                // Voss-McCartney: row n changes every 2ⁿ frames, white on top
+                self.pink_counter = self.pink_counter.wrapping_add(1);
+                let row = self.pink_counter.trailing_zeros() as usize;
+                if row < PINK_ROWS {
+                    self.pink[row] = white(&mut self.noise) / (PINK_ROWS as i32 + 1);
+                }
+                let white = white(&mut self.noise) / (PINK_ROWS as i32 + 1);
+                self.pink.iter().sum::<i32>() + white
+            }
+        }
+    }
+
+    fn advance_phase(&mut self) {
+        self.phase = self.phase.wrapping_add(self.step);
+        if self.sweep_left > 0 {
+            self.sweep_left -= 1;
+            let left = (self.sweep_to as i64 - self.sweep_from as i64) * self.sweep_left as i64;
+            self.step = (self.sweep_to as i64 - left / self.sweep_frames as i64) as u32;
+        }
+    }
+
+    /// The envelope level for this frame, then one frame along.
+    fn advance_envelope(&mut self) -> i32 {
+        let envelope = self.envelope;
+        let sustain = UNITY * envelope.sustain.min(100) as i32 / 100;
+        loop {
+            let (length, level) = match self.stage {
+                Stage::Attack => {
+                    let length = self.frames(envelope.attack_ms);
+                    (length, ramp(0, UNITY, self.position, length))
+                }
+                Stage::Decay => {
+                    let length = self.frames(envelope.decay_ms);
+                    (length, ramp(UNITY, sustain, self.position, length))
+                }
+                Stage::Release(from) => {
+                    let length = self.frames(envelope.release_ms);
+                    (length, ramp(from, 0, self.position, length))
+                }
+                Stage::Sustain => {
+                    self.level = sustain;
+                    return sustain;
+                }
+                Stage::Off => return 0,
+            };
+            // A stage that takes no time at all is skipped
+            if self.position < length {
+                self.position += 1;
+                if self.position == length {
+                    self.next_stage();
+                }
+                self.level = level;
+                return level;
+            }
+            self.next_stage();
+        }
+    }
+
+    fn next_stage(&mut self) {
+        self.position = 0;
+        self.stage = match self.stage {
+            Stage::Attack => Stage::Decay,
+            Stage::Decay => Stage::Sustain,
+            _ => Stage::Off,
+        };
+    }
+}
+
+impl PcmSource for Synth {
+    /// 16-bit stereo frames, as the I2S takes them.
+    fn fill(&mut self, out: &mut [u8]) -> usize {
+        let output = OutputFormat::STEREO_16;
+        let mut written = 0;
+        let mut block = [[0i16; 2]; BLOCK];
+        loop {
+            let room = ((out.len() - written) / output.frame_size()).min(BLOCK);
+            let produced = self.render(&mut block[..room]);
+            written += encode(&block[..produced], output, &mut out[written..]);
+            if produced < BLOCK {
+                return written;
+            }
+        }
+    }
+}
+
+/// `position` frames along a straight line from `from` to `to` over `length`.
+fn ramp(from: i32, to: i32, position: u32, length: u32) -> i32 {
+    from + ((to - from) as i64 * position as i64 / length.max(1) as i64) as i32
+}
+
+/// A full-scale sine at `phase`, a whole cycle being 2³², interpolated in
+/// the table.
+pub(crate) fn sine(phase: u32) -> i32 {
+    let index = (phase >> 24) as usize;
+    let fraction = ((phase >> 8) & 0xFFFF) as i32;
+    let (a, b) = (SINE[index] as i32, SINE[index + 1] as i32);
+    a + (((b - a) * fraction) >> 16)
+}
+
+/// A random full-scale sample.
+fn white(noise: &mut XorShift) -> i32 {
+    (noise.next() >> 16) as i32 - (1 << 15)
+}
+
+const fn sine_table() -> [i16; TABLE + 1] {
+    let mut table = [0; TABLE + 1];
+    let mut i = 0;
+    while i <= TABLE {
+        // Taylor series over -π..π, well within half a step of the true sine
+        let mut x = 2.0 * PI * i as f64 / TABLE as f64;
+        if x > PI {
+            x -= 2.0 * PI;
+        }
+        let mut term = x;
+        let mut sine = x;
+        let mut n = 1;
+        while n < 12 {
+            term *= -x * x / ((2 * n) * (2 * n + 1)) as f64;
+            sine += term;
+            n += 1;
+        }
+        let scaled = sine * i16::MAX as f64;
+        table[i] = if scaled < 0.0 {
+            (scaled - 0.5) as i16
+        } else {
+            (scaled + 0.5) as i16
+        };
+        i += 1;
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    const RATE: u32 = 8000;
+
+    fn note(waveform: Waveform, hz: f32, frames: usize) -> Vec<i16> {
+        let mut synth = Synth::new(waveform, RATE);
+        synth.set_envelope(Envelope::FLAT);
+        synth.set_frequency(hz);
+        synth.note_on();
+        (0..frames).map(|_| synth.next_sample()).collect()
+    }
+
+    /// Frequency from the rising zero crossings.
+    fn measure_frequency(samples: &[i16]) -> f64 {
+        let mut crossings = Vec::new();
+        for i in 1..samples.len() {
+            let (a, b) = (samples[i - 1] as f64, samples[i] as f64);
+            if a < 0.0 && b >= 0.0 {
+                crossings.push(i as f64 - 1.0 + a / (a - b));
+            }
+        }
+        let cycles = crossings.len() - 1;
+        cycles as f64 * RATE as f64 / (crossings[cycles] - crossings[0])
+    }
+
+    fn rms(samples: &[i16]) -> f64 {
+        let sum: f64 = samples.iter().map(|&s| (s as f64).powi(2)).sum();
+        (sum / samples.len() as f64).sqrt()
+    }
+
+    #[test]
+    fn sine_table_is_a_sine() {
+        for (i, &entry) in SINE.iter().enumerate() {
+            let exact = (2.0 * PI * i as f64 / TABLE as f64).sin() * i16::MAX as f64;
+            assert!((entry as f64 - exact).abs() <= 0.5, "{i}: {entry}");
+        }
+    }
+
+    #[test]
+    fn periodic_waveforms_have_the_right_pitch_and_peak() {
+        for waveform in [
+            Waveform::Sine,
+            Waveform::Triangle,
+            Waveform::Saw,
+            Waveform::Square { duty: 50 },
+            Waveform::Square { duty: 20 },
+        ] {
+            for hz in [110.0, 440.0, 1234.5] {
+                let samples = note(waveform, hz, RATE as usize);
+                let measured = measure_frequency(&samples);
+                assert!(
+                    (measured - hz as f64).abs() < hz as f64 * 0.001,
+                    "{waveform:?}: {measured} Hz instead of {hz} Hz"
+                );
+                let peak = samples.iter().map(|s| s.unsigned_abs()).max().unwrap();
+                assert!(peak >= 32000, "{waveform:?} at {hz} Hz peaks at {peak}");
+            }
+        }
+    }
+
+    #[test]
+    fn amplitude_scales_the_level() {
+        let mut synth = Synth::new(Waveform::Sine, RATE);
+        synth.set_envelope(Envelope::FLAT);
+        synth.set_frequency(250.0);
+        synth.set_amplitude(8000);
+        synth.note_on();
+        let samples: Vec<i16> = (0..RATE).map(|_| synth.next_sample()).collect();
+        let peak = samples.iter().map(|s| s.unsigned_abs()).max().unwrap();
+        assert!((7995..=8000).contains(&peak), "{peak}");
+        // A sine's RMS is its peak over √2
+        assert!((rms(&samples) - 8000.0 / 2f64.sqrt()).abs() < 10.0);
+    }
+
+    #[test]
+    fn square_duty_sets_the_time_high() {
+        for duty in [10, 25, 50, 90] {
+            let samples = note(Waveform::Square { duty }, 100.0, RATE as usize);
+            let high = samples.iter().filter(|&&s| s > 0).count();
+            let expected = RATE as usize * duty as usize / 100;
+            assert!(high.abs_diff(expected) <= 100, "{duty}%: {high}");
+        }
+    }
+
+    #[test]
+    fn pink_noise_is_darker_than_white() {
+        // The difference of neighbouring samples weighs the high frequencies
+        let brightness = |samples: &[i16]| {
+            let diff: f64 = samples
+                .windows(2)
+                .map(|w| (w[1] as f64 - w[0] as f64).powi(2))
+                .sum();
+            (diff / samples.len() as f64).sqrt() / rms(samples)
+        };
+        let white = note(Waveform::WhiteNoise, 0.0, RATE as usize);
+        let pink = note(Waveform::PinkNoise, 0.0, RATE as usize);
+
+        let mean = white.iter().map(|&s| s as f64).sum::<f64>() / white.len() as f64;
+        assert!(mean.abs() < 500.0);
+        assert!(rms(&white) > 15000.0);
+        assert!(rms(&pink) > 3000.0);
+        // White noise: √2. Pink is well below it
+        assert!((brightness(&white) - 2f64.sqrt()).abs() < 0.05);
+        assert!(brightness(&pink) < 0.8, "{}", brightness(&pink));
+    }
+
+    #[test]
+    fn envelope_goes_up_down_holds_and_ends() {
+        // 1 kHz square wave at 8 kHz: every fourth sample is a peak
+        let mut synth = Synth::new(Waveform::Square { duty: 50 }, RATE);
+        synth.set_frequency(1000.0);
+        synth.set_envelope(Envelope::new(10, 10, 50, 20));
+        synth.note_on();
+        let held: Vec<i16> = (0..400).map(|_| synth.next_sample()).collect();
+        let level = |s: &[i16]| s.iter().map(|s| s.unsigned_abs()).collect::<Vec<_>>();
+
+        let attack = level(&held[..80]);
+        assert_eq!(attack[0], 0);
+        assert!(attack.windows(2).all(|w| w[0] <= w[1]));
+        assert!(level(&held[80..81])[0] >= 32700);
+        let decay = level(&held[80..160]);
+        assert!(decay.windows(2).all(|w| w[0] >= w[1]));
+        assert!(level(&held[160..]).iter().all(|&l| l.abs_diff(16383) <= 1));
+
+        synth.note_off();
+        let mut buffer = [[1i16; 2]; 200];
+        assert_eq!(synth.render(&mut buffer), 160);
+        let release = level(&buffer[..160].iter().map(|f| f[0]).collect::<Vec<_>>());
+        assert!(release.windows(2).all(|w| w[0] >= w[1]));
+        assert!(release[159] < 200);
+        assert!(!synth.is_active());
+        assert_eq!(synth.next_sample(), 0);
+    }
+
+    #[test]
+    fn sweep_glides_to_the_target() {
+        let mut synth = Synth::new(Waveform::Sine, RATE);
+        synth.set_envelope(Envelope::FLAT);
+        synth.set_frequency(200.0);
+        synth.note_on();
+        synth.sweep(1000.0, 500);
+        let swept: Vec<i16> = (0..RATE / 2).map(|_| synth.next_sample()).collect();
+        assert!((synth.frequency() - 1000.0).abs() < 0.01);
+
+        // Halfway through, the pitch is halfway there
+        let middle = measure_frequency(&swept[1800..2200]);
+        assert!((middle - 600.0).abs() < 20.0, "{middle}");
+        let after: Vec<i16> = (0..RATE).map(|_| synth.next_sample()).collect();
+        assert!((measure_frequency(&after) - 1000.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn plays_one_note_as_a_source() {
+        let mut synth = Synth::new(Waveform::Triangle, RATE);
+        synth.set_frequency(500.0);
+        assert_eq!(synth.fill(&mut [0; 64]), 0);
+
+        synth.note_on();
+        let mut buffer = std::vec![0u8; 4 * 1000];
+        assert_eq!(synth.fill(&mut buffer), buffer.len());
+        assert!(buffer.chunks_exact(4).all(|f| f[..2] == f[2..]));
+
+        // The default release is 5 ms: 40 frames
+        synth.note_off();
+        assert_eq!(synth.fill(&mut buffer), 4 * 40);
+        assert_eq!(synth.fill(&mut buffer), 0);
+    }
+}
@@ -0,0 +1,379 @@
+//! Buzzer songs on the speaker: the tones `buzzer-music` plays on the piezo
+//! (a frequency and a duration each) rendered as PCM with a chosen
+//! [`Timbre`], for the same I2S path as the clips.
+
+use core::f64::consts::{LN_2, PI};
+
+use rtttl::Tone;
+
+use crate::convert::{encode, OutputFormat};
+use crate::gain::UNITY;
+use crate::sink::PcmSource;
+use crate::synth::sine;
+use crate::Frame;
+
+/// Fade in at the start of each tone, so notes do not click.
+const ATTACK_MS: u32 = 2;
+/// Fade out at the end of each tone, which also separates repeated notes.
+const RELEASE_MS: u32 = 8;
+
+/// The bell fades by half every this many milliseconds.
+const BELL_HALF_LIFE_MS: f64 = 150.0;
+/// Modulator frequency over carrier frequency: not a whole number, so the
+/// partials are inharmonic like a bell's.
+const BELL_RATIO: (u64, u64) = (7, 5);
+/// Peak modulation index, in radians, as phase units per full-scale
+/// modulator sample.
+const BELL_INDEX: i64 = (5.0 / (2.0 * PI) * 4_294_967_296.0 / 32768.0) as i64;
+
+/// Frames rendered per step of [`Tune::fill`].
+const BLOCK: usize = 64;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Timbre {
+    /// The piezo's own sound, with its edges rounded off (PolyBLEP) so the
+    /// harmonics above half the sample rate do not fold back as noise.
+    #[default]
+    Square,
+    /// A pure tone.
+    Sine,
+    /// Two-operator FM that dies away during each note.
+    Bell,
+}
+
+/// Renders a song tone by tone as 16-bit stereo frames. A tone of 0 Hz is a
+/// rest, and so is one too high for the sample rate.
+#[derive(Clone, Debug)]
+pub struct Tune<'a> {
+    tones: &'a [Tone],
+    /// Index of the tone after the one playing.
+    next: usize,
+    timbre: Timbre,
+    sample_rate: u32,
+    amplitude: i16,
+    /// End of the tone playing, from the start of the song: tones start on
+    /// the frame nearest their time, so rounding never adds up.
+    end_ms: u64,
+    end_frame: u64,
+    /// Frames into the tone playing, and frames left of it.
+    position: u32,
+    left: u32,
+    /// Carrier and modulator, a whole cycle being 2³².
+    phase: u32,
+    step: u32,
+    mod_phase: u32,
+    mod_step: u32,
+    /// Bell level, where 1 << 30 is full, and what it is multiplied by every
+    /// frame.
+    level: i64,
+    decay: i64,
+    attack: u32,
+    release: u32,
+}
+
+impl<'a> Tune<'a> {
+    /// Plays `tones` in `timbre` at half of full scale, leaving room to mix
+    /// it with clips.
+    pub fn new(tones: &'a [Tone], timbre: Timbre, sample_rate: u32) -> Self {
+        let sample_rate = sample_rate.max(1);
+        let frames = |ms: u32| (sample_rate as u64 * ms as u64 / 1000).max(1) as u32;
+        let half_life = BELL_HALF_LIFE_MS * sample_rate as f64 / 1000.0;
+        Self {
+            tones,
+            next: 0,
+            timbre,
+            sample_rate,
+            amplitude: i16::MAX / 2,
+            end_ms: 0,
+            end_frame: 0,
+            position: 0,
+            left: 0,
+            phase: 0,
+            step: 0,
+            mod_phase: 0,
+            mod_step: 0,
+            level: 0,
+            decay: (libm::exp(-LN_2 / half_life) * (1u64 << 30) as f64) as i64,
+            attack: frames(ATTACK_MS),
+            release: frames(RELEASE_MS),
+        }
+    }
+
+    pub fn timbre(&self) -> Timbre {
+        self.timbre
+    }
+
+    /// Takes effect from the next tone.
+    pub fn set_timbre(&mut self, timbre: Timbre) {
+        self.timbre = timbre;
+    }
+
+    /// Peak sample value. Negative values count as their magnitude.
+    pub fn set_amplitude(&mut self, peak: i16) {
+        self.amplitude = peak.saturating_abs();
+    }
+
+    /// Length of the whole song.
+    pub fn duration_ms(&self) -> u64 {
+        self.tones.iter().map(|tone| tone.duration as u64).sum()
+    }
+
+    /// Back to the first tone.
+    pub fn rewind(&mut self) {
+        self.next = 0;
+        self.end_ms = 0;
+        self.end_frame = 0;
+        self.left = 0;
+    }
+
+    /// The next sample, `None` after the last tone.
+    pub fn next_sample(&mut self) -> Option<i16> {
+        while self.left == 0 {
+            self.start_next()?;
+        }
+        let wave = self.oscillate();
+        // Straight ramps in and out of every tone
+        let fade_in = UNITY as i64 * self.position as i64 / self.attack as i64;
+        let fade_out = UNITY as i64 * self.left as i64 / self.release as i64;
+        let envelope = fade_in.min(fade_out).min(UNITY as i64);
+        self.position += 1;
+        self.left -= 1;
+
+        let sample = (wave as i64 * self.amplitude as i64) >> 15;
+        Some(((sample * envelope) >> 16) as i16)
+    }
+
+    /// Fills `frames` with the same sample on both channels and returns how
+    /// many it wrote: fewer than asked once the song has ended.
+    pub fn render(&mut self, frames: &mut [Frame]) -> usize {
+        for (i, frame) in frames.iter_mut().enumerate() {
+            let Some(sample) = self.next_sample() else {
+                return i;
+            };
+            *frame = [sample, sample];
+        }
+        frames.len()
+    }
+
+    fn start_next(&mut self) -> Option<()> {
+        let tone = *self.tones.get(self.next)?;
+        self.next += 1;
+        self.end_ms += tone.duration as u64;
+        let end_frame = (self.end_ms * self.sample_rate as u64 + 500) / 1000;
+        self.left = (end_frame - self.end_frame) as u32;
+        self.end_frame = end_frame;
+        self.position = 0;
+
+        // Up to just under half the sample rate, the rest would alias
+        let frequency = tone.frequency as u64;
+        let audible = frequency > 0 && 2 * frequency < self.sample_rate as u64;
+        self.step = if audible {
+            ((frequency << 32) / self.sample_rate as u64) as u32
+        } else {
+            0
+        };
+        self.mod_step = (self.step as u64 * BELL_RATIO.0 / BELL_RATIO.1) as u32;
+        self.phase = 0;
+        self.mod_phase = 0;
+        self.level = 1 << 30;
+        Some(())
+    }
+
+    /// The timbre at the current phase, full scale, then one frame along.
+    fn oscillate(&mut self) -> i32 {
+        if self.step == 0 {
+            return 0;
+        }
+        let (phase, step) = (self.phase, self.step);
+        self.phase = phase.wrapping_add(step);
+        match self.timbre {
+            Timbre::Sine => sine(phase),
+            Timbre::Square => {
+                let naive = if phase < 1 << 31 {
+                    i16::MAX as i32
+                } else {
+                    -(i16::MAX as i32)
+                };
+                // Up at the start of the cycle, down halfway through
+                let rounded = naive + blep(phase, step) - blep(phase.wrapping_add(1 << 31), step);
+                rounded.clamp(-(i16::MAX as i32), i16::MAX as i32)
+            }
+            Timbre::Bell => {
+                let modulator = sine(self.mod_phase) as i64;
+                self.mod_phase = self.mod_phase.wrapping_add(self.mod_step);
+                // The brightness fades with the level, as a struck bell's does
+                let offset = (modulator * BELL_INDEX * self.level) >> 30;
+                let carrier = sine(phase.wrapping_add(offset as u32)) as i64;
+                let sample = (carrier * self.level) >> 30;
+                self.level = (self.level * self.decay) >> 30;
+                sample as i32
+            }
+        }
+    }
+}
+
+impl PcmSource for Tune<'_> {
+    /// 16-bit stereo frames, as the I2S takes them.
+    fn fill(&mut self, out: &mut [u8]) -> usize {
+        let output = OutputFormat::STEREO_16;
+        let mut written = 0;
+        let mut block = [[0i16; 2]; BLOCK];
+        loop {
+            let room = ((out.len() - written) / output.frame_size()).min(BLOCK);
+            let produced = self.render(&mut block[..room]);
+            written += encode(&block[..produced], output, &mut out[written..]);
+            if produced < BLOCK {
+                return written;
+            }
+        }
+    }
+}
+
+/// What rounds off a rising jump from -full scale to +full scale at phase 0
+/// (PolyBLEP): a two-frame polynomial around the jump, 0 elsewhere.
+fn blep(phase: u32, step: u32) -> i32 {
+    const ONE: i64 = i16::MAX as i64;
+    let (phase, step) = (phase as i64, step as i64);
+    let cycle = 1i64 << 32;
+    if phase < step {
+        // Just after the jump: x from 0 to 1
+        let x = (phase << 15) / step;
+        (2 * x - ((x * x) >> 15) - ONE) as i32
+    } else if phase > cycle - step {
+        // Just before it: x from -1 to 0
+        let x = ((phase - cycle) << 15) / step;
+        (((x * x) >> 15) + 2 * x + ONE) as i32
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::synth::{Envelope, Synth, Waveform};
+
+    extern crate std;
+    use std::vec::Vec;
+
+    const RATE: u32 = 11025;
+
+    const fn tone(frequency: u32, duration: u32) -> Tone {
+        Tone {
+            frequency,
+            duration,
+        }
+    }
+
+    fn samples(tune: &mut Tune) -> Vec<i16> {
+        core::iter::from_fn(|| tune.next_sample()).collect()
+    }
+
+    /// Level of `hz` in `samples`, from one bin of a discrete Fourier
+    /// transform.
+    fn level_at(samples: &[i16], hz: f64) -> f64 {
+        let (mut re, mut im) = (0.0, 0.0);
+        for (i, &s) in samples.iter().enumerate() {
+            let angle = 2.0 * PI * hz * i as f64 / RATE as f64;
+            re += s as f64 * libm::cos(angle);
+            im += s as f64 * libm::sin(angle);
+        }
+        libm::sqrt(re * re + im * im) / samples.len() as f64
+    }
+
+    fn rms(samples: &[i16]) -> f64 {
+        let sum: f64 = samples.iter().map(|&s| (s as f64) * (s as f64)).sum();
+        libm::sqrt(sum / samples.len() as f64)
+    }
+
+    #[test]
+    fn tones_start_on_the_frame_nearest_their_time() {
+        // 33 ms is 363.825 frames: the error must not add up
+        let song = [tone(440, 33); 30];
+        let mut tune = Tune::new(&song, Timbre::Sine, RATE);
+        assert_eq!(tune.duration_ms(), 990);
+        assert_eq!(samples(&mut tune).len(), 10915);
+        assert_eq!(tune.next_sample(), None);
+        tune.rewind();
+        assert_eq!(samples(&mut tune).len(), 10915);
+    }
+
+    #[test]
+    fn every_tone_fades_in_and_out() {
+        let song = [tone(1000, 100), tone(0, 50), tone(1000, 100)];
+        for timbre in [Timbre::Square, Timbre::Sine, Timbre::Bell] {
+            let out = samples(&mut Tune::new(&song, timbre, RATE));
+            let (first, rest) = (1103, 551);
+            assert_eq!(out.len(), 2 * first + rest - 1);
+            assert_eq!(out[0], 0, "{timbre:?}");
+            assert!(out[first - 1].unsigned_abs() < 2000, "{timbre:?}");
+            assert!(out[first..first + rest].iter().all(|&s| s == 0));
+            let peak = out.iter().map(|s| s.unsigned_abs()).max().unwrap();
+            assert!((12000..=16383).contains(&peak), "{timbre:?}: {peak}");
+        }
+    }
+
+    #[test]
+    fn pitch_is_the_tone_frequency() {
+        let song = [tone(440, 1000)];
+        for timbre in [Timbre::Square, Timbre::Sine] {
+            let out = samples(&mut Tune::new(&song, timbre, RATE));
+            let fundamental = level_at(&out, 440.0);
+            assert!(fundamental > 4000.0, "{timbre:?}: {fundamental}");
+            assert!(level_at(&out, 430.0) < fundamental / 50.0);
+        }
+        // A pure tone has no harmonics, a square wave odd ones
+        let sine = samples(&mut Tune::new(&song, Timbre::Sine, RATE));
+        assert!(level_at(&sine, 1320.0) < 10.0);
+        let square = samples(&mut Tune::new(&song, Timbre::Square, RATE));
+        let ratio = level_at(&square, 1320.0) / level_at(&square, 440.0);
+        assert!((ratio - 1.0 / 3.0).abs() < 0.03, "{ratio}");
+    }
+
+    #[test]
+    fn square_harmonics_fold_back_less_than_a_naive_square() {
+        // The third harmonic of 2 kHz, 6 kHz, folds back to 5025 Hz, the
+        // fifth (10 kHz) to 1025 Hz
+        let song = [tone(2000, 1000)];
+        let out = samples(&mut Tune::new(&song, Timbre::Square, RATE));
+        let mut naive = Synth::new(Waveform::Square { duty: 50 }, RATE);
+        naive.set_envelope(Envelope::FLAT);
+        naive.set_amplitude(i16::MAX / 2);
+        naive.set_frequency(2000.0);
+        naive.note_on();
+        let naive: Vec<i16> = (0..out.len()).map(|_| naive.next_sample()).collect();
+
+        for alias in [5025.0, 1025.0] {
+            let ours = level_at(&out, alias) / level_at(&out, 2000.0);
+            let theirs = level_at(&naive, alias) / level_at(&naive, 2000.0);
+            assert!(ours < theirs / 2.0, "{alias} Hz: {ours} against {theirs}");
+        }
+    }
+
+    #[test]
+    fn bells_die_away() {
+        let out = samples(&mut Tune::new(&[tone(880, 1000)], Timbre::Bell, RATE));
+        let tenth = out.len() / 10;
+        let (start, end) = (rms(&out[..tenth]), rms(&out[9 * tenth..]));
+        assert!(start > 4000.0, "{start}");
+        // 150 ms half-life: 6 halvings between the two
+        assert!(end < start / 30.0, "{start} then {end}");
+    }
+
+    #[test]
+    fn too_high_tones_are_rests() {
+        let out = samples(&mut Tune::new(&[tone(6000, 100)], Timbre::Square, RATE));
+        assert_eq!(out.len(), 1103);
+        assert!(out.iter().all(|&s| s == 0));
+    }
+
+    #[test]
+    fn plays_as_a_source() {
+        let song = [tone(523, 20), tone(659, 20)];
+        let mut tune = Tune::new(&song, Timbre::Bell, RATE);
+        let mut buffer = std::vec![0u8; 4 * 1000];
+        assert_eq!(tune.fill(&mut buffer), 4 * 441);
+        assert!(buffer[..4 * 441].chunks_exact(4).all(|f| f[..2] == f[2..]));
+        assert_eq!(tune.fill(&mut buffer), 0);
+    }
+}
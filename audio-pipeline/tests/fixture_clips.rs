@@ -0,0 +1,42 @@
+//! Converts the WAV fixtures of `wav-parser` the way the audio tasks do.
+
+use audio_pipeline::{Converter, OutputFormat};
+
+const MP3_PLAYER_CLIP: &[u8] = include_bytes!("../../wav-parser/tests/fixtures/pcm8_mono_8000.wav");
+const STEREO_LIST: &[u8] =
+    include_bytes!("../../wav-parser/tests/fixtures/pcm16_stereo_44100_list.wav");
+
+#[test]
+fn eight_bit_mono_clip_fills_sixteen_bit_stereo_frames() {
+    let wav = wav_parser::parse(MP3_PLAYER_CLIP).unwrap();
+    let conv = Converter::new(&wav.format, OutputFormat::STEREO_16).unwrap();
+
+    let mut dma = [0u8; 4096];
+    let mut offset = 0;
+    let mut frames = 0;
+    while offset < wav.data.len() {
+        let progress = conv.convert(&wav.data[offset..], &mut dma);
+        for (frame, src) in dma[..progress.written]
+            .chunks_exact(4)
+            .zip(&wav.data[offset..])
+        {
+            let left = i16::from_le_bytes([frame[0], frame[1]]);
+            let right = i16::from_le_bytes([frame[2], frame[3]]);
+            assert_eq!(left, (*src as i16 - 128) << 8);
+            assert_eq!(left, right);
+        }
+        offset += progress.consumed;
+        frames += progress.written / 4;
+    }
+    assert_eq!(frames, wav.frames() as usize);
+}
+
+#[test]
+fn stereo_clip_is_unchanged() {
+    let wav = wav_parser::parse(STEREO_LIST).unwrap();
+    let conv = Converter::new(&wav.format, OutputFormat::STEREO_16).unwrap();
+    let mut dma = vec![0u8; wav.data.len()];
+    let progress = conv.convert(wav.data, &mut dma);
+    assert_eq!(progress.written, wav.data.len());
+    assert_eq!(dma, wav.data);
+}
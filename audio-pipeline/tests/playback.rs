@@ -0,0 +1,168 @@
+//! Runs the playback loop of the audio tasks against host sinks.
+
+use audio_pipeline::{
+    play, ClipStream, MemoryRing, MemorySink, OutputFormat, Played, Quality, RawSource, RingWriter,
+    Timbre, Tone, Tune, WavFileSink,
+};
+
+const MP3_PLAYER_CLIP: &[u8] = include_bytes!("../../wav-parser/tests/fixtures/pcm8_mono_8000.wav");
+const LOOP_CLIP: &[u8] =
+    include_bytes!("../../wav-parser/tests/fixtures/pcm16_mono_11025_loop.wav");
+
+/// Small stand-in for the 65472-byte DMA buffer of the players.
+const DMA_BUFFER_SIZE: usize = 4092;
+
+#[test]
+fn clip_is_sent_in_padded_dma_buffers() {
+    let wav = wav_parser::parse(MP3_PLAYER_CLIP).unwrap();
+    let mut stream = ClipStream::new(&wav, OutputFormat::STEREO_16, 8000, Quality::Sinc).unwrap();
+    let mut sink = MemorySink::new(OutputFormat::STEREO_16, 8000);
+    let mut tx_buffer = [0xAAu8; DMA_BUFFER_SIZE];
+
+    let played = play(&mut stream, &mut tx_buffer, &mut sink).unwrap();
+
+    let audio_bytes = wav.frames() as usize * 4;
+    assert_eq!(
+        played,
+        Played {
+            buffers: audio_bytes.div_ceil(DMA_BUFFER_SIZE),
+            bytes: audio_bytes,
+        }
+    );
+    assert!(sink.writes().iter().all(|&len| len == DMA_BUFFER_SIZE));
+    assert!(sink.bytes()[audio_bytes..].iter().all(|&b| b == 0));
+
+    // Same rate: every 8-bit mono sample lands on both channels, unfiltered
+    let frames = sink.frames();
+    for (frame, &sample) in frames.iter().zip(wav.data) {
+        let expected = (sample as i16 - 128) << 8;
+        assert_eq!(*frame, [expected, expected]);
+    }
+}
+
+#[test]
+fn resampled_clip_keeps_its_duration() {
+    let wav = wav_parser::parse(LOOP_CLIP).unwrap();
+    let mut stream = ClipStream::new(&wav, OutputFormat::STEREO_16, 44100, Quality::Sinc).unwrap();
+    let mut sink = MemorySink::new(OutputFormat::STEREO_16, 44100);
+    let played = play(&mut stream, &mut [0u8; DMA_BUFFER_SIZE], &mut sink).unwrap();
+
+    // 1100 frames at 11025 Hz are 4400 frames at 44100 Hz
+    assert_eq!(played.bytes / 4, wav.frames() as usize * 4);
+
+    let saved = sink.to_wav();
+    let header = wav_parser::parse_header(&saved).unwrap();
+    assert_eq!(header.format.sample_rate, 44100);
+    assert_eq!(header.data_len, played.buffers * DMA_BUFFER_SIZE);
+}
+
+#[test]
+fn wav_file_sink_writes_a_playable_file() {
+    let path = std::env::temp_dir().join(format!("audio-pipeline-{}.wav", std::process::id()));
+    let data: Vec<u8> = (0..=255).collect();
+
+    let mut sink = WavFileSink::create(&path, OutputFormat::STEREO_16, 11025).unwrap();
+    play(&mut RawSource(&data), &mut [0u8; 100], &mut sink).unwrap();
+    sink.finish().unwrap();
+
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let wav = wav_parser::parse(&bytes).unwrap();
+    assert_eq!(wav.format, OutputFormat::STEREO_16.wav_format(11025));
+    assert_eq!(wav.data.len(), 300);
+    assert_eq!(&wav.data[..256], &data[..]);
+    assert!(wav.data[256..].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn buzzer_song_renders_to_a_wav_file() {
+    // The opening of Ode to Joy as buzzer-music plays it
+    let song = [330, 330, 349, 392, 392, 349, 330, 294, 0, 262].map(|frequency| Tone {
+        frequency,
+        duration: 250,
+    });
+    for timbre in [Timbre::Square, Timbre::Sine, Timbre::Bell] {
+        let path = std::env::temp_dir().join(format!(
+            "audio-pipeline-tune-{timbre:?}-{}.wav",
+            std::process::id()
+        ));
+        let mut tune = Tune::new(&song, timbre, 11025);
+        let mut sink = WavFileSink::create(&path, OutputFormat::STEREO_16, 11025).unwrap();
+        let played = play(&mut tune, &mut [0u8; DMA_BUFFER_SIZE], &mut sink).unwrap();
+        sink.finish().unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let wav = wav_parser::parse(&bytes).unwrap();
+        assert_eq!(wav.format, OutputFormat::STEREO_16.wav_format(11025));
+        // 2.5 s of song, then the padding of the last buffer
+        assert_eq!(played.bytes, 4 * 27563);
+        assert_eq!(wav.data.len(), played.buffers * DMA_BUFFER_SIZE);
+        // The rest is silent
+        let rest = &wav.data[4 * 22050..4 * 24806];
+        assert!(rest.iter().all(|&b| b == 0), "{timbre:?}");
+        assert!(wav.data[..4 * 22050].iter().any(|&b| b != 0));
+    }
+}
+
+/// Stand-in for `block_on`: the host ring never has to wait.
+fn run<F: std::future::Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let mut context = std::task::Context::from_waker(std::task::Waker::noop());
+    loop {
+        if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn ring_plays_clips_back_to_back() {
+    let wav = wav_parser::parse(MP3_PLAYER_CLIP).unwrap();
+    let mut stream = ClipStream::new(&wav, OutputFormat::STEREO_16, 8000, Quality::Sinc).unwrap();
+    // 4 descriptors of 4092 bytes; the I2S plays half a descriptor per push
+    let ring = MemoryRing::new(4 * DMA_BUFFER_SIZE, DMA_BUFFER_SIZE, DMA_BUFFER_SIZE / 2);
+    let mut writer = RingWriter::new(ring, 4);
+
+    let bytes = run(writer.play(&mut stream)).unwrap() as usize;
+    stream.rewind();
+    run(writer.play(&mut stream)).unwrap();
+    // Enough silence to push both clips out of the ring
+    for _ in 0..8 {
+        run(writer.silence()).unwrap();
+    }
+
+    let stats = writer.stats();
+    assert_eq!(stats.underruns, 0);
+    assert_eq!(stats.audio, 2 * bytes as u64);
+    // The ring's initial silence, both clips without a byte between them,
+    // then the silence pushed after them
+    let played = writer.ring().played();
+    let start = 4 * DMA_BUFFER_SIZE;
+    assert!(played[..start].iter().all(|&byte| byte == 0));
+    assert_eq!(
+        played[start..start + bytes],
+        played[start + bytes..start + 2 * bytes]
+    );
+    let mut expected = MemorySink::new(OutputFormat::STEREO_16, 8000);
+    stream.rewind();
+    play(&mut stream, &mut [0u8; DMA_BUFFER_SIZE], &mut expected).unwrap();
+    assert_eq!(played[start..start + bytes], expected.bytes()[..bytes]);
+}
+
+#[test]
+fn ring_counts_underruns_of_a_slow_producer() {
+    let wav = wav_parser::parse(MP3_PLAYER_CLIP).unwrap();
+    let mut stream = ClipStream::new(&wav, OutputFormat::STEREO_16, 8000, Quality::Linear).unwrap();
+    // The I2S plays more than a descriptor between pushes
+    let ring = MemoryRing::new(
+        4 * DMA_BUFFER_SIZE,
+        DMA_BUFFER_SIZE,
+        3 * DMA_BUFFER_SIZE / 2,
+    );
+    let mut writer = RingWriter::new(ring, 4);
+    run(writer.play(&mut stream)).unwrap();
+    assert!(writer.stats().underruns > 0);
+    // Every audio byte was still pushed
+    assert_eq!(writer.stats().audio, wav.frames() as u64 * 4);
+}
@@ -0,0 +1,386 @@
+//! Plays and records WAV files on an in-memory FAT16 image through
+//! `embedded-sdmmc`, as `music-player` and `sdcard` do on the SD card.
+
+use std::cell::RefCell;
+
+use audio_pipeline::{
+    play, DirEvent, DirPlayer, FileStream, MemorySink, OutputFormat, Quality, Skip, WavRecorder,
+};
+use embedded_sdmmc::{
+    Block, BlockCount, BlockDevice, BlockIdx, Mode, TimeSource, Timestamp, VolumeIdx, VolumeManager,
+};
+use wav_parser::Format;
+
+/// Blocks of the FAT16 volume: just over the 4085 clusters FAT16 needs.
+const VOLUME_BLOCKS: u32 = 4400;
+const FAT_BLOCKS: u16 = 18;
+const ROOT_ENTRIES: u16 = 512;
+
+/// A card held in memory.
+struct RamDisk {
+    blocks: RefCell<Vec<Block>>,
+}
+
+impl RamDisk {
+    /// An MBR with one partition, formatted as an empty FAT16 volume.
+    fn fat16() -> Self {
+        let mut blocks = vec![Block::new(); 1 + VOLUME_BLOCKS as usize];
+
+        let mbr = &mut blocks[0];
+        let partition = &mut mbr[446..462];
+        partition[4] = 0x06; // FAT16
+        partition[8..12].copy_from_slice(&1u32.to_le_bytes());
+        partition[12..16].copy_from_slice(&VOLUME_BLOCKS.to_le_bytes());
+        mbr[510..].copy_from_slice(&[0x55, 0xAA]);
+
+        let bpb = &mut blocks[1];
+        bpb[..11].copy_from_slice(b"\xEB\x3C\x90MSWIN4.1");
+        bpb[11..13].copy_from_slice(&512u16.to_le_bytes());
+        bpb[13] = 1; // block per cluster
+        bpb[14..16].copy_from_slice(&1u16.to_le_bytes()); // reserved blocks
+        bpb[16] = 2; // FATs
+        bpb[17..19].copy_from_slice(&ROOT_ENTRIES.to_le_bytes());
+        bpb[19..21].copy_from_slice(&(VOLUME_BLOCKS as u16).to_le_bytes());
+        bpb[21] = 0xF8;
+        bpb[22..24].copy_from_slice(&FAT_BLOCKS.to_le_bytes());
+        bpb[38] = 0x29;
+        bpb[43..62].copy_from_slice(b"MUSIC      FAT16   ");
+        bpb[510..].copy_from_slice(&[0x55, 0xAA]);
+
+        // Clusters 0 and 1 are reserved in both FATs
+        for fat in [2, 2 + FAT_BLOCKS as usize] {
+            blocks[fat][..4].copy_from_slice(&[0xF8, 0xFF, 0xFF, 0xFF]);
+        }
+        Self {
+            blocks: RefCell::new(blocks),
+        }
+    }
+}
+
+impl BlockDevice for RamDisk {
+    type Error = ();
+
+    fn read(&self, blocks: &mut [Block], start: BlockIdx) -> Result<(), ()> {
+        let disk = self.blocks.borrow();
+        let start = start.0 as usize;
+        blocks.clone_from_slice(disk.get(start..start + blocks.len()).ok_or(())?);
+        Ok(())
+    }
+
+    fn write(&self, blocks: &[Block], start: BlockIdx) -> Result<(), ()> {
+        let mut disk = self.blocks.borrow_mut();
+        let start = start.0 as usize;
+        disk.get_mut(start..start + blocks.len())
+            .ok_or(())?
+            .clone_from_slice(blocks);
+        Ok(())
+    }
+
+    fn num_blocks(&self) -> Result<BlockCount, ()> {
+        Ok(BlockCount(self.blocks.borrow().len() as u32))
+    }
+}
+
+struct Clock;
+
+impl TimeSource for Clock {
+    fn get_timestamp(&self) -> Timestamp {
+        Timestamp::from_calendar(2024, 1, 1, 0, 0, 0).unwrap()
+    }
+}
+
+/// 16-bit WAV of a ramp starting at `start`.
+fn ramp_wav(channels: u16, rate: u32, frames: usize, start: i16) -> Vec<u8> {
+    let samples = frames * channels as usize;
+    let mut wav =
+        wav_parser::write_header(&Format::pcm(channels, rate, 16), samples as u32 * 2).to_vec();
+    for i in 0..samples {
+        wav.extend_from_slice(&(start + i as i16).to_le_bytes());
+    }
+    wav
+}
+
+/// `wav` with a chunk `id` of `size` bytes inserted at `at`, padded to an
+/// even length.
+fn with_chunk(wav: &[u8], at: usize, id: &[u8; 4], size: usize) -> Vec<u8> {
+    let mut out = wav[..at].to_vec();
+    out.extend_from_slice(id);
+    out.extend_from_slice(&(size as u32).to_le_bytes());
+    out.resize(out.len() + size + size % 2, 0x55);
+    out.extend_from_slice(&wav[at..]);
+    let riff_size = out.len() as u32 - 8;
+    out[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    out
+}
+
+/// A volume holding `files`, written through `embedded-sdmmc` itself.
+fn card(files: &[(&str, &[u8])]) -> VolumeManager<RamDisk, Clock> {
+    let volume_mgr = VolumeManager::new(RamDisk::fat16(), Clock);
+    {
+        let volume = volume_mgr.open_volume(VolumeIdx(0)).unwrap();
+        let root = volume.open_root_dir().unwrap();
+        for (name, bytes) in files {
+            let file = root
+                .open_file_in_dir(*name, Mode::ReadWriteCreateOrTruncate)
+                .unwrap();
+            file.write(bytes).unwrap();
+            file.close().unwrap();
+        }
+    }
+    volume_mgr
+}
+
+/// Plays the root directory of `volume_mgr` to the end, `chunk` bytes at a
+/// time, and returns the frames and what the player reported.
+fn play_dir(
+    volume_mgr: &VolumeManager<RamDisk, Clock>,
+    chunk: usize,
+) -> (Vec<[i16; 2]>, Vec<DirEvent<()>>) {
+    let volume = volume_mgr.open_volume(VolumeIdx(0)).unwrap();
+    let root = volume.open_root_dir().unwrap();
+    let mut events = Vec::new();
+    let mut sink = MemorySink::new(OutputFormat::STEREO_16, 8000);
+    {
+        let mut player = DirPlayer::new(
+            &root,
+            OutputFormat::STEREO_16,
+            8000,
+            Quality::Sinc,
+            |event| events.push(event),
+        );
+        play(&mut player, &mut vec![0u8; chunk], &mut sink).unwrap();
+    }
+    (sink.frames(), events)
+}
+
+/// The whole content of the file `name` in the root directory.
+fn read_back(volume_mgr: &VolumeManager<RamDisk, Clock>, name: &str) -> Vec<u8> {
+    let volume = volume_mgr.open_volume(VolumeIdx(0)).unwrap();
+    let root = volume.open_root_dir().unwrap();
+    let file = root.open_file_in_dir(name, Mode::ReadOnly).unwrap();
+    let mut bytes = vec![0; file.length() as usize];
+    let mut read = 0;
+    while read < bytes.len() {
+        read += file.read(&mut bytes[read..]).unwrap();
+    }
+    bytes
+}
+
+fn names(events: &[DirEvent<()>]) -> Vec<String> {
+    events
+        .iter()
+        .map(|event| match event {
+            DirEvent::Started(name) => format!("started {name}"),
+            DirEvent::Finished(name) => format!("finished {name}"),
+            DirEvent::Skipped(name, _) => format!("skipped {name}"),
+            DirEvent::Cut(name, _) => format!("cut {name}"),
+            DirEvent::Ended(_) => "ended".into(),
+        })
+        .collect()
+}
+
+#[test]
+fn a_file_stream_reads_past_the_first_block() {
+    let bytes = ramp_wav(1, 8000, 1000, 0);
+    let volume_mgr = card(&[("RAMP.WAV", &bytes)]);
+    let volume = volume_mgr.open_volume(VolumeIdx(0)).unwrap();
+    let root = volume.open_root_dir().unwrap();
+    let file = root.open_file_in_dir("RAMP.WAV", Mode::ReadOnly).unwrap();
+
+    let mut stream = FileStream::new(file, OutputFormat::STEREO_16, 8000, Quality::Sinc)
+        .map_err(|_| "not played")
+        .unwrap();
+    let mut sink = MemorySink::new(OutputFormat::STEREO_16, 8000);
+    let played = play(&mut stream, &mut [0u8; 1000], &mut sink).unwrap();
+
+    assert_eq!(played.bytes, 1000 * 4);
+    let frames = sink.frames();
+    assert!((0..1000).all(|i| frames[i] == [i as i16, i as i16]));
+    assert_eq!(stream.take_error().map(|_| ()), None);
+}
+
+#[test]
+fn chunks_before_the_audio_are_skipped_however_long() {
+    let ramp = ramp_wav(1, 8000, 100, 0);
+    // Tags after `fmt `, and junk before it: both longer than a block
+    let tagged = with_chunk(&ramp, 36, b"LIST", 1999);
+    let padded = with_chunk(&ramp, 12, b"JUNK", 3000);
+    let volume_mgr = card(&[("A.WAV", &tagged), ("B.WAV", &padded)]);
+    let (frames, events) = play_dir(&volume_mgr, 4 * 64);
+
+    let ramp: Vec<[i16; 2]> = (0..100).map(|i| [i, i]).collect();
+    assert_eq!(frames[..100], ramp);
+    assert_eq!(frames[100..200], ramp);
+    assert_eq!(
+        names(&events),
+        [
+            "started A.WAV",
+            "finished A.WAV",
+            "started B.WAV",
+            "finished B.WAV",
+            "ended"
+        ]
+    );
+}
+
+#[test]
+fn a_chunk_past_the_end_of_the_file_is_skipped() {
+    let mut cut = with_chunk(&ramp_wav(1, 8000, 100, 0), 36, b"LIST", 1000);
+    cut.truncate(600);
+    let volume_mgr = card(&[("CUT.WAV", &cut)]);
+    let (_, events) = play_dir(&volume_mgr, 4 * 64);
+
+    assert!(matches!(
+        events[0],
+        DirEvent::Skipped(
+            _,
+            Skip::Wav(wav_parser::Error::TruncatedChunk {
+                id: [b'L', b'I', b'S', b'T'],
+                offset: 36
+            })
+        )
+    ));
+}
+
+#[test]
+fn files_play_in_name_order_without_a_gap() {
+    let first = ramp_wav(1, 8000, 700, 0);
+    let second = ramp_wav(2, 8000, 300, 1000);
+    // Written out of order: the directory order is not the play order
+    let volume_mgr = card(&[("B.WAV", &second), ("A.WAV", &first)]);
+    // Buffers that do not line up with the end of the first file
+    let (frames, events) = play_dir(&volume_mgr, 4 * 256);
+
+    let mut expected: Vec<[i16; 2]> = (0..700).map(|i| [i, i]).collect();
+    expected.extend((0..300).map(|i| [1000 + 2 * i, 1001 + 2 * i]));
+    assert_eq!(frames[..1000], expected);
+    // Only the padding of the last buffer is silence
+    assert_eq!(frames.len(), 1024);
+    assert_eq!(
+        names(&events),
+        [
+            "started A.WAV",
+            "finished A.WAV",
+            "started B.WAV",
+            "finished B.WAV",
+            "ended"
+        ]
+    );
+}
+
+#[test]
+fn unplayable_files_are_skipped_with_a_reason() {
+    let song = ramp_wav(1, 8000, 100, 0);
+    let mut adpcm = ramp_wav(1, 8000, 100, 0);
+    adpcm[20] = 0x11; // IMA ADPCM format tag
+    let volume_mgr = card(&[
+        ("README.TXT", b"not audio"),
+        ("BROKEN.WAV", b"RIFF\x04\x00\x00\x00AVI "),
+        ("ADPCM.WAV", &adpcm),
+        ("SONG.WAV", &song),
+    ]);
+    let (frames, events) = play_dir(&volume_mgr, 4 * 64);
+
+    assert_eq!(frames[..100], (0..100).map(|i| [i, i]).collect::<Vec<_>>());
+    assert_eq!(
+        names(&events),
+        [
+            "skipped ADPCM.WAV",
+            "skipped BROKEN.WAV",
+            "skipped README.TXT",
+            "started SONG.WAV",
+            "finished SONG.WAV",
+            "ended"
+        ]
+    );
+    assert!(matches!(
+        events[1],
+        DirEvent::Skipped(_, Skip::Wav(wav_parser::Error::NotWave))
+    ));
+    assert!(matches!(events[2], DirEvent::Skipped(_, Skip::NotWav)));
+}
+
+#[test]
+fn an_empty_directory_just_ends() {
+    let volume_mgr = card(&[]);
+    let (frames, events) = play_dir(&volume_mgr, 4 * 64);
+    assert!(frames.is_empty());
+    assert!(matches!(events[..], [DirEvent::Ended(None)]));
+}
+
+#[test]
+fn a_recording_gets_its_sizes_on_finish() {
+    let volume_mgr = card(&[]);
+    let samples: Vec<u8> = (0..20_000i32)
+        .flat_map(|i| ((i * 7) as i16).to_le_bytes())
+        .collect();
+    {
+        let volume = volume_mgr.open_volume(VolumeIdx(0)).unwrap();
+        let root = volume.open_root_dir().unwrap();
+        let file = root
+            .open_file_in_dir("REC.WAV", Mode::ReadWriteCreateOrTruncate)
+            .unwrap();
+        let mut recorder = WavRecorder::new(file, Format::pcm(2, 8000, 16)).unwrap();
+        // Chunks that split frames, spanning many clusters, with syncs
+        // seeking back to the header on the way
+        let mut at = 0;
+        while at < samples.len() {
+            let end = (at + 333).min(samples.len());
+            at += recorder.write(&samples[at..end]).unwrap();
+            if at % 10 == 0 {
+                recorder.sync().unwrap();
+            }
+        }
+        assert_eq!(recorder.finish().unwrap(), 40_000);
+    }
+
+    let bytes = read_back(&volume_mgr, "REC.WAV");
+    assert_eq!(bytes.len(), 44 + 40_000);
+    assert_eq!(&bytes[4..8], &(36u32 + 40_000).to_le_bytes());
+    let wav = wav_parser::parse(&bytes).unwrap();
+    assert_eq!(wav.format, Format::pcm(2, 8000, 16));
+    assert_eq!(wav.data, &samples[..]);
+}
+
+#[test]
+fn a_synced_recording_plays_after_a_reset() {
+    let volume_mgr = card(&[]);
+    {
+        let volume = volume_mgr.open_volume(VolumeIdx(0)).unwrap();
+        let root = volume.open_root_dir().unwrap();
+        let file = root
+            .open_file_in_dir("REC.WAV", Mode::ReadWriteCreateOrTruncate)
+            .unwrap();
+        let mut recorder = WavRecorder::new(file, Format::pcm(1, 8000, 16)).unwrap();
+        recorder.write(&[1; 1000]).unwrap();
+        recorder.sync().unwrap();
+        recorder.write(&[2; 600]).unwrap();
+        // Never finished: dropping closes the file as a reset would leave it
+    }
+
+    let bytes = read_back(&volume_mgr, "REC.WAV");
+    let header = wav_parser::parse_header(&bytes).unwrap();
+    assert_eq!(header.data_len, 1000);
+    assert_eq!(&bytes[44..1044], &[1; 1000]);
+}
+
+#[test]
+fn an_odd_8_bit_recording_is_padded() {
+    let volume_mgr = card(&[]);
+    {
+        let volume = volume_mgr.open_volume(VolumeIdx(0)).unwrap();
+        let root = volume.open_root_dir().unwrap();
+        let file = root
+            .open_file_in_dir("REC.WAV", Mode::ReadWriteCreateOrTruncate)
+            .unwrap();
+        let mut recorder = WavRecorder::new(file, Format::pcm(1, 8000, 8)).unwrap();
+        assert_eq!(recorder.write(&[0x80; 101]).unwrap(), 101);
+        assert_eq!(recorder.finish().unwrap(), 101);
+    }
+
+    let bytes = read_back(&volume_mgr, "REC.WAV");
+    assert_eq!(bytes.len(), 44 + 102);
+    assert_eq!(&bytes[4..8], &(36u32 + 102).to_le_bytes());
+    assert_eq!(&bytes[40..44], &101u32.to_le_bytes());
+    assert_eq!(wav_parser::parse(&bytes).unwrap().data.len(), 101);
+}
@@ -1,10 +1,23 @@
+use std::path::Path;
+use std::{env, fs};
+
 fn main() {
     linker_be_nice();
+    convert_songs();
     println!("cargo:rustc-link-arg=-Tdefmt.x");
     // make sure linkall.x is the last linker script (otherwise might cause problems with flip-link)
     println!("cargo:rustc-link-arg=-Tlinkall.x");
 }
 
+/// Turns every `songs/*.mid` into a `ToneValue` array in `OUT_DIR/melodies.rs`,
+/// listed in `MELODIES` with the file name.
+fn convert_songs() {
+    println!("cargo:rerun-if-changed=songs");
+    let source = smf::melodies_source("songs").unwrap_or_else(|err| panic!("songs: {err}"));
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("melodies.rs");
+    fs::write(out, source).unwrap();
+}
+
 fn linker_be_nice() {
     let args: Vec<String> = std::env::args().collect();
     if args.len() > 1 {
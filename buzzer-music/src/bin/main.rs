@@ -6,13 +6,18 @@
     holding buffers for the duration of a data transfer."
 )]
 
-use defmt::info;
+use alloc::vec::Vec;
+use buzzer_music::buzzer_task::buzzer;
+use buzzer_music::{tones, BuzzerCommand, COMMANDS, FINISHED};
+use buzzer_player::{Command, Tone};
+use defmt::{info, warn};
 use embassy_executor::Spawner;
 use embassy_time::{Duration, Timer};
 use esp_hal::ledc::{channel, timer, LSGlobalClkSource};
 use esp_hal::{clock::CpuClock, ledc::Ledc};
 use esp_hal::timer::systimer::SystemTimer;
 use esp_hal_buzzer::{notes::*, song, Buzzer, ToneValue};
+use static_cell::StaticCell;
 use {esp_backtrace as _, esp_println as _};
 
 extern crate alloc;
@@ -21,6 +26,26 @@ extern crate alloc;
 // For more information see: <https://docs.espressif.com/projects/esp-idf/en/stable/esp32/api-reference/system/app_image_format.html#application-description>
 esp_bootloader_esp_idf::esp_app_desc!();
 
+/// Ringtones read at runtime. Any RTTTL text works the same, wherever it
+/// comes from: flash, an SD card, the serial port or the network.
+const RINGTONES: [&str; 2] = [
+    "NokiaTune:d=4,o=5,b=225:8e6,8d6,f#,g#,8c#6,8b,d,e,8b,8a,c#,e,2a",
+    "Tetris:d=4,o=5,b=160:e6,8b,8c6,8d6,16e6,16d6,8c6,8b,a,8a,8c6,e6,8d6,8c6,b,8b,8c6,d6,e6,c6,a,2a",
+];
+
+/// `MELODIES`, converted from `songs/*.mid` by build.rs, as tones for the
+/// buzzer task: the generated code only needs the two fields.
+mod melodies {
+    use buzzer_player::Tone as ToneValue;
+
+    include!(concat!(env!("OUT_DIR"), "/melodies.rs"));
+}
+
+static ZELDA: [Tone; 245] = tones(&ZELDA_THEME);
+
+/// The buzzer task borrows the LEDC for as long as it runs.
+static LEDC: StaticCell<Ledc<'static>> = StaticCell::new();
+
 #[esp_hal_embassy::main]
 async fn main(spawner: Spawner) {
     // generator version: 0.5.0
@@ -35,20 +60,63 @@ async fn main(spawner: Spawner) {
 
     info!("Embassy initialized!");
 
-    let mut pwm = Ledc::new(peripherals.LEDC);
+    let pwm = LEDC.init(Ledc::new(peripherals.LEDC));
     pwm.set_global_slow_clock(LSGlobalClkSource::APBClk);
+    let pwm: &'static Ledc = pwm;
 
-    let mut buzzer = Buzzer::new(&pwm, timer::Number::Timer0, channel::Number::Channel0, peripherals.GPIO5);
+    let piezo = Buzzer::new(pwm, timer::Number::Timer0, channel::Number::Channel0, peripherals.GPIO5);
+    spawner.spawn(buzzer(piezo)).unwrap();
 
+    // Parsed once; the buzzer task needs the tones for as long as it plays them
+    let mut ringtones: Vec<(&str, &'static [Tone])> = Vec::new();
+    for text in RINGTONES {
+        match rtttl::parse(text) {
+            Ok(ringtone) => ringtones.push((ringtone.name, ringtone.tones().collect::<Vec<_>>().leak())),
+            Err(err) => warn!("Skipping ringtone: {}", defmt::Display2Format(&err)),
+        }
+    }
 
     loop {
-        buzzer.play_song(ZELDA_THEME).unwrap();
+        // The task plays on while this one changes tempo and key halfway
+        info!("Playing Zelda");
+        send(Command::Play(&ZELDA)).await;
+        Timer::after(Duration::from_secs(10)).await;
+        info!("Faster");
+        send(Command::SetTempo(150)).await;
+        Timer::after(Duration::from_secs(10)).await;
+        info!("An octave down");
+        send(Command::Transpose(-12)).await;
+        FINISHED.wait().await;
+        send(Command::SetTempo(100)).await;
+        send(Command::Transpose(0)).await;
         Timer::after(Duration::from_secs(1)).await;
+
+        for &(name, tones) in &ringtones {
+            info!("Playing {}", name);
+            send(Command::Play(tones)).await;
+            FINISHED.wait().await;
+            Timer::after(Duration::from_secs(1)).await;
+        }
+
+        for (name, melody) in melodies::MELODIES {
+            info!("Looping {} for 30 s", name);
+            send(Command::SetLooping(true)).await;
+            send(Command::Play(melody)).await;
+            Timer::after(Duration::from_secs(30)).await;
+            send(Command::Stop).await;
+            send(Command::SetLooping(false)).await;
+            FINISHED.wait().await;
+            Timer::after(Duration::from_secs(1)).await;
+        }
     }
 
     // for inspiration have a look at the examples at https://github.com/esp-rs/esp-hal/tree/esp-hal-v1.0.0-rc.0/examples/src/bin
 }
 
+async fn send(command: BuzzerCommand) {
+    COMMANDS.send(command).await;
+}
+
 pub const ZELDA_THEME: [ToneValue; 245] = song!(
     308,
     [
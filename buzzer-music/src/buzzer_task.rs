@@ -0,0 +1,56 @@
+use buzzer_player::{Scheduler, ToneOutput};
+use defmt::warn;
+use embassy_futures::select::{select, Either};
+use embassy_time::{Instant, Timer};
+use esp_hal_buzzer::Buzzer;
+
+use crate::{COMMANDS, FINISHED};
+
+/// The piezo behind the scheduler. A tone that fails is logged and left out:
+/// the song goes on.
+struct Piezo<'a>(Buzzer<'a>);
+
+impl ToneOutput for Piezo<'_> {
+    fn play(&mut self, frequency: u32) {
+        if let Err(err) = self.0.play(frequency) {
+            warn!("Tone of {} Hz: {}", frequency, defmt::Debug2Format(&err));
+        }
+    }
+
+    fn mute(&mut self) {
+        if let Err(err) = self.0.mute() {
+            warn!("Mute: {}", defmt::Debug2Format(&err));
+        }
+    }
+}
+
+/// Owns the buzzer and plays what `COMMANDS` asks for, one tone at a time.
+/// Between tones it sleeps on a timer, so the other tasks keep running, and
+/// a command wakes it up at once.
+#[embassy_executor::task]
+pub async fn buzzer(buzzer: Buzzer<'static>) {
+    let mut piezo = Piezo(buzzer);
+    let mut scheduler = Scheduler::new();
+    piezo.mute();
+    let mut playing = false;
+
+    loop {
+        let deadline = scheduler.poll(Instant::now().as_millis(), &mut piezo);
+        if playing && deadline.is_none() {
+            FINISHED.signal(());
+        }
+        playing = deadline.is_some();
+
+        let command = match deadline {
+            Some(ms) => {
+                match select(COMMANDS.receive(), Timer::at(Instant::from_millis(ms))).await {
+                    Either::First(command) => command,
+                    // Time for the next tone
+                    Either::Second(()) => continue,
+                }
+            }
+            None => COMMANDS.receive().await,
+        };
+        scheduler.handle(command, Instant::now().as_millis(), &mut piezo);
+    }
+}
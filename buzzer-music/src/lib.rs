@@ -1 +1,37 @@
 #![no_std]
+
+use buzzer_player::{Command, Tone};
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_sync::channel::Channel;
+use embassy_sync::signal::Signal;
+use esp_hal_buzzer::ToneValue;
+
+pub mod buzzer_task;
+
+pub type BuzzerCommand = Command<'static>;
+
+/// Commands for the buzzer task, applied between two tones or in the middle
+/// of one.
+pub static COMMANDS: Channel<CriticalSectionRawMutex, BuzzerCommand, 4> = Channel::new();
+
+/// Signalled by the buzzer task when it falls silent: the song played to its
+/// end or was stopped.
+pub static FINISHED: Signal<CriticalSectionRawMutex, ()> = Signal::new();
+
+/// A `song!` array as the tones the buzzer task plays, at compile time:
+/// `static ZELDA: [Tone; 245] = tones(&ZELDA_THEME);`
+pub const fn tones<const N: usize>(song: &[ToneValue; N]) -> [Tone; N] {
+    let mut tones = [Tone {
+        frequency: 0,
+        duration: 0,
+    }; N];
+    let mut i = 0;
+    while i < N {
+        tones[i] = Tone {
+            frequency: song[i].frequency,
+            duration: song[i].duration,
+        };
+        i += 1;
+    }
+    tones
+}
@@ -0,0 +1,240 @@
+//! Note scheduling for a buzzer task that must not block the executor.
+//!
+//! `Buzzer::play_song` waits in a busy loop for the whole song. A
+//! [`Scheduler`] instead starts one tone at a time and says when the next one
+//! is due, so the task sleeps on a timer in between and wakes early for the
+//! [`Command`]s other tasks send: play, stop, loop, tempo and transpose.
+//!
+//! Time is a plain millisecond count given by the caller (`Instant::now()` on
+//! the board, a counter in tests) and the tones go to a [`ToneOutput`], so
+//! the scheduling runs on the host against a mock buzzer.
+//!
+//! ```
+//! use buzzer_player::{Command, Scheduler, Tone, ToneOutput};
+//!
+//! struct Log(Vec<u32>);
+//!
+//! impl ToneOutput for Log {
+//!     fn play(&mut self, frequency: u32) {
+//!         self.0.push(frequency);
+//!     }
+//!
+//!     fn mute(&mut self) {
+//!         self.0.push(0);
+//!     }
+//! }
+//!
+//! let song = [Tone { frequency: 440, duration: 100 }, Tone { frequency: 880, duration: 50 }];
+//! let mut scheduler = Scheduler::new();
+//! let mut buzzer = Log(Vec::new());
+//! scheduler.handle(Command::Play(&song), 0, &mut buzzer);
+//! assert_eq!(scheduler.poll(0, &mut buzzer), Some(100));
+//! assert_eq!(scheduler.poll(100, &mut buzzer), Some(150));
+//! assert_eq!(scheduler.poll(150, &mut buzzer), None);
+//! assert_eq!(buzzer.0, [440, 880, 0]);
+//! ```
+
+#![no_std]
+
+pub use rtttl::Tone;
+
+/// Tempo as written, in percent.
+pub const NORMAL_TEMPO: u16 = 100;
+/// Tempo limits, in percent: a tenth to ten times as fast.
+pub const MIN_TEMPO: u16 = 10;
+pub const MAX_TEMPO: u16 = 1000;
+/// Transposition limit, in semitones either way: four octaves.
+pub const MAX_TRANSPOSE: i8 = 48;
+
+/// The semitones of an octave as frequency ratios, in 1/65536.
+const SEMITONES: [u64; 12] = [
+    65536, 69433, 73562, 77936, 82570, 87480, 92682, 98193, 104032, 110218, 116772, 123715,
+];
+
+/// What drives the piezo: `esp_hal_buzzer::Buzzer` on the board, a log in
+/// tests.
+pub trait ToneOutput {
+    /// Sounds `frequency` Hz until the next call. Never called with 0.
+    fn play(&mut self, frequency: u32);
+    /// Silence, for rests and at the end.
+    fn mute(&mut self);
+}
+
+/// What other tasks ask of the buzzer task.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command<'a> {
+    /// Play a song from its first tone, replacing the current one.
+    Play(&'a [Tone]),
+    Stop,
+    /// Start the song over when it ends, now and for the next ones.
+    SetLooping(bool),
+    /// Speed in percent of the written tempo, from [`MIN_TEMPO`] to
+    /// [`MAX_TEMPO`]. The tone playing is stretched from now on.
+    SetTempo(u16),
+    /// Shift every note by this many semitones, up to [`MAX_TRANSPOSE`]
+    /// either way. The tone playing is shifted at once.
+    Transpose(i8),
+}
+
+/// Plays a song tone by tone at the times [`poll`](Scheduler::poll) is
+/// given.
+#[derive(Clone, Debug)]
+pub struct Scheduler<'a> {
+    song: &'a [Tone],
+    /// Index of the tone after the one playing.
+    next: usize,
+    /// When the tone playing ends, `None` when idle.
+    until: Option<u64>,
+    /// When the current pass over the song started, to stop a looping song
+    /// whose tones all last 0 ms.
+    pass_start: u64,
+    looping: bool,
+    tempo: u16,
+    transpose: i8,
+}
+
+impl Default for Scheduler<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Scheduler<'a> {
+    /// An idle scheduler at the written tempo and pitch, not looping.
+    pub const fn new() -> Self {
+        Self {
+            song: &[],
+            next: 0,
+            until: None,
+            pass_start: 0,
+            looping: false,
+            tempo: NORMAL_TEMPO,
+            transpose: 0,
+        }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.until.is_some()
+    }
+
+    pub fn is_looping(&self) -> bool {
+        self.looping
+    }
+
+    pub fn tempo(&self) -> u16 {
+        self.tempo
+    }
+
+    pub fn transpose(&self) -> i8 {
+        self.transpose
+    }
+
+    /// Applies `command` at `now` ms. Call [`poll`](Scheduler::poll) after
+    /// it for the new deadline.
+    pub fn handle(&mut self, command: Command<'a>, now: u64, out: &mut impl ToneOutput) {
+        match command {
+            Command::Play(song) => {
+                self.song = song;
+                self.next = 0;
+                self.until = Some(now);
+                self.pass_start = now;
+                // Starts the first tone
+                self.poll(now, out);
+            }
+            Command::Stop => {
+                if self.until.is_some() {
+                    self.stop(out);
+                }
+            }
+            Command::SetLooping(looping) => self.looping = looping,
+            Command::SetTempo(tempo) => {
+                let tempo = tempo.clamp(MIN_TEMPO, MAX_TEMPO);
+                if let Some(until) = self.until {
+                    let left = until.saturating_sub(now);
+                    self.until = Some(now + left * self.tempo as u64 / tempo as u64);
+                }
+                self.tempo = tempo;
+            }
+            Command::Transpose(semitones) => {
+                self.transpose = semitones.clamp(-MAX_TRANSPOSE, MAX_TRANSPOSE);
+                if self.until.is_some() {
+                    self.sound(self.song[self.next - 1], out);
+                }
+            }
+        }
+    }
+
+    /// Moves on to the tone playing at `now` ms and returns when it ends, or
+    /// `None` once the song is over. Tones missed by a late call are skipped,
+    /// not played in a burst.
+    pub fn poll(&mut self, now: u64, out: &mut impl ToneOutput) -> Option<u64> {
+        let mut until = self.until?;
+        if until > now {
+            return Some(until);
+        }
+        while until <= now {
+            if self.next == self.song.len() {
+                if !self.looping || until == self.pass_start {
+                    self.stop(out);
+                    return None;
+                }
+                self.next = 0;
+                self.pass_start = until;
+            }
+            until += self.duration(self.song[self.next]);
+            self.next += 1;
+        }
+        self.until = Some(until);
+        self.sound(self.song[self.next - 1], out);
+        Some(until)
+    }
+
+    fn stop(&mut self, out: &mut impl ToneOutput) {
+        self.until = None;
+        self.next = 0;
+        out.mute();
+    }
+
+    fn sound(&self, tone: Tone, out: &mut impl ToneOutput) {
+        match transposed(tone.frequency, self.transpose) {
+            0 => out.mute(),
+            frequency => out.play(frequency),
+        }
+    }
+
+    /// `tone`'s duration at the tempo in force, in ms.
+    fn duration(&self, tone: Tone) -> u64 {
+        tone.duration as u64 * NORMAL_TEMPO as u64 / self.tempo as u64
+    }
+}
+
+/// `frequency` moved by `semitones` on the equal-tempered scale, rounded to
+/// the Hz. A rest (0 Hz) stays one.
+pub fn transposed(frequency: u32, semitones: i8) -> u32 {
+    let (octaves, semitone) = (semitones.div_euclid(12), semitones.rem_euclid(12));
+    // 16 fractional bits, then the octaves: a shift right of 12 to 20 bits
+    let shift = (16 - octaves as i32) as u32;
+    let scaled = frequency as u64 * SEMITONES[semitone as usize];
+    let rounded = (scaled + (1 << (shift - 1))) >> shift;
+    rounded.min(u32::MAX as u64) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transposing_follows_the_equal_tempered_scale() {
+        // A4 to A5, E5, A3 and G#4
+        assert_eq!(transposed(440, 12), 880);
+        assert_eq!(transposed(440, 7), 659);
+        assert_eq!(transposed(440, -12), 220);
+        assert_eq!(transposed(440, -1), 415);
+        assert_eq!(transposed(440, 0), 440);
+        // The `NOTE_*` values of the buzzer crate, a tritone apart
+        assert_eq!(transposed(262, 6), 371);
+        assert_eq!(transposed(0, 5), 0);
+        assert_eq!(transposed(1, -MAX_TRANSPOSE), 0);
+        assert_eq!(transposed(4186, MAX_TRANSPOSE), 66976);
+    }
+}
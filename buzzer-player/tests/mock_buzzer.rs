@@ -0,0 +1,154 @@
+//! Drives a `Scheduler` the way the buzzer task does, with a mock buzzer that
+//! logs what it is asked to sound and when.
+
+use buzzer_player::{Command, Scheduler, Tone, ToneOutput, MAX_TEMPO};
+
+const fn tone(frequency: u32, duration: u32) -> Tone {
+    Tone {
+        frequency,
+        duration,
+    }
+}
+
+const SONG: [Tone; 4] = [tone(262, 100), tone(294, 100), tone(0, 50), tone(330, 200)];
+
+/// `(ms, Hz)` for every change, 0 Hz for silence.
+#[derive(Default)]
+struct MockBuzzer {
+    now: u64,
+    log: Vec<(u64, u32)>,
+}
+
+impl ToneOutput for MockBuzzer {
+    fn play(&mut self, frequency: u32) {
+        assert_ne!(frequency, 0);
+        self.log.push((self.now, frequency));
+    }
+
+    fn mute(&mut self) {
+        self.log.push((self.now, 0));
+    }
+}
+
+/// The task's loop without commands: sleep until each deadline, then poll.
+fn run(scheduler: &mut Scheduler, buzzer: &mut MockBuzzer) {
+    while let Some(deadline) = scheduler.poll(buzzer.now, buzzer) {
+        buzzer.now = deadline;
+    }
+}
+
+fn start(song: &[Tone]) -> (Scheduler<'_>, MockBuzzer) {
+    let mut scheduler = Scheduler::new();
+    let mut buzzer = MockBuzzer::default();
+    scheduler.handle(Command::Play(song), 0, &mut buzzer);
+    (scheduler, buzzer)
+}
+
+#[test]
+fn tones_start_when_the_previous_one_ends() {
+    let (mut scheduler, mut buzzer) = start(&SONG);
+    assert!(scheduler.is_playing());
+    run(&mut scheduler, &mut buzzer);
+    assert_eq!(
+        buzzer.log,
+        [(0, 262), (100, 294), (200, 0), (250, 330), (450, 0)]
+    );
+    assert!(!scheduler.is_playing());
+}
+
+#[test]
+fn early_polls_change_nothing_and_late_ones_skip() {
+    let (mut scheduler, mut buzzer) = start(&SONG);
+    assert_eq!(scheduler.poll(40, &mut buzzer), Some(100));
+    // Woken up late, in the middle of the rest
+    buzzer.now = 220;
+    assert_eq!(scheduler.poll(220, &mut buzzer), Some(250));
+    assert_eq!(buzzer.log, [(0, 262), (220, 0)]);
+}
+
+#[test]
+fn stop_and_play_cut_the_song() {
+    let (mut scheduler, mut buzzer) = start(&SONG);
+    buzzer.now = 150;
+    scheduler.handle(Command::Stop, 150, &mut buzzer);
+    assert_eq!(scheduler.poll(150, &mut buzzer), None);
+    // Stopping again is nothing to do
+    scheduler.handle(Command::Stop, 150, &mut buzzer);
+
+    let other = [tone(440, 30)];
+    buzzer.now = 200;
+    scheduler.handle(Command::Play(&SONG), 200, &mut buzzer);
+    buzzer.now = 210;
+    scheduler.handle(Command::Play(&other), 210, &mut buzzer);
+    run(&mut scheduler, &mut buzzer);
+    assert_eq!(
+        buzzer.log,
+        [(0, 262), (150, 0), (200, 262), (210, 440), (240, 0)]
+    );
+}
+
+#[test]
+fn looping_songs_start_over_until_stopped() {
+    let (mut scheduler, mut buzzer) = start(&SONG[..2]);
+    scheduler.handle(Command::SetLooping(true), 0, &mut buzzer);
+    for _ in 0..5 {
+        buzzer.now = scheduler.poll(buzzer.now, &mut buzzer).unwrap();
+    }
+    assert_eq!(
+        buzzer.log,
+        [(0, 262), (100, 294), (200, 262), (300, 294), (400, 262)]
+    );
+    scheduler.handle(Command::SetLooping(false), 500, &mut buzzer);
+    run(&mut scheduler, &mut buzzer);
+    assert_eq!(buzzer.log[5..], [(500, 294), (600, 0)]);
+}
+
+#[test]
+fn silent_or_empty_songs_end_at_once() {
+    let blank = [tone(440, 0), tone(0, 0)];
+    let (mut scheduler, mut buzzer) = start(&blank);
+    scheduler.handle(Command::SetLooping(true), 0, &mut buzzer);
+    assert_eq!(scheduler.poll(0, &mut buzzer), None);
+    let (mut scheduler, mut buzzer) = start(&[]);
+    assert_eq!(scheduler.poll(0, &mut buzzer), None);
+    assert_eq!(buzzer.log, [(0, 0)]);
+}
+
+#[test]
+fn tempo_stretches_the_tone_playing_and_the_next_ones() {
+    let (mut scheduler, mut buzzer) = start(&SONG);
+    // Twice as fast halfway through the first tone: 50 ms left become 25
+    buzzer.now = 50;
+    scheduler.handle(Command::SetTempo(200), 50, &mut buzzer);
+    assert_eq!(scheduler.poll(50, &mut buzzer), Some(75));
+    run(&mut scheduler, &mut buzzer);
+    assert_eq!(
+        buzzer.log,
+        [(0, 262), (75, 294), (125, 0), (150, 330), (250, 0)]
+    );
+
+    scheduler.handle(Command::SetTempo(u16::MAX), 0, &mut buzzer);
+    assert_eq!(scheduler.tempo(), MAX_TEMPO);
+}
+
+#[test]
+fn transposing_shifts_the_tone_playing_at_once() {
+    let (mut scheduler, mut buzzer) = start(&SONG);
+    buzzer.now = 30;
+    scheduler.handle(Command::Transpose(12), 30, &mut buzzer);
+    assert_eq!(scheduler.poll(30, &mut buzzer), Some(100));
+    run(&mut scheduler, &mut buzzer);
+    // Rests stay silent
+    assert_eq!(
+        buzzer.log,
+        [
+            (0, 262),
+            (30, 524),
+            (100, 588),
+            (200, 0),
+            (250, 660),
+            (450, 0)
+        ]
+    );
+    assert_eq!(scheduler.transpose(), 12);
+}
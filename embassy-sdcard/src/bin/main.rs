@@ -6,6 +6,7 @@
     holding buffers for the duration of a data transfer."
 )]
 
+use audio_pipeline::{Envelope, PcmSource, Synth, Waveform};
 use defmt::info;
 use embassy_executor::Spawner;
 use embassy_time::{Delay, Duration, Timer};
@@ -71,7 +72,15 @@ async fn main(spawner: Spawner) {
 
     // Fill buffer with square wave data
     // Each frame: two 16-bit signed samples (left, right)
-    fill_square_wave(tx_buffer, 440, 44100);
+    // The DMA loops over the buffer, so it holds a whole number of cycles
+    // near 440 Hz at full level: no attack and no jump at the seam
+    let frames = (tx_buffer.len() / 4) as u32;
+    let cycles = (440 * frames + 44100 / 2) / 44100;
+    let mut synth = Synth::new(Waveform::Square { duty: 50 }, 44100);
+    synth.set_envelope(Envelope::FLAT);
+    synth.set_frequency(cycles as f32 * 44100.0 / frames as f32);
+    synth.note_on();
+    synth.fill(tx_buffer);
 
     println!(
         "Filled DMA buffer with {} bytes of square wave",
@@ -111,27 +120,3 @@ async fn main(spawner: Spawner) {
     }
 
     // for inspiration have a look at the examples at https://github.com/esp-rs/esp-hal/tree/esp-hal-v1.0.0-rc.0/examples/src/bin
-
-
-// Fill DMA buffer with a stereo square wave at a given frequency
-fn fill_square_wave(buffer: &mut [u8], freq_hz: u32, sample_rate: u32) {
-    let samples_per_cycle = sample_rate / freq_hz;
-    let half_cycle = samples_per_cycle / 2;
-    let frame_size = 4; // Stereo: 16-bit left + 16-bit right
-    let mut i = 0;
-
-    while i < buffer.len() / frame_size {
-        let sample_val: i16 = if (i % samples_per_cycle as usize) < half_cycle as usize {
-            0x7FFF  // max positive
-        } else {
-            0x8000u16 as i16  // max negative
-        };
-        // Left channel
-        buffer[i * frame_size + 0] = sample_val as u8;
-        buffer[i * frame_size + 1] = (sample_val >> 8) as u8;
-        // Right channel
-        buffer[i * frame_size + 2] = sample_val as u8;
-        buffer[i * frame_size + 3] = (sample_val >> 8) as u8;
-        i += 1;
-    }
-}
@@ -0,0 +1,132 @@
+/// Quantizer step sizes, indexed by the step index.
+const STEP_SIZES: [i16; 89] = [
+    7, 8, 9, 10, 11, 12, 13, 14, 16, 17, 19, 21, 23, 25, 28, 31, 34, 37, 41, 45, 50, 55, 60, 66,
+    73, 80, 88, 97, 107, 118, 130, 143, 157, 173, 190, 209, 230, 253, 279, 307, 337, 371, 408, 449,
+    494, 544, 598, 658, 724, 796, 876, 963, 1060, 1166, 1282, 1411, 1552, 1707, 1878, 2066, 2272,
+    2499, 2749, 3024, 3327, 3660, 4026, 4428, 4871, 5358, 5894, 6484, 7132, 7845, 8630, 9493,
+    10442, 11487, 12635, 13899, 15289, 16818, 18500, 20350, 22385, 24623, 27086, 29794, 32767,
+];
+
+/// Step index change after a code of each magnitude.
+const INDEX_ADJUST: [i8; 8] = [-1, -1, -1, -1, 2, 4, 6, 8];
+
+/// Highest step index.
+const MAX_INDEX: u8 = STEP_SIZES.len() as u8 - 1;
+
+/// Predictor state of one channel. The encoder and decoder update it the
+/// same way after every code, so they stay in step.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Channel {
+    /// The last sample.
+    pub predictor: i16,
+    /// Index of the current step size, 0..=88.
+    pub step_index: u8,
+}
+
+impl Channel {
+    /// Reads a block header: predictor (little-endian), step index and a
+    /// reserved byte. An out of range index is clamped.
+    pub fn from_header(header: &[u8]) -> Self {
+        Self {
+            predictor: i16::from_le_bytes([header[0], header[1]]),
+            step_index: header[2].min(MAX_INDEX),
+        }
+    }
+
+    /// The block header that restarts decoding from this state.
+    pub fn header(&self) -> [u8; 4] {
+        let [low, high] = self.predictor.to_le_bytes();
+        [low, high, self.step_index, 0]
+    }
+
+    /// Decodes one 4-bit code into the next sample.
+    pub fn decode(&mut self, code: u8) -> i16 {
+        let step = STEP_SIZES[self.step_index as usize] as i32;
+        // diff = (code magnitude + 1/2) * step / 4, the way the reference
+        // computes it with shifts
+        let mut diff = step >> 3;
+        if code & 4 != 0 {
+            diff += step;
+        }
+        if code & 2 != 0 {
+            diff += step >> 1;
+        }
+        if code & 1 != 0 {
+            diff += step >> 2;
+        }
+        let predictor = if code & 8 != 0 {
+            self.predictor as i32 - diff
+        } else {
+            self.predictor as i32 + diff
+        };
+        self.predictor = predictor.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        let index = self.step_index as i8 + INDEX_ADJUST[(code & 7) as usize];
+        self.step_index = index.clamp(0, MAX_INDEX as i8) as u8;
+        self.predictor
+    }
+
+    /// Codes `sample` as the step closest to it and updates the state as
+    /// the decoder will.
+    pub fn encode(&mut self, sample: i16) -> u8 {
+        let step = STEP_SIZES[self.step_index as usize] as i32;
+        let mut diff = sample as i32 - self.predictor as i32;
+        let mut code = 0;
+        if diff < 0 {
+            code = 8;
+            diff = -diff;
+        }
+        // Successive approximation of diff / step in three bits
+        let mut threshold = step;
+        for bit in [4, 2, 1] {
+            if diff >= threshold {
+                code |= bit;
+                diff -= threshold;
+            }
+            threshold >>= 1;
+        }
+        self.decode(code);
+        code
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_codes_by_the_step_size() {
+        let mut channel = Channel::default();
+        // Step 7: 0111 adds 7 + 3 + 1 + 0 and moves the index up 8
+        assert_eq!(channel.decode(0b0111), 11);
+        assert_eq!(channel.step_index, 8);
+        // Step 16: 1000 subtracts 16 >> 3 and moves the index down 1
+        assert_eq!(channel.decode(0b1000), 9);
+        assert_eq!(channel.step_index, 7);
+    }
+
+    #[test]
+    fn clamps_the_predictor_and_index() {
+        let mut channel = Channel {
+            predictor: 32000,
+            step_index: 88,
+        };
+        assert_eq!(channel.decode(0b0111), i16::MAX);
+        assert_eq!(channel.step_index, 88);
+        let mut channel = Channel::from_header(&[0, 0x80, 200, 0]);
+        assert_eq!(channel.predictor, i16::MIN);
+        assert_eq!(channel.step_index, 88);
+        assert_eq!(channel.decode(0b1111), i16::MIN);
+    }
+
+    #[test]
+    fn encoder_tracks_the_decoder() {
+        let mut encoder = Channel::default();
+        let mut decoder = Channel::default();
+        for i in 0..500 {
+            let sample = ((i * 97) % 2001 - 1000) as i16 * 20;
+            let code = encoder.encode(sample);
+            assert_eq!(decoder.decode(code), encoder.predictor);
+        }
+        assert_eq!(encoder, decoder);
+    }
+}
@@ -0,0 +1,220 @@
+use wav_parser::{Codec, Error, Format};
+
+use crate::channel::Channel;
+use crate::{nibble_position, HEADER_LEN, MAX_CHANNELS};
+
+/// Streams the `data` chunk of an IMA ADPCM WAV into 16-bit samples.
+///
+/// It remembers how far into the current block it is, so the output can be
+/// taken in buffers of any size.
+#[derive(Clone, Copy, Debug)]
+pub struct Decoder {
+    channels: usize,
+    block_align: usize,
+    samples_per_block: usize,
+    state: [Channel; MAX_CHANNELS],
+    /// Frames of the current block already decoded.
+    frame: usize,
+}
+
+impl Decoder {
+    pub fn new(format: &Format) -> Result<Self, Error> {
+        let Codec::ImaAdpcm { samples_per_block } = format.codec else {
+            return Err(Error::UnsupportedCodec(format.codec.tag()));
+        };
+        if format.channels as usize > MAX_CHANNELS {
+            return Err(Error::UnsupportedFormat(
+                "ADPCM is decoded for 1 or 2 channels",
+            ));
+        }
+        Ok(Self {
+            channels: format.channels as usize,
+            block_align: format.block_align as usize,
+            samples_per_block: samples_per_block as usize,
+            state: [Channel::default(); MAX_CHANNELS],
+            frame: 0,
+        })
+    }
+
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Frames in every block but the last.
+    pub fn samples_per_block(&self) -> usize {
+        self.samples_per_block
+    }
+
+    /// Decodes frames into `out`, interleaved, and returns the bytes of
+    /// `data` consumed and the frames written.
+    ///
+    /// `data` starts with the block being decoded and holds whole blocks,
+    /// except at the end of the clip. Only finished blocks are consumed: pass
+    /// the unconsumed rest again on the next call.
+    pub fn decode(&mut self, data: &[u8], out: &mut [i16]) -> (usize, usize) {
+        let channels = self.channels;
+        let capacity = out.len() / channels;
+        let mut consumed = 0;
+        let mut written = 0;
+
+        while written < capacity {
+            let block = &data[consumed..(consumed + self.block_align).min(data.len())];
+            let frames = self.block_frames(block.len());
+            if frames == 0 {
+                break;
+            }
+
+            let out = &mut out[written * channels..][..channels];
+            if self.frame == 0 {
+                for (c, sample) in out.iter_mut().enumerate() {
+                    self.state[c] = Channel::from_header(&block[c * HEADER_LEN..]);
+                    *sample = self.state[c].predictor;
+                }
+            } else {
+                for (c, sample) in out.iter_mut().enumerate() {
+                    let (byte, high) = nibble_position(self.frame, c, channels);
+                    let code = if high {
+                        block[byte] >> 4
+                    } else {
+                        block[byte] & 0xF
+                    };
+                    *sample = self.state[c].decode(code);
+                }
+            }
+            written += 1;
+            self.frame += 1;
+            if self.frame == frames {
+                consumed += block.len();
+                self.frame = 0;
+            }
+        }
+        (consumed, written)
+    }
+
+    /// Starts over at the beginning of a block.
+    pub fn reset(&mut self) {
+        self.frame = 0;
+    }
+
+    /// Byte offset in `data` of the block holding `frame`: blocks decode on
+    /// their own, so a seek starts there after a [`Decoder::reset`].
+    pub fn block_offset(&self, frame: usize) -> usize {
+        frame / self.samples_per_block * self.block_align
+    }
+
+    /// Frames in a block of `len` bytes: `samples_per_block` for whole
+    /// blocks, fewer for a short last block.
+    fn block_frames(&self, len: usize) -> usize {
+        let header = HEADER_LEN * self.channels;
+        if len < header {
+            return 0;
+        }
+        ((len - header) * 2 / self.channels + 1).min(self.samples_per_block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+
+    /// Stereo, 24-byte blocks: 8 header bytes and 8 frames of nibbles.
+    fn stereo() -> Decoder {
+        Decoder::new(&Format::ima_adpcm(2, 8000, 24)).unwrap()
+    }
+
+    fn block(left: i16, right: i16) -> [u8; 24] {
+        let mut block = [0u8; 24];
+        block[..4].copy_from_slice(
+            &Channel {
+                predictor: left,
+                step_index: 0,
+            }
+            .header(),
+        );
+        block[4..8].copy_from_slice(
+            &Channel {
+                predictor: right,
+                step_index: 0,
+            }
+            .header(),
+        );
+        // Left: 8 codes of +7 (0x7); right: 8 codes of -7 (0xF)
+        block[8..12].fill(0x77);
+        block[12..16].fill(0xFF);
+        block[16..20].fill(0x77);
+        block[20..24].fill(0xFF);
+        block
+    }
+
+    #[test]
+    fn decodes_interleaved_channels() {
+        let data = block(100, -100);
+        let mut decoder = stereo();
+        let mut out = [0i16; 2 * 17];
+        assert_eq!(decoder.decode(&data, &mut out), (24, 17));
+        assert_eq!(out[..2], [100, -100]);
+        // The first code of each channel moves by 7 + 3 + 1
+        assert_eq!(out[2..4], [111, -111]);
+        // Frames 1..=8 come from the first 4 bytes of each channel, 9..=16
+        // from the next
+        let mut left = Channel {
+            predictor: 100,
+            step_index: 0,
+        };
+        let mut right = Channel {
+            predictor: -100,
+            step_index: 0,
+        };
+        for frame in 1..17 {
+            assert_eq!(out[2 * frame], left.decode(0x7));
+            assert_eq!(out[2 * frame + 1], right.decode(0xF));
+        }
+    }
+
+    #[test]
+    fn resumes_inside_a_block() {
+        let data = [block(0, 0), block(1000, 2000)].concat();
+        let mut whole = [0i16; 2 * 34];
+        assert_eq!(stereo().decode(&data, &mut whole), (48, 34));
+
+        let mut decoder = stereo();
+        let mut out = [0i16; 2 * 5];
+        let mut pieces = std::vec::Vec::new();
+        let mut offset = 0;
+        loop {
+            let (consumed, written) = decoder.decode(&data[offset..], &mut out);
+            if written == 0 {
+                break;
+            }
+            offset += consumed;
+            pieces.extend_from_slice(&out[..2 * written]);
+        }
+        assert_eq!(pieces, whole);
+        assert_eq!(offset, data.len());
+    }
+
+    #[test]
+    fn a_short_last_block_ends_the_clip() {
+        // Header and 4 bytes per channel: 1 + 8 frames
+        let data = [&block(0, 0)[..], &block(5, 5)[..16]].concat();
+        let mut out = [0i16; 2 * 100];
+        assert_eq!(stereo().decode(&data, &mut out), (40, 17 + 9));
+        assert_eq!(out[2 * 17], 5);
+    }
+
+    #[test]
+    fn rejects_other_codecs() {
+        assert_eq!(
+            Decoder::new(&Format::pcm(1, 8000, 16)).unwrap_err(),
+            Error::UnsupportedCodec(1)
+        );
+        let mut surround = Format::ima_adpcm(2, 8000, 48);
+        surround.channels = 6;
+        assert!(matches!(
+            Decoder::new(&surround).unwrap_err(),
+            Error::UnsupportedFormat(_)
+        ));
+    }
+}
@@ -0,0 +1,175 @@
+use wav_parser::{Error, Format};
+
+use crate::channel::Channel;
+use crate::{nibble_position, HEADER_LEN, MAX_CHANNELS};
+
+/// Codes 16-bit samples as IMA ADPCM blocks.
+#[derive(Clone, Copy, Debug)]
+pub struct Encoder {
+    format: Format,
+    state: [Channel; MAX_CHANNELS],
+}
+
+impl Encoder {
+    /// An encoder for blocks of `block_align` bytes, a multiple of
+    /// `4 * channels` (256 per channel is usual).
+    pub fn new(channels: u16, sample_rate: u32, block_align: u16) -> Result<Self, Error> {
+        if channels == 0 || channels as usize > MAX_CHANNELS {
+            return Err(Error::UnsupportedFormat(
+                "ADPCM is encoded for 1 or 2 channels",
+            ));
+        }
+        let unit = HEADER_LEN * channels as usize;
+        if block_align as usize <= unit || block_align as usize % unit != 0 {
+            return Err(Error::UnsupportedFormat(
+                "ADPCM block align must be a multiple of 4 bytes per channel",
+            ));
+        }
+        Ok(Self {
+            format: Format::ima_adpcm(channels, sample_rate, block_align),
+            state: [Channel::default(); MAX_CHANNELS],
+        })
+    }
+
+    /// The `fmt ` of the encoded data.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Frames coded in a whole block.
+    pub fn samples_per_block(&self) -> usize {
+        self.format.frames(self.format.block_align as usize) as usize
+    }
+
+    /// Codes up to [`Encoder::samples_per_block`] interleaved frames into
+    /// one block and returns its length: `block_align`, or less for the last,
+    /// short block of a clip.
+    pub fn encode_block(&mut self, frames: &[i16], out: &mut [u8]) -> usize {
+        let channels = self.format.channels as usize;
+        let count = (frames.len() / channels).min(self.samples_per_block());
+        if count == 0 {
+            return 0;
+        }
+        // Codes go 8 frames per channel at a time; pad a short block by
+        // repeating its last frame
+        let coded = (count - 1).div_ceil(8) * 8;
+        let len = HEADER_LEN * channels + coded / 2 * channels;
+        out[..len].fill(0);
+
+        for c in 0..channels {
+            // The step index carries over from the previous block, which
+            // saves the first frames of a block from adapting again
+            self.state[c].predictor = frames[c];
+            out[c * HEADER_LEN..][..HEADER_LEN].copy_from_slice(&self.state[c].header());
+            for index in 1..=coded {
+                let frame = index.min(count - 1);
+                let code = self.state[c].encode(frames[frame * channels + c]);
+                let (byte, high) = nibble_position(index, c, channels);
+                out[byte] |= if high { code << 4 } else { code };
+            }
+        }
+        len
+    }
+}
+
+/// Encodes interleaved 16-bit samples into a complete IMA ADPCM WAV file.
+#[cfg(feature = "std")]
+pub fn encode_pcm(
+    samples: &[i16],
+    channels: u16,
+    sample_rate: u32,
+    block_align: u16,
+) -> std::vec::Vec<u8> {
+    let mut encoder = Encoder::new(channels, sample_rate, block_align)
+        .expect("1 or 2 channels and a block align of 4 bytes per channel");
+    let format = encoder.format();
+    let frames = samples.len() / channels as usize;
+    let block_samples = encoder.samples_per_block() * channels as usize;
+
+    let mut data = std::vec::Vec::new();
+    let mut block = std::vec![0u8; block_align as usize];
+    for chunk in samples[..frames * channels as usize].chunks(block_samples) {
+        let len = encoder.encode_block(chunk, &mut block);
+        data.extend_from_slice(&block[..len]);
+    }
+
+    // RIFF header with the extended `fmt ` (samples per block) and the
+    // `fact` chunk that ADPCM files carry
+    let mut wav = std::vec::Vec::with_capacity(60 + data.len());
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(52 + data.len() as u32).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&20u32.to_le_bytes());
+    wav.extend_from_slice(&format.codec.tag().to_le_bytes());
+    wav.extend_from_slice(&format.channels.to_le_bytes());
+    wav.extend_from_slice(&format.sample_rate.to_le_bytes());
+    wav.extend_from_slice(&format.byte_rate.to_le_bytes());
+    wav.extend_from_slice(&format.block_align.to_le_bytes());
+    wav.extend_from_slice(&format.bits_per_sample.to_le_bytes());
+    wav.extend_from_slice(&2u16.to_le_bytes());
+    wav.extend_from_slice(&(encoder.samples_per_block() as u16).to_le_bytes());
+    wav.extend_from_slice(b"fact");
+    wav.extend_from_slice(&4u32.to_le_bytes());
+    wav.extend_from_slice(&(frames as u32).to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    wav.extend_from_slice(&data);
+    if data.len() % 2 == 1 {
+        wav.push(0);
+    }
+    wav
+}
+
+/// Converts a PCM WAV file (8, 16, 24 or 32 bits, mono or stereo) into an
+/// IMA ADPCM WAV file, for a `build.rs` that compresses the clips it embeds.
+#[cfg(feature = "std")]
+pub fn encode_wav(pcm_wav: &[u8], block_align: u16) -> Result<std::vec::Vec<u8>, Error> {
+    let wav = wav_parser::parse(pcm_wav)?;
+    let format = wav.format;
+    if format.codec != wav_parser::Codec::Pcm {
+        return Err(Error::UnsupportedCodec(format.codec.tag()));
+    }
+    // Checks the channels and block size before any work
+    Encoder::new(format.channels, format.sample_rate, block_align)?;
+
+    let size = format.bytes_per_sample();
+    let samples: std::vec::Vec<i16> = wav
+        .data
+        .chunks_exact(size)
+        .map(|sample| match size {
+            // 8-bit WAV is unsigned; wider samples keep their top 16 bits
+            1 => (sample[0] as i16 - 128) << 8,
+            _ => i16::from_le_bytes([sample[size - 2], sample[size - 1]]),
+        })
+        .collect();
+    Ok(encode_pcm(
+        &samples,
+        format.channels,
+        format.sample_rate,
+        block_align,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lays_out_a_short_block() {
+        let mut encoder = Encoder::new(1, 8000, 256).unwrap();
+        assert_eq!(encoder.samples_per_block(), 505);
+        let mut out = [0xAAu8; 256];
+        // Header frame and 3 coded frames, padded to 8 codes
+        let len = encoder.encode_block(&[500, 500, 500, 500], &mut out);
+        assert_eq!(len, 4 + 4);
+        assert_eq!(out[..4], [0xF4, 0x01, 0, 0]);
+        assert_eq!(out[8], 0xAA);
+    }
+
+    #[test]
+    fn rejects_bad_layouts() {
+        assert!(Encoder::new(3, 8000, 384).is_err());
+        assert!(Encoder::new(2, 8000, 260).is_err());
+        assert!(Encoder::new(1, 8000, 4).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+//! IMA/DVI ADPCM, the 4:1 compression of WAV files with format tag `0x0011`.
+//!
+//! Every sample is coded as a 4-bit step relative to a prediction, so a clip
+//! takes a quarter of the flash of 16-bit PCM. The data is cut into blocks
+//! that start with the predictor state of every channel, which keeps the
+//! decoder small: a few bytes of state and no buffers.
+//!
+//! * [`Decoder`] (`no_std`) turns the `data` chunk of an ADPCM WAV into
+//!   interleaved 16-bit samples, a buffer at a time.
+//! * [`Encoder`] codes 16-bit samples block by block, and with the `std`
+//!   feature [`encode_wav`] converts a whole PCM WAV file, e.g. from a
+//!   `build.rs`.
+//!
+//! ```
+//! let pcm: Vec<i16> = (0..2000).map(|i| ((i as f32 / 10.0).sin() * 8000.0) as i16).collect();
+//! let wav = ima_adpcm::encode_pcm(&pcm, 1, 8000, 256);
+//! let wav = wav_parser::parse(&wav).unwrap();
+//!
+//! let mut decoder = ima_adpcm::Decoder::new(&wav.format).unwrap();
+//! // The last block is padded to whole bytes per channel
+//! let frames = wav.format.frames(wav.data.len()) as usize;
+//! assert_eq!(frames, 2004);
+//! let mut out = vec![0i16; frames];
+//! let (consumed, written) = decoder.decode(wav.data, &mut out);
+//! assert_eq!((consumed, written), (wav.data.len(), frames));
+//! ```
+
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+mod channel;
+mod decoder;
+mod encoder;
+
+pub use channel::Channel;
+pub use decoder::Decoder;
+pub use encoder::Encoder;
+#[cfg(feature = "std")]
+pub use encoder::{encode_pcm, encode_wav};
+
+/// Channels the decoder and encoder handle: mono and stereo.
+pub const MAX_CHANNELS: usize = 2;
+
+/// Bytes of block header per channel: predictor, step index, reserved.
+const HEADER_LEN: usize = 4;
+
+/// Position of frame `index` (from 1, frame 0 is in the header) of
+/// `channel` in a block: its byte, and whether it is the high nibble.
+/// Channels take turns every 4 bytes (8 frames).
+fn nibble_position(index: usize, channel: usize, channels: usize) -> (usize, bool) {
+    let i = index - 1;
+    let byte = HEADER_LEN * channels + (i / 8 * channels + channel) * 4 + i % 8 / 2;
+    (byte, i % 2 == 1)
+}
@@ -0,0 +1,95 @@
+//! Encodes clips to IMA ADPCM and decodes them back, measuring how much of
+//! the signal survives.
+
+use ima_adpcm::{encode_pcm, encode_wav, Decoder};
+
+const PCM16_MONO: &[u8] = include_bytes!("../../wav-parser/tests/fixtures/pcm16_mono_8000.wav");
+
+/// Decodes a whole ADPCM WAV file, a few frames at a time.
+fn decode(file: &[u8]) -> Vec<i16> {
+    let wav = wav_parser::parse(file).unwrap();
+    let mut decoder = Decoder::new(&wav.format).unwrap();
+    let channels = decoder.channels();
+    let mut out = [0i16; 2 * 100];
+    let mut samples = Vec::new();
+    let mut offset = 0;
+    loop {
+        let (consumed, written) = decoder.decode(&wav.data[offset..], &mut out);
+        if written == 0 {
+            break;
+        }
+        offset += consumed;
+        samples.extend_from_slice(&out[..written * channels]);
+    }
+    assert_eq!(offset, wav.data.len());
+    samples
+}
+
+fn snr_db(original: &[i16], decoded: &[i16]) -> f64 {
+    let (mut signal, mut noise) = (0.0, 0.0);
+    for (&a, &b) in original.iter().zip(decoded) {
+        signal += (a as f64).powi(2);
+        noise += (a as f64 - b as f64).powi(2);
+    }
+    10.0 * (signal / noise).log10()
+}
+
+/// A sweep from 100 Hz to 2 kHz at 8 kHz, at half of full scale.
+fn chirp(frames: usize) -> Vec<i16> {
+    (0..frames)
+        .map(|i| {
+            let t = i as f64 / 8000.0;
+            let phase = 2.0 * std::f64::consts::PI * (100.0 * t + 950.0 * t * t);
+            (phase.sin() * 16000.0) as i16
+        })
+        .collect()
+}
+
+#[test]
+fn mono_chirp() {
+    let pcm = chirp(8000);
+    let decoded = decode(&encode_pcm(&pcm, 1, 8000, 256));
+    assert!(decoded.len() >= pcm.len());
+    let snr = snr_db(&pcm, &decoded);
+    assert!(snr > 20.0, "{snr:.1} dB");
+}
+
+#[test]
+fn stereo_keeps_the_channels_apart() {
+    let left = chirp(3000);
+    let pcm: Vec<i16> = left.iter().flat_map(|&l| [l, l / -4]).collect();
+    let decoded = decode(&encode_pcm(&pcm, 2, 8000, 512));
+    let snr = snr_db(&pcm, &decoded[..pcm.len()]);
+    assert!(snr > 25.0, "{snr:.1} dB");
+}
+
+#[test]
+fn wav_fixture() {
+    let source = wav_parser::parse(PCM16_MONO).unwrap();
+    let adpcm = encode_wav(PCM16_MONO, 256).unwrap();
+    // A quarter of the size, plus the block headers
+    assert!(adpcm.len() < PCM16_MONO.len() / 3);
+
+    let wav = wav_parser::parse(&adpcm).unwrap();
+    assert_eq!(wav.format.sample_rate, 8000);
+    assert!(wav.frames() >= source.frames());
+
+    let pcm: Vec<i16> = source
+        .data
+        .chunks_exact(2)
+        .map(|sample| i16::from_le_bytes([sample[0], sample[1]]))
+        .collect();
+    let snr = snr_db(&pcm, &decode(&adpcm));
+    // The coder starts from the smallest step and takes about 100 frames to
+    // reach the full-scale tone of this short clip (30 dB after that)
+    assert!(snr > 18.0, "{snr:.1} dB");
+}
+
+#[test]
+fn rejects_compressed_sources() {
+    let adpcm = encode_pcm(&[0; 100], 1, 8000, 256);
+    assert_eq!(
+        encode_wav(&adpcm, 256),
+        Err(wav_parser::Error::UnsupportedCodec(0x11))
+    );
+}
@@ -0,0 +1,47 @@
+//! Scalefactor band boundaries, ISO/IEC 11172-3 Table B.8 and 13818-3
+//! Table B.2, indexed by `Header::rate_index`.
+
+/// Start line of every long block band, and 576.
+#[rustfmt::skip]
+pub(crate) static LONG: [[u16; 23]; 9] = [
+    // MPEG-1: 44.1, 48, 32 kHz
+    [0, 4, 8, 12, 16, 20, 24, 30, 36, 44, 52, 62, 74, 90, 110, 134, 162, 196, 238, 288, 342, 418, 576],
+    [0, 4, 8, 12, 16, 20, 24, 30, 36, 42, 50, 60, 72, 88, 106, 128, 156, 190, 230, 276, 330, 384, 576],
+    [0, 4, 8, 12, 16, 20, 24, 30, 36, 44, 54, 66, 82, 102, 126, 156, 194, 240, 296, 364, 448, 550, 576],
+    // MPEG-2: 22.05, 24, 16 kHz
+    [0, 6, 12, 18, 24, 30, 36, 44, 54, 66, 80, 96, 116, 140, 168, 200, 238, 284, 336, 396, 464, 522, 576],
+    [0, 6, 12, 18, 24, 30, 36, 44, 54, 66, 80, 96, 114, 136, 162, 194, 232, 278, 332, 394, 464, 540, 576],
+    [0, 6, 12, 18, 24, 30, 36, 44, 54, 66, 80, 96, 116, 140, 168, 200, 238, 284, 336, 396, 464, 522, 576],
+    // MPEG-2.5: 11.025, 12, 8 kHz
+    [0, 6, 12, 18, 24, 30, 36, 44, 54, 66, 80, 96, 116, 140, 168, 200, 238, 284, 336, 396, 464, 522, 576],
+    [0, 6, 12, 18, 24, 30, 36, 44, 54, 66, 80, 96, 116, 140, 168, 200, 238, 284, 336, 396, 464, 522, 576],
+    [0, 12, 24, 36, 48, 60, 72, 88, 108, 132, 160, 192, 232, 280, 336, 400, 476, 566, 568, 570, 572, 574, 576],
+];
+
+/// Start line of every short block band within one window, and 192.
+#[rustfmt::skip]
+pub(crate) static SHORT: [[u16; 14]; 9] = [
+    [0, 4, 8, 12, 16, 22, 30, 40, 52, 66, 84, 106, 136, 192],
+    [0, 4, 8, 12, 16, 22, 28, 38, 50, 64, 80, 100, 126, 192],
+    [0, 4, 8, 12, 16, 22, 30, 42, 58, 78, 104, 138, 180, 192],
+    [0, 4, 8, 12, 18, 24, 32, 42, 56, 74, 100, 132, 174, 192],
+    [0, 4, 8, 12, 18, 26, 36, 48, 62, 80, 104, 136, 180, 192],
+    [0, 4, 8, 12, 18, 26, 36, 48, 62, 80, 104, 134, 174, 192],
+    [0, 4, 8, 12, 18, 26, 36, 48, 62, 80, 104, 134, 174, 192],
+    [0, 4, 8, 12, 18, 26, 36, 48, 62, 80, 104, 134, 174, 192],
+    [0, 8, 16, 24, 36, 52, 72, 96, 124, 160, 162, 164, 166, 192],
+];
+
+/// Lines of a mixed block that are coded as long blocks (two subbands).
+pub(crate) const MIXED_LONG_LINES: usize = 36;
+
+/// First short band of a mixed block.
+pub(crate) const MIXED_FIRST_SHORT: usize = 3;
+
+/// Long bands below [`MIXED_LONG_LINES`]: 8 for MPEG-1, 6 for MPEG-2.
+pub(crate) fn mixed_long_bands(rate_index: usize) -> usize {
+    LONG[rate_index]
+        .iter()
+        .take_while(|&&start| (start as usize) < MIXED_LONG_LINES)
+        .count()
+}
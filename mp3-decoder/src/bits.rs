@@ -0,0 +1,62 @@
+/// Reads big-endian bit fields. Reading past the end yields zeros, so a
+/// corrupt length decodes silence instead of panicking.
+pub(crate) struct BitReader<'a> {
+    data: &'a [u8],
+    /// Bits read so far.
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn seek(&mut self, position: usize) {
+        self.position = position;
+    }
+
+    /// Reads `count` bits, at most 24.
+    pub fn bits(&mut self, count: u32) -> u32 {
+        debug_assert!(count <= 24);
+        if count == 0 {
+            return 0;
+        }
+        let byte = self.position / 8;
+        let mut window = 0u32;
+        for i in 0..4 {
+            window = window << 8 | *self.data.get(byte + i).unwrap_or(&0) as u32;
+        }
+        let value = (window << (self.position % 8)) >> (32 - count);
+        self.position += count as usize;
+        value
+    }
+
+    pub fn bit(&mut self) -> bool {
+        self.bits(1) == 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_fields_across_bytes() {
+        let mut bits = BitReader::new(&[0b1011_0011, 0b0101_1111, 0xFF]);
+        assert!(bits.bit());
+        assert_eq!(bits.bits(3), 0b011);
+        assert_eq!(bits.bits(9), 0b0_0110_1011);
+        assert_eq!(bits.position(), 13);
+        assert_eq!(bits.bits(0), 0);
+        assert_eq!(bits.bits(11), 0b111_1111_1111);
+        // Past the end
+        assert_eq!(bits.bits(8), 0);
+
+        bits.seek(16);
+        assert_eq!(bits.bits(8), 0xFF);
+    }
+}
@@ -0,0 +1,241 @@
+use crate::bits::BitReader;
+use crate::error::Error;
+use crate::header::{sync, FrameInfo, Version};
+use crate::huffman;
+use crate::hybrid::{self, Slots, SLOTS, SUBBANDS};
+use crate::requantize::requantize;
+use crate::scalefactors::Scalefactors;
+use crate::side_info::SideInfo;
+use crate::stereo;
+use crate::synth::Synth;
+use crate::GRANULE_LINES;
+
+/// Main data can start at most this many bytes back in earlier frames.
+const MAX_RESERVOIR: usize = 511;
+
+/// Room for the reservoir plus the main data of the largest frame.
+const RESERVOIR_CAPACITY: usize = 2048;
+
+/// What one call to [`Decoder::decode_frame`] did.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub info: FrameInfo,
+    /// Where the frame starts in the data, after any tag or garbage.
+    pub offset: usize,
+    /// Bytes to skip to get to the next frame.
+    pub consumed: usize,
+    /// Samples per channel written to the output. 0 when the frame cannot
+    /// be decoded: its side information is corrupt, or its main data starts
+    /// in frames that were not decoded (the first frames after a seek). The
+    /// frame still feeds the bit reservoir.
+    pub samples: usize,
+}
+
+/// Decodes a stream frame by frame. It keeps the state that carries over
+/// from one frame to the next, so frames must come in order; call
+/// [`Decoder::reset`] before jumping elsewhere in the stream.
+pub struct Decoder {
+    reservoir: [u8; RESERVOIR_CAPACITY],
+    reservoir_len: usize,
+    /// Frequency lines of the granule being decoded, per channel.
+    lines: [[i32; GRANULE_LINES]; 2],
+    /// Second halves of the previous granule's IMDCT blocks.
+    overlap: [[i32; GRANULE_LINES]; 2],
+    slots: Slots,
+    synth: [Synth; 2],
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decoder {
+    pub const fn new() -> Self {
+        Self {
+            reservoir: [0; RESERVOIR_CAPACITY],
+            reservoir_len: 0,
+            lines: [[0; GRANULE_LINES]; 2],
+            overlap: [[0; GRANULE_LINES]; 2],
+            slots: [[0; SUBBANDS]; SLOTS],
+            synth: [Synth::new(), Synth::new()],
+        }
+    }
+
+    /// Forgets the previous frames, for decoding from another position.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Decodes the first frame in `data` into interleaved samples in `pcm`,
+    /// which must hold `info.samples * info.channels` of them
+    /// ([`MAX_SAMPLES_PER_FRAME`](crate::MAX_SAMPLES_PER_FRAME) always do).
+    /// Bytes before the frame (ID3v2 tags, garbage) are skipped.
+    pub fn decode_frame(&mut self, data: &[u8], pcm: &mut [i16]) -> Result<Frame, Error> {
+        let (offset, header) = sync(data)?;
+        let info = header.info;
+        let channels = info.channels as usize;
+        let needed = info.samples * channels;
+        if pcm.len() < needed {
+            return Err(Error::BufferTooSmall { needed });
+        }
+        let frame = &data[offset..offset + header.len];
+        let mut result = Frame {
+            info,
+            offset,
+            consumed: offset + header.len,
+            samples: 0,
+        };
+
+        let side_start = if header.crc { 6 } else { 4 };
+        let main_start = side_start + header.side_info_len();
+        let side = SideInfo::parse(&frame[side_start..], &header);
+        let main_data = frame.get(main_start..).unwrap_or(&[]);
+        let Some(main) = self.fill_reservoir(main_data, side.map(|side| side.main_data_begin))
+        else {
+            return Ok(result);
+        };
+        let side = side.unwrap_or_default();
+
+        let Self {
+            reservoir,
+            lines,
+            overlap,
+            slots,
+            synth,
+            ..
+        } = self;
+        let mut bits = BitReader::new(&reservoir[main.0..main.1]);
+        let end_of_data = (main.1 - main.0) * 8;
+        let granules = if info.version == Version::Mpeg1 { 2 } else { 1 };
+        let mut previous = [Scalefactors::default(); 2];
+
+        for (granule, side_granules) in side.granules[..granules].iter().enumerate() {
+            let mut scalefactors = [Scalefactors::default(); 2];
+            for ch in 0..channels {
+                let gr = &side_granules[ch];
+                let start = bits.position();
+                let end = start + gr.part2_3_length;
+                let sf = if info.version == Version::Mpeg1 {
+                    let previous = (granule == 1).then_some(&previous[ch]);
+                    Scalefactors::read_mpeg1(&mut bits, gr, side.scfsi[ch], previous)
+                } else {
+                    Scalefactors::read_lsf(&mut bits, gr, ch == 1 && header.intensity())
+                };
+                let count = if end > end_of_data || bits.position() > end {
+                    // Corrupt: this granule decodes to silence
+                    lines[ch].fill(0);
+                    bits.seek(end);
+                    0
+                } else {
+                    huffman::decode(&mut bits, gr, end, &mut lines[ch])
+                };
+                requantize(&mut lines[ch], count, gr, &sf, header.rate_index);
+                scalefactors[ch] = sf;
+            }
+            previous = scalefactors;
+
+            if channels == 2 && (header.mid_side() || header.intensity()) {
+                stereo::process(lines, &header, &side_granules[1], &scalefactors[1]);
+            }
+            for ch in 0..channels {
+                let gr = &side_granules[ch];
+                hybrid::synthesize(
+                    &mut lines[ch],
+                    gr,
+                    header.rate_index,
+                    &mut overlap[ch],
+                    slots,
+                );
+                let granule_out = &mut pcm[granule * GRANULE_LINES * channels..];
+                for (slot, subbands) in slots.iter().enumerate() {
+                    let out = &mut granule_out[slot * SUBBANDS * channels + ch..];
+                    synth[ch].slot(subbands, out, channels);
+                }
+            }
+        }
+
+        result.samples = info.samples;
+        Ok(result)
+    }
+
+    /// Appends the frame's main data to the bit reservoir and returns where
+    /// this frame's main data lies in it, or `None` if it begins before the
+    /// data the reservoir holds.
+    fn fill_reservoir(
+        &mut self,
+        main_data: &[u8],
+        main_data_begin: Option<usize>,
+    ) -> Option<(usize, usize)> {
+        let keep = self.reservoir_len.min(MAX_RESERVOIR);
+        self.reservoir
+            .copy_within(self.reservoir_len - keep..self.reservoir_len, 0);
+        let len = main_data.len().min(RESERVOIR_CAPACITY - keep);
+        self.reservoir[keep..keep + len].copy_from_slice(&main_data[..len]);
+        self.reservoir_len = keep + len;
+        let begin = keep.checked_sub(main_data_begin?)?;
+        Some((begin, self.reservoir_len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::Header;
+    use crate::MAX_SAMPLES_PER_FRAME;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    /// A silent MPEG-1 frame: 128 kbit/s, 44.1 kHz, joint stereo, with
+    /// all-zero side information and main data.
+    fn silent_frame() -> Vec<u8> {
+        let header = Header::parse(&[0xFF, 0xFB, 0x90, 0x64]).unwrap();
+        let mut frame = std::vec![0; header.len];
+        frame[..4].copy_from_slice(&[0xFF, 0xFB, 0x90, 0x64]);
+        frame
+    }
+
+    #[test]
+    fn decodes_silence() {
+        let frame = silent_frame();
+        let stream = [frame.clone(), frame].concat();
+        let mut decoder = Decoder::new();
+        let mut pcm = [1i16; MAX_SAMPLES_PER_FRAME];
+        let decoded = decoder.decode_frame(&stream, &mut pcm).unwrap();
+        assert_eq!(decoded.offset, 0);
+        assert_eq!(decoded.consumed, 417);
+        assert_eq!(decoded.samples, 1152);
+        assert!(pcm.iter().all(|&sample| sample == 0));
+    }
+
+    #[test]
+    fn waits_for_the_reservoir_to_fill() {
+        let mut frame = silent_frame();
+        // main_data_begin = 100, with nothing decoded before
+        frame[4] = 100 >> 1;
+        frame[5] = (100 & 1) << 7;
+        let stream = [frame.clone(), silent_frame()].concat();
+        let mut decoder = Decoder::new();
+        let mut pcm = [0i16; MAX_SAMPLES_PER_FRAME];
+        assert_eq!(decoder.decode_frame(&stream, &mut pcm).unwrap().samples, 0);
+        // Now the previous frame's main data is there to start in
+        let decoded = decoder.decode_frame(&stream[417..], &mut pcm).unwrap();
+        assert_eq!(decoded.samples, 1152);
+        assert_eq!(
+            decoder.decode_frame(&frame, &mut pcm).unwrap().samples,
+            1152
+        );
+    }
+
+    #[test]
+    fn checks_the_output_size() {
+        let frame = silent_frame();
+        let mut pcm = [0i16; 1000];
+        assert_eq!(
+            Decoder::new().decode_frame(&frame, &mut pcm),
+            Err(Error::BufferTooSmall { needed: 2304 })
+        );
+    }
+}
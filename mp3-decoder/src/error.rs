@@ -0,0 +1,24 @@
+use core::fmt;
+
+/// Everything that stops a frame from being decoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// There is no frame header in the data: the end of the stream, or not MP3.
+    NoFrame,
+    /// The frame found at `offset` runs past the end of the data.
+    Truncated { offset: usize },
+    /// The output buffer cannot hold the `needed` samples of the frame.
+    BufferTooSmall { needed: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NoFrame => f.write_str("no MP3 frame in the data"),
+            Error::Truncated { offset } => write!(f, "frame at byte {offset} is truncated"),
+            Error::BufferTooSmall { needed } => {
+                write!(f, "output buffer too small, {needed} samples needed")
+            }
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use crate::error::Error;
+
+/// MPEG audio version. MPEG-2 and 2.5 ("LSF", low sampling frequencies)
+/// frames carry one granule instead of two.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Version {
+    Mpeg1,
+    Mpeg2,
+    Mpeg25,
+}
+
+/// Channel mode of a frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Stereo,
+    /// Stereo with mid/side and/or intensity coding.
+    JointStereo,
+    /// Two independent mono channels.
+    DualChannel,
+    Mono,
+}
+
+/// What a frame header says about the audio.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameInfo {
+    pub version: Version,
+    pub mode: Mode,
+    pub channels: u8,
+    pub sample_rate: u32,
+    pub bitrate_kbps: u32,
+    /// Samples per channel in a frame: 1152 for MPEG-1, 576 otherwise.
+    pub samples: usize,
+}
+
+/// A parsed 4-byte frame header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Header {
+    pub info: FrameInfo,
+    /// A 16-bit CRC follows the header.
+    pub crc: bool,
+    /// Mid/side and intensity stereo flags of joint stereo frames.
+    pub mode_extension: u8,
+    /// Row of the scalefactor band tables: version * 3 + sampling frequency.
+    pub rate_index: usize,
+    /// Whole frame length in bytes, header included.
+    pub len: usize,
+}
+
+const BITRATES_MPEG1: [u32; 15] = [
+    0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320,
+];
+const BITRATES_LSF: [u32; 15] = [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+const SAMPLE_RATES: [u32; 3] = [44100, 48000, 32000];
+
+impl Header {
+    /// Parses a Layer III header. Free-format streams (no bitrate) are not
+    /// supported and, like reserved values, read as "not a header".
+    pub fn parse(bytes: &[u8]) -> Option<Header> {
+        let &[b0, b1, b2, b3, ..] = bytes else {
+            return None;
+        };
+        if b0 != 0xFF || b1 & 0xE0 != 0xE0 || (b1 >> 1) & 3 != 1 {
+            return None;
+        }
+        let (version, row, shift) = match (b1 >> 3) & 3 {
+            0 => (Version::Mpeg25, 2, 2),
+            2 => (Version::Mpeg2, 1, 1),
+            3 => (Version::Mpeg1, 0, 0),
+            _ => return None,
+        };
+        let bitrate_index = (b2 >> 4) as usize;
+        let rate = ((b2 >> 2) & 3) as usize;
+        if bitrate_index == 0 || bitrate_index == 15 || rate == 3 {
+            return None;
+        }
+
+        let mode = match b3 >> 6 {
+            0 => Mode::Stereo,
+            1 => Mode::JointStereo,
+            2 => Mode::DualChannel,
+            _ => Mode::Mono,
+        };
+        let sample_rate = SAMPLE_RATES[rate] >> shift;
+        let padding = ((b2 >> 1) & 1) as usize;
+        let (bitrate_kbps, samples) = match version {
+            Version::Mpeg1 => (BITRATES_MPEG1[bitrate_index], 1152),
+            _ => (BITRATES_LSF[bitrate_index], 576),
+        };
+        // Bytes = samples / 8 * bitrate / sample rate
+        let len = (samples / 8) * bitrate_kbps as usize * 1000 / sample_rate as usize + padding;
+
+        Some(Header {
+            info: FrameInfo {
+                version,
+                mode,
+                channels: if mode == Mode::Mono { 1 } else { 2 },
+                sample_rate,
+                bitrate_kbps,
+                samples,
+            },
+            crc: b1 & 1 == 0,
+            mode_extension: (b3 >> 4) & 3,
+            rate_index: row * 3 + rate,
+            len,
+        })
+    }
+
+    /// Bytes of side information after the header (and CRC).
+    pub fn side_info_len(&self) -> usize {
+        match (self.info.version, self.info.channels) {
+            (Version::Mpeg1, 1) => 17,
+            (Version::Mpeg1, _) => 32,
+            (_, 1) => 9,
+            _ => 17,
+        }
+    }
+
+    pub fn mid_side(&self) -> bool {
+        self.info.mode == Mode::JointStereo && self.mode_extension & 2 != 0
+    }
+
+    pub fn intensity(&self) -> bool {
+        self.info.mode == Mode::JointStereo && self.mode_extension & 1 != 0
+    }
+
+    /// Whether `other` can be the next frame of the same stream.
+    fn continues_with(&self, other: &Header) -> bool {
+        self.info.version == other.info.version && self.rate_index == other.rate_index
+    }
+}
+
+/// Finds the first frame in `data`, skipping an ID3v2 tag and any bytes that
+/// are not a frame. Returns its offset and what it holds.
+pub fn find_frame(data: &[u8]) -> Result<(usize, FrameInfo), Error> {
+    sync(data).map(|(offset, header)| (offset, header.info))
+}
+
+/// Looks for a header that is followed by another header of the same stream
+/// (or by the end of the data, or an ID3v1 tag), so a stray `0xFFF` in the
+/// audio data is not taken for a frame.
+pub(crate) fn sync(data: &[u8]) -> Result<(usize, Header), Error> {
+    let mut offset = id3v2_len(data);
+    while offset + 4 <= data.len() {
+        if let Some(header) = Header::parse(&data[offset..]) {
+            let end = offset + header.len;
+            let next = data.get(end..).ok_or(Error::Truncated { offset })?;
+            let followed = next.len() < 4
+                || next.starts_with(b"TAG")
+                || Header::parse(next).is_some_and(|n| header.continues_with(&n));
+            if followed {
+                return Ok((offset, header));
+            }
+        }
+        offset += 1;
+    }
+    Err(Error::NoFrame)
+}
+
+/// Length of the ID3v2 tag at the start of `data`, or 0.
+fn id3v2_len(data: &[u8]) -> usize {
+    match data {
+        [b'I', b'D', b'3', _, _, flags, size @ ..] if size.len() >= 4 => {
+            let size = size[..4]
+                .iter()
+                .fold(0, |len, &b| len << 7 | (b & 0x7F) as usize);
+            let footer = if flags & 0x10 != 0 { 10 } else { 0 };
+            (10 + size + footer).min(data.len())
+        }
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// MPEG-1 Layer III, 128 kbit/s, 44.1 kHz, joint stereo, no CRC.
+    const HEADER: [u8; 4] = [0xFF, 0xFB, 0x90, 0x64];
+
+    #[test]
+    fn parses_a_mpeg1_header() {
+        let header = Header::parse(&HEADER).unwrap();
+        assert_eq!(
+            header.info,
+            FrameInfo {
+                version: Version::Mpeg1,
+                mode: Mode::JointStereo,
+                channels: 2,
+                sample_rate: 44100,
+                bitrate_kbps: 128,
+                samples: 1152,
+            }
+        );
+        assert!(!header.crc);
+        assert_eq!(header.len, 417);
+        assert_eq!(header.side_info_len(), 32);
+        assert!(header.mid_side());
+        assert!(!header.intensity());
+    }
+
+    #[test]
+    fn parses_lsf_headers() {
+        // MPEG-2, 64 kbit/s, 22.05 kHz, mono, padded, with CRC
+        let header = Header::parse(&[0xFF, 0xF2, 0x82, 0xC0]).unwrap();
+        assert_eq!(header.info.version, Version::Mpeg2);
+        assert_eq!(header.info.sample_rate, 22050);
+        assert_eq!(header.info.channels, 1);
+        assert_eq!(header.info.samples, 576);
+        assert!(header.crc);
+        assert_eq!(header.len, 72 * 64000 / 22050 + 1);
+        assert_eq!(header.side_info_len(), 9);
+        assert_eq!(header.rate_index, 3);
+
+        // MPEG-2.5, 8 kHz
+        let header = Header::parse(&[0xFF, 0xE3, 0x18, 0x00]).unwrap();
+        assert_eq!(header.info.version, Version::Mpeg25);
+        assert_eq!(header.info.sample_rate, 8000);
+        assert_eq!(header.rate_index, 8);
+    }
+
+    #[test]
+    fn rejects_other_layers_and_reserved_values() {
+        assert!(Header::parse(&[0xFF, 0xFD, 0x90, 0x64]).is_none()); // Layer II
+        assert!(Header::parse(&[0xFF, 0xFB, 0x00, 0x64]).is_none()); // free format
+        assert!(Header::parse(&[0xFF, 0xFB, 0xF0, 0x64]).is_none()); // bad bitrate
+        assert!(Header::parse(&[0xFF, 0xFB, 0x9C, 0x64]).is_none()); // bad rate
+        assert!(Header::parse(&[0xFF, 0xEB, 0x90, 0x64]).is_none()); // reserved version
+        assert!(Header::parse(&HEADER[..3]).is_none());
+    }
+
+    #[test]
+    fn sync_skips_tags_and_false_headers() {
+        let mut data = [0u8; 10 + 3 + 417 + 4 + 500];
+        data[..10].copy_from_slice(b"ID3\x04\x00\x00\x00\x00\x00\x03");
+        data[10..13].copy_from_slice(b"TOO"); // tag body
+        data[13..17].copy_from_slice(&HEADER);
+        data[430..434].copy_from_slice(&HEADER);
+        // Looks like a header but is not followed by one
+        data[20..24].copy_from_slice(&HEADER);
+
+        let (offset, info) = find_frame(&data).unwrap();
+        assert_eq!(offset, 13);
+        assert_eq!(info.sample_rate, 44100);
+
+        // Without the real first frame, neither header is followed by another
+        assert_eq!(sync(&data[14..]), Err(Error::NoFrame));
+        assert_eq!(find_frame(&[0; 100]), Err(Error::NoFrame));
+    }
+
+    #[test]
+    fn a_frame_past_the_end_is_truncated() {
+        assert_eq!(sync(&HEADER), Err(Error::Truncated { offset: 0 }));
+        assert_eq!(
+            sync(&[0, 0, 0xFF, 0xFB, 0x90, 0x64, 0]),
+            Err(Error::Truncated { offset: 2 })
+        );
+    }
+}
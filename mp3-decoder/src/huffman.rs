@@ -0,0 +1,200 @@
+use crate::bits::BitReader;
+use crate::huffman_tables::{BIG_VALUES, COUNT1};
+use crate::side_info::Granule;
+use crate::GRANULE_LINES;
+
+/// Jumps of this size or more continue at the node they land on, without
+/// reading another bit (the trees are too big for 8-bit offsets).
+const LONG_JUMP: u16 = 250;
+
+/// Decodes the quantized lines of one channel in one granule, reading up to
+/// bit `end`. Returns how many lines were decoded: the rest are zero.
+pub(crate) fn decode(
+    bits: &mut BitReader,
+    gr: &Granule,
+    end: usize,
+    lines: &mut [i32; GRANULE_LINES],
+) -> usize {
+    let mut line = 0;
+
+    // Big values: pairs, with the table chosen by region
+    while line < gr.big_values {
+        let region = gr
+            .region_start
+            .iter()
+            .filter(|&&start| line >= start)
+            .count();
+        let region_end = match region {
+            0 | 1 => gr.region_start[region].min(gr.big_values),
+            _ => gr.big_values,
+        };
+        let table = &BIG_VALUES[gr.table_select[region] as usize];
+        while line < region_end {
+            let pair = decode_tree(bits, table.tree);
+            for (i, value) in [pair >> 4, pair & 15].into_iter().enumerate() {
+                let mut value = value as i32;
+                if value == 15 && table.linbits > 0 {
+                    value += bits.bits(table.linbits) as i32;
+                }
+                lines[line + i] = signed(bits, value);
+            }
+            line += 2;
+        }
+    }
+
+    // Count1: quadruples of -1, 0 or 1 until the bits run out
+    let tree = COUNT1[gr.count1_table as usize];
+    let big_values_end = line;
+    while line + 4 <= GRANULE_LINES && bits.position() < end {
+        let quad = decode_tree(bits, tree);
+        for i in 0..4 {
+            lines[line + i] = signed(bits, (quad >> (3 - i) & 1) as i32);
+        }
+        line += 4;
+    }
+    // The last quadruple read into the next granule's bits: it was padding
+    if bits.position() > end && line > big_values_end {
+        line -= 4;
+    }
+
+    lines[line..].fill(0);
+    bits.seek(end);
+    line
+}
+
+/// Walks a code tree to its leaf value. An empty tree (table 0) codes zeros.
+fn decode_tree(bits: &mut BitReader, tree: &[u16]) -> u8 {
+    let mut node = 0;
+    while let Some(&entry) = tree.get(node) {
+        if entry & 0xFF00 == 0 {
+            return entry as u8;
+        }
+        let one = bits.bit();
+        loop {
+            let jump = match tree.get(node) {
+                Some(&entry) if one => entry & 0xFF,
+                Some(&entry) => entry >> 8,
+                None => return 0,
+            };
+            node += jump as usize;
+            if jump < LONG_JUMP {
+                break;
+            }
+        }
+    }
+    0
+}
+
+/// Reads the sign bit that follows every non-zero value.
+fn signed(bits: &mut BitReader, value: i32) -> i32 {
+    if value != 0 && bits.bit() {
+        -value
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    fn granule(big_values: usize, table: u8) -> Granule {
+        Granule {
+            big_values,
+            table_select: [table; 3],
+            region_start: [GRANULE_LINES; 2],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn decodes_pairs_signs_and_count1() {
+        // Table 1: "1" = (0, 0), "001" = (0, 1) + sign, "01" = (1, 0) + sign
+        // Then count1 table A: "1" = 0000, "0101" = 0001 + sign
+        let data = [0b1001_0011, 0b1010_1100];
+        let end = 14;
+        let mut lines = [7; GRANULE_LINES];
+        let mut bits = BitReader::new(&data);
+        let decoded = decode(&mut bits, &granule(6, 1), end, &mut lines);
+
+        assert_eq!(decoded, 14);
+        assert_eq!(lines[..14], [0, 0, 0, 1, -1, 0, 0, 0, 0, 0, 0, 0, 0, -1]);
+        assert!(lines[14..].iter().all(|&line| line == 0));
+        assert_eq!(bits.position(), end);
+    }
+
+    #[test]
+    fn drops_a_quadruple_that_overruns_the_granule() {
+        // Count1 table B codes each quadruple in 4 bits ("1111" = 0000,
+        // "1110" = 0001); the second one ends past `end`
+        let data = [0b1110_1111, 0b1000_0000];
+        let mut lines = [0; GRANULE_LINES];
+        let mut bits = BitReader::new(&data);
+        let gr = Granule {
+            count1_table: 1,
+            ..granule(0, 0)
+        };
+        assert_eq!(decode(&mut bits, &gr, 6, &mut lines), 4);
+        assert_eq!(lines[..4], [0, 0, 0, -1]);
+        assert_eq!(lines[4..8], [0; 4]);
+    }
+
+    #[test]
+    fn linbits_extend_fifteen() {
+        // Table 16, linbits 1. Walk the tree to find the code of (15, 0).
+        let table = &BIG_VALUES[16];
+        let code = find_code(table.tree, 0xF0).unwrap();
+        let mut data = [0u8; 8];
+        let mut position = 0;
+        let mut put = |bit: bool| {
+            if bit {
+                data[position / 8] |= 0x80 >> (position % 8);
+            }
+            position += 1;
+        };
+        code.iter().for_each(|&bit| put(bit));
+        put(true); // linbits: 15 + 1
+        put(true); // negative
+        let end = code.len() + 2;
+
+        let mut lines = [0; GRANULE_LINES];
+        let decoded = decode(&mut BitReader::new(&data), &granule(2, 16), end, &mut lines);
+        assert_eq!(decoded, 2);
+        assert_eq!(lines[..2], [-16, 0]);
+    }
+
+    /// Depth-first search for the bits that lead to leaf `value`.
+    fn find_code(tree: &[u16], value: u16) -> Option<Vec<bool>> {
+        fn walk(tree: &[u16], node: usize, value: u16, path: &mut Vec<bool>) -> bool {
+            let entry = tree[node];
+            if entry & 0xFF00 == 0 {
+                return entry == value;
+            }
+            for one in [false, true] {
+                let mut next = node;
+                loop {
+                    let jump = if one {
+                        tree[next] & 0xFF
+                    } else {
+                        tree[next] >> 8
+                    };
+                    next += jump as usize;
+                    if jump < LONG_JUMP {
+                        break;
+                    }
+                }
+                path.push(one);
+                if walk(tree, next, value, path) {
+                    return true;
+                }
+                path.pop();
+            }
+            false
+        }
+        let mut path = Vec::new();
+        walk(tree, 0, value, &mut path).then_some(path)
+    }
+}
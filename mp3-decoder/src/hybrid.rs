@@ -0,0 +1,230 @@
+use crate::bands::{MIXED_FIRST_SHORT, MIXED_LONG_LINES, SHORT};
+use crate::side_info::{BlockType, Granule};
+use crate::tables::{ALIAS_CA, ALIAS_CS, IMDCT_LONG, IMDCT_SHORT, WINDOW_LONG, WINDOW_SHORT};
+use crate::{mul, GRANULE_LINES};
+
+/// Polyphase subbands, each holding 18 lines of a granule.
+pub(crate) const SUBBANDS: usize = 32;
+
+/// Time slots of a granule: one sample of every subband each.
+pub(crate) const SLOTS: usize = 18;
+
+/// Subband samples of one channel in one granule, by time slot.
+pub(crate) type Slots = [[i32; SUBBANDS]; SLOTS];
+
+/// Turns the lines of one channel into subband samples: reorders short
+/// blocks, cancels aliasing, runs the IMDCT of every subband and overlaps
+/// it with the previous granule's second half, kept in `overlap`.
+pub(crate) fn synthesize(
+    lines: &mut [i32; GRANULE_LINES],
+    gr: &Granule,
+    rate_index: usize,
+    overlap: &mut [i32; GRANULE_LINES],
+    out: &mut Slots,
+) {
+    if gr.block_type == BlockType::Short {
+        reorder(lines, gr.mixed, &SHORT[rate_index]);
+    }
+    antialias(lines, gr);
+
+    for subband in 0..SUBBANDS {
+        let range = subband * 18..(subband + 1) * 18;
+        let input = &lines[range.clone()];
+        let overlap = &mut overlap[range];
+        let mut block = [0; 36];
+        if input.iter().all(|&line| line == 0) {
+            // Only the tail of the previous granule is left
+        } else {
+            match gr.block_type {
+                BlockType::Short if !gr.mixed || subband >= 2 => short_blocks(input, &mut block),
+                BlockType::Start => imdct36(input, 1, &mut block),
+                BlockType::Stop => imdct36(input, 2, &mut block),
+                _ => imdct36(input, 0, &mut block),
+            }
+        }
+        for (slot, samples) in out.iter_mut().enumerate() {
+            let mut sample = block[slot] + overlap[slot];
+            // Every odd subband comes out frequency inverted
+            if subband % 2 == 1 && slot % 2 == 1 {
+                sample = -sample;
+            }
+            samples[subband] = sample;
+        }
+        overlap.copy_from_slice(&block[SLOTS..]);
+    }
+}
+
+/// Short blocks are coded band by band, each band holding its three
+/// windows in a row. The IMDCT wants the windows interleaved instead.
+fn reorder(lines: &mut [i32; GRANULE_LINES], mixed: bool, short: &[u16; 14]) {
+    let first_band = if mixed { MIXED_FIRST_SHORT } else { 0 };
+    let first_line = short[first_band] as usize * 3;
+    let mut reordered = [0; GRANULE_LINES];
+    for band in first_band..13 {
+        let start = short[band] as usize;
+        let width = short[band + 1] as usize - start;
+        for window in 0..3 {
+            for line in 0..width {
+                reordered[3 * (start + line) + window] = lines[3 * start + window * width + line];
+            }
+        }
+    }
+    lines[first_line..].copy_from_slice(&reordered[first_line..]);
+}
+
+/// Butterflies across the subband boundaries of long blocks.
+fn antialias(lines: &mut [i32; GRANULE_LINES], gr: &Granule) {
+    let boundaries = match (gr.block_type, gr.mixed) {
+        (BlockType::Short, false) => return,
+        (BlockType::Short, true) => MIXED_LONG_LINES / 18,
+        _ => SUBBANDS,
+    };
+    for boundary in 1..boundaries {
+        for i in 0..8 {
+            let below = boundary * 18 - 1 - i;
+            let above = boundary * 18 + i;
+            let (low, high) = (lines[below], lines[above]);
+            lines[below] = mul(low, ALIAS_CS[i]) - mul(high, ALIAS_CA[i]);
+            lines[above] = mul(high, ALIAS_CS[i]) + mul(low, ALIAS_CA[i]);
+        }
+    }
+}
+
+/// 36-point IMDCT of one long block, windowed by window `shape` (normal,
+/// start or stop).
+fn imdct36(input: &[i32], shape: usize, block: &mut [i32; 36]) {
+    // Outputs 9..18 mirror 0..9 negated, 27..36 mirror 18..27
+    for (row, coefficients) in IMDCT_LONG.chunks_exact(18).enumerate() {
+        let value = dot(input, coefficients);
+        if row < 9 {
+            block[row] = value;
+            block[17 - row] = -value;
+        } else {
+            block[row + 9] = value;
+            block[35 - (row - 9)] = value;
+        }
+    }
+    let window = &WINDOW_LONG[shape * 36..][..36];
+    for (sample, &coefficient) in block.iter_mut().zip(window) {
+        *sample = mul(*sample, coefficient);
+    }
+}
+
+/// Three 12-point IMDCTs, windowed and overlapped in the middle of the
+/// block.
+fn short_blocks(input: &[i32], block: &mut [i32; 36]) {
+    for window in 0..3 {
+        let mut lines = [0; 6];
+        for (k, line) in lines.iter_mut().enumerate() {
+            *line = input[3 * k + window];
+        }
+        let mut output = [0; 12];
+        for (row, coefficients) in IMDCT_SHORT.chunks_exact(6).enumerate() {
+            let value = dot(&lines, coefficients);
+            if row < 3 {
+                output[row] = value;
+                output[5 - row] = -value;
+            } else {
+                output[row + 3] = value;
+                output[11 - (row - 3)] = value;
+            }
+        }
+        let start = 6 + 6 * window;
+        for (i, (&sample, &coefficient)) in output.iter().zip(&WINDOW_SHORT).enumerate() {
+            block[start + i] += mul(sample, coefficient);
+        }
+    }
+}
+
+/// Sum of Q24 samples times Q28 coefficients, rounded once.
+fn dot(samples: &[i32], coefficients: &[i32]) -> i32 {
+    let sum: i64 = samples
+        .iter()
+        .zip(coefficients)
+        .map(|(&sample, &coefficient)| sample as i64 * coefficient as i64)
+        .sum();
+    (sum >> 28) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::f64::consts::PI;
+
+    const ONE: f64 = (1 << 24) as f64;
+
+    /// The ISO formula, windowed, in floating point.
+    fn reference(input: &[f64], n: usize, window: impl Fn(usize) -> f64) -> std::vec::Vec<f64> {
+        (0..n)
+            .map(|i| {
+                let sum: f64 = (0..n / 2)
+                    .map(|k| {
+                        let angle = PI / (2 * n) as f64 * (2 * i + 1 + n / 2) as f64;
+                        input[k] * (angle * (2 * k + 1) as f64).cos()
+                    })
+                    .sum();
+                sum * window(i)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn long_imdct_matches_the_formula() {
+        let input: [f64; 18] = core::array::from_fn(|k| ((k * 7 % 11) as f64 - 5.0) / 20.0);
+        let fixed = input.map(|x| (x * ONE) as i32);
+        let mut block = [0; 36];
+        imdct36(&fixed, 0, &mut block);
+
+        let expected = reference(&input, 36, |i| (PI / 36.0 * (i as f64 + 0.5)).sin());
+        for (&got, want) in block.iter().zip(expected) {
+            assert!((got as f64 / ONE - want).abs() < 1e-5, "{got} vs {want}");
+        }
+    }
+
+    #[test]
+    fn short_windows_overlap_in_the_middle() {
+        let mut input = [0; 18];
+        input[1] = 1 << 22; // window 1, first line
+        let mut block = [0; 36];
+        short_blocks(&input, &mut block);
+
+        let expected = reference(&[0.25, 0.0, 0.0, 0.0, 0.0, 0.0], 12, |i| {
+            (PI / 12.0 * (i as f64 + 0.5)).sin()
+        });
+        assert!(block[..12].iter().all(|&sample| sample == 0));
+        for (&got, want) in block[12..24].iter().zip(expected) {
+            assert!((got as f64 / ONE - want).abs() < 1e-5);
+        }
+        assert!(block[24..].iter().all(|&sample| sample == 0));
+    }
+
+    #[test]
+    fn reorders_short_bands_by_window() {
+        // 44.1 kHz band 0 is 4 lines wide per window
+        let mut lines = [0; GRANULE_LINES];
+        for (i, line) in lines[..12].iter_mut().enumerate() {
+            *line = i as i32;
+        }
+        reorder(&mut lines, false, &SHORT[0]);
+        assert_eq!(lines[..12], [0, 4, 8, 1, 5, 9, 2, 6, 10, 3, 7, 11]);
+    }
+
+    #[test]
+    fn silence_flushes_the_overlap() {
+        let mut overlap = [0; GRANULE_LINES];
+        overlap[18 + 1] = 100;
+        let mut out = [[0; SUBBANDS]; SLOTS];
+        synthesize(
+            &mut [0; GRANULE_LINES],
+            &Granule::default(),
+            0,
+            &mut overlap,
+            &mut out,
+        );
+        // Subband 1, slot 1 is inverted
+        assert_eq!(out[1][1], -100);
+        assert!(overlap.iter().all(|&sample| sample == 0));
+    }
+}
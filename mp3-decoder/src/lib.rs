@@ -0,0 +1,53 @@
+//! MPEG-1 and MPEG-2/2.5 Layer III (MP3) decoder for the ESP32-C3.
+//!
+//! The RV32IMC core has no floating point unit, so the whole decoder runs in
+//! fixed point: samples are Q24 `i32`s, coefficients Q28, and products go
+//! through 64-bit integers. The tables are generated by `build.rs`.
+//!
+//! There is no allocation. A [`Decoder`] holds everything it needs between
+//! frames (bit reservoir, overlap and synthesis buffers, about 22 KiB), and
+//! each call decodes one frame into a caller-provided buffer:
+//!
+//! ```ignore
+//! let mut decoder = Decoder::new();
+//! let mut pcm = [0i16; MAX_SAMPLES_PER_FRAME];
+//! let mut data = &MP3_DATA[..];
+//! while let Ok(frame) = decoder.decode_frame(data, &mut pcm) {
+//!     play(&pcm[..frame.samples * frame.info.channels as usize]);
+//!     data = &data[frame.consumed..];
+//! }
+//! ```
+
+#![no_std]
+
+mod bands;
+mod bits;
+mod decoder;
+mod error;
+mod header;
+mod huffman;
+mod huffman_tables;
+mod hybrid;
+mod requantize;
+mod scalefactors;
+mod side_info;
+mod stereo;
+mod synth;
+mod tables;
+
+pub use decoder::{Decoder, Frame};
+pub use error::Error;
+pub use header::{find_frame, FrameInfo, Mode, Version};
+
+/// Largest number of `i16`s one frame decodes to: 1152 samples on each of
+/// two channels.
+pub const MAX_SAMPLES_PER_FRAME: usize = 1152 * 2;
+
+/// Lines (frequency samples) in a granule, for one channel.
+const GRANULE_LINES: usize = 576;
+
+/// Q24 samples times Q28 coefficients.
+#[inline(always)]
+fn mul(sample: i32, coefficient: i32) -> i32 {
+    ((sample as i64 * coefficient as i64) >> 28) as i32
+}
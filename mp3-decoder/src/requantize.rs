@@ -0,0 +1,142 @@
+use crate::bands::{mixed_long_bands, LONG, MIXED_FIRST_SHORT, SHORT};
+use crate::scalefactors::{Scalefactors, PRETAB};
+use crate::side_info::{BlockType, Granule};
+use crate::tables::{GAIN_FRACTION, POW43};
+use crate::GRANULE_LINES;
+
+/// Largest requantized sample, 8.0 in Q24: real streams stay far below, and
+/// the headroom keeps the sums of the later stages from overflowing.
+const MAX_SAMPLE: i64 = 8 << 24;
+
+/// Turns the first `count` quantized lines into Q24 samples:
+/// `sign(is) * |is|^(4/3) * 2^(q / 4)`, with the exponent `q` (in quarter
+/// steps) from the global gain, subblock gain and scalefactor of the band.
+pub(crate) fn requantize(
+    lines: &mut [i32; GRANULE_LINES],
+    count: usize,
+    gr: &Granule,
+    sf: &Scalefactors,
+    rate_index: usize,
+) {
+    let shift = 1 + gr.scalefac_scale as u32;
+    let long = &LONG[rate_index];
+    let short = &SHORT[rate_index];
+
+    let long_bands = match (gr.block_type, gr.mixed) {
+        (BlockType::Short, false) => 0,
+        (BlockType::Short, true) => mixed_long_bands(rate_index),
+        _ => 22,
+    };
+    for band in 0..long_bands {
+        let start = long[band] as usize;
+        if start >= count {
+            return;
+        }
+        let end = (long[band + 1] as usize).min(count);
+        let mut scalefactor = sf.long[band] as i32;
+        if sf.preflag {
+            scalefactor += PRETAB[band] as i32;
+        }
+        let q = gr.global_gain - 210 - (scalefactor << shift);
+        scale(&mut lines[start..end], q);
+    }
+    if gr.block_type != BlockType::Short {
+        return;
+    }
+
+    let first_short = if gr.mixed { MIXED_FIRST_SHORT } else { 0 };
+    for band in first_short..13 {
+        // Before reordering, the three windows of a band follow each other
+        let start = short[band] as usize * 3;
+        let width = (short[band + 1] - short[band]) as usize;
+        for window in 0..3 {
+            let start = start + window * width;
+            if start >= count {
+                return;
+            }
+            let end = (start + width).min(count);
+            let scalefactor = sf.short[band][window] as i32;
+            let q =
+                gr.global_gain - 210 - 8 * gr.subblock_gain[window] as i32 - (scalefactor << shift);
+            scale(&mut lines[start..end], q);
+        }
+    }
+}
+
+/// Requantizes lines that share the exponent `q`.
+fn scale(lines: &mut [i32], q: i32) {
+    // 2^(q / 4) in Q24 is 2^((q + 44) / 4) >> 13 with POW43 in Q13: split
+    // the exponent into a shift and a table fraction
+    let e = q + 44;
+    let shift = 30 - (e >> 2);
+    let fraction = GAIN_FRACTION[(e & 3) as usize];
+    for line in lines {
+        if *line == 0 {
+            continue;
+        }
+        let magnitude = POW43[line.unsigned_abs() as usize] as i64 * fraction;
+        let value = match shift {
+            64.. => 0,
+            0.. => magnitude >> shift,
+            // Only corrupt streams get here
+            _ => MAX_SAMPLE,
+        }
+        .min(MAX_SAMPLE) as i32;
+        *line = if *line < 0 { -value } else { value };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: i32 = 1 << 24;
+
+    #[test]
+    fn applies_the_power_law_and_gains() {
+        let gr = Granule {
+            global_gain: 210,
+            ..Default::default()
+        };
+        let mut lines = [0; GRANULE_LINES];
+        lines[..4].copy_from_slice(&[1, -1, 8, 0]);
+        requantize(&mut lines, 4, &gr, &Scalefactors::default(), 0);
+        // 8^(4/3) = 16 clips to 8.0
+        assert_eq!(lines[..4], [ONE, -ONE, 8 * ONE, 0]);
+
+        // With scalefac_scale, scalefactor 1 halves band 1 (otherwise 1/sqrt 2)
+        let gr = Granule {
+            global_gain: 214,
+            scalefac_scale: true,
+            ..gr
+        };
+        let sf = Scalefactors {
+            long: [
+                0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            ],
+            ..Default::default()
+        };
+        let mut lines = [0; GRANULE_LINES];
+        lines[..8].copy_from_slice(&[1; 8]);
+        requantize(&mut lines, 8, &gr, &sf, 0);
+        assert_eq!(lines[..4], [2 * ONE; 4]);
+        assert_eq!(lines[4..8], [ONE; 4]);
+    }
+
+    #[test]
+    fn short_windows_have_their_own_gain() {
+        // Band 0 is 4 lines per window at 44.1 kHz
+        let gr = Granule {
+            global_gain: 210,
+            block_type: BlockType::Short,
+            subblock_gain: [0, 1, 2],
+            ..Default::default()
+        };
+        let mut lines = [0; GRANULE_LINES];
+        lines[..12].copy_from_slice(&[1; 12]);
+        requantize(&mut lines, 12, &gr, &Scalefactors::default(), 0);
+        assert_eq!(lines[..4], [ONE; 4]);
+        assert_eq!(lines[4..8], [ONE / 4; 4]);
+        assert_eq!(lines[8..12], [ONE / 16; 4]);
+    }
+}
@@ -0,0 +1,267 @@
+use crate::bits::BitReader;
+use crate::side_info::{BlockType, Granule};
+
+/// Scalefactors of one channel in one granule, plus what intensity stereo
+/// needs to know about them.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Scalefactors {
+    /// Per long band; the last band has none and stays 0.
+    pub long: [u8; 22],
+    /// Per short band and window; the last band stays 0.
+    pub short: [[u8; 3]; 13],
+    /// Boost the high long bands by [`PRETAB`].
+    pub preflag: bool,
+    /// Intensity positions at or above these are not intensity coded.
+    pub intensity_limit_long: [u8; 22],
+    pub intensity_limit_short: [u8; 13],
+}
+
+/// Long band boosts applied when `preflag` is set, ISO Table B.6.
+pub(crate) const PRETAB: [u8; 22] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 3, 3, 3, 2, 0,
+];
+
+/// MPEG-1 `(slen1, slen2)` bit widths per `scalefac_compress`.
+const SLEN: [(u32, u32); 16] = [
+    (0, 0),
+    (0, 1),
+    (0, 2),
+    (0, 3),
+    (3, 0),
+    (1, 1),
+    (1, 2),
+    (1, 3),
+    (2, 1),
+    (2, 2),
+    (2, 3),
+    (3, 1),
+    (3, 2),
+    (3, 3),
+    (4, 2),
+    (4, 3),
+];
+
+/// MPEG-2 scalefactors per partition, ISO/IEC 13818-3 Table B.1, indexed by
+/// table (3 more for the intensity coded right channel) and block kind
+/// (long, short, mixed).
+const PARTITIONS: [[[u8; 4]; 3]; 6] = [
+    [[6, 5, 5, 5], [9, 9, 9, 9], [6, 9, 9, 9]],
+    [[6, 5, 7, 3], [9, 9, 12, 6], [6, 9, 12, 6]],
+    [[11, 10, 0, 0], [18, 18, 0, 0], [15, 18, 0, 0]],
+    [[7, 7, 7, 0], [12, 12, 12, 0], [6, 15, 12, 0]],
+    [[6, 6, 6, 3], [12, 9, 9, 6], [6, 12, 9, 6]],
+    [[8, 8, 5, 0], [15, 12, 9, 0], [6, 18, 9, 0]],
+];
+
+/// In MPEG-1 the intensity position 7 means "not intensity coded".
+const MPEG1_INTENSITY_LIMIT: u8 = 7;
+
+impl Scalefactors {
+    /// Reads MPEG-1 scalefactors. In granule 1, the long band groups flagged
+    /// in `scfsi` are kept from `previous` instead.
+    pub fn read_mpeg1(
+        bits: &mut BitReader,
+        gr: &Granule,
+        scfsi: u8,
+        previous: Option<&Scalefactors>,
+    ) -> Scalefactors {
+        let (slen1, slen2) = SLEN[gr.scalefac_compress as usize];
+        let mut sf = Scalefactors {
+            preflag: gr.preflag,
+            intensity_limit_long: [MPEG1_INTENSITY_LIMIT; 22],
+            intensity_limit_short: [MPEG1_INTENSITY_LIMIT; 13],
+            ..Default::default()
+        };
+
+        if gr.block_type == BlockType::Short {
+            let first_short = if gr.mixed {
+                for long in &mut sf.long[..8] {
+                    *long = bits.bits(slen1) as u8;
+                }
+                3
+            } else {
+                0
+            };
+            for band in first_short..12 {
+                let slen = if band < 6 { slen1 } else { slen2 };
+                for window in &mut sf.short[band] {
+                    *window = bits.bits(slen) as u8;
+                }
+            }
+        } else {
+            const GROUPS: [(usize, usize); 4] = [(0, 6), (6, 11), (11, 16), (16, 21)];
+            for (i, &(start, end)) in GROUPS.iter().enumerate() {
+                match previous {
+                    Some(previous) if scfsi & (8 >> i) != 0 => {
+                        sf.long[start..end].copy_from_slice(&previous.long[start..end]);
+                    }
+                    _ => {
+                        let slen = if i < 2 { slen1 } else { slen2 };
+                        for long in &mut sf.long[start..end] {
+                            *long = bits.bits(slen) as u8;
+                        }
+                    }
+                }
+            }
+        }
+        sf
+    }
+
+    /// Reads MPEG-2/2.5 scalefactors. The right channel of an intensity
+    /// stereo frame codes its lengths differently.
+    pub fn read_lsf(bits: &mut BitReader, gr: &Granule, intensity_right: bool) -> Scalefactors {
+        let sfc = gr.scalefac_compress;
+        let (slen, table, preflag) = if intensity_right {
+            let sfc = sfc >> 1;
+            match sfc {
+                0..180 => ([sfc / 36, sfc % 36 / 6, sfc % 6, 0], 3, false),
+                180..244 => {
+                    let sfc = sfc - 180;
+                    ([sfc >> 4 & 3, sfc >> 2 & 3, sfc & 3, 0], 4, false)
+                }
+                _ => {
+                    let sfc = sfc - 244;
+                    ([sfc / 3, sfc % 3, 0, 0], 5, false)
+                }
+            }
+        } else {
+            match sfc {
+                0..400 => (
+                    [(sfc >> 4) / 5, (sfc >> 4) % 5, sfc >> 2 & 3, sfc & 3],
+                    0,
+                    false,
+                ),
+                400..500 => {
+                    let sfc = sfc - 400;
+                    ([(sfc >> 2) / 5, (sfc >> 2) % 5, sfc & 3, 0], 1, false)
+                }
+                _ => {
+                    let sfc = sfc - 500;
+                    ([sfc / 3, sfc % 3, 0, 0], 2, true)
+                }
+            }
+        };
+        let kind = match (gr.block_type, gr.mixed) {
+            (BlockType::Short, false) => 1,
+            (BlockType::Short, true) => 2,
+            _ => 0,
+        };
+
+        let mut sf = Scalefactors {
+            preflag,
+            ..Default::default()
+        };
+        let mut index = 0;
+        for (&count, &slen) in PARTITIONS[table][kind].iter().zip(&slen) {
+            let limit = ((1u32 << slen) - 1) as u8;
+            for _ in 0..count {
+                let value = bits.bits(slen) as u8;
+                match kind {
+                    0 => sf.set_long(index, value, limit),
+                    1 => sf.set_short(index, value, limit),
+                    _ if index < 6 => sf.set_long(index, value, limit),
+                    _ => sf.set_short(index - 6 + 9, value, limit),
+                }
+                index += 1;
+            }
+        }
+        sf
+    }
+
+    fn set_long(&mut self, band: usize, value: u8, limit: u8) {
+        self.long[band] = value;
+        self.intensity_limit_long[band] = limit;
+    }
+
+    /// Sets the `index`th short scalefactor, counting band by band with the
+    /// three windows of each band in a row.
+    fn set_short(&mut self, index: usize, value: u8, limit: u8) {
+        let (band, window) = (index / 3, index % 3);
+        self.short[band][window] = value;
+        self.intensity_limit_short[band] = limit;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mpeg1_long_blocks_reuse_shared_groups() {
+        // slen1 = 1, slen2 = 3: bands 0..11 take 1 bit, 11..21 take 3
+        let gr = Granule {
+            scalefac_compress: 7,
+            ..Default::default()
+        };
+        let data = [0xFF, 0xEC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let first = Scalefactors::read_mpeg1(&mut BitReader::new(&data), &gr, 0, None);
+        assert_eq!(first.long[..11], [1; 11]);
+        assert_eq!(first.long[11], 0b011);
+        assert_eq!(first.long[12..], [0; 10]);
+
+        // Granule 1 shares groups 0 and 2: only 5 + 5 values are read
+        let mut bits = BitReader::new(&[0b1111_1001, 0b0010_0100, 0b1001_0010, 0b0100_0000]);
+        let second = Scalefactors::read_mpeg1(&mut bits, &gr, 0b1010, Some(&first));
+        assert_eq!(bits.position(), 5 + 5 * 3);
+        assert_eq!(second.long[..6], first.long[..6]);
+        assert_eq!(second.long[6..11], [1; 5]);
+        assert_eq!(second.long[11..16], first.long[11..16]);
+        assert_eq!(second.long[16..21], [0b001, 0b001, 0b001, 0b001, 0b001]);
+        assert_eq!(second.intensity_limit_long, [7; 22]);
+    }
+
+    #[test]
+    fn mpeg1_short_and_mixed_blocks() {
+        let short = Granule {
+            scalefac_compress: 15, // slen1 = 4, slen2 = 3
+            block_type: BlockType::Short,
+            ..Default::default()
+        };
+        let mut bits = BitReader::new(&[0x12; 32]);
+        let sf = Scalefactors::read_mpeg1(&mut bits, &short, 0, None);
+        assert_eq!(bits.position(), 18 * 4 + 18 * 3);
+        assert_eq!(sf.short[0], [1, 2, 1]);
+        assert_eq!(sf.short[12], [0; 3]);
+
+        let mixed = Granule {
+            mixed: true,
+            ..short
+        };
+        let mut bits = BitReader::new(&[0x12; 32]);
+        let sf = Scalefactors::read_mpeg1(&mut bits, &mixed, 0, None);
+        assert_eq!(bits.position(), 8 * 4 + 9 * 4 + 18 * 3);
+        assert_eq!(sf.long[..8], [1, 2, 1, 2, 1, 2, 1, 2]);
+        assert_eq!(sf.short[..3], [[0; 3]; 3]);
+    }
+
+    #[test]
+    fn lsf_partitions_and_intensity_limits() {
+        // scalefac_compress 500 + 3 * 2 + 1: slen [2, 1], table 2, preflag
+        let gr = Granule {
+            scalefac_compress: 507,
+            ..Default::default()
+        };
+        let mut bits = BitReader::new(&[0xFF; 16]);
+        let sf = Scalefactors::read_lsf(&mut bits, &gr, false);
+        assert_eq!(bits.position(), 11 * 2 + 10);
+        assert!(sf.preflag);
+        assert_eq!(sf.long[..11], [3; 11]);
+        assert_eq!(sf.long[11..21], [1; 10]);
+        assert_eq!(sf.intensity_limit_long[0], 3);
+        assert_eq!(sf.intensity_limit_long[20], 1);
+
+        // Intensity coded right channel, short blocks: 244 + 3 + 1 -> slen [1, 1]
+        let gr = Granule {
+            scalefac_compress: (244 + 4) << 1,
+            block_type: BlockType::Short,
+            ..Default::default()
+        };
+        let mut bits = BitReader::new(&[0x00; 16]);
+        let sf = Scalefactors::read_lsf(&mut bits, &gr, true);
+        // The third partition has 0-bit scalefactors
+        assert_eq!(bits.position(), 15 + 12);
+        assert!(!sf.preflag);
+        assert_eq!(sf.intensity_limit_short[..9], [1; 9]);
+        assert_eq!(sf.intensity_limit_short[9..12], [0; 3]);
+    }
+}
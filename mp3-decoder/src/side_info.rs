@@ -0,0 +1,219 @@
+use crate::bands::{LONG, SHORT};
+use crate::bits::BitReader;
+use crate::header::{Header, Version};
+use crate::GRANULE_LINES;
+
+/// Window shape of a granule.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum BlockType {
+    #[default]
+    Normal,
+    /// Long to short transition.
+    Start,
+    /// Three short windows.
+    Short,
+    /// Short to long transition.
+    Stop,
+}
+
+/// Side information of one channel in one granule.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Granule {
+    /// Bits of scalefactors and Huffman data.
+    pub part2_3_length: usize,
+    /// Lines coded as pairs with the big-values tables.
+    pub big_values: usize,
+    pub global_gain: i32,
+    pub scalefac_compress: u32,
+    pub block_type: BlockType,
+    /// The two lowest subbands are long blocks, the rest short.
+    pub mixed: bool,
+    pub table_select: [u8; 3],
+    pub subblock_gain: [u8; 3],
+    /// First lines of Huffman regions 1 and 2.
+    pub region_start: [usize; 2],
+    pub preflag: bool,
+    pub scalefac_scale: bool,
+    pub count1_table: u8,
+}
+
+/// Everything between the header and the main data.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct SideInfo {
+    /// How far back in the bit reservoir the main data starts, in bytes.
+    pub main_data_begin: usize,
+    /// Per channel, the scalefactor groups granule 1 shares with granule 0.
+    pub scfsi: [u8; 2],
+    pub granules: [[Granule; 2]; 2],
+}
+
+impl SideInfo {
+    /// Reads the side information. `None` means it contradicts itself.
+    pub fn parse(data: &[u8], header: &Header) -> Option<SideInfo> {
+        let mut bits = BitReader::new(data);
+        let channels = header.info.channels as usize;
+        let mpeg1 = header.info.version == Version::Mpeg1;
+        let mut side = SideInfo::default();
+
+        if mpeg1 {
+            side.main_data_begin = bits.bits(9) as usize;
+            bits.bits(if channels == 1 { 5 } else { 3 });
+            for scfsi in &mut side.scfsi[..channels] {
+                *scfsi = bits.bits(4) as u8;
+            }
+        } else {
+            side.main_data_begin = bits.bits(8) as usize;
+            bits.bits(channels as u32);
+        }
+
+        let granules = if mpeg1 { 2 } else { 1 };
+        for granule in &mut side.granules[..granules] {
+            for gr in &mut granule[..channels] {
+                gr.part2_3_length = bits.bits(12) as usize;
+                gr.big_values = bits.bits(9) as usize * 2;
+                if gr.big_values > GRANULE_LINES {
+                    return None;
+                }
+                gr.global_gain = bits.bits(8) as i32;
+                gr.scalefac_compress = bits.bits(if mpeg1 { 4 } else { 9 });
+
+                let long = &LONG[header.rate_index];
+                if bits.bit() {
+                    gr.block_type = match bits.bits(2) {
+                        1 => BlockType::Start,
+                        2 => BlockType::Short,
+                        3 => BlockType::Stop,
+                        _ => return None,
+                    };
+                    gr.mixed = bits.bit();
+                    for table in &mut gr.table_select[..2] {
+                        *table = bits.bits(5) as u8;
+                    }
+                    for gain in &mut gr.subblock_gain {
+                        *gain = bits.bits(3) as u8;
+                    }
+                    // Region 0 is the first 36 lines (or 3 short bands), and
+                    // there is no region 2
+                    gr.region_start[0] = match (gr.block_type, gr.mixed) {
+                        (BlockType::Short, false) => SHORT[header.rate_index][3] as usize * 3,
+                        (BlockType::Short, true) => 36,
+                        _ => long[8] as usize,
+                    };
+                    gr.region_start[1] = GRANULE_LINES;
+                } else {
+                    for table in &mut gr.table_select {
+                        *table = bits.bits(5) as u8;
+                    }
+                    let region0 = bits.bits(4) as usize;
+                    let region1 = bits.bits(3) as usize;
+                    gr.region_start[0] = long[region0 + 1] as usize;
+                    gr.region_start[1] = long[(region0 + region1 + 2).min(22)] as usize;
+                }
+
+                gr.preflag = mpeg1 && bits.bit();
+                gr.scalefac_scale = bits.bit();
+                gr.count1_table = bits.bits(1) as u8;
+            }
+        }
+        Some(side)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes fields MSB first, the way the encoder packs side information.
+    struct BitWriter {
+        bytes: [u8; 32],
+        position: usize,
+    }
+
+    impl BitWriter {
+        fn new() -> Self {
+            Self {
+                bytes: [0; 32],
+                position: 0,
+            }
+        }
+
+        fn put(&mut self, count: usize, value: u32) -> &mut Self {
+            for i in (0..count).rev() {
+                if value >> i & 1 == 1 {
+                    self.bytes[self.position / 8] |= 0x80 >> (self.position % 8);
+                }
+                self.position += 1;
+            }
+            self
+        }
+    }
+
+    fn header(bytes: [u8; 4]) -> Header {
+        Header::parse(&bytes).unwrap()
+    }
+
+    #[test]
+    fn reads_mpeg1_mono_long_and_short_granules() {
+        let mut w = BitWriter::new();
+        w.put(9, 300).put(5, 0).put(4, 0b1010);
+        // Granule 0: long block, regions 3 + 2
+        w.put(12, 1000).put(9, 100).put(8, 150).put(4, 9).put(1, 0);
+        w.put(5, 1).put(5, 15).put(5, 24).put(4, 3).put(3, 2);
+        w.put(1, 1).put(1, 0).put(1, 1);
+        // Granule 1: short block, not mixed
+        w.put(12, 500).put(9, 20).put(8, 140).put(4, 2).put(1, 1);
+        w.put(2, 2)
+            .put(1, 0)
+            .put(5, 7)
+            .put(5, 13)
+            .put(3, 1)
+            .put(3, 2)
+            .put(3, 3);
+        w.put(1, 0).put(1, 1).put(1, 0);
+        assert_eq!(w.position, 17 * 8);
+
+        // MPEG-1, 44.1 kHz, mono
+        let side = SideInfo::parse(&w.bytes, &header([0xFF, 0xFB, 0x90, 0xC4])).unwrap();
+        assert_eq!(side.main_data_begin, 300);
+        assert_eq!(side.scfsi[0], 0b1010);
+
+        let long = &side.granules[0][0];
+        assert_eq!(long.part2_3_length, 1000);
+        assert_eq!(long.big_values, 200);
+        assert_eq!(long.global_gain, 150);
+        assert_eq!(long.scalefac_compress, 9);
+        assert_eq!(long.block_type, BlockType::Normal);
+        assert_eq!(long.table_select, [1, 15, 24]);
+        assert_eq!(long.region_start, [16, 30]);
+        assert!(long.preflag && !long.scalefac_scale);
+        assert_eq!(long.count1_table, 1);
+
+        let short = &side.granules[1][0];
+        assert_eq!(short.block_type, BlockType::Short);
+        assert!(!short.mixed);
+        assert_eq!(short.table_select[..2], [7, 13]);
+        assert_eq!(short.subblock_gain, [1, 2, 3]);
+        assert_eq!(short.region_start, [36, 576]);
+        assert!(!short.preflag && short.scalefac_scale);
+    }
+
+    #[test]
+    fn rejects_impossible_values() {
+        let mono = header([0xFF, 0xFB, 0x90, 0xC4]);
+        // big_values above 288 pairs
+        let mut w = BitWriter::new();
+        w.put(18, 0).put(12, 0).put(9, 289);
+        assert!(SideInfo::parse(&w.bytes, &mono).is_none());
+
+        // Window switching with block type 0
+        let mut w = BitWriter::new();
+        w.put(18, 0)
+            .put(12, 0)
+            .put(9, 0)
+            .put(8, 0)
+            .put(4, 0)
+            .put(1, 1)
+            .put(2, 0);
+        assert!(SideInfo::parse(&w.bytes, &mono).is_none());
+    }
+}
@@ -0,0 +1,237 @@
+use crate::bands::{mixed_long_bands, LONG, MIXED_FIRST_SHORT, SHORT};
+use crate::header::{Header, Version};
+use crate::mul;
+use crate::scalefactors::Scalefactors;
+use crate::side_info::{BlockType, Granule};
+use crate::tables::{INTENSITY_MPEG1, INTENSITY_MPEG2};
+use crate::GRANULE_LINES;
+
+/// 1.0 in Q28.
+const ONE: i32 = 1 << 28;
+
+/// `1 / sqrt(2)` in Q28, the mid/side normalization.
+const FRAC_1_SQRT_2: i32 = 189_812_531;
+
+/// How one band of a joint stereo granule is coded.
+#[derive(Clone, Copy)]
+enum Coding {
+    /// Left and right, or mid and side with `mid_side`.
+    Channels,
+    /// Only the left channel carries the band; these are the channel gains.
+    Intensity(i32, i32),
+}
+
+/// Undoes mid/side and intensity stereo on requantized lines, before short
+/// blocks are reordered. `gr` and `sf` are the right channel's: intensity
+/// positions are coded as its scalefactors.
+pub(crate) fn process(
+    [left, right]: &mut [[i32; GRANULE_LINES]; 2],
+    header: &Header,
+    gr: &Granule,
+    sf: &Scalefactors,
+) {
+    let mid_side = header.mid_side();
+    if !header.intensity() {
+        if mid_side {
+            apply(&mut left[..], &mut right[..], Coding::Channels, true);
+        }
+        return;
+    }
+
+    let gains = Gains {
+        mpeg1: header.info.version == Version::Mpeg1,
+        scale: (gr.scalefac_compress & 1) as usize * 16,
+    };
+    let long = &LONG[header.rate_index];
+    let short = &SHORT[header.rate_index];
+    let (long_bands, first_short) = match (gr.block_type, gr.mixed) {
+        (BlockType::Short, false) => (0, 0),
+        (BlockType::Short, true) => (mixed_long_bands(header.rate_index), MIXED_FIRST_SHORT),
+        _ => (22, 13),
+    };
+
+    // Intensity coding starts above the last non-zero line of the right
+    // channel, in each window of short blocks
+    let mut short_bounds = [first_short; 3];
+    for (window, bound) in short_bounds.iter_mut().enumerate() {
+        for band in (first_short..13).rev() {
+            let (start, end) = short_lines(short, band, window);
+            if right[start..end].iter().any(|&line| line != 0) {
+                *bound = band + 1;
+                break;
+            }
+        }
+    }
+    // The long bands of a mixed block are only intensity coded when the
+    // short part is entirely
+    let long_bound = if short_bounds.iter().any(|&bound| bound > first_short) {
+        long_bands
+    } else {
+        let last = right[..long[long_bands] as usize]
+            .iter()
+            .rposition(|&line| line != 0);
+        let bound = last.map_or(0, |line| line + 1);
+        long[..long_bands]
+            .iter()
+            .take_while(|&&start| (start as usize) < bound)
+            .count()
+    };
+
+    for band in 0..long_bands {
+        let (start, end) = (long[band] as usize, long[band + 1] as usize);
+        // The last band has no scalefactor and uses the one below
+        let coded = band.min(20);
+        let coding = if band < long_bound {
+            Coding::Channels
+        } else {
+            gains.coding(sf.long[coded], sf.intensity_limit_long[coded])
+        };
+        apply(
+            &mut left[start..end],
+            &mut right[start..end],
+            coding,
+            mid_side,
+        );
+    }
+    for (window, &bound) in short_bounds.iter().enumerate() {
+        for band in first_short..13 {
+            let (start, end) = short_lines(short, band, window);
+            let coded = band.min(11);
+            let coding = if band < bound {
+                Coding::Channels
+            } else {
+                gains.coding(sf.short[coded][window], sf.intensity_limit_short[coded])
+            };
+            apply(
+                &mut left[start..end],
+                &mut right[start..end],
+                coding,
+                mid_side,
+            );
+        }
+    }
+}
+
+/// Lines of one window of a short band, before reordering.
+fn short_lines(short: &[u16; 14], band: usize, window: usize) -> (usize, usize) {
+    let width = (short[band + 1] - short[band]) as usize;
+    let start = short[band] as usize * 3 + window * width;
+    (start, start + width)
+}
+
+struct Gains {
+    mpeg1: bool,
+    /// Offset of the MPEG-2 intensity scale in [`INTENSITY_MPEG2`].
+    scale: usize,
+}
+
+impl Gains {
+    fn coding(&self, position: u8, limit: u8) -> Coding {
+        if position >= limit {
+            return Coding::Channels;
+        }
+        let position = position as usize;
+        if self.mpeg1 {
+            Coding::Intensity(
+                INTENSITY_MPEG1[2 * position],
+                INTENSITY_MPEG1[2 * position + 1],
+            )
+        } else if position == 0 {
+            Coding::Intensity(ONE, ONE)
+        } else if position % 2 == 1 {
+            Coding::Intensity(INTENSITY_MPEG2[self.scale + position.div_ceil(2)], ONE)
+        } else {
+            Coding::Intensity(ONE, INTENSITY_MPEG2[self.scale + position / 2])
+        }
+    }
+}
+
+fn apply(left: &mut [i32], right: &mut [i32], coding: Coding, mid_side: bool) {
+    match coding {
+        Coding::Channels if mid_side => {
+            for (l, r) in left.iter_mut().zip(right) {
+                let (mid, side) = (*l, *r);
+                *l = mul(mid + side, FRAC_1_SQRT_2);
+                *r = mul(mid - side, FRAC_1_SQRT_2);
+            }
+        }
+        Coding::Channels => {}
+        Coding::Intensity(left_gain, right_gain) => {
+            for (l, r) in left.iter_mut().zip(right) {
+                let value = *l;
+                *l = mul(value, left_gain);
+                *r = mul(value, right_gain);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: i32 = 1 << 24;
+
+    fn header(mode_extension: u8) -> Header {
+        // MPEG-1, 44.1 kHz, joint stereo
+        Header::parse(&[0xFF, 0xFB, 0x90, 0x40 | mode_extension << 4]).unwrap()
+    }
+
+    #[test]
+    fn mid_side_becomes_left_and_right() {
+        let mut lines = [[0; GRANULE_LINES]; 2];
+        lines[0][0] = SAMPLE;
+        lines[1][0] = SAMPLE / 2;
+        process(
+            &mut lines,
+            &header(2),
+            &Granule::default(),
+            &Scalefactors::default(),
+        );
+        assert!((lines[0][0] - mul(SAMPLE * 3 / 2, FRAC_1_SQRT_2)).abs() <= 1);
+        assert!((lines[1][0] - mul(SAMPLE / 2, FRAC_1_SQRT_2)).abs() <= 1);
+    }
+
+    #[test]
+    fn intensity_starts_above_the_right_channel() {
+        let mut lines = [[SAMPLE; GRANULE_LINES]; 2];
+        // The right channel ends in band 1 (lines 4..8)
+        lines[1][..5].fill(SAMPLE / 2);
+        lines[1][5..].fill(0);
+        let mut sf = Scalefactors {
+            intensity_limit_long: [7; 22],
+            ..Default::default()
+        };
+        sf.long[2] = 6; // all left
+        sf.long[3] = 7; // not intensity coded
+        sf.long[20] = 0; // all right, also for band 21
+        process(&mut lines, &header(1), &Granule::default(), &sf);
+
+        assert_eq!(lines[0][..8], [SAMPLE; 8]);
+        assert_eq!(lines[1][..5], [SAMPLE / 2; 5]);
+        assert_eq!(lines[1][5..8], [0; 3]);
+        assert_eq!(lines[0][8..12], [SAMPLE; 4]);
+        assert_eq!(lines[1][8..12], [0; 4]);
+        assert_eq!(lines[1][12..16], [0; 4]);
+        assert!(lines[0][418..].iter().all(|&line| line == 0));
+        assert!(lines[1][418..].iter().all(|&line| line == SAMPLE));
+    }
+
+    #[test]
+    fn lsf_intensity_scales_one_channel() {
+        let gains = Gains {
+            mpeg1: false,
+            scale: 16,
+        };
+        let Coding::Intensity(left, right) = gains.coding(3, 7) else {
+            panic!("position 3 is intensity coded");
+        };
+        // io = 2^(-1/2), squared
+        assert_eq!((left, right), (ONE / 2, ONE));
+        let Coding::Intensity(left, right) = gains.coding(2, 7) else {
+            panic!("position 2 is intensity coded");
+        };
+        assert_eq!((left, right), (ONE, INTENSITY_MPEG2[17]));
+        assert!(matches!(gains.coding(7, 7), Coding::Channels));
+    }
+}
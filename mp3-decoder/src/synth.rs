@@ -0,0 +1,134 @@
+use crate::hybrid::SUBBANDS;
+use crate::tables::{COS64, SYNTH_WINDOW};
+
+/// Polyphase synthesis filterbank of one channel, ISO/IEC 11172-3 Annex
+/// A.2: turns 32 subband samples into 32 PCM samples at a time.
+pub(crate) struct Synth {
+    /// The last 16 matrixed vectors `V`, 64 values each, newest at `offset`.
+    v: [i32; 1024],
+    offset: usize,
+}
+
+impl Synth {
+    pub const fn new() -> Self {
+        Self {
+            v: [0; 1024],
+            offset: 0,
+        }
+    }
+
+    /// Filters one time slot of Q24 subband samples into `out`, which is
+    /// written every `stride`th sample so channels can be interleaved.
+    pub fn slot(&mut self, subbands: &[i32; SUBBANDS], out: &mut [i16], stride: usize) {
+        self.offset = (self.offset + 1024 - 64) % 1024;
+        let v = &mut self.v[self.offset..][..64];
+
+        // V[i] = sum S[k] cos((16 + i)(2k + 1) pi / 64) folds onto the
+        // DCT-II X[n] = sum S[k] cos(n (2k + 1) pi / 64), with X[32] = 0
+        let mut x = [0; SUBBANDS];
+        dct(subbands, &mut x);
+        for (i, value) in v.iter_mut().enumerate() {
+            *value = match i {
+                0..16 => x[16 + i],
+                16 => 0,
+                17..48 => -x[48 - i],
+                _ => -x[i - 48],
+            };
+        }
+
+        // Windowing: U takes the first and last 32 values of every other
+        // 64-value step of V going back in time, and D weights them
+        for j in 0..SUBBANDS {
+            let mut sum = 0i64;
+            for i in 0..8 {
+                let even = (self.offset + 128 * i + j) % 1024;
+                let odd = (self.offset + 128 * i + 96 + j) % 1024;
+                sum += self.v[even] as i64 * SYNTH_WINDOW[64 * i + j] as i64;
+                sum += self.v[odd] as i64 * SYNTH_WINDOW[64 * i + 32 + j] as i64;
+            }
+            // Q24 to 16 bits, rounded and clipped
+            let sample = ((sum >> 28) + 256) >> 9;
+            out[j * stride] = sample.clamp(i16::MIN as i64, i16::MAX as i64) as i16;
+        }
+    }
+}
+
+/// DCT-II `X[n] = sum x[k] cos(n (2k + 1) pi / 2N)`, for `N` a power of two
+/// up to 32. The even outputs are a half size DCT of the folded input, the
+/// odd ones are computed directly.
+fn dct(input: &[i32], output: &mut [i32]) {
+    let n = input.len();
+    if n == 1 {
+        output[0] = input[0];
+        return;
+    }
+    let half = n / 2;
+    let mut sums = [0; SUBBANDS / 2];
+    let mut differences = [0; SUBBANDS / 2];
+    for k in 0..half {
+        sums[k] = input[k] + input[n - 1 - k];
+        differences[k] = input[k] - input[n - 1 - k];
+    }
+    let mut even = [0; SUBBANDS / 2];
+    dct(&sums[..half], &mut even[..half]);
+
+    // cos((2p + 1)(2k + 1) pi / 2N) is COS64 at (2p + 1)(2k + 1) 32 / N
+    let step = 32 / n;
+    for p in 0..half {
+        output[2 * p] = even[p];
+        let mut sum = 0i64;
+        for (k, &difference) in differences[..half].iter().enumerate() {
+            let index = (2 * p + 1) * (2 * k + 1) * step % 128;
+            sum += difference as i64 * COS64[index] as i64;
+        }
+        output[2 * p + 1] = (sum >> 28) as i32;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn dct_matches_the_formula() {
+        let input: [i32; 32] = core::array::from_fn(|k| ((k * 13 % 29) as i32 - 14) << 20);
+        let mut output = [0; 32];
+        dct(&input, &mut output);
+        for (n, &got) in output.iter().enumerate() {
+            let want: f64 = input
+                .iter()
+                .enumerate()
+                .map(|(k, &x)| x as f64 * (PI * n as f64 * (2 * k + 1) as f64 / 64.0).cos())
+                .sum();
+            assert!((got as f64 - want).abs() < 64.0, "X[{n}]: {got} vs {want}");
+        }
+    }
+
+    #[test]
+    fn a_dc_subband_settles_to_a_constant() {
+        // Subband 0 at a quarter of full scale is a DC signal after the
+        // filter delay of 512 samples
+        let mut synth = Synth::new();
+        let mut subbands = [0; SUBBANDS];
+        subbands[0] = 1 << 22;
+        let mut out = [0i16; SUBBANDS];
+        for _ in 0..20 {
+            synth.slot(&subbands, &mut out, 1);
+        }
+        for &sample in &out {
+            assert!((sample as i32 - 8192).abs() <= 64, "{sample}");
+        }
+    }
+
+    #[test]
+    fn writes_interleaved() {
+        let mut synth = Synth::new();
+        let mut out = [7i16; 2 * SUBBANDS];
+        synth.slot(&[0; SUBBANDS], &mut out, 2);
+        assert!(out.iter().step_by(2).all(|&sample| sample == 0));
+        assert!(out.iter().skip(1).step_by(2).all(|&sample| sample == 7));
+    }
+}
@@ -0,0 +1,3 @@
+//! Fixed-point tables written by `build.rs`.
+
+include!(concat!(env!("OUT_DIR"), "/tables.rs"));
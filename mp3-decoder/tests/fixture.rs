@@ -0,0 +1,76 @@
+//! Decodes the MP3 clip of the WAV player with this decoder and with
+//! minimp3, and compares the two.
+
+use mp3_decoder::{Decoder, Error, MAX_SAMPLES_PER_FRAME};
+
+const MP3: &[u8] = include_bytes!("../../wav-hex-player/src/audios/Free_Test_Data_100KB_MP3.mp3");
+
+fn decode_all(data: &[u8]) -> (u32, u8, Vec<i16>) {
+    let mut decoder = Decoder::new();
+    let mut pcm = [0i16; MAX_SAMPLES_PER_FRAME];
+    let (mut rate, mut channels) = (0, 0);
+    let mut samples = Vec::new();
+    let mut data = data;
+    loop {
+        match decoder.decode_frame(data, &mut pcm) {
+            Ok(frame) => {
+                rate = frame.info.sample_rate;
+                channels = frame.info.channels;
+                samples.extend_from_slice(&pcm[..frame.samples * channels as usize]);
+                data = &data[frame.consumed..];
+            }
+            Err(Error::NoFrame) => break,
+            Err(error) => panic!("{error}"),
+        }
+    }
+    (rate, channels, samples)
+}
+
+fn reference(data: &[u8]) -> (u32, u8, Vec<i16>) {
+    let mut decoder = minimp3::Decoder::new(data);
+    let (mut rate, mut channels) = (0, 0);
+    let mut samples = Vec::new();
+    loop {
+        match decoder.next_frame() {
+            Ok(frame) => {
+                rate = frame.sample_rate as u32;
+                channels = frame.channels as u8;
+                samples.extend_from_slice(&frame.data);
+            }
+            Err(minimp3::Error::Eof) => break,
+            Err(error) => panic!("{error:?}"),
+        }
+    }
+    (rate, channels, samples)
+}
+
+#[test]
+fn matches_the_reference_decoder() {
+    let (rate, channels, samples) = decode_all(MP3);
+    let (reference_rate, reference_channels, expected) = reference(MP3);
+    assert_eq!((rate, channels), (reference_rate, reference_channels));
+    assert_eq!(samples.len(), expected.len());
+
+    let signal: f64 = expected.iter().map(|&s| (s as f64).powi(2)).sum();
+    let noise: f64 = samples
+        .iter()
+        .zip(&expected)
+        .map(|(&got, &want)| (got as f64 - want as f64).powi(2))
+        .sum();
+    let snr = 10.0 * (signal / noise.max(1.0)).log10();
+    assert!(snr > 80.0, "SNR {snr:.1} dB");
+}
+
+#[test]
+fn decodes_from_any_frame() {
+    // Starting mid-stream, the first frames wait for the bit reservoir
+    let mut decoder = Decoder::new();
+    let mut pcm = [0i16; MAX_SAMPLES_PER_FRAME];
+    let mut data = &MP3[MP3.len() / 2..];
+    let mut decoded = 0;
+    while let Ok(frame) = decoder.decode_frame(data, &mut pcm) {
+        decoded += frame.samples;
+        data = &data[frame.consumed..];
+    }
+    assert!(decoded > 0);
+}
@@ -1,4 +1,10 @@
 fn main() {
+    // Every file in src/audios becomes an `AudioClip`, embedded as it is: the
+    // I2S runs at the clip's own rate
+    audio_assets::Assets::new("src/audios")
+        .generate()
+        .unwrap_or_else(|err| panic!("{err}"));
+
     linker_be_nice();
     println!("cargo:rustc-link-arg=-Tdefmt.x");
     // make sure linkall.x is the last linker script (otherwise might cause problems with flip-link)
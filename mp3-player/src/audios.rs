@@ -0,0 +1,4 @@
+//! The clips in `src/audios`, generated by `build.rs`: the `AudioClip` enum,
+//! one `static` per file and what each clip holds (`AudioClip::info`).
+
+include!(concat!(env!("OUT_DIR"), "/audios.rs"));
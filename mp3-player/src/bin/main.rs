@@ -8,19 +8,20 @@
 
 use defmt::info;
 use embassy_executor::Spawner;
+use embassy_futures::select::{select, Either};
 use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
-use embassy_sync::mutex::Mutex;
 use embassy_sync::signal::Signal;
 use embassy_time::Timer;
 use esp_hal::dma_buffers;
-use esp_hal::i2s::master::I2sTx;
+use esp_hal::i2s::master::asynch::I2sWriteDmaTransferAsync;
 use esp_hal::i2s::master::{I2s, Standard};
 use esp_hal::time::Rate;
 use esp_hal::timer::systimer::SystemTimer;
-use esp_hal::Blocking;
 use esp_hal::{clock::CpuClock, i2s::master::DataFormat};
 use esp_println as _;
 use esp_println::println;
+use audio_pipeline::{ClipStream, Gain, OutputFormat, PcmSource, Quality, RingWriter, MAX_VOLUME};
+use mp3_player::audios::WAV_DATA;
 
 
 #[panic_handler]
@@ -34,116 +35,52 @@ extern crate alloc;
 // For more information see: <https://docs.espressif.com/projects/esp-idf/en/stable/esp32/api-reference/system/app_image_format.html#application-description>
 esp_bootloader_esp_idf::esp_app_desc!();
 
-pub const DMA_BUFFER_SIZE: usize = 65472;
+/// The DMA ring: 8 descriptors of 4092 bytes the I2S loops over.
+pub const DMA_BUFFER_SIZE: usize = 8 * 4092;
 
-
-static AUDIO_MACHINE: Mutex<CriticalSectionRawMutex, Option<I2sTx<'static, Blocking>>> =
-    Mutex::new(None);
+/// The circular DMA transfer the I2S plays without stopping.
+type I2sRing = I2sWriteDmaTransferAsync<'static, &'static mut [u8; DMA_BUFFER_SIZE]>;
 pub static AUDIO_TRIGGER: Signal<CriticalSectionRawMutex, ()> = Signal::new();
 
 
-// ---------- WAV info ----------
-#[derive(Clone, Copy, Debug)]
-struct WavInfo {
-    audio_format: u16,   // 1 = PCM
-    num_channels: u16,   // 1 or 2
-    sample_rate: u32,
-    bits_per_sample: u16,// 16
-    data_size: u32,      // bytes
-    block_align: u16,    // bytes per frame (channels * bits/8)
-}
-
-// Minimal parser for 16-bit PCM, finds the "fmt " & "data" chunks in the 44B header
-fn parse_wav_header(h: &[u8]) -> Option<WavInfo> {
-    if h.len() < 44 || &h[0..4] != b"RIFF" || &h[8..12] != b"WAVE" { return None; }
-
-    let mut p = 12usize;
-    let mut fmt_found = false;
-    let mut audio_format = 0u16;
-    let mut num_channels = 0u16;
-    let mut sample_rate = 0u32;
-    let mut bits_per_sample = 0u16;
-    let mut block_align = 0u16;
-    let mut data_size = 0u32;
-
-    while p + 8 <= h.len() {
-        let id = &h[p..p+4];
-        let sz = u32::from_le_bytes(h[p+4..p+8].try_into().unwrap());
-        let body = p + 8;
-        let next = body + sz as usize;
-
-        if id == b"fmt " && body + 16 <= h.len() {
-            audio_format = u16::from_le_bytes(h[body..body+2].try_into().unwrap());
-            num_channels = u16::from_le_bytes(h[body+2..body+4].try_into().unwrap());
-            sample_rate = u32::from_le_bytes(h[body+4..body+8].try_into().unwrap());
-            let _byte_rate = u32::from_le_bytes(h[body+8..body+12].try_into().unwrap());
-            block_align = u16::from_le_bytes(h[body+12..body+14].try_into().unwrap());
-            bits_per_sample = u16::from_le_bytes(h[body+14..body+16].try_into().unwrap());
-            fmt_found = true;
-        } else if id == b"data" {
-            data_size = sz;
-            break;
-        }
-        // chunks are word-aligned
-        p = next + (next % 2);
-    }
-
-    if !fmt_found || data_size == 0 { return None; }
-    Some(WavInfo {
-        audio_format,
-        num_channels,
-        sample_rate,
-        bits_per_sample,
-        data_size,
-        block_align,
-    })
-}
-
-
 #[embassy_executor::task]
-pub async fn audio(
-    audio_machine: &'static Mutex<CriticalSectionRawMutex, Option<I2sTx<'static, Blocking>>>,
-    tx_buffer: &'static mut [u8],
-) {
-    let pcm_data = &WAV_DATA;
-    let pcm_len = pcm_data.len();
-    println!("PCM Length: {}", pcm_len);
+pub async fn audio(ring: I2sRing) {
+    // Skip the RIFF header: only the samples of the data chunk go to the speaker
+    let wav = wav_parser::parse(&WAV_DATA).expect("embedded WAV is broken");
+    println!("PCM Length: {}", wav.data.len());
+    let mut writer = RingWriter::new(ring, OutputFormat::STEREO_16.frame_size());
 
     loop {
         info!("STARTING LOOP FROM AUDIO TASK");
-        // Check if audio playback is enabled based on temperature
-        AUDIO_TRIGGER.wait().await;
+        // Check if audio playback is enabled based on temperature. Until then
+        // the ring plays silence, or it would replay the end of the last clip
+        if let Either::Second(err) = select(AUDIO_TRIGGER.wait(), writer.idle()).await {
+            println!("I2S error: {:?}", err);
+            AUDIO_TRIGGER.wait().await;
+        }
 
         println!("Temperature condition met. Starting audio playback...");
 
-        let mut offset = 0;
-        // Play the entire audio clip in chunks
-        while offset < pcm_len {
-            let chunk_size = core::cmp::min(DMA_BUFFER_SIZE, pcm_len - offset);
-            println!("offset: {offset}");
-
-            // Copy PCM data to the DMA buffer
-            tx_buffer[..chunk_size].copy_from_slice(&pcm_data[offset..offset + chunk_size]);
-
-            // Zero-pad the rest of the buffer if necessary
-            if chunk_size < DMA_BUFFER_SIZE {
-                tx_buffer[chunk_size..].fill(0);
-            }
-
-            // Perform the DMA transfer
-            let mut transfer_guard = audio_machine.lock().await;
-            if let Some(i2s_tx) = transfer_guard.as_mut() {
-                // Start transfer and wait for completion
-                i2s_tx.write_dma(&tx_buffer).unwrap().is_done();
-            }
-            // Release the lock as soon as possible
-            drop(transfer_guard);
-
-            offset += chunk_size;
-
-            // Optional: Small delay between chunks if needed
-            // Timer::after_micros(10).await;
+        // The clip is 8-bit unsigned mono: widen it to the 16-bit stereo frames the I2S
+        // expects. The I2S runs at the clip's own rate, so nothing is resampled.
+        let stream = ClipStream::new(
+            &wav,
+            OutputFormat::STEREO_16,
+            wav.format.sample_rate,
+            Quality::Linear,
+        )
+        .expect("no converter for this WAV");
+        // Fade in, and let the last sample decay instead of stopping on it
+        let mut gain = Gain::new(wav.format.sample_rate, MAX_VOLUME);
+        gain.fade_in();
+        let mut source = Faded { stream, gain };
+
+        // Refill the ring as the I2S plays it: no gap, no padding between chunks
+        match writer.play(&mut source).await {
+            Ok(bytes) => println!("Played {} bytes, {:?}", bytes, writer.stats()),
+            Err(err) => println!("I2S error: {:?}", err),
         }
+
         println!("Audio playback finished for this loop.");
         // Optional: Add a small delay before checking the condition again
         // to avoid playing back-to-back immediately if the clip is short.
@@ -154,6 +91,26 @@ pub async fn audio(
     }
 }
 
+/// The clip through the gain stage, so it starts and ends without a pop.
+struct Faded<'a> {
+    stream: ClipStream<'a>,
+    gain: Gain,
+}
+
+impl PcmSource for Faded<'_> {
+    fn fill(&mut self, tx_buffer: &mut [u8]) -> usize {
+        let mut written = self.stream.fill(tx_buffer);
+        self.gain
+            .apply(&mut tx_buffer[..written], OutputFormat::STEREO_16);
+        if written < tx_buffer.len() {
+            written += self
+                .gain
+                .tail(&mut tx_buffer[written..], OutputFormat::STEREO_16);
+        }
+        written
+    }
+}
+
 #[esp_hal_embassy::main]
 async fn main(spawner: Spawner) {
     // generator version: 0.5.0
@@ -171,30 +128,34 @@ async fn main(spawner: Spawner) {
     // Initialize I2S for audio output
     let dma_channel = peripherals.DMA_CH0;
     let (tx_buffer, tx_descriptors, _, _) = dma_buffers!(DMA_BUFFER_SIZE, 0);
+    let header = wav_parser::parse(&WAV_DATA)
+        .expect("embedded WAV is broken")
+        .header();
 
     let i2s = I2s::new(
         peripherals.I2S0,
         Standard::Philips,
-        DataFormat::Data16Channel16, // Mono format
-        Rate::from_hz(7000),  // Use actual sample rate from WAV
+        DataFormat::Data16Channel16, // 16-bit stereo frames, filled by the Converter
+        Rate::from_hz(header.format.sample_rate), // Use actual sample rate from WAV
         dma_channel,
-    );
+    )
+    .into_async();
     let i2s = i2s.with_mclk(peripherals.GPIO5); // MCLK not used but required by driver
-    let i2s_tx: esp_hal::i2s::master::I2sTx<'_, esp_hal::Blocking> = i2s
+    let i2s_tx = i2s
         .i2s_tx
         .with_bclk(peripherals.GPIO21)
         .with_ws(peripherals.GPIO20)
         .with_dout(peripherals.GPIO10)
         .build(tx_descriptors);
-        {
-        *(AUDIO_MACHINE.lock().await) = Some(i2s_tx);
-    }
+    // The I2S loops over the (zeroed) buffer from now on; the audio task
+    // owns the transfer and keeps refilling it
+    let ring = i2s_tx.write_dma_circular_async(tx_buffer).unwrap();
 
     // TODO: Spawn some tasks
-    spawner.spawn(audio(&AUDIO_MACHINE, tx_buffer)).unwrap();
+    spawner.spawn(audio(ring)).unwrap();
 
     loop {
-        let info = parse_wav_header(&WAV_DATA);
+        let info = wav_parser::parse(&WAV_DATA).map(|wav| wav.header());
         AUDIO_TRIGGER.signal(());
         println!("{:?}", info);
         Timer::after_secs(10).await;
@@ -206,1188 +167,3 @@ async fn main(spawner: Spawner) {
 
     // for inspiration have a look at the examples at https://github.com/esp-rs/esp-hal/tree/esp-hal-v1.0.0-rc.0/examples/src/bin
 }
-
-pub static WAV_DATA: [u8; 18898] = [
-    0x52, 0x49, 0x46, 0x46, 0xCA, 0x49, 0x00, 0x00, 0x57, 0x41, 0x56, 0x45, 0x66, 0x6D, 0x74, 0x20, 
-0x10, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x40, 0x1F, 0x00, 0x00, 0x40, 0x1F, 0x00, 0x00, 
-0x01, 0x00, 0x08, 0x00, 0x64, 0x61, 0x74, 0x61, 0xA6, 0x49, 0x00, 0x00, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 
-0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x81, 
-0x81, 0x80, 0x80, 0x7F, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x80, 0x80, 0x81, 0x81, 0x82, 0x82, 0x82, 
-0x81, 0x80, 0x7F, 0x7D, 0x7C, 0x7B, 0x7B, 0x7C, 0x7E, 0x81, 0x83, 0x85, 0x85, 0x84, 0x81, 0x7E, 
-0x7C, 0x7B, 0x7B, 0x7C, 0x7E, 0x80, 0x81, 0x82, 0x82, 0x81, 0x81, 0x80, 0x80, 0x80, 0x80, 0x7F, 
-0x7E, 0x7E, 0x7D, 0x7D, 0x7D, 0x7E, 0x7F, 0x80, 0x81, 0x81, 0x81, 0x80, 0x7F, 0x7E, 0x7E, 0x7E, 
-0x7E, 0x7F, 0x80, 0x81, 0x81, 0x82, 0x81, 0x81, 0x81, 0x80, 0x80, 0x81, 0x81, 0x81, 0x80, 0x7F, 
-0x7E, 0x7D, 0x7C, 0x7B, 0x7C, 0x7E, 0x80, 0x82, 0x84, 0x84, 0x83, 0x82, 0x7F, 0x7D, 0x7C, 0x7B, 
-0x7C, 0x7D, 0x7F, 0x81, 0x82, 0x82, 0x82, 0x81, 0x81, 0x80, 0x7F, 0x7F, 0x7E, 0x7D, 0x7C, 0x7C, 
-0x7D, 0x7E, 0x80, 0x82, 0x83, 0x83, 0x83, 0x81, 0x7F, 0x7D, 0x7C, 0x7D, 0x7E, 0x80, 0x82, 0x84, 
-0x84, 0x84, 0x82, 0x82, 0x81, 0x81, 0x81, 0x81, 0x7E, 0x7D, 0x7B, 0x77, 0x79, 0x79, 0x7D, 0x81, 
-0x85, 0x88, 0x88, 0x87, 0x82, 0x7E, 0x7A, 0x77, 0x77, 0x7A, 0x7D, 0x80, 0x83, 0x83, 0x83, 0x82, 
-0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7C, 0x7C, 0x7B, 0x7B, 0x7E, 0x80, 0x83, 0x84, 0x84, 
-0x83, 0x80, 0x7E, 0x7C, 0x7C, 0x7D, 0x7F, 0x82, 0x84, 0x85, 0x84, 0x83, 0x82, 0x80, 0x81, 0x82, 
-0x82, 0x83, 0x82, 0x7D, 0x7D, 0x77, 0x75, 0x78, 0x79, 0x7E, 0x84, 0x89, 0x89, 0x89, 0x85, 0x80, 
-0x7B, 0x78, 0x76, 0x78, 0x7C, 0x7D, 0x81, 0x83, 0x82, 0x82, 0x81, 0x80, 0x80, 0x80, 0x7F, 0x7E, 
-0x7E, 0x7B, 0x7A, 0x7A, 0x7B, 0x7D, 0x81, 0x84, 0x85, 0x86, 0x83, 0x81, 0x7D, 0x7B, 0x7A, 0x7C, 
-0x7E, 0x82, 0x84, 0x85, 0x85, 0x84, 0x83, 0x81, 0x82, 0x83, 0x83, 0x83, 0x83, 0x7C, 0x7B, 0x76, 
-0x73, 0x77, 0x79, 0x7F, 0x85, 0x8B, 0x8A, 0x8A, 0x86, 0x7F, 0x7A, 0x77, 0x76, 0x77, 0x7C, 0x7E, 
-0x82, 0x84, 0x83, 0x83, 0x81, 0x81, 0x80, 0x80, 0x7F, 0x7E, 0x7D, 0x7A, 0x79, 0x79, 0x7B, 0x7E, 
-0x82, 0x85, 0x86, 0x87, 0x82, 0x80, 0x7C, 0x7A, 0x7A, 0x7D, 0x80, 0x83, 0x86, 0x85, 0x85, 0x83, 
-0x82, 0x82, 0x83, 0x84, 0x85, 0x83, 0x82, 0x7A, 0x79, 0x73, 0x71, 0x77, 0x7A, 0x82, 0x88, 0x8D, 
-0x8B, 0x8A, 0x83, 0x7D, 0x78, 0x76, 0x76, 0x78, 0x7D, 0x7E, 0x82, 0x83, 0x81, 0x82, 0x82, 0x82, 
-0x82, 0x83, 0x80, 0x7D, 0x7B, 0x76, 0x76, 0x77, 0x7A, 0x7F, 0x85, 0x88, 0x88, 0x87, 0x81, 0x7D, 
-0x7A, 0x78, 0x7A, 0x7E, 0x82, 0x84, 0x87, 0x85, 0x84, 0x82, 0x82, 0x82, 0x85, 0x86, 0x86, 0x85, 
-0x80, 0x79, 0x74, 0x73, 0x6F, 0x77, 0x7C, 0x84, 0x8B, 0x8E, 0x8C, 0x87, 0x83, 0x7A, 0x78, 0x76, 
-0x77, 0x79, 0x7E, 0x7F, 0x80, 0x82, 0x80, 0x81, 0x83, 0x84, 0x85, 0x85, 0x82, 0x7D, 0x79, 0x73, 
-0x73, 0x74, 0x78, 0x80, 0x85, 0x8A, 0x8A, 0x88, 0x82, 0x7D, 0x79, 0x77, 0x79, 0x7B, 0x7F, 0x82, 
-0x85, 0x86, 0x86, 0x86, 0x84, 0x84, 0x83, 0x83, 0x84, 0x83, 0x82, 0x80, 0x7C, 0x76, 0x76, 0x72, 
-0x76, 0x7D, 0x81, 0x88, 0x8C, 0x8B, 0x86, 0x82, 0x7C, 0x77, 0x78, 0x79, 0x7A, 0x7C, 0x7F, 0x7D, 
-0x80, 0x82, 0x82, 0x86, 0x87, 0x86, 0x83, 0x7F, 0x79, 0x76, 0x77, 0x77, 0x7C, 0x80, 0x81, 0x82, 
-0x82, 0x7F, 0x7E, 0x80, 0x7F, 0x80, 0x81, 0x7E, 0x7C, 0x7B, 0x7B, 0x7D, 0x81, 0x85, 0x87, 0x89, 
-0x87, 0x84, 0x82, 0x80, 0x7D, 0x81, 0x84, 0x83, 0x86, 0x85, 0x7D, 0x78, 0x77, 0x71, 0x74, 0x7E, 
-0x80, 0x87, 0x8C, 0x89, 0x83, 0x81, 0x7B, 0x77, 0x7A, 0x7C, 0x7B, 0x7E, 0x7E, 0x7B, 0x7E, 0x81, 
-0x82, 0x86, 0x88, 0x84, 0x81, 0x7D, 0x78, 0x76, 0x78, 0x7A, 0x7D, 0x82, 0x81, 0x80, 0x80, 0x7E, 
-0x7D, 0x7F, 0x80, 0x7F, 0x7E, 0x7D, 0x7B, 0x7C, 0x7F, 0x81, 0x84, 0x86, 0x84, 0x83, 0x83, 0x82, 
-0x82, 0x84, 0x84, 0x82, 0x85, 0x83, 0x80, 0x82, 0x82, 0x79, 0x79, 0x7B, 0x71, 0x7A, 0x81, 0x81, 
-0x88, 0x8B, 0x85, 0x82, 0x81, 0x7D, 0x79, 0x7D, 0x7D, 0x79, 0x7D, 0x7D, 0x7B, 0x82, 0x87, 0x86, 
-0x89, 0x87, 0x81, 0x7B, 0x7A, 0x78, 0x78, 0x7E, 0x80, 0x80, 0x82, 0x80, 0x7D, 0x80, 0x7F, 0x80, 
-0x82, 0x81, 0x7F, 0x7D, 0x7D, 0x7D, 0x7E, 0x81, 0x82, 0x82, 0x84, 0x83, 0x84, 0x85, 0x85, 0x84, 
-0x82, 0x80, 0x80, 0x82, 0x85, 0x87, 0x85, 0x84, 0x78, 0x72, 0x76, 0x70, 0x7A, 0x86, 0x85, 0x88, 
-0x89, 0x81, 0x7F, 0x7F, 0x80, 0x7F, 0x7D, 0x7C, 0x76, 0x74, 0x7B, 0x7D, 0x83, 0x8B, 0x87, 0x84, 
-0x82, 0x7C, 0x7B, 0x7D, 0x7D, 0x7C, 0x7A, 0x7C, 0x7B, 0x7C, 0x82, 0x83, 0x81, 0x82, 0x7E, 0x7B, 
-0x7C, 0x7C, 0x7C, 0x7E, 0x7E, 0x7E, 0x7E, 0x81, 0x82, 0x83, 0x84, 0x84, 0x81, 0x81, 0x82, 0x81, 
-0x81, 0x82, 0x81, 0x83, 0x86, 0x84, 0x84, 0x84, 0x7A, 0x72, 0x78, 0x73, 0x75, 0x84, 0x85, 0x81, 
-0x87, 0x83, 0x7E, 0x83, 0x84, 0x7F, 0x7D, 0x7B, 0x77, 0x76, 0x7B, 0x81, 0x82, 0x84, 0x86, 0x81, 
-0x7E, 0x82, 0x81, 0x7E, 0x80, 0x7D, 0x78, 0x7B, 0x7E, 0x7D, 0x81, 0x82, 0x7D, 0x7C, 0x7D, 0x7C, 
-0x7F, 0x82, 0x81, 0x7F, 0x7E, 0x7D, 0x7E, 0x80, 0x82, 0x83, 0x81, 0x80, 0x81, 0x81, 0x81, 0x84, 
-0x84, 0x82, 0x83, 0x84, 0x84, 0x86, 0x86, 0x82, 0x7F, 0x78, 0x75, 0x79, 0x7A, 0x7B, 0x81, 0x81, 
-0x7C, 0x80, 0x83, 0x81, 0x85, 0x88, 0x82, 0x7E, 0x7D, 0x7A, 0x7B, 0x7F, 0x7F, 0x7E, 0x7D, 0x7C, 
-0x7D, 0x80, 0x82, 0x84, 0x84, 0x81, 0x80, 0x7F, 0x7F, 0x81, 0x81, 0x7E, 0x7A, 0x78, 0x78, 0x7A, 
-0x7E, 0x81, 0x83, 0x82, 0x81, 0x81, 0x81, 0x81, 0x82, 0x81, 0x7F, 0x7F, 0x7F, 0x80, 0x82, 0x83, 
-0x83, 0x83, 0x82, 0x82, 0x85, 0x87, 0x86, 0x85, 0x83, 0x7F, 0x77, 0x77, 0x7B, 0x78, 0x7A, 0x81, 
-0x7E, 0x7B, 0x83, 0x84, 0x81, 0x87, 0x87, 0x80, 0x80, 0x80, 0x7B, 0x7D, 0x7F, 0x7B, 0x7A, 0x7C, 
-0x7B, 0x7D, 0x82, 0x82, 0x83, 0x83, 0x81, 0x81, 0x81, 0x81, 0x81, 0x7F, 0x7C, 0x79, 0x78, 0x79, 
-0x7C, 0x7E, 0x80, 0x81, 0x80, 0x81, 0x82, 0x82, 0x81, 0x82, 0x7F, 0x7E, 0x7F, 0x7F, 0x80, 0x82, 
-0x82, 0x81, 0x82, 0x82, 0x83, 0x85, 0x86, 0x86, 0x85, 0x82, 0x81, 0x7D, 0x78, 0x7A, 0x7A, 0x77, 
-0x7D, 0x80, 0x7A, 0x80, 0x85, 0x80, 0x84, 0x89, 0x81, 0x7F, 0x83, 0x7C, 0x7A, 0x80, 0x7C, 0x7A, 
-0x7E, 0x7C, 0x7B, 0x81, 0x82, 0x81, 0x84, 0x83, 0x81, 0x82, 0x82, 0x80, 0x80, 0x7E, 0x7B, 0x79, 
-0x79, 0x7B, 0x7C, 0x7F, 0x81, 0x80, 0x80, 0x83, 0x82, 0x81, 0x83, 0x80, 0x7E, 0x7F, 0x7E, 0x7E, 
-0x82, 0x81, 0x80, 0x83, 0x83, 0x82, 0x85, 0x85, 0x84, 0x85, 0x84, 0x83, 0x81, 0x7C, 0x78, 0x7A, 
-0x77, 0x78, 0x7F, 0x7E, 0x7C, 0x82, 0x81, 0x80, 0x86, 0x85, 0x81, 0x83, 0x7F, 0x7B, 0x7E, 0x7E, 
-0x7B, 0x7C, 0x7D, 0x7B, 0x7D, 0x81, 0x81, 0x82, 0x84, 0x82, 0x81, 0x81, 0x81, 0x7F, 0x7F, 0x7D, 
-0x7B, 0x7A, 0x7A, 0x7A, 0x7D, 0x7F, 0x7F, 0x81, 0x82, 0x81, 0x82, 0x82, 0x80, 0x7F, 0x7E, 0x7D, 
-0x7E, 0x80, 0x81, 0x81, 0x82, 0x82, 0x82, 0x84, 0x84, 0x83, 0x84, 0x85, 0x82, 0x82, 0x85, 0x7E, 
-0x77, 0x7C, 0x79, 0x75, 0x80, 0x7F, 0x7A, 0x81, 0x82, 0x7E, 0x85, 0x86, 0x80, 0x82, 0x81, 0x7B, 
-0x7C, 0x7F, 0x7B, 0x7A, 0x7D, 0x7B, 0x7B, 0x81, 0x82, 0x80, 0x83, 0x82, 0x7E, 0x80, 0x81, 0x7E, 
-0x7E, 0x7E, 0x7A, 0x7A, 0x7C, 0x7A, 0x7C, 0x7F, 0x7E, 0x7E, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7D, 
-0x7F, 0x7F, 0x7E, 0x81, 0x82, 0x81, 0x82, 0x82, 0x80, 0x82, 0x83, 0x81, 0x81, 0x84, 0x84, 0x85, 
-0x86, 0x86, 0x74, 0x75, 0x7C, 0x74, 0x83, 0x83, 0x7F, 0x7E, 0x84, 0x84, 0x84, 0x8B, 0x80, 0x7A, 
-0x7C, 0x7A, 0x7A, 0x82, 0x81, 0x7D, 0x81, 0x84, 0x83, 0x88, 0x87, 0x7E, 0x7B, 0x7B, 0x7B, 0x7E, 
-0x81, 0x7F, 0x7E, 0x81, 0x83, 0x83, 0x85, 0x7D, 0x77, 0x79, 0x7C, 0x7E, 0x82, 0x82, 0x7E, 0x7F, 
-0x82, 0x81, 0x82, 0x80, 0x7B, 0x7D, 0x81, 0x83, 0x83, 0x84, 0x80, 0x7F, 0x82, 0x83, 0x81, 0x85, 
-0x82, 0x81, 0x84, 0x89, 0x74, 0x6F, 0x7B, 0x70, 0x82, 0x87, 0x86, 0x7D, 0x86, 0x83, 0x7E, 0x87, 
-0x7A, 0x73, 0x74, 0x7C, 0x7A, 0x85, 0x87, 0x7F, 0x82, 0x85, 0x81, 0x81, 0x80, 0x76, 0x73, 0x7A, 
-0x7E, 0x81, 0x86, 0x83, 0x80, 0x82, 0x84, 0x7F, 0x7F, 0x75, 0x6F, 0x77, 0x80, 0x85, 0x89, 0x87, 
-0x80, 0x7F, 0x81, 0x7D, 0x7C, 0x7A, 0x79, 0x7F, 0x88, 0x89, 0x85, 0x85, 0x80, 0x7F, 0x82, 0x85, 
-0x80, 0x84, 0x83, 0x84, 0x89, 0x88, 0x71, 0x71, 0x78, 0x77, 0x86, 0x8A, 0x85, 0x7D, 0x89, 0x83, 
-0x83, 0x82, 0x78, 0x6F, 0x78, 0x80, 0x81, 0x89, 0x85, 0x80, 0x81, 0x87, 0x80, 0x7F, 0x7B, 0x74, 
-0x76, 0x80, 0x84, 0x85, 0x87, 0x81, 0x80, 0x83, 0x82, 0x7B, 0x7A, 0x73, 0x71, 0x7D, 0x87, 0x87, 
-0x89, 0x85, 0x7E, 0x7E, 0x7F, 0x79, 0x78, 0x7A, 0x7C, 0x83, 0x8B, 0x88, 0x82, 0x82, 0x7F, 0x7F, 
-0x81, 0x82, 0x7E, 0x82, 0x84, 0x87, 0x88, 0x8B, 0x73, 0x6B, 0x77, 0x78, 0x84, 0x88, 0x87, 0x7A, 
-0x88, 0x86, 0x83, 0x80, 0x78, 0x6E, 0x75, 0x83, 0x82, 0x88, 0x85, 0x81, 0x81, 0x89, 0x81, 0x7D, 
-0x79, 0x74, 0x77, 0x81, 0x86, 0x84, 0x86, 0x81, 0x81, 0x83, 0x83, 0x7A, 0x78, 0x75, 0x74, 0x7D, 
-0x86, 0x86, 0x85, 0x85, 0x7F, 0x7E, 0x7F, 0x7A, 0x77, 0x7B, 0x7E, 0x83, 0x89, 0x88, 0x81, 0x82, 
-0x80, 0x80, 0x7F, 0x80, 0x7E, 0x81, 0x84, 0x87, 0x87, 0x89, 0x81, 0x6A, 0x74, 0x76, 0x7E, 0x83, 
-0x88, 0x7D, 0x81, 0x8A, 0x83, 0x82, 0x7A, 0x73, 0x6F, 0x7F, 0x80, 0x83, 0x83, 0x82, 0x7F, 0x86, 
-0x86, 0x7E, 0x7A, 0x76, 0x75, 0x7B, 0x84, 0x82, 0x83, 0x81, 0x82, 0x82, 0x85, 0x7F, 0x78, 0x72, 
-0x73, 0x7B, 0x83, 0x86, 0x84, 0x82, 0x81, 0x80, 0x7F, 0x7B, 0x78, 0x79, 0x7E, 0x84, 0x88, 0x88, 
-0x81, 0x81, 0x81, 0x80, 0x7D, 0x81, 0x7E, 0x82, 0x84, 0x89, 0x86, 0x89, 0x81, 0x6A, 0x76, 0x77, 
-0x7F, 0x81, 0x88, 0x7C, 0x82, 0x8B, 0x86, 0x82, 0x7C, 0x75, 0x71, 0x80, 0x80, 0x81, 0x81, 0x82, 
-0x7F, 0x88, 0x88, 0x80, 0x7B, 0x79, 0x76, 0x7B, 0x82, 0x80, 0x80, 0x81, 0x83, 0x84, 0x87, 0x7D, 
-0x75, 0x76, 0x79, 0x7E, 0x84, 0x83, 0x7E, 0x82, 0x83, 0x80, 0x7F, 0x7D, 0x7A, 0x7D, 0x83, 0x84, 
-0x85, 0x84, 0x81, 0x80, 0x83, 0x7F, 0x80, 0x80, 0x82, 0x82, 0x88, 0x89, 0x88, 0x80, 0x6D, 0x74, 
-0x79, 0x7E, 0x81, 0x84, 0x7D, 0x81, 0x8A, 0x89, 0x82, 0x7D, 0x76, 0x72, 0x7C, 0x80, 0x80, 0x7E, 
-0x82, 0x80, 0x87, 0x89, 0x84, 0x7C, 0x7B, 0x78, 0x7A, 0x7F, 0x80, 0x7C, 0x7F, 0x82, 0x81, 0x83, 
-0x81, 0x7C, 0x7A, 0x7E, 0x7D, 0x7E, 0x7F, 0x7D, 0x7D, 0x81, 0x81, 0x81, 0x81, 0x81, 0x80, 0x83, 
-0x82, 0x81, 0x81, 0x80, 0x7E, 0x80, 0x82, 0x83, 0x83, 0x85, 0x82, 0x86, 0x89, 0x87, 0x80, 0x6D, 
-0x71, 0x76, 0x7E, 0x81, 0x85, 0x7E, 0x82, 0x89, 0x8A, 0x85, 0x7D, 0x77, 0x73, 0x7A, 0x7E, 0x80, 
-0x7D, 0x81, 0x81, 0x87, 0x88, 0x86, 0x7E, 0x7B, 0x79, 0x7B, 0x79, 0x78, 0x78, 0x7B, 0x84, 0x88, 
-0x88, 0x83, 0x80, 0x7D, 0x7E, 0x7C, 0x7A, 0x79, 0x7C, 0x81, 0x85, 0x86, 0x85, 0x83, 0x82, 0x80, 
-0x7F, 0x7F, 0x7E, 0x7C, 0x7E, 0x81, 0x81, 0x83, 0x82, 0x82, 0x85, 0x86, 0x85, 0x81, 0x80, 0x7F, 
-0x82, 0x81, 0x79, 0x76, 0x77, 0x7C, 0x80, 0x83, 0x80, 0x7F, 0x80, 0x81, 0x7F, 0x7C, 0x7A, 0x7B, 
-0x7F, 0x81, 0x83, 0x81, 0x81, 0x7F, 0x80, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x81, 0x82, 0x81, 
-0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7E, 0x7F, 0x80, 0x81, 0x80, 0x7E, 0x7F, 0x7F, 0x81, 0x81, 
-0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x81, 0x81, 0x7F, 0x7E, 0x7E, 
-0x80, 0x81, 0x80, 0x7F, 0x7F, 0x80, 0x81, 0x82, 0x80, 0x7E, 0x7E, 0x7D, 0x7D, 0x80, 0x80, 0x7F, 
-0x7F, 0x7E, 0x7F, 0x82, 0x82, 0x7F, 0x7D, 0x7E, 0x7F, 0x80, 0x7F, 0x7C, 0x7D, 0x80, 0x81, 0x81, 
-0x80, 0x7E, 0x7E, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 
-0x80, 0x81, 0x7F, 0x7F, 0x7F, 0x80, 0x82, 0x81, 0x7F, 0x7F, 0x81, 0x81, 0x81, 0x80, 0x7F, 0x80, 
-0x80, 0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x80, 0x80, 0x7F, 0x80, 0x81, 0x81, 
-0x80, 0x80, 0x7F, 0x80, 0x82, 0x81, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x80, 0x80, 0x81, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x81, 0x80, 0x7F, 0x80, 0x7E, 0x7E, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7E, 
-0x7F, 0x7F, 0x7E, 0x7F, 0x80, 0x80, 0x7F, 0x7E, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7F, 0x7E, 0x7F, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7E, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7E, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 
-0x80, 0x7F, 0x80, 0x7E, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x81, 0x7F, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x81, 0x80, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7E, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x81, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x81, 0x80, 0x80, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 
-0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 
-0x7F, 0x80, 0x81, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7E, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 
-0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x81, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x81, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7E, 0x80, 0x7E, 0x80, 0x7E, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7E, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x81, 0x7E, 0x81, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x81, 0x7E, 0x81, 0x7E, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x81, 0x7F, 
-0x80, 0x7F, 0x81, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x81, 0x7F, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x7F, 0x81, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x81, 0x7E, 0x80, 0x7E, 0x80, 0x80, 0x7F, 0x7F, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 
-0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7E, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7E, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x7E, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x81, 
-0x81, 0x81, 0x82, 0x83, 0x82, 0x82, 0x83, 0x82, 0x85, 0x7F, 0x7E, 0x85, 0x7F, 0x7C, 0x7D, 0x80, 
-0x7C, 0x7D, 0x7B, 0x7D, 0x80, 0x7C, 0x7C, 0x80, 0x80, 0x7D, 0x7F, 0x7E, 0x80, 0x7E, 0x7E, 0x7E, 
-0x80, 0x7F, 0x7E, 0x81, 0x82, 0x83, 0x83, 0x83, 0x85, 0x86, 0x87, 0x7C, 0x84, 0x8A, 0x7B, 0x7C, 
-0x80, 0x80, 0x79, 0x7C, 0x78, 0x7F, 0x7E, 0x79, 0x7D, 0x83, 0x7F, 0x7C, 0x82, 0x81, 0x80, 0x7D, 
-0x7E, 0x7E, 0x7F, 0x7A, 0x7C, 0x7F, 0x7E, 0x7F, 0x80, 0x81, 0x85, 0x84, 0x85, 0x89, 0x87, 0x7F, 
-0x87, 0x89, 0x7B, 0x7E, 0x80, 0x7D, 0x78, 0x7B, 0x78, 0x7D, 0x7B, 0x77, 0x7E, 0x82, 0x7D, 0x7D, 
-0x83, 0x81, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7A, 0x7D, 0x7E, 0x7C, 0x7E, 0x82, 0x81, 0x84, 0x84, 
-0x87, 0x89, 0x84, 0x80, 0x8D, 0x85, 0x78, 0x82, 0x82, 0x7A, 0x77, 0x7C, 0x79, 0x7D, 0x77, 0x79, 
-0x82, 0x7F, 0x7A, 0x80, 0x85, 0x7E, 0x7E, 0x82, 0x80, 0x7D, 0x7C, 0x7D, 0x7F, 0x7C, 0x7D, 0x82, 
-0x81, 0x80, 0x84, 0x88, 0x88, 0x85, 0x82, 0x8B, 0x8A, 0x78, 0x82, 0x86, 0x7B, 0x75, 0x7D, 0x7D, 
-0x77, 0x78, 0x79, 0x81, 0x7D, 0x79, 0x80, 0x86, 0x7D, 0x7C, 0x84, 0x82, 0x7B, 0x7D, 0x80, 0x7D, 
-0x7B, 0x7E, 0x82, 0x7F, 0x7F, 0x85, 0x88, 0x86, 0x85, 0x86, 0x87, 0x8B, 0x7C, 0x80, 0x87, 0x7A, 
-0x76, 0x7E, 0x7D, 0x74, 0x7A, 0x7A, 0x7C, 0x7D, 0x7B, 0x7F, 0x83, 0x7E, 0x7D, 0x85, 0x80, 0x7B, 
-0x80, 0x80, 0x7B, 0x7C, 0x80, 0x7F, 0x7E, 0x7F, 0x86, 0x84, 0x85, 0x86, 0x88, 0x85, 0x89, 0x85, 
-0x7C, 0x87, 0x7F, 0x79, 0x7A, 0x7F, 0x76, 0x77, 0x7B, 0x79, 0x7C, 0x7A, 0x7E, 0x80, 0x80, 0x7C, 
-0x83, 0x81, 0x7E, 0x7E, 0x80, 0x7C, 0x7C, 0x7D, 0x7F, 0x7D, 0x7E, 0x82, 0x84, 0x80, 0x86, 0x8A, 
-0x85, 0x83, 0x89, 0x8A, 0x7A, 0x84, 0x83, 0x7C, 0x79, 0x7D, 0x7B, 0x76, 0x7A, 0x79, 0x7E, 0x79, 
-0x7B, 0x81, 0x80, 0x7C, 0x81, 0x84, 0x7D, 0x7F, 0x81, 0x7F, 0x7C, 0x7F, 0x7D, 0x7F, 0x7D, 0x81, 
-0x82, 0x82, 0x81, 0x89, 0x88, 0x83, 0x86, 0x89, 0x8A, 0x7A, 0x85, 0x86, 0x7B, 0x78, 0x80, 0x7E, 
-0x74, 0x7B, 0x7C, 0x7C, 0x78, 0x7D, 0x81, 0x7E, 0x7C, 0x83, 0x84, 0x7B, 0x80, 0x84, 0x7E, 0x7A, 
-0x81, 0x80, 0x7A, 0x7D, 0x82, 0x81, 0x7D, 0x85, 0x84, 0x85, 0x87, 0x86, 0x85, 0x87, 0x89, 0x7B, 
-0x86, 0x84, 0x7B, 0x7B, 0x80, 0x7B, 0x76, 0x7D, 0x79, 0x7B, 0x79, 0x7D, 0x7E, 0x7E, 0x7C, 0x81, 
-0x82, 0x7C, 0x82, 0x82, 0x7E, 0x7C, 0x82, 0x7E, 0x7C, 0x7D, 0x80, 0x7F, 0x7E, 0x82, 0x83, 0x82, 
-0x83, 0x89, 0x84, 0x83, 0x87, 0x8A, 0x7C, 0x82, 0x86, 0x7B, 0x7B, 0x7E, 0x7E, 0x76, 0x7B, 0x7B, 
-0x7B, 0x78, 0x7C, 0x7F, 0x7C, 0x7D, 0x82, 0x82, 0x7B, 0x83, 0x83, 0x7C, 0x7E, 0x82, 0x7E, 0x7B, 
-0x7F, 0x7E, 0x7E, 0x7D, 0x81, 0x81, 0x81, 0x83, 0x86, 0x86, 0x84, 0x88, 0x83, 0x8B, 0x81, 0x7F, 
-0x87, 0x7E, 0x7B, 0x7E, 0x81, 0x74, 0x7C, 0x7C, 0x7A, 0x79, 0x7D, 0x7E, 0x7C, 0x7E, 0x7F, 0x82, 
-0x7D, 0x81, 0x82, 0x7F, 0x7E, 0x82, 0x7F, 0x7C, 0x7F, 0x7D, 0x7E, 0x7E, 0x80, 0x7F, 0x82, 0x82, 
-0x83, 0x84, 0x85, 0x87, 0x84, 0x87, 0x84, 0x89, 0x7F, 0x81, 0x87, 0x7C, 0x7C, 0x7F, 0x7F, 0x75, 
-0x7E, 0x7C, 0x79, 0x7A, 0x7D, 0x7E, 0x7B, 0x7E, 0x80, 0x80, 0x7C, 0x82, 0x81, 0x7E, 0x7F, 0x83, 
-0x7E, 0x7D, 0x81, 0x7D, 0x7E, 0x7F, 0x7F, 0x7D, 0x82, 0x81, 0x81, 0x82, 0x85, 0x84, 0x85, 0x86, 
-0x86, 0x84, 0x84, 0x88, 0x7E, 0x83, 0x81, 0x7E, 0x7C, 0x7E, 0x7C, 0x79, 0x7D, 0x79, 0x7C, 0x7A, 
-0x7D, 0x7B, 0x7E, 0x7D, 0x7F, 0x7F, 0x7F, 0x81, 0x80, 0x7F, 0x80, 0x81, 0x7E, 0x80, 0x7F, 0x7E, 
-0x7E, 0x80, 0x7E, 0x7E, 0x80, 0x80, 0x80, 0x82, 0x82, 0x84, 0x83, 0x86, 0x84, 0x85, 0x84, 0x84, 
-0x85, 0x7D, 0x84, 0x80, 0x7D, 0x7C, 0x80, 0x79, 0x79, 0x7D, 0x79, 0x7A, 0x7A, 0x7D, 0x7B, 0x7D, 
-0x7D, 0x80, 0x7D, 0x80, 0x81, 0x80, 0x7F, 0x82, 0x81, 0x7E, 0x81, 0x7E, 0x7F, 0x7E, 0x7E, 0x7E, 
-0x7E, 0x7E, 0x7F, 0x7F, 0x80, 0x81, 0x82, 0x82, 0x84, 0x83, 0x85, 0x84, 0x85, 0x82, 0x86, 0x81, 
-0x7E, 0x84, 0x7E, 0x7D, 0x7C, 0x7F, 0x78, 0x7C, 0x7B, 0x7B, 0x79, 0x7C, 0x7D, 0x7B, 0x7D, 0x7F, 
-0x80, 0x7D, 0x82, 0x80, 0x80, 0x80, 0x82, 0x7F, 0x80, 0x80, 0x7E, 0x7E, 0x7E, 0x7E, 0x7D, 0x7F, 
-0x7F, 0x7E, 0x7F, 0x82, 0x80, 0x82, 0x83, 0x84, 0x83, 0x84, 0x86, 0x84, 0x83, 0x82, 0x87, 0x7E, 
-0x80, 0x83, 0x7D, 0x7C, 0x7E, 0x7D, 0x78, 0x7D, 0x7B, 0x7B, 0x7A, 0x7D, 0x7D, 0x7B, 0x7F, 0x80, 
-0x7E, 0x7F, 0x83, 0x7F, 0x81, 0x81, 0x82, 0x7F, 0x81, 0x80, 0x7E, 0x7E, 0x7F, 0x7E, 0x7C, 0x7F, 
-0x7E, 0x7D, 0x7F, 0x81, 0x7F, 0x81, 0x83, 0x82, 0x82, 0x84, 0x84, 0x83, 0x85, 0x84, 0x81, 0x84, 
-0x82, 0x7E, 0x82, 0x7F, 0x7C, 0x7D, 0x7E, 0x7A, 0x7B, 0x7D, 0x7B, 0x7B, 0x7B, 0x7E, 0x7B, 0x7D, 
-0x7F, 0x7F, 0x7D, 0x81, 0x81, 0x7F, 0x81, 0x81, 0x80, 0x7F, 0x81, 0x7F, 0x7F, 0x7F, 0x7E, 0x7D, 
-0x7D, 0x7E, 0x7D, 0x7D, 0x7F, 0x7F, 0x7E, 0x80, 0x81, 0x81, 0x83, 0x83, 0x83, 0x84, 0x84, 0x84, 
-0x86, 0x82, 0x82, 0x87, 0x7E, 0x80, 0x84, 0x7D, 0x7C, 0x80, 0x7E, 0x7A, 0x7D, 0x7D, 0x7C, 0x7A, 
-0x7E, 0x7F, 0x7B, 0x7F, 0x82, 0x7E, 0x7F, 0x84, 0x80, 0x81, 0x83, 0x82, 0x80, 0x82, 0x81, 0x80, 
-0x80, 0x80, 0x7F, 0x7C, 0x7F, 0x7E, 0x7C, 0x7E, 0x80, 0x7D, 0x7F, 0x81, 0x80, 0x80, 0x83, 0x83, 
-0x82, 0x84, 0x85, 0x84, 0x83, 0x85, 0x84, 0x82, 0x80, 0x88, 0x7D, 0x7E, 0x84, 0x7D, 0x7B, 0x7E, 
-0x7F, 0x78, 0x7D, 0x7C, 0x7D, 0x79, 0x7F, 0x7F, 0x7B, 0x7F, 0x82, 0x7E, 0x7E, 0x85, 0x7F, 0x81, 
-0x81, 0x83, 0x7F, 0x81, 0x81, 0x7F, 0x7E, 0x80, 0x7F, 0x7B, 0x7E, 0x7E, 0x7C, 0x7C, 0x80, 0x7D, 
-0x7D, 0x80, 0x80, 0x7E, 0x81, 0x82, 0x81, 0x82, 0x83, 0x83, 0x82, 0x84, 0x83, 0x82, 0x84, 0x7E, 
-0x86, 0x7F, 0x7C, 0x83, 0x7D, 0x7C, 0x7E, 0x80, 0x79, 0x7E, 0x7C, 0x7D, 0x7B, 0x7D, 0x7F, 0x7C, 
-0x7E, 0x80, 0x80, 0x7D, 0x84, 0x7F, 0x81, 0x81, 0x83, 0x7F, 0x81, 0x82, 0x7F, 0x7F, 0x7F, 0x80, 
-0x7B, 0x7F, 0x7E, 0x7C, 0x7C, 0x7F, 0x7C, 0x7C, 0x7F, 0x7E, 0x7D, 0x80, 0x81, 0x80, 0x81, 0x83, 
-0x82, 0x83, 0x84, 0x84, 0x82, 0x85, 0x83, 0x84, 0x80, 0x82, 0x85, 0x79, 0x83, 0x80, 0x7D, 0x7C, 
-0x81, 0x7B, 0x7B, 0x7E, 0x7D, 0x7D, 0x7A, 0x82, 0x7B, 0x7E, 0x7F, 0x82, 0x7C, 0x82, 0x82, 0x7F, 
-0x81, 0x83, 0x82, 0x7F, 0x83, 0x81, 0x81, 0x7E, 0x82, 0x7E, 0x7D, 0x80, 0x7E, 0x7C, 0x7E, 0x7F, 
-0x7A, 0x7E, 0x7E, 0x7D, 0x7C, 0x81, 0x7F, 0x7E, 0x81, 0x82, 0x80, 0x81, 0x85, 0x81, 0x83, 0x84, 
-0x85, 0x81, 0x84, 0x83, 0x83, 0x7D, 0x85, 0x83, 0x78, 0x85, 0x7E, 0x7C, 0x7B, 0x82, 0x7A, 0x7C, 
-0x7D, 0x7E, 0x7B, 0x7B, 0x82, 0x7A, 0x7E, 0x80, 0x82, 0x7B, 0x83, 0x81, 0x7F, 0x80, 0x83, 0x82, 
-0x7E, 0x83, 0x80, 0x80, 0x7E, 0x82, 0x7D, 0x7D, 0x7F, 0x7E, 0x7B, 0x7D, 0x7F, 0x7A, 0x7D, 0x7E, 
-0x7D, 0x7B, 0x81, 0x7E, 0x7E, 0x80, 0x82, 0x7F, 0x81, 0x83, 0x82, 0x81, 0x84, 0x83, 0x81, 0x83, 
-0x83, 0x81, 0x83, 0x80, 0x7F, 0x86, 0x79, 0x82, 0x80, 0x7B, 0x7C, 0x80, 0x7C, 0x7B, 0x7F, 0x7C, 
-0x7E, 0x7A, 0x81, 0x7D, 0x7D, 0x7F, 0x82, 0x7D, 0x80, 0x83, 0x7E, 0x81, 0x81, 0x82, 0x7E, 0x83, 
-0x81, 0x80, 0x7F, 0x81, 0x80, 0x7D, 0x81, 0x7F, 0x7D, 0x7D, 0x7F, 0x7C, 0x7D, 0x7E, 0x7E, 0x7D, 
-0x7E, 0x80, 0x7D, 0x7F, 0x80, 0x80, 0x7F, 0x82, 0x82, 0x80, 0x82, 0x82, 0x82, 0x81, 0x84, 0x81, 
-0x82, 0x82, 0x84, 0x7E, 0x81, 0x85, 0x7A, 0x81, 0x80, 0x7D, 0x7C, 0x80, 0x7D, 0x7B, 0x7E, 0x7E, 
-0x7E, 0x7A, 0x81, 0x7D, 0x7D, 0x7E, 0x82, 0x7D, 0x7F, 0x82, 0x7F, 0x7F, 0x81, 0x83, 0x7E, 0x81, 
-0x81, 0x81, 0x7E, 0x81, 0x81, 0x7D, 0x80, 0x80, 0x7E, 0x7C, 0x81, 0x7D, 0x7C, 0x7F, 0x7F, 0x7D, 
-0x7D, 0x80, 0x7D, 0x7E, 0x80, 0x81, 0x7E, 0x81, 0x82, 0x80, 0x81, 0x83, 0x82, 0x80, 0x85, 0x82, 
-0x83, 0x81, 0x84, 0x81, 0x82, 0x80, 0x82, 0x83, 0x7C, 0x83, 0x7F, 0x7E, 0x7D, 0x82, 0x7C, 0x7D, 
-0x80, 0x7E, 0x7E, 0x7D, 0x81, 0x7D, 0x7F, 0x80, 0x82, 0x7D, 0x81, 0x82, 0x7F, 0x81, 0x81, 0x81, 
-0x7F, 0x82, 0x80, 0x81, 0x7F, 0x82, 0x7F, 0x7F, 0x80, 0x7F, 0x7E, 0x7E, 0x80, 0x7D, 0x7E, 0x7E, 
-0x7F, 0x7D, 0x7E, 0x7F, 0x7E, 0x7E, 0x80, 0x80, 0x7E, 0x81, 0x80, 0x80, 0x80, 0x82, 0x81, 0x80, 
-0x82, 0x81, 0x81, 0x81, 0x82, 0x81, 0x81, 0x81, 0x81, 0x7F, 0x7F, 0x81, 0x7E, 0x7E, 0x80, 0x7F, 
-0x7C, 0x7E, 0x7F, 0x7C, 0x7D, 0x7E, 0x7E, 0x7D, 0x7F, 0x7F, 0x7E, 0x7E, 0x80, 0x7F, 0x7E, 0x81, 
-0x80, 0x7F, 0x80, 0x81, 0x7F, 0x80, 0x81, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 
-0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7D, 0x7F, 0x7F, 0x7E, 0x7E, 0x80, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x82, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x81, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 
-0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x80, 
-0x80, 0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7E, 0x7F, 0x7F, 0x7E, 0x7F, 0x7E, 0x7F, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x80, 0x80, 0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 
-0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 
-0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 
-0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 
-0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 
-0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 
-0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 
-0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7D, 
-0x7E, 0x82, 0x80, 0x7D, 0x7E, 0x81, 0x82, 0x7D, 0x7E, 0x81, 0x82, 0x7E, 0x7D, 0x81, 0x81, 0x7F, 
-0x7E, 0x81, 0x82, 0x81, 0x81, 0x81, 0x82, 0x80, 0x7F, 0x81, 0x80, 0x7B, 0x7C, 0x82, 0x7D, 0x7C, 
-0x7F, 0x81, 0x81, 0x80, 0x81, 0x81, 0x84, 0x80, 0x7D, 0x80, 0x80, 0x7D, 0x7B, 0x7F, 0x7F, 0x7E, 
-0x7F, 0x80, 0x82, 0x81, 0x80, 0x80, 0x83, 0x81, 0x7C, 0x7F, 0x80, 0x7E, 0x7D, 0x7E, 0x7F, 0x80, 
-0x7F, 0x7D, 0x80, 0x82, 0x7D, 0x7D, 0x81, 0x80, 0x7D, 0x7E, 0x80, 0x80, 0x7F, 0x7E, 0x80, 0x82, 
-0x80, 0x7E, 0x82, 0x83, 0x80, 0x80, 0x83, 0x82, 0x82, 0x80, 0x82, 0x80, 0x81, 0x80, 0x80, 0x7C, 
-0x79, 0x86, 0x7B, 0x7A, 0x7E, 0x83, 0x7E, 0x7D, 0x84, 0x80, 0x83, 0x7F, 0x7F, 0x81, 0x81, 0x7C, 
-0x7B, 0x82, 0x7E, 0x7C, 0x7F, 0x80, 0x81, 0x80, 0x80, 0x80, 0x84, 0x80, 0x7C, 0x7F, 0x81, 0x7C, 
-0x79, 0x7E, 0x7E, 0x7E, 0x7D, 0x7E, 0x82, 0x81, 0x7F, 0x80, 0x84, 0x80, 0x7E, 0x80, 0x81, 0x7F, 
-0x7F, 0x80, 0x82, 0x81, 0x81, 0x83, 0x84, 0x83, 0x82, 0x83, 0x81, 0x82, 0x81, 0x7D, 0x76, 0x83, 
-0x7F, 0x73, 0x7E, 0x82, 0x80, 0x7A, 0x85, 0x83, 0x83, 0x82, 0x7E, 0x83, 0x82, 0x7C, 0x78, 0x81, 
-0x7E, 0x79, 0x7E, 0x80, 0x81, 0x80, 0x81, 0x82, 0x84, 0x83, 0x7C, 0x81, 0x81, 0x7E, 0x78, 0x7C, 
-0x7E, 0x7B, 0x7C, 0x7C, 0x82, 0x81, 0x7F, 0x81, 0x84, 0x82, 0x7E, 0x81, 0x81, 0x7F, 0x7E, 0x7E, 
-0x81, 0x80, 0x80, 0x80, 0x84, 0x84, 0x82, 0x82, 0x86, 0x81, 0x80, 0x84, 0x7F, 0x7C, 0x77, 0x86, 
-0x77, 0x77, 0x7E, 0x80, 0x7F, 0x7C, 0x85, 0x81, 0x87, 0x7F, 0x81, 0x84, 0x81, 0x7C, 0x7A, 0x81, 
-0x7B, 0x7A, 0x7D, 0x7F, 0x80, 0x7F, 0x81, 0x82, 0x85, 0x81, 0x7E, 0x83, 0x81, 0x7B, 0x7A, 0x7E, 
-0x7C, 0x7A, 0x7B, 0x7E, 0x81, 0x7F, 0x7F, 0x83, 0x84, 0x80, 0x80, 0x83, 0x81, 0x7F, 0x7F, 0x80, 
-0x82, 0x7F, 0x80, 0x81, 0x83, 0x82, 0x82, 0x83, 0x84, 0x84, 0x80, 0x84, 0x81, 0x82, 0x74, 0x82, 
-0x80, 0x73, 0x7B, 0x7E, 0x82, 0x76, 0x84, 0x81, 0x84, 0x83, 0x7F, 0x84, 0x82, 0x81, 0x78, 0x81, 
-0x7F, 0x79, 0x7B, 0x7E, 0x80, 0x7B, 0x7F, 0x7F, 0x83, 0x82, 0x7C, 0x80, 0x83, 0x7F, 0x79, 0x80, 
-0x80, 0x7B, 0x7C, 0x7D, 0x80, 0x7E, 0x7D, 0x80, 0x83, 0x81, 0x7F, 0x83, 0x84, 0x81, 0x81, 0x83, 
-0x82, 0x80, 0x80, 0x80, 0x80, 0x82, 0x7F, 0x81, 0x85, 0x81, 0x80, 0x83, 0x85, 0x81, 0x7E, 0x78, 
-0x86, 0x7E, 0x73, 0x7C, 0x82, 0x7D, 0x75, 0x84, 0x81, 0x82, 0x7E, 0x81, 0x85, 0x83, 0x7F, 0x7B, 
-0x86, 0x7F, 0x79, 0x7B, 0x80, 0x7A, 0x79, 0x7E, 0x7B, 0x80, 0x7F, 0x7D, 0x81, 0x85, 0x80, 0x7D, 
-0x85, 0x81, 0x7E, 0x7F, 0x80, 0x7F, 0x7E, 0x7F, 0x7F, 0x82, 0x7F, 0x80, 0x82, 0x84, 0x7F, 0x81, 
-0x84, 0x81, 0x80, 0x82, 0x82, 0x7F, 0x84, 0x80, 0x80, 0x82, 0x82, 0x80, 0x83, 0x81, 0x81, 0x79, 
-0x84, 0x83, 0x73, 0x7F, 0x83, 0x7E, 0x75, 0x83, 0x80, 0x7E, 0x7F, 0x7E, 0x82, 0x80, 0x7E, 0x7A, 
-0x84, 0x7E, 0x79, 0x7D, 0x81, 0x7D, 0x7B, 0x80, 0x7F, 0x81, 0x80, 0x7E, 0x82, 0x84, 0x80, 0x7E, 
-0x84, 0x82, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7D, 0x7F, 0x81, 0x7F, 0x7F, 0x81, 0x81, 0x80, 0x82, 
-0x82, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x7F, 0x81, 0x81, 0x7F, 0x80, 0x81, 0x80, 0x80, 0x82, 
-0x80, 0x7F, 0x80, 0x80, 0x7E, 0x7D, 0x7D, 0x7D, 0x7D, 0x7C, 0x7D, 0x7D, 0x7E, 0x7D, 0x7E, 0x7E, 
-0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x81, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 
-0x80, 0x7F, 0x80, 0x81, 0x7F, 0x80, 0x81, 0x7F, 0x81, 0x81, 0x7F, 0x80, 0x80, 0x7F, 0x81, 0x81, 
-0x7F, 0x80, 0x82, 0x7F, 0x7F, 0x82, 0x80, 0x80, 0x80, 0x80, 0x7E, 0x80, 0x7F, 0x7E, 0x81, 0x80, 
-0x7D, 0x80, 0x83, 0x7E, 0x80, 0x82, 0x80, 0x7F, 0x81, 0x7E, 0x7F, 0x7F, 0x7D, 0x7D, 0x7E, 0x7D, 
-0x7C, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x81, 0x80, 0x7F, 0x81, 0x81, 0x80, 0x7F, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7E, 0x80, 0x7F, 0x7F, 0x7F, 0x81, 0x81, 0x7F, 0x80, 0x80, 0x81, 0x7F, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x81, 0x80, 0x7F, 0x80, 0x81, 0x80, 0x80, 0x80, 0x81, 0x80, 0x80, 
-0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7E, 0x80, 0x81, 0x7E, 0x7F, 0x81, 0x80, 0x7E, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7F, 0x80, 0x7E, 0x7D, 0x81, 0x7F, 0x7E, 0x7E, 0x80, 0x80, 0x7E, 
-0x7F, 0x7F, 0x81, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x81, 0x7F, 0x80, 
-0x80, 0x80, 0x80, 0x7F, 0x80, 0x7E, 0x81, 0x80, 0x7F, 0x80, 0x81, 0x7F, 0x7F, 0x82, 0x80, 0x7E, 
-0x80, 0x81, 0x7F, 0x7F, 0x80, 0x7F, 0x81, 0x80, 0x7F, 0x7E, 0x80, 0x81, 0x7D, 0x80, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7E, 0x80, 0x7F, 0x81, 0x7E, 0x80, 0x80, 0x7F, 0x81, 0x7E, 0x81, 
-0x7E, 0x82, 0x7E, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x81, 0x7F, 0x81, 0x7F, 
-0x80, 0x81, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x80, 0x7E, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x7F, 0x80, 0x80, 0x80, 0x7E, 0x7F, 0x80, 0x7F, 0x80, 0x7E, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x7F, 0x7F, 0x7F, 0x81, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x81, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x81, 0x7E, 0x81, 0x7E, 0x81, 0x7F, 0x7F, 0x80, 0x7E, 0x80, 0x7E, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 
-0x80, 0x81, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x81, 0x7E, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7E, 0x7F, 0x81, 0x7E, 0x80, 0x7E, 0x81, 
-0x7F, 0x7F, 0x80, 0x7F, 0x81, 0x7F, 0x7F, 0x80, 0x81, 0x7E, 0x80, 0x7F, 0x81, 0x7E, 0x80, 0x7F, 
-0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x81, 0x7D, 0x81, 0x7E, 0x82, 0x7E, 0x7F, 0x7F, 0x80, 0x80, 0x7E, 
-0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x81, 0x7F, 0x80, 0x7F, 0x81, 0x7F, 0x7E, 0x81, 0x7F, 0x80, 
-0x7F, 0x7F, 0x81, 0x7F, 0x80, 0x7E, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 
-0x7F, 0x80, 0x7E, 0x81, 0x7E, 0x81, 0x7E, 0x80, 0x7F, 0x80, 0x80, 0x7E, 0x81, 0x7E, 0x81, 0x7E, 
-0x81, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 
-0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 
-0x81, 0x81, 0x81, 0x81, 0x81, 0x82, 0x81, 0x80, 0x7E, 0x7E, 0x7F, 0x7E, 0x7E, 0x7D, 0x7D, 0x7D, 
-0x7F, 0x7F, 0x7E, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7D, 
-0x7F, 0x7D, 0x7E, 0x7E, 0x7F, 0x80, 0x81, 0x81, 0x81, 0x83, 0x83, 0x83, 0x83, 0x83, 0x84, 0x84, 
-0x83, 0x80, 0x7D, 0x80, 0x7D, 0x7D, 0x7A, 0x7B, 0x7A, 0x7D, 0x7F, 0x7B, 0x7F, 0x7E, 0x83, 0x82, 
-0x84, 0x81, 0x80, 0x83, 0x81, 0x81, 0x7E, 0x7E, 0x7C, 0x7E, 0x7E, 0x7B, 0x7C, 0x7C, 0x7D, 0x7F, 
-0x7E, 0x7F, 0x7E, 0x81, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x81, 0x80, 0x82, 0x82, 0x85, 0x84, 
-0x88, 0x85, 0x83, 0x7D, 0x81, 0x7F, 0x7B, 0x7C, 0x76, 0x7A, 0x79, 0x81, 0x7A, 0x7D, 0x80, 0x82, 
-0x85, 0x84, 0x84, 0x80, 0x85, 0x83, 0x80, 0x7F, 0x7D, 0x7D, 0x7D, 0x7E, 0x7B, 0x7A, 0x7D, 0x7C, 
-0x7E, 0x7F, 0x80, 0x7F, 0x82, 0x82, 0x80, 0x81, 0x80, 0x7F, 0x80, 0x82, 0x80, 0x82, 0x86, 0x86, 
-0x89, 0x89, 0x87, 0x7D, 0x7F, 0x83, 0x7A, 0x7B, 0x76, 0x78, 0x77, 0x80, 0x7E, 0x79, 0x82, 0x83, 
-0x86, 0x85, 0x86, 0x80, 0x83, 0x87, 0x80, 0x7E, 0x7D, 0x7C, 0x7C, 0x7E, 0x7C, 0x79, 0x7E, 0x7E, 
-0x7E, 0x7F, 0x81, 0x81, 0x82, 0x84, 0x7F, 0x80, 0x80, 0x7F, 0x7D, 0x80, 0x80, 0x81, 0x85, 0x86, 
-0x88, 0x8A, 0x8B, 0x84, 0x7C, 0x82, 0x7F, 0x7A, 0x78, 0x76, 0x76, 0x79, 0x81, 0x7B, 0x7C, 0x83, 
-0x85, 0x86, 0x86, 0x84, 0x80, 0x85, 0x85, 0x7E, 0x7D, 0x7C, 0x7B, 0x7B, 0x7C, 0x79, 0x79, 0x7F, 
-0x7D, 0x7D, 0x80, 0x81, 0x81, 0x83, 0x81, 0x7E, 0x80, 0x80, 0x7D, 0x7D, 0x7F, 0x80, 0x82, 0x84, 
-0x85, 0x87, 0x8C, 0x8A, 0x87, 0x7B, 0x7E, 0x82, 0x79, 0x78, 0x73, 0x77, 0x77, 0x80, 0x7C, 0x79, 
-0x82, 0x84, 0x87, 0x84, 0x84, 0x80, 0x83, 0x86, 0x7E, 0x7B, 0x7B, 0x7B, 0x7B, 0x7B, 0x79, 0x78, 
-0x7F, 0x7C, 0x7D, 0x80, 0x7F, 0x82, 0x81, 0x82, 0x7C, 0x80, 0x7F, 0x7D, 0x7D, 0x7E, 0x80, 0x82, 
-0x83, 0x84, 0x86, 0x8C, 0x8A, 0x86, 0x86, 0x74, 0x85, 0x7C, 0x78, 0x73, 0x74, 0x7A, 0x75, 0x84, 
-0x76, 0x7E, 0x85, 0x87, 0x86, 0x83, 0x86, 0x80, 0x86, 0x84, 0x7A, 0x7C, 0x7B, 0x7E, 0x78, 0x7A, 
-0x79, 0x79, 0x7E, 0x7D, 0x7F, 0x7E, 0x84, 0x82, 0x82, 0x81, 0x7E, 0x80, 0x7F, 0x7F, 0x7C, 0x80, 
-0x81, 0x81, 0x85, 0x85, 0x88, 0x89, 0x8A, 0x87, 0x89, 0x74, 0x81, 0x81, 0x76, 0x76, 0x73, 0x7A, 
-0x74, 0x84, 0x79, 0x7B, 0x85, 0x87, 0x86, 0x83, 0x86, 0x81, 0x84, 0x86, 0x79, 0x7B, 0x7E, 0x7B, 
-0x7A, 0x79, 0x77, 0x77, 0x81, 0x7C, 0x7C, 0x81, 0x83, 0x83, 0x84, 0x81, 0x7D, 0x83, 0x83, 0x7D, 
-0x7E, 0x7F, 0x81, 0x82, 0x85, 0x81, 0x85, 0x8A, 0x8A, 0x88, 0x87, 0x83, 0x76, 0x85, 0x7E, 0x74, 
-0x76, 0x78, 0x7A, 0x79, 0x81, 0x77, 0x7F, 0x86, 0x84, 0x83, 0x83, 0x86, 0x82, 0x85, 0x81, 0x7A, 
-0x7E, 0x7D, 0x7A, 0x76, 0x79, 0x79, 0x7D, 0x7E, 0x7C, 0x81, 0x84, 0x85, 0x82, 0x82, 0x81, 0x83, 
-0x81, 0x7F, 0x7E, 0x80, 0x81, 0x80, 0x81, 0x7F, 0x85, 0x86, 0x86, 0x85, 0x89, 0x89, 0x87, 0x78, 
-0x7E, 0x82, 0x79, 0x7C, 0x73, 0x7A, 0x7A, 0x82, 0x79, 0x7A, 0x83, 0x81, 0x85, 0x7F, 0x81, 0x80, 
-0x85, 0x81, 0x7B, 0x7D, 0x7C, 0x7F, 0x7B, 0x7A, 0x7A, 0x7F, 0x80, 0x7E, 0x80, 0x81, 0x85, 0x84, 
-0x81, 0x80, 0x82, 0x82, 0x80, 0x7F, 0x7E, 0x81, 0x81, 0x7F, 0x7F, 0x81, 0x84, 0x82, 0x82, 0x82, 
-0x82, 0x86, 0x84, 0x81, 0x7C, 0x81, 0x80, 0x7C, 0x7C, 0x7A, 0x7D, 0x7E, 0x7F, 0x7A, 0x7E, 0x80, 
-0x7F, 0x80, 0x7E, 0x7F, 0x80, 0x81, 0x7D, 0x7E, 0x7E, 0x7D, 0x7F, 0x7C, 0x7D, 0x7F, 0x80, 0x7F, 
-0x80, 0x80, 0x80, 0x82, 0x80, 0x80, 0x81, 0x82, 0x7E, 0x81, 0x7F, 0x7F, 0x81, 0x80, 0x7F, 0x81, 
-0x82, 0x7E, 0x82, 0x80, 0x81, 0x81, 0x81, 0x80, 0x80, 0x82, 0x7D, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7E, 0x7E, 0x81, 0x7D, 0x80, 0x7E, 0x80, 0x7E, 0x7F, 0x7E, 0x7F, 0x80, 0x7E, 0x7F, 0x7E, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x81, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x81, 
-0x7F, 0x7F, 0x82, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7E, 0x81, 0x7F, 0x80, 0x7F, 0x7F, 
-0x80, 0x7F, 0x80, 0x7E, 0x81, 0x7F, 0x81, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7D, 0x81, 0x7D, 0x80, 
-0x7F, 0x7E, 0x80, 0x7F, 0x80, 0x7D, 0x82, 0x7D, 0x81, 0x7F, 0x7F, 0x80, 0x7F, 0x81, 0x7D, 0x82, 
-0x7D, 0x80, 0x80, 0x7E, 0x81, 0x7F, 0x80, 0x7E, 0x81, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x7F, 0x7F, 0x81, 0x7D, 0x80, 0x7F, 0x7F, 0x82, 0x7F, 0x81, 0x7F, 0x82, 0x7E, 0x81, 0x7F, 0x7F, 
-0x81, 0x7E, 0x81, 0x7D, 0x82, 0x7D, 0x80, 0x80, 0x7E, 0x81, 0x7E, 0x81, 0x7E, 0x80, 0x7F, 0x7F, 
-0x80, 0x7E, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x81, 0x7F, 0x80, 0x80, 0x7F, 0x81, 
-0x7E, 0x7F, 0x7E, 0x80, 0x80, 0x7E, 0x81, 0x7E, 0x81, 0x7E, 0x81, 0x7E, 0x80, 0x80, 0x7E, 0x81, 
-0x7E, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x81, 0x7E, 0x82, 0x7D, 0x82, 0x7D, 0x81, 0x7E, 0x7F, 
-0x80, 0x7E, 0x82, 0x7C, 0x84, 0x7B, 0x84, 0x7C, 0x82, 0x7E, 0x80, 0x82, 0x7C, 0x84, 0x7C, 0x83, 
-0x7D, 0x81, 0x7F, 0x7E, 0x81, 0x7E, 0x81, 0x7E, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 
-0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7E, 0x81, 0x7E, 0x81, 0x7E, 0x81, 0x7F, 0x80, 0x80, 0x7E, 0x81, 
-0x7D, 0x82, 0x7D, 0x82, 0x7D, 0x82, 0x7E, 0x80, 0x80, 0x7F, 0x81, 0x7E, 0x82, 0x7D, 0x82, 0x7E, 
-0x81, 0x7E, 0x81, 0x7D, 0x81, 0x7D, 0x81, 0x7F, 0x80, 0x81, 0x7E, 0x82, 0x7D, 0x83, 0x7D, 0x83, 
-0x7D, 0x82, 0x7E, 0x81, 0x7E, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x81, 0x7E, 0x81, 0x7D, 0x82, 0x7D, 
-0x82, 0x7C, 0x82, 0x7D, 0x81, 0x7F, 0x7F, 0x82, 0x7D, 0x83, 0x7C, 0x83, 0x7C, 0x83, 0x7D, 0x81, 
-0x7E, 0x80, 0x81, 0x7D, 0x82, 0x7B, 0x84, 0x7B, 0x83, 0x7D, 0x80, 0x81, 0x7E, 0x82, 0x7D, 0x83, 
-0x7D, 0x82, 0x7E, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7E, 0x81, 0x7E, 0x81, 0x7F, 0x81, 0x7E, 0x81, 0x7F, 0x80, 0x81, 
-0x7E, 0x82, 0x7D, 0x83, 0x7C, 0x83, 0x7D, 0x81, 0x7F, 0x7E, 0x82, 0x7D, 0x82, 0x7D, 0x82, 0x7E, 
-0x80, 0x80, 0x7F, 0x81, 0x7E, 0x81, 0x7F, 0x80, 0x80, 0x7F, 0x81, 0x7F, 0x81, 0x7F, 0x80, 0x7F, 
-0x80, 0x80, 0x7E, 0x81, 0x7E, 0x81, 0x7E, 0x80, 0x7F, 0x7F, 0x81, 0x7D, 0x82, 0x7E, 0x80, 0x80, 
-0x7E, 0x82, 0x7E, 0x81, 0x7E, 0x80, 0x80, 0x7E, 0x81, 0x7D, 0x82, 0x7D, 0x82, 0x7D, 0x81, 0x7F, 
-0x7F, 0x81, 0x7D, 0x83, 0x7C, 0x84, 0x7B, 0x83, 0x7D, 0x81, 0x80, 0x7E, 0x82, 0x7C, 0x84, 0x7A, 
-0x85, 0x7B, 0x82, 0x7E, 0x80, 0x81, 0x7D, 0x83, 0x7B, 0x84, 0x7B, 0x83, 0x7D, 0x82, 0x7E, 0x80, 
-0x81, 0x7E, 0x83, 0x7C, 0x83, 0x7C, 0x82, 0x7D, 0x81, 0x7E, 0x80, 0x7F, 0x7F, 0x80, 0x7E, 0x81, 
-0x7D, 0x82, 0x7C, 0x83, 0x7C, 0x83, 0x7D, 0x81, 0x7F, 0x7F, 0x81, 0x7C, 0x84, 0x7A, 0x85, 0x7B, 
-0x82, 0x7F, 0x7E, 0x83, 0x7A, 0x85, 0x7A, 0x84, 0x7C, 0x80, 0x81, 0x7C, 0x85, 0x79, 0x85, 0x7B, 
-0x83, 0x7E, 0x7E, 0x82, 0x7B, 0x85, 0x7B, 0x84, 0x7B, 0x83, 0x7E, 0x80, 0x81, 0x7C, 0x84, 0x7B, 
-0x84, 0x7C, 0x82, 0x7E, 0x80, 0x80, 0x7E, 0x82, 0x7D, 0x82, 0x7D, 0x82, 0x7E, 0x81, 0x7F, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x81, 0x7D, 0x82, 0x7D, 0x81, 0x7F, 0x7E, 0x82, 
-0x7C, 0x83, 0x7C, 0x82, 0x7E, 0x80, 0x80, 0x7E, 0x82, 0x7C, 0x83, 0x7C, 0x82, 0x7E, 0x80, 0x80, 
-0x7E, 0x81, 0x7E, 0x82, 0x7E, 0x81, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x81, 0x7E, 0x81, 0x7F, 
-0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7E, 0x82, 0x7D, 0x82, 0x7D, 0x82, 0x7E, 0x80, 0x80, 0x7D, 
-0x83, 0x7B, 0x84, 0x7B, 0x84, 0x7C, 0x81, 0x7F, 0x7E, 0x82, 0x7D, 0x82, 0x7D, 0x82, 0x7D, 0x82, 
-0x7D, 0x81, 0x7F, 0x7F, 0x81, 0x7E, 0x80, 0x7F, 0x80, 0x7E, 0x82, 0x7E, 0x7F, 0x81, 0x7E, 0x81, 
-0x7F, 0x81, 0x7E, 0x81, 0x7F, 0x7E, 0x81, 0x7E, 0x80, 0x7F, 0x82, 0x7C, 0x84, 0x7D, 0x7F, 0x82, 
-0x7D, 0x81, 0x7F, 0x80, 0x7F, 0x81, 0x7F, 0x7E, 0x82, 0x7E, 0x7F, 0x82, 0x7D, 0x81, 0x7E, 0x81, 
-0x7D, 0x82, 0x7F, 0x7E, 0x83, 0x7B, 0x83, 0x7D, 0x81, 0x7F, 0x80, 0x80, 0x7E, 0x82, 0x7D, 0x81, 
-0x80, 0x7E, 0x81, 0x7F, 0x7F, 0x80, 0x7E, 0x81, 0x7E, 0x82, 0x7E, 0x80, 0x81, 0x7D, 0x82, 0x7E, 
-0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x81, 0x7E, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x81, 0x7E, 0x80, 0x7F, 0x80, 0x80, 0x7E, 0x80, 0x7E, 
-0x81, 0x7F, 0x81, 0x7E, 0x81, 0x80, 0x7F, 0x81, 0x7D, 0x80, 0x80, 0x80, 0x7F, 0x7E, 0x81, 0x7F, 
-0x80, 0x80, 0x7D, 0x82, 0x7F, 0x7F, 0x7F, 0x7F, 0x81, 0x7F, 0x81, 0x7E, 0x80, 0x80, 0x7E, 0x81, 
-0x7E, 0x81, 0x7E, 0x81, 0x7F, 0x7F, 0x81, 0x7D, 0x81, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x81, 0x7F, 0x7F, 0x80, 0x7E, 0x80, 0x80, 0x7F, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7E, 0x81, 0x7F, 0x7F, 0x80, 0x7E, 0x81, 0x7E, 0x80, 0x7F, 
-0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7E, 0x7F, 0x80, 0x80, 
-0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7E, 0x81, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7E, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x81, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7E, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 
-0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x81, 0x81, 0x80, 0x81, 0x81, 0x82, 0x81, 0x80, 0x80, 
-0x81, 0x81, 0x7F, 0x7E, 0x7D, 0x7C, 0x7D, 0x7C, 0x7C, 0x7B, 0x7C, 0x7D, 0x7E, 0x7F, 0x7E, 0x7F, 
-0x7F, 0x81, 0x81, 0x81, 0x80, 0x80, 0x80, 0x81, 0x81, 0x80, 0x81, 0x82, 0x82, 0x82, 0x84, 0x84, 
-0x84, 0x85, 0x84, 0x7E, 0x81, 0x80, 0x7C, 0x7E, 0x7B, 0x7A, 0x7B, 0x7C, 0x7C, 0x7B, 0x7D, 0x7D, 
-0x7D, 0x81, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7E, 0x7D, 0x7D, 0x7D, 0x7E, 0x7F, 0x7F, 
-0x81, 0x81, 0x83, 0x84, 0x84, 0x84, 0x85, 0x86, 0x87, 0x87, 0x86, 0x7E, 0x7D, 0x81, 0x7B, 0x7E, 
-0x7B, 0x77, 0x78, 0x7B, 0x7D, 0x7C, 0x7D, 0x7F, 0x7D, 0x82, 0x84, 0x80, 0x82, 0x81, 0x7F, 0x81, 
-0x80, 0x7E, 0x7C, 0x7B, 0x7B, 0x7B, 0x7D, 0x7D, 0x7E, 0x7F, 0x81, 0x83, 0x83, 0x83, 0x84, 0x83, 
-0x84, 0x85, 0x85, 0x86, 0x86, 0x85, 0x80, 0x7D, 0x80, 0x7C, 0x7C, 0x7D, 0x78, 0x79, 0x7A, 0x7C, 
-0x7D, 0x7E, 0x80, 0x7E, 0x7F, 0x83, 0x82, 0x83, 0x82, 0x80, 0x80, 0x80, 0x80, 0x7E, 0x7D, 0x7C, 
-0x7B, 0x7B, 0x7C, 0x7D, 0x7E, 0x7F, 0x80, 0x81, 0x82, 0x83, 0x83, 0x83, 0x83, 0x83, 0x84, 0x86, 
-0x85, 0x86, 0x84, 0x81, 0x7D, 0x7E, 0x7D, 0x7C, 0x7D, 0x7A, 0x78, 0x79, 0x7B, 0x7C, 0x7E, 0x7F, 
-0x7F, 0x7F, 0x81, 0x82, 0x82, 0x84, 0x82, 0x81, 0x80, 0x80, 0x7E, 0x7E, 0x7D, 0x7C, 0x7A, 0x7B, 
-0x7B, 0x7C, 0x7F, 0x80, 0x81, 0x81, 0x82, 0x83, 0x83, 0x85, 0x85, 0x85, 0x87, 0x87, 0x87, 0x84, 
-0x83, 0x7E, 0x7E, 0x7C, 0x7B, 0x7B, 0x7A, 0x79, 0x78, 0x7A, 0x7A, 0x7C, 0x7E, 0x80, 0x81, 0x82, 
-0x82, 0x82, 0x82, 0x82, 0x82, 0x81, 0x81, 0x7F, 0x7D, 0x7C, 0x7C, 0x7B, 0x7B, 0x7B, 0x7C, 0x7D, 
-0x7F, 0x81, 0x82, 0x83, 0x83, 0x83, 0x84, 0x84, 0x85, 0x86, 0x87, 0x87, 0x84, 0x82, 0x7E, 0x7E, 
-0x7C, 0x7C, 0x7C, 0x7B, 0x7B, 0x7A, 0x7A, 0x7A, 0x7C, 0x7D, 0x7F, 0x80, 0x81, 0x81, 0x81, 0x81, 
-0x81, 0x81, 0x81, 0x81, 0x80, 0x7F, 0x7E, 0x7D, 0x7C, 0x7C, 0x7C, 0x7D, 0x7F, 0x80, 0x81, 0x81, 
-0x81, 0x81, 0x82, 0x83, 0x84, 0x85, 0x85, 0x85, 0x85, 0x84, 0x82, 0x7F, 0x7E, 0x7D, 0x7E, 0x7D, 
-0x7E, 0x7D, 0x7C, 0x7C, 0x7B, 0x7D, 0x7D, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 
-0x80, 0x80, 0x7F, 0x7F, 0x7E, 0x7D, 0x7D, 0x7E, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 
-0x82, 0x82, 0x83, 0x84, 0x84, 0x84, 0x83, 0x82, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7D, 
-0x7D, 0x7D, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x82, 0x82, 
-0x83, 0x83, 0x84, 0x83, 0x82, 0x81, 0x81, 0x80, 0x80, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 
-0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 
-0x7E, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x82, 0x82, 0x82, 0x83, 
-0x83, 0x83, 0x83, 0x82, 0x82, 0x81, 0x80, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 
-0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x82, 0x82, 0x82, 0x82, 
-0x83, 0x83, 0x82, 0x82, 0x81, 0x80, 0x80, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7D, 0x7D, 0x7D, 0x7D, 
-0x7D, 0x7D, 0x7D, 0x7D, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x82, 0x82, 0x82, 0x83, 0x83, 
-0x83, 0x83, 0x82, 0x81, 0x80, 0x80, 0x7F, 0x7F, 0x7E, 0x7E, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 
-0x7D, 0x7D, 0x7D, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x82, 0x82, 0x82, 0x82, 0x83, 0x83, 0x83, 
-0x82, 0x82, 0x81, 0x80, 0x80, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 
-0x7D, 0x7D, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x82, 0x82, 0x82, 0x82, 0x83, 0x83, 0x83, 0x82, 
-0x82, 0x81, 0x80, 0x80, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 
-0x7D, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x82, 0x82, 0x82, 0x82, 0x83, 0x83, 0x83, 0x82, 0x82, 
-0x81, 0x81, 0x80, 0x80, 0x7F, 0x7F, 0x7E, 0x7E, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 
-0x7D, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x82, 0x82, 0x82, 0x82, 0x82, 0x82, 0x82, 0x82, 0x82, 
-0x81, 0x81, 0x80, 0x80, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 
-0x7D, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x82, 0x82, 0x82, 0x82, 0x82, 0x82, 0x82, 0x82, 
-0x82, 0x81, 0x81, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 
-0x7D, 0x7D, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x82, 0x82, 0x82, 0x82, 
-0x82, 0x82, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7D, 0x7D, 
-0x7D, 0x7D, 0x7D, 0x7D, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 
-0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7E, 
-0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 
-0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 
-0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7E, 0x7F, 0x7F, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 
-0x81, 0x82, 0x81, 0x81, 0x82, 0x82, 0x80, 0x81, 0x81, 0x80, 0x7F, 0x80, 0x80, 0x7E, 0x7E, 0x7F, 
-0x7F, 0x7D, 0x7D, 0x7F, 0x7E, 0x7D, 0x7E, 0x7F, 0x7E, 0x7E, 0x80, 0x80, 0x7F, 0x80, 0x81, 0x81, 
-0x80, 0x81, 0x81, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7E, 0x7D, 0x7E, 0x7E, 0x7D, 0x7D, 
-0x7D, 0x7E, 0x7D, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x80, 0x80, 0x81, 0x81, 0x81, 0x82, 0x82, 0x82, 
-0x82, 0x83, 0x83, 0x83, 0x82, 0x83, 0x82, 0x82, 0x82, 0x81, 0x80, 0x7F, 0x7F, 0x7F, 0x7D, 0x7D, 
-0x7E, 0x7D, 0x7C, 0x7D, 0x7E, 0x7D, 0x7D, 0x7E, 0x7F, 0x7E, 0x7F, 0x80, 0x81, 0x80, 0x81, 0x82, 
-0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x7F, 0x7F, 0x7E, 0x7E, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7C, 
-0x7E, 0x7E, 0x7D, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x81, 0x81, 0x80, 0x81, 0x82, 0x81, 0x81, 0x82, 
-0x83, 0x82, 0x82, 0x83, 0x83, 0x82, 0x82, 0x82, 0x82, 0x81, 0x80, 0x80, 0x80, 0x7F, 0x7E, 0x7E, 
-0x7E, 0x7D, 0x7C, 0x7D, 0x7D, 0x7C, 0x7C, 0x7E, 0x7E, 0x7E, 0x7E, 0x80, 0x81, 0x80, 0x80, 0x82, 
-0x82, 0x80, 0x81, 0x82, 0x81, 0x7F, 0x80, 0x80, 0x7F, 0x7E, 0x7E, 0x7E, 0x7D, 0x7D, 0x7E, 0x7E, 
-0x7C, 0x7D, 0x7E, 0x7E, 0x7D, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 
-0x81, 0x82, 0x82, 0x81, 0x82, 0x83, 0x82, 0x82, 0x82, 0x83, 0x82, 0x81, 0x81, 0x81, 0x80, 0x7F, 
-0x7E, 0x7F, 0x7E, 0x7C, 0x7D, 0x7D, 0x7C, 0x7C, 0x7D, 0x7E, 0x7D, 0x7D, 0x7F, 0x80, 0x7F, 0x80, 
-0x81, 0x82, 0x81, 0x81, 0x82, 0x82, 0x81, 0x80, 0x81, 0x80, 0x7F, 0x7F, 0x7F, 0x7E, 0x7D, 0x7D, 
-0x7D, 0x7D, 0x7D, 0x7D, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x81, 
-0x81, 0x81, 0x81, 0x81, 0x81, 0x82, 0x82, 0x82, 0x82, 0x82, 0x82, 0x82, 0x82, 0x82, 0x82, 0x81, 
-0x80, 0x81, 0x80, 0x7E, 0x7E, 0x7F, 0x7D, 0x7C, 0x7D, 0x7D, 0x7D, 0x7C, 0x7D, 0x7E, 0x7E, 0x7E, 
-0x7F, 0x80, 0x7F, 0x80, 0x81, 0x81, 0x81, 0x81, 0x82, 0x82, 0x80, 0x80, 0x81, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7E, 0x7E, 0x7D, 0x7D, 0x7E, 0x7D, 0x7D, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 
-0x80, 0x80, 0x80, 0x81, 0x81, 0x80, 0x81, 0x82, 0x81, 0x81, 0x83, 0x81, 0x82, 0x82, 0x82, 0x82, 
-0x81, 0x82, 0x83, 0x81, 0x7F, 0x81, 0x81, 0x7D, 0x7E, 0x7F, 0x7E, 0x7C, 0x7D, 0x7E, 0x7D, 0x7C, 
-0x7C, 0x80, 0x7D, 0x7C, 0x80, 0x80, 0x7F, 0x7F, 0x82, 0x81, 0x80, 0x81, 0x82, 0x82, 0x7F, 0x81, 
-0x81, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7D, 0x7D, 0x7E, 0x7E, 0x7D, 0x7E, 0x7F, 
-0x7E, 0x7E, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x81, 0x80, 0x80, 0x81, 0x81, 0x80, 0x82, 0x82, 0x81, 
-0x82, 0x82, 0x82, 0x82, 0x82, 0x81, 0x82, 0x81, 0x7F, 0x81, 0x81, 0x7E, 0x7F, 0x7F, 0x7E, 0x7D, 
-0x7D, 0x7D, 0x7D, 0x7C, 0x7C, 0x7F, 0x7E, 0x7D, 0x7F, 0x80, 0x7F, 0x7F, 0x81, 0x81, 0x80, 0x81, 
-0x82, 0x82, 0x80, 0x81, 0x81, 0x80, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7D, 0x7D, 0x7E, 
-0x7E, 0x7D, 0x7E, 0x7F, 0x7E, 0x7E, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x81, 0x80, 0x80, 0x82, 0x81, 
-0x81, 0x82, 0x82, 0x81, 0x82, 0x82, 0x82, 0x82, 0x81, 0x82, 0x82, 0x80, 0x80, 0x81, 0x7F, 0x7E, 
-0x7F, 0x7E, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7C, 0x7E, 0x7E, 0x7D, 0x7E, 0x7F, 0x7F, 0x7F, 0x80, 
-0x81, 0x81, 0x80, 0x81, 0x82, 0x81, 0x81, 0x81, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 
-0x7D, 0x7D, 0x7E, 0x7D, 0x7E, 0x7E, 0x7E, 0x7F, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x81, 0x81, 0x81, 0x81, 0x82, 0x82, 0x81, 0x82, 0x83, 0x82, 0x82, 0x82, 0x82, 0x82, 0x81, 0x80, 
-0x82, 0x80, 0x7E, 0x7F, 0x7F, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7C, 0x7D, 0x7E, 0x7D, 0x7E, 0x7F, 
-0x7F, 0x7F, 0x80, 0x81, 0x81, 0x80, 0x81, 0x82, 0x81, 0x80, 0x81, 0x81, 0x80, 0x7F, 0x7F, 0x7F, 
-0x7E, 0x7E, 0x7F, 0x7E, 0x7D, 0x7E, 0x7E, 0x7D, 0x7E, 0x7E, 0x7F, 0x7E, 0x7E, 0x80, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x81, 0x82, 0x81, 0x81, 0x82, 0x82, 0x81, 0x82, 0x83, 0x82, 0x82, 0x82, 
-0x82, 0x82, 0x80, 0x80, 0x82, 0x7E, 0x7E, 0x7F, 0x7E, 0x7D, 0x7D, 0x7D, 0x7D, 0x7C, 0x7C, 0x7E, 
-0x7E, 0x7D, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x81, 0x81, 0x80, 0x82, 0x82, 0x81, 0x81, 0x81, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x7E, 0x7E, 0x7E, 0x7D, 0x7D, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7E, 
-0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x80, 0x82, 0x82, 0x81, 0x82, 0x83, 
-0x82, 0x82, 0x82, 0x82, 0x83, 0x80, 0x80, 0x82, 0x7F, 0x7D, 0x7F, 0x7E, 0x7D, 0x7D, 0x7C, 0x7D, 
-0x7D, 0x7B, 0x7D, 0x7F, 0x7C, 0x7E, 0x80, 0x7F, 0x7F, 0x80, 0x81, 0x81, 0x80, 0x81, 0x82, 0x81, 
-0x80, 0x81, 0x81, 0x7F, 0x7F, 0x80, 0x7F, 0x7E, 0x7E, 0x7F, 0x7E, 0x7D, 0x7E, 0x7E, 0x7D, 0x7D, 
-0x7E, 0x7E, 0x7E, 0x7E, 0x80, 0x7F, 0x7F, 0x80, 0x81, 0x80, 0x80, 0x81, 0x82, 0x81, 0x81, 0x82, 
-0x82, 0x81, 0x82, 0x82, 0x82, 0x82, 0x82, 0x82, 0x81, 0x7F, 0x81, 0x81, 0x7D, 0x7F, 0x7F, 0x7D, 
-0x7D, 0x7D, 0x7D, 0x7D, 0x7C, 0x7C, 0x7F, 0x7D, 0x7C, 0x7F, 0x80, 0x7F, 0x80, 0x81, 0x81, 0x81, 
-0x80, 0x82, 0x82, 0x80, 0x80, 0x81, 0x80, 0x7F, 0x80, 0x80, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7D, 
-0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7E, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 
-0x81, 0x80, 0x81, 0x82, 0x81, 0x81, 0x82, 0x82, 0x81, 0x81, 0x81, 0x82, 0x80, 0x80, 0x81, 0x80, 
-0x7E, 0x7F, 0x7F, 0x7D, 0x7D, 0x7E, 0x7E, 0x7D, 0x7D, 0x7E, 0x7F, 0x7E, 0x7E, 0x7F, 0x80, 0x7F, 
-0x7F, 0x81, 0x81, 0x80, 0x80, 0x81, 0x81, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7E, 
-0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x81, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 
-0x80, 0x80, 0x81, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7D, 0x7E, 0x7F, 0x7E, 
-0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x82, 0x81, 
-0x81, 0x81, 0x82, 0x81, 0x80, 0x80, 0x81, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7E, 0x7E, 0x7F, 0x7E, 
-0x7D, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 
-0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x81, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x81, 0x80, 0x7F, 0x7F, 
-0x80, 0x7F, 0x7E, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7F, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x81, 0x80, 
-0x7F, 0x80, 0x80, 0x7F, 0x7E, 0x7F, 0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 0x7E, 
-0x7E, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x81, 0x81, 0x80, 0x81, 0x82, 0x81, 0x80, 0x81, 0x82, 0x81, 0x80, 0x80, 0x81, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7E, 0x7F, 0x7F, 0x7E, 0x7D, 0x7F, 0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 
-0x7E, 0x7E, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7F, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x81, 0x80, 0x81, 0x81, 0x82, 0x81, 0x81, 0x82, 0x82, 0x81, 0x81, 0x82, 0x81, 0x80, 0x80, 0x81, 
-0x80, 0x7E, 0x7F, 0x7F, 0x7E, 0x7D, 0x7E, 0x7F, 0x7D, 0x7D, 0x7E, 0x7F, 0x7E, 0x7E, 0x7F, 0x80, 
-0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x81, 0x81, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7E, 0x7E, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x81, 0x81, 
-0x81, 0x81, 0x82, 0x82, 0x82, 0x83, 0x82, 0x82, 0x82, 0x82, 0x82, 0x82, 0x80, 0x80, 0x82, 0x7F, 
-0x7D, 0x80, 0x7F, 0x7C, 0x7D, 0x7F, 0x7D, 0x7D, 0x7D, 0x7E, 0x7F, 0x7E, 0x7E, 0x80, 0x81, 0x7E, 
-0x7F, 0x82, 0x81, 0x7F, 0x81, 0x82, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 
-0x7D, 0x7E, 0x7D, 0x7D, 0x7D, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x80, 0x80, 0x81, 0x81, 0x81, 0x82, 
-0x82, 0x82, 0x82, 0x83, 0x82, 0x83, 0x82, 0x81, 0x83, 0x82, 0x7F, 0x7F, 0x83, 0x7F, 0x7B, 0x7F, 
-0x80, 0x7C, 0x7A, 0x7F, 0x7E, 0x7C, 0x7C, 0x7E, 0x80, 0x7E, 0x7D, 0x80, 0x82, 0x7F, 0x7E, 0x82, 
-0x82, 0x7F, 0x80, 0x82, 0x81, 0x7F, 0x80, 0x80, 0x80, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7C, 0x7D, 
-0x7E, 0x7D, 0x7D, 0x7E, 0x7F, 0x7E, 0x7E, 0x80, 0x81, 0x80, 0x81, 0x82, 0x81, 0x82, 0x82, 0x82, 
-0x83, 0x82, 0x82, 0x83, 0x82, 0x80, 0x83, 0x84, 0x7E, 0x7E, 0x83, 0x80, 0x79, 0x7D, 0x81, 0x7B, 
-0x79, 0x7E, 0x7E, 0x7C, 0x7C, 0x7E, 0x80, 0x80, 0x7F, 0x7F, 0x83, 0x82, 0x7E, 0x81, 0x84, 0x81, 
-0x7E, 0x81, 0x82, 0x7F, 0x7E, 0x7E, 0x7F, 0x7E, 0x7D, 0x7C, 0x7D, 0x7F, 0x7D, 0x7B, 0x7E, 0x80, 
-0x7D, 0x7C, 0x81, 0x81, 0x7E, 0x7F, 0x82, 0x81, 0x7F, 0x80, 0x82, 0x82, 0x81, 0x81, 0x83, 0x83, 
-0x82, 0x83, 0x84, 0x82, 0x83, 0x83, 0x7E, 0x80, 0x83, 0x7D, 0x78, 0x7F, 0x81, 0x77, 0x78, 0x80, 
-0x7E, 0x7A, 0x7E, 0x82, 0x80, 0x80, 0x81, 0x81, 0x83, 0x83, 0x80, 0x80, 0x83, 0x81, 0x7D, 0x7E, 
-0x81, 0x7E, 0x7B, 0x7D, 0x7F, 0x7D, 0x7D, 0x7D, 0x7E, 0x7D, 0x7F, 0x7E, 0x7E, 0x80, 0x80, 0x7F, 
-0x7E, 0x81, 0x80, 0x7E, 0x80, 0x81, 0x80, 0x7F, 0x82, 0x81, 0x82, 0x84, 0x82, 0x84, 0x85, 0x85, 
-0x82, 0x84, 0x84, 0x7D, 0x80, 0x82, 0x7C, 0x77, 0x7C, 0x80, 0x77, 0x79, 0x7E, 0x7F, 0x7D, 0x7E, 
-0x82, 0x80, 0x84, 0x84, 0x81, 0x82, 0x84, 0x83, 0x7D, 0x80, 0x81, 0x7D, 0x7C, 0x7C, 0x7E, 0x7B, 
-0x7C, 0x7E, 0x7D, 0x7F, 0x80, 0x81, 0x7E, 0x80, 0x82, 0x7F, 0x7E, 0x7E, 0x80, 0x7E, 0x7E, 0x7E, 
-0x7E, 0x7F, 0x7F, 0x80, 0x80, 0x82, 0x82, 0x83, 0x85, 0x84, 0x85, 0x86, 0x86, 0x83, 0x84, 0x83, 
-0x7A, 0x7E, 0x80, 0x7A, 0x75, 0x79, 0x7F, 0x78, 0x7B, 0x7E, 0x7F, 0x81, 0x83, 0x85, 0x80, 0x86, 
-0x87, 0x81, 0x7F, 0x81, 0x82, 0x7C, 0x7C, 0x7C, 0x7A, 0x7C, 0x7C, 0x7D, 0x7A, 0x7F, 0x82, 0x80, 
-0x81, 0x82, 0x84, 0x81, 0x7F, 0x7E, 0x7D, 0x7F, 0x7C, 0x7A, 0x7B, 0x7E, 0x7F, 0x7C, 0x7F, 0x81, 
-0x83, 0x84, 0x83, 0x84, 0x86, 0x87, 0x84, 0x84, 0x86, 0x84, 0x83, 0x82, 0x7A, 0x78, 0x7F, 0x7D, 
-0x75, 0x74, 0x7E, 0x7E, 0x7D, 0x80, 0x80, 0x82, 0x86, 0x88, 0x81, 0x80, 0x86, 0x82, 0x7F, 0x7C, 
-0x7C, 0x7B, 0x7C, 0x7D, 0x78, 0x7A, 0x7E, 0x81, 0x7F, 0x7F, 0x83, 0x83, 0x85, 0x84, 0x81, 0x7D, 
-0x7C, 0x7E, 0x7B, 0x79, 0x78, 0x7B, 0x7D, 0x7E, 0x80, 0x7E, 0x82, 0x85, 0x85, 0x85, 0x83, 0x84, 
-0x85, 0x86, 0x83, 0x82, 0x83, 0x83, 0x82, 0x82, 0x7B, 0x76, 0x7C, 0x7F, 0x7A, 0x77, 0x7C, 0x7F, 
-0x80, 0x85, 0x81, 0x7F, 0x84, 0x87, 0x83, 0x7F, 0x80, 0x7E, 0x7E, 0x7F, 0x7C, 0x79, 0x7A, 0x7F, 
-0x7D, 0x7D, 0x7F, 0x80, 0x81, 0x83, 0x84, 0x81, 0x82, 0x83, 0x7D, 0x7B, 0x7C, 0x7B, 0x78, 0x7B, 
-0x7D, 0x7B, 0x7F, 0x82, 0x81, 0x81, 0x84, 0x84, 0x83, 0x86, 0x83, 0x81, 0x84, 0x84, 0x83, 0x82, 
-0x84, 0x82, 0x82, 0x81, 0x79, 0x79, 0x7D, 0x7E, 0x79, 0x7A, 0x7D, 0x7C, 0x82, 0x84, 0x81, 0x80, 
-0x84, 0x85, 0x81, 0x82, 0x7F, 0x7C, 0x7E, 0x7F, 0x7C, 0x7A, 0x7D, 0x7C, 0x7E, 0x81, 0x80, 0x7F, 
-0x82, 0x84, 0x81, 0x83, 0x83, 0x7D, 0x7A, 0x7C, 0x7C, 0x79, 0x7B, 0x7B, 0x7B, 0x80, 0x83, 0x81, 
-0x80, 0x83, 0x83, 0x84, 0x85, 0x82, 0x81, 0x84, 0x84, 0x83, 0x83, 0x83, 0x80, 0x83, 0x83, 0x7A, 
-0x78, 0x7C, 0x7C, 0x7A, 0x7D, 0x7C, 0x7B, 0x81, 0x85, 0x81, 0x81, 0x83, 0x81, 0x81, 0x84, 0x7F, 
-0x7B, 0x7D, 0x7E, 0x7C, 0x7D, 0x7E, 0x7A, 0x7D, 0x81, 0x80, 0x81, 0x82, 0x80, 0x80, 0x84, 0x82, 
-0x7C, 0x7B, 0x7C, 0x7B, 0x7C, 0x7E, 0x7B, 0x7C, 0x81, 0x82, 0x80, 0x81, 0x82, 0x82, 0x84, 0x84, 
-0x81, 0x82, 0x83, 0x82, 0x83, 0x84, 0x82, 0x81, 0x84, 0x82, 0x7C, 0x7D, 0x7E, 0x7C, 0x7C, 0x7E, 
-0x7C, 0x7B, 0x7F, 0x80, 0x80, 0x81, 0x81, 0x7F, 0x80, 0x82, 0x80, 0x7F, 0x80, 0x7F, 0x7E, 0x80, 
-0x80, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x80, 0x7F, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x81, 0x81, 0x81, 0x80, 0x81, 0x82, 0x81, 0x82, 
-0x82, 0x82, 0x83, 0x84, 0x81, 0x7E, 0x7F, 0x80, 0x7F, 0x7E, 0x7F, 0x7E, 0x7D, 0x7F, 0x7E, 0x7E, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7F, 0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x82, 0x82, 0x82, 0x82, 0x82, 0x80, 0x7E, 
-0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7E, 0x7D, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x81, 
-0x81, 0x81, 0x81, 0x82, 0x82, 0x82, 0x83, 0x82, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7E, 0x7D, 
-0x7E, 0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x82, 0x82, 0x83, 0x83, 
-0x82, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7E, 0x7D, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7E, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x80, 0x81, 0x81, 0x81, 0x82, 0x82, 0x83, 0x83, 0x83, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7E, 
-0x7C, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7E, 0x7F, 0x81, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x81, 0x7F, 0x80, 0x80, 0x81, 0x80, 0x82, 0x82, 0x83, 0x84, 0x84, 
-0x82, 0x7E, 0x81, 0x7F, 0x7F, 0x7E, 0x7E, 0x7C, 0x7D, 0x7E, 0x7E, 0x7F, 0x7E, 0x7F, 0x7E, 0x81, 
-0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x81, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7D, 0x7E, 
-0x7E, 0x7F, 0x7F, 0x7F, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x81, 0x80, 0x80, 0x81, 
-0x82, 0x81, 0x83, 0x83, 0x83, 0x84, 0x84, 0x81, 0x7E, 0x80, 0x7F, 0x7F, 0x7D, 0x7D, 0x7A, 0x7D, 
-0x7F, 0x7E, 0x7F, 0x7E, 0x7F, 0x7F, 0x83, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x7D, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x7D, 0x7C, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x80, 0x80, 0x7F, 0x7E, 
-0x7F, 0x7F, 0x81, 0x81, 0x80, 0x81, 0x82, 0x82, 0x82, 0x84, 0x83, 0x84, 0x85, 0x82, 0x7C, 0x7F, 
-0x7E, 0x7E, 0x7C, 0x7C, 0x79, 0x7B, 0x81, 0x7F, 0x7F, 0x7E, 0x81, 0x80, 0x85, 0x81, 0x7F, 0x80, 
-0x81, 0x81, 0x7F, 0x80, 0x7B, 0x7D, 0x7F, 0x7F, 0x7D, 0x7F, 0x7D, 0x7C, 0x7F, 0x7F, 0x7E, 0x7E, 
-0x80, 0x7E, 0x81, 0x80, 0x7E, 0x7E, 0x7F, 0x80, 0x80, 0x83, 0x80, 0x83, 0x84, 0x84, 0x85, 0x85, 
-0x86, 0x84, 0x86, 0x7B, 0x7B, 0x7D, 0x7C, 0x7A, 0x79, 0x7B, 0x77, 0x83, 0x81, 0x80, 0x80, 0x84, 
-0x83, 0x84, 0x85, 0x7D, 0x7F, 0x7F, 0x80, 0x7C, 0x7E, 0x7A, 0x7B, 0x80, 0x7F, 0x7E, 0x7F, 0x82, 
-0x7E, 0x80, 0x7F, 0x7D, 0x7E, 0x7F, 0x7E, 0x7D, 0x80, 0x7C, 0x7E, 0x7F, 0x80, 0x80, 0x85, 0x83, 
-0x85, 0x85, 0x87, 0x87, 0x87, 0x87, 0x85, 0x81, 0x73, 0x7B, 0x79, 0x79, 0x75, 0x7A, 0x77, 0x80, 
-0x89, 0x81, 0x84, 0x85, 0x89, 0x84, 0x87, 0x7B, 0x7A, 0x7D, 0x7C, 0x78, 0x78, 0x7A, 0x79, 0x83, 
-0x81, 0x80, 0x81, 0x88, 0x85, 0x81, 0x7D, 0x7B, 0x7D, 0x7C, 0x7A, 0x77, 0x7D, 0x7F, 0x81, 0x7E, 
-0x7F, 0x82, 0x87, 0x87, 0x83, 0x86, 0x87, 0x8C, 0x86, 0x83, 0x81, 0x85, 0x7B, 0x71, 0x78, 0x76, 
-0x78, 0x79, 0x7D, 0x7A, 0x87, 0x8A, 0x84, 0x87, 0x86, 0x85, 0x82, 0x83, 0x75, 0x77, 0x7B, 0x79, 
-0x78, 0x7A, 0x7C, 0x7F, 0x88, 0x83, 0x81, 0x85, 0x88, 0x80, 0x7B, 0x79, 0x78, 0x7C, 0x7A, 0x78, 
-0x79, 0x82, 0x82, 0x81, 0x80, 0x81, 0x85, 0x88, 0x85, 0x82, 0x87, 0x88, 0x8A, 0x82, 0x83, 0x81, 
-0x85, 0x74, 0x72, 0x79, 0x78, 0x7B, 0x7A, 0x7E, 0x7C, 0x8E, 0x88, 0x83, 0x84, 0x86, 0x84, 0x81, 
-0x7D, 0x72, 0x7B, 0x7C, 0x7A, 0x77, 0x7D, 0x7E, 0x85, 0x88, 0x80, 0x82, 0x87, 0x86, 0x79, 0x78, 
-0x78, 0x7B, 0x7D, 0x79, 0x78, 0x7E, 0x87, 0x80, 0x80, 0x7F, 0x84, 0x87, 0x87, 0x81, 0x84, 0x89, 
-0x87, 0x87, 0x83, 0x81, 0x84, 0x82, 0x6D, 0x75, 0x7B, 0x79, 0x78, 0x7C, 0x7C, 0x82, 0x91, 0x84, 
-0x81, 0x85, 0x88, 0x82, 0x80, 0x78, 0x72, 0x7E, 0x7D, 0x76, 0x77, 0x7F, 0x80, 0x86, 0x85, 0x7E, 
-0x83, 0x8A, 0x80, 0x75, 0x7A, 0x7A, 0x7C, 0x7C, 0x78, 0x79, 0x84, 0x85, 0x7D, 0x7E, 0x82, 0x85, 
-0x87, 0x86, 0x80, 0x86, 0x8B, 0x87, 0x85, 0x83, 0x83, 0x84, 0x81, 0x6E, 0x73, 0x7E, 0x7A, 0x77, 
-0x79, 0x7E, 0x82, 0x8E, 0x85, 0x7E, 0x85, 0x89, 0x83, 0x7C, 0x7A, 0x75, 0x7C, 0x7F, 0x77, 0x76, 
-0x7E, 0x84, 0x83, 0x84, 0x80, 0x81, 0x85, 0x80, 0x79, 0x78, 0x7D, 0x7C, 0x7C, 0x7B, 0x7B, 0x80, 
-0x82, 0x7F, 0x7D, 0x83, 0x85, 0x85, 0x84, 0x83, 0x85, 0x88, 0x87, 0x85, 0x83, 0x82, 0x83, 0x81, 
-0x73, 0x73, 0x7D, 0x7B, 0x79, 0x7A, 0x7F, 0x80, 0x89, 0x88, 0x80, 0x82, 0x86, 0x85, 0x7D, 0x7C, 
-0x79, 0x79, 0x7E, 0x7C, 0x79, 0x7A, 0x81, 0x82, 0x82, 0x82, 0x7F, 0x7E, 0x82, 0x80, 0x7A, 0x7B, 
-0x7E, 0x7D, 0x7D, 0x7E, 0x7C, 0x7E, 0x82, 0x82, 0x81, 0x83, 0x84, 0x85, 0x85, 0x86, 0x83, 0x85, 
-0x87, 0x85, 0x82, 0x80, 0x7D, 0x74, 0x79, 0x7C, 0x79, 0x77, 0x7C, 0x80, 0x7F, 0x87, 0x86, 0x81, 
-0x82, 0x86, 0x85, 0x7E, 0x7E, 0x7B, 0x7A, 0x7C, 0x7D, 0x7B, 0x78, 0x7E, 0x81, 0x81, 0x7F, 0x7E, 
-0x80, 0x80, 0x82, 0x7F, 0x7D, 0x7D, 0x7E, 0x7F, 0x7E, 0x7F, 0x7E, 0x80, 0x81, 0x82, 0x82, 0x84, 
-0x85, 0x85, 0x84, 0x86, 0x87, 0x85, 0x84, 0x82, 0x7F, 0x77, 0x78, 0x7A, 0x7A, 0x78, 0x7B, 0x7D, 
-0x7C, 0x81, 0x86, 0x84, 0x81, 0x84, 0x85, 0x81, 0x82, 0x81, 0x7C, 0x7A, 0x7C, 0x7D, 0x7A, 0x7C, 
-0x7D, 0x7C, 0x7C, 0x7E, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x7F, 0x7E, 0x7F, 0x80, 
-0x80, 0x80, 0x81, 0x80, 0x82, 0x83, 0x83, 0x82, 0x83, 0x83, 0x83, 0x83, 0x82, 0x7F, 0x7D, 0x7E, 
-0x7E, 0x7D, 0x7D, 0x7D, 0x7D, 0x7D, 0x7E, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 
-0x7E, 0x7F, 0x7F, 0x7E, 0x7F, 0x7F, 0x7F, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x82, 
-0x82, 0x82, 0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 
-0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 
-0x81, 0x81, 0x81, 0x81, 0x82, 0x81, 0x81, 0x81, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x80, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x81, 0x81, 0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 
-0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 
-0x81, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x81, 0x80, 0x80, 0x80, 0x80, 0x81, 
-0x83, 0x83, 0x83, 0x81, 0x7F, 0x7E, 0x7D, 0x7D, 0x7E, 0x7F, 0x7F, 0x7E, 0x7E, 0x7D, 0x7D, 0x7F, 
-0x80, 0x81, 0x81, 0x81, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7D, 0x7C, 0x7C, 0x7D, 0x7E, 
-0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x85, 0x86, 0x85, 
-0x81, 0x7D, 0x7A, 0x78, 0x79, 0x7C, 0x7E, 0x7E, 0x7F, 0x7E, 0x7E, 0x7F, 0x82, 0x84, 0x84, 0x84, 
-0x82, 0x7E, 0x7D, 0x7D, 0x7D, 0x7E, 0x7F, 0x7E, 0x7B, 0x79, 0x7A, 0x7B, 0x7E, 0x82, 0x83, 0x82, 
-0x82, 0x81, 0x81, 0x82, 0x84, 0x84, 0x84, 0x84, 0x85, 0x85, 0x85, 0x84, 0x80, 0x7B, 0x79, 0x77, 
-0x78, 0x7A, 0x7D, 0x7E, 0x7E, 0x7F, 0x80, 0x81, 0x83, 0x86, 0x85, 0x83, 0x81, 0x7E, 0x7B, 0x7B, 
-0x7D, 0x7D, 0x7D, 0x7C, 0x79, 0x76, 0x79, 0x7E, 0x81, 0x84, 0x85, 0x84, 0x81, 0x81, 0x82, 0x83, 
-0x83, 0x86, 0x87, 0x85, 0x86, 0x87, 0x82, 0x7C, 0x7A, 0x78, 0x77, 0x78, 0x7C, 0x7E, 0x7D, 0x7E, 
-0x81, 0x81, 0x82, 0x86, 0x87, 0x84, 0x81, 0x7F, 0x7D, 0x7B, 0x7C, 0x7D, 0x7C, 0x7B, 0x79, 0x77, 
-0x77, 0x7C, 0x80, 0x83, 0x84, 0x84, 0x82, 0x81, 0x82, 0x84, 0x84, 0x85, 0x86, 0x86, 0x86, 0x87, 
-0x85, 0x7F, 0x79, 0x77, 0x77, 0x78, 0x7B, 0x7E, 0x7E, 0x7D, 0x7E, 0x80, 0x83, 0x85, 0x86, 0x85, 
-0x82, 0x7E, 0x7C, 0x7D, 0x7E, 0x7E, 0x7D, 0x7C, 0x7A, 0x77, 0x77, 0x7C, 0x81, 0x83, 0x83, 0x83, 
-0x81, 0x81, 0x81, 0x83, 0x83, 0x82, 0x82, 0x83, 0x84, 0x85, 0x85, 0x82, 0x7E, 0x7B, 0x7B, 0x7C, 
-0x7E, 0x7F, 0x7F, 0x7E, 0x7D, 0x7D, 0x7F, 0x81, 0x81, 0x81, 0x7F, 0x7E, 0x7E, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x7F, 0x7E, 0x7D, 0x7D, 0x7E, 0x80, 0x80, 0x7F, 0x7E, 0x7E, 0x7F, 0x80, 0x7F, 0x80, 
-0x80, 0x81, 0x82, 0x82, 0x82, 0x84, 0x84, 0x83, 0x80, 0x7F, 0x7F, 0x81, 0x81, 0x7F, 0x7E, 0x7E, 
-0x7E, 0x7D, 0x7D, 0x7D, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x80, 0x80, 0x80, 0x7F, 0x7E, 0x7F, 0x7E, 
-0x7E, 0x7E, 0x7F, 0x80, 0x7F, 0x7E, 0x7E, 0x7F, 0x80, 0x80, 0x80, 0x81, 0x82, 0x83, 0x84, 0x84, 
-0x83, 0x81, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7E, 0x7E, 0x7E, 0x7D, 0x7D, 0x7E, 0x7F, 0x7F, 0x7E, 
-0x7E, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x81, 0x82, 0x82, 0x83, 0x84, 0x85, 0x83, 0x80, 0x7F, 0x7F, 0x80, 
-0x80, 0x7F, 0x7D, 0x7C, 0x7D, 0x7C, 0x7D, 0x7F, 0x80, 0x80, 0x7F, 0x7E, 0x7F, 0x81, 0x81, 0x80, 
-0x80, 0x7F, 0x7E, 0x7F, 0x7F, 0x7D, 0x7C, 0x7D, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x81, 
-0x81, 0x82, 0x84, 0x86, 0x86, 0x86, 0x83, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7D, 0x79, 0x79, 0x7B, 
-0x7D, 0x80, 0x81, 0x80, 0x7F, 0x80, 0x81, 0x83, 0x84, 0x81, 0x7F, 0x7E, 0x7E, 0x7E, 0x80, 0x7C, 
-0x76, 0x76, 0x7B, 0x7D, 0x80, 0x84, 0x82, 0x80, 0x83, 0x84, 0x83, 0x85, 0x87, 0x86, 0x88, 0x89, 
-0x84, 0x7C, 0x78, 0x7B, 0x7B, 0x79, 0x7C, 0x7A, 0x76, 0x7D, 0x84, 0x82, 0x85, 0x87, 0x81, 0x81, 
-0x85, 0x82, 0x80, 0x7F, 0x79, 0x79, 0x7B, 0x7C, 0x7C, 0x79, 0x74, 0x79, 0x7F, 0x82, 0x85, 0x83, 
-0x80, 0x84, 0x86, 0x85, 0x87, 0x87, 0x87, 0x8A, 0x8A, 0x81, 0x78, 0x74, 0x76, 0x78, 0x7A, 0x7B, 
-0x78, 0x79, 0x82, 0x87, 0x88, 0x8A, 0x85, 0x80, 0x82, 0x83, 0x7E, 0x7C, 0x78, 0x75, 0x79, 0x7D, 
-0x7D, 0x7C, 0x78, 0x77, 0x7F, 0x83, 0x82, 0x83, 0x80, 0x81, 0x87, 0x88, 0x86, 0x87, 0x88, 0x8A, 
-0x8D, 0x82, 0x76, 0x72, 0x76, 0x77, 0x79, 0x7B, 0x77, 0x79, 0x85, 0x8A, 0x89, 0x8B, 0x84, 0x7F, 
-0x83, 0x83, 0x7D, 0x79, 0x75, 0x74, 0x7A, 0x7F, 0x7E, 0x76, 0x78, 0x7F, 0x81, 0x84, 0x83, 0x7D, 
-0x81, 0x88, 0x87, 0x89, 0x87, 0x87, 0x8B, 0x8F, 0x85, 0x76, 0x70, 0x75, 0x78, 0x77, 0x79, 0x74, 
-0x79, 0x86, 0x8C, 0x8A, 0x8A, 0x84, 0x81, 0x85, 0x84, 0x7B, 0x75, 0x74, 0x75, 0x7B, 0x7F, 0x7A, 
-0x75, 0x7C, 0x81, 0x83, 0x83, 0x7E, 0x7B, 0x82, 0x88, 0x87, 0x88, 0x86, 0x89, 0x8E, 0x91, 0x85, 
-0x74, 0x6E, 0x78, 0x7B, 0x76, 0x76, 0x72, 0x7A, 0x8A, 0x8F, 0x88, 0x86, 0x84, 0x85, 0x87, 0x83, 
-0x76, 0x70, 0x76, 0x7A, 0x7D, 0x7C, 0x78, 0x77, 0x81, 0x85, 0x81, 0x7E, 0x7B, 0x7E, 0x84, 0x87, 
-0x83, 0x85, 0x86, 0x8B, 0x91, 0x91, 0x83, 0x76, 0x71, 0x78, 0x7E, 0x72, 0x71, 0x72, 0x7E, 0x8A, 
-0x8E, 0x86, 0x83, 0x88, 0x8A, 0x87, 0x7F, 0x74, 0x71, 0x7A, 0x7D, 0x7A, 0x76, 0x79, 0x7E, 0x85, 
-0x82, 0x7B, 0x7E, 0x7E, 0x82, 0x80, 0x80, 0x80, 0x87, 0x89, 0x89, 0x8C, 0x8F, 0x8C, 0x7E, 0x76, 
-0x70, 0x7C, 0x75, 0x72, 0x73, 0x79, 0x85, 0x8B, 0x8B, 0x83, 0x88, 0x8A, 0x87, 0x7F, 0x78, 0x73, 
-0x79, 0x7B, 0x7A, 0x75, 0x7A, 0x7F, 0x83, 0x83, 0x7B, 0x7D, 0x7F, 0x82, 0x7E, 0x7E, 0x81, 0x84, 
-0x8B, 0x86, 0x8A, 0x8C, 0x92, 0x86, 0x7C, 0x6F, 0x75, 0x7F, 0x71, 0x71, 0x72, 0x81, 0x88, 0x8E, 
-0x84, 0x82, 0x8C, 0x8C, 0x84, 0x7A, 0x75, 0x76, 0x7C, 0x7B, 0x74, 0x75, 0x7E, 0x83, 0x85, 0x7D, 
-0x7B, 0x7F, 0x83, 0x80, 0x7A, 0x7E, 0x82, 0x89, 0x87, 0x83, 0x89, 0x91, 0x93, 0x81, 0x7B, 0x6C, 
-0x7D, 0x7F, 0x6F, 0x6E, 0x74, 0x87, 0x87, 0x8C, 0x7F, 0x86, 0x8F, 0x8D, 0x7F, 0x76, 0x78, 0x7A, 
-0x7E, 0x77, 0x70, 0x77, 0x84, 0x84, 0x7F, 0x7A, 0x7D, 0x83, 0x84, 0x7B, 0x78, 0x7F, 0x86, 0x86, 
-0x83, 0x80, 0x8B, 0x90, 0x92, 0x83, 0x7D, 0x72, 0x79, 0x82, 0x6D, 0x6F, 0x74, 0x86, 0x83, 0x88, 
-0x81, 0x84, 0x91, 0x8B, 0x81, 0x77, 0x7E, 0x7B, 0x7D, 0x75, 0x70, 0x78, 0x84, 0x82, 0x7C, 0x7D, 
-0x7F, 0x84, 0x84, 0x7A, 0x78, 0x80, 0x86, 0x82, 0x81, 0x81, 0x87, 0x8E, 0x8E, 0x8C, 0x82, 0x82, 
-0x72, 0x7D, 0x7A, 0x6E, 0x73, 0x79, 0x82, 0x80, 0x89, 0x83, 0x88, 0x8C, 0x88, 0x80, 0x7D, 0x80, 
-0x78, 0x79, 0x75, 0x75, 0x7A, 0x7F, 0x7D, 0x7C, 0x83, 0x81, 0x82, 0x80, 0x7C, 0x7C, 0x82, 0x81, 
-0x7E, 0x7F, 0x84, 0x84, 0x89, 0x88, 0x8D, 0x8B, 0x86, 0x79, 0x71, 0x82, 0x74, 0x75, 0x72, 0x7C, 
-0x7E, 0x86, 0x86, 0x7F, 0x89, 0x8B, 0x89, 0x7F, 0x7E, 0x7A, 0x7B, 0x7C, 0x76, 0x73, 0x7B, 0x7F, 
-0x80, 0x7F, 0x82, 0x80, 0x83, 0x83, 0x7C, 0x7C, 0x81, 0x80, 0x7E, 0x7F, 0x81, 0x81, 0x89, 0x89, 
-0x8B, 0x8E, 0x87, 0x80, 0x70, 0x81, 0x7A, 0x74, 0x72, 0x76, 0x7E, 0x80, 0x8A, 0x7D, 0x85, 0x8A, 
-0x8C, 0x83, 0x7E, 0x7E, 0x78, 0x7F, 0x79, 0x73, 0x75, 0x7E, 0x81, 0x7E, 0x7F, 0x80, 0x83, 0x83, 
-0x81, 0x7C, 0x7E, 0x80, 0x7E, 0x7B, 0x7E, 0x81, 0x83, 0x86, 0x87, 0x8B, 0x92, 0x8A, 0x83, 0x73, 
-0x7B, 0x81, 0x75, 0x72, 0x6F, 0x7C, 0x7F, 0x88, 0x7F, 0x7F, 0x89, 0x8E, 0x8A, 0x7E, 0x7E, 0x7B, 
-0x80, 0x7D, 0x75, 0x72, 0x7A, 0x82, 0x80, 0x7F, 0x81, 0x83, 0x84, 0x82, 0x7E, 0x7A, 0x7E, 0x7F, 
-0x7D, 0x7B, 0x7D, 0x80, 0x83, 0x85, 0x85, 0x89, 0x8D, 0x90, 0x87, 0x81, 0x74, 0x7D, 0x7D, 0x74, 
-0x70, 0x72, 0x7D, 0x7D, 0x86, 0x80, 0x82, 0x88, 0x8C, 0x89, 0x81, 0x81, 0x7C, 0x7C, 0x7B, 0x78, 
-0x75, 0x77, 0x7E, 0x7E, 0x81, 0x83, 0x84, 0x85, 0x86, 0x82, 0x79, 0x7C, 0x7B, 0x7A, 0x7A, 0x7D, 
-0x7C, 0x7D, 0x83, 0x84, 0x85, 0x87, 0x8A, 0x8A, 0x8F, 0x8D, 0x85, 0x76, 0x73, 0x7E, 0x77, 0x74, 
-0x70, 0x74, 0x78, 0x83, 0x8A, 0x82, 0x84, 0x88, 0x8E, 0x89, 0x84, 0x7E, 0x78, 0x7B, 0x7D, 0x7A, 
-0x74, 0x77, 0x7C, 0x7F, 0x84, 0x84, 0x82, 0x82, 0x86, 0x88, 0x7F, 0x7B, 0x77, 0x79, 0x7C, 0x7D, 
-0x7C, 0x7A, 0x7E, 0x83, 0x86, 0x85, 0x85, 0x85, 0x89, 0x8D, 0x8E, 0x88, 0x7E, 0x72, 0x79, 0x7C, 
-0x78, 0x73, 0x71, 0x77, 0x7A, 0x87, 0x86, 0x83, 0x84, 0x88, 0x8B, 0x87, 0x86, 0x7E, 0x79, 0x7B, 
-0x7C, 0x7B, 0x78, 0x7A, 0x79, 0x7C, 0x81, 0x84, 0x84, 0x82, 0x84, 0x84, 0x84, 0x7F, 0x7B, 0x7A, 
-0x78, 0x7C, 0x7D, 0x7D, 0x7B, 0x7E, 0x82, 0x83, 0x84, 0x84, 0x85, 0x86, 0x88, 0x8A, 0x88, 0x85, 
-0x7F, 0x78, 0x7B, 0x7A, 0x7A, 0x78, 0x79, 0x79, 0x79, 0x81, 0x83, 0x84, 0x83, 0x85, 0x83, 0x82, 
-0x84, 0x82, 0x80, 0x7D, 0x7C, 0x7B, 0x7B, 0x7E, 0x7D, 0x7B, 0x7C, 0x7F, 0x82, 0x81, 0x83, 0x81, 
-0x81, 0x81, 0x7F, 0x7E, 0x7C, 0x7D, 0x7C, 0x7D, 0x7E, 0x7F, 0x80, 0x80, 0x81, 0x82, 0x84, 0x84, 
-0x83, 0x84, 0x83, 0x84, 0x85, 0x84, 0x7F, 0x7B, 0x7D, 0x7C, 0x7D, 0x7C, 0x7E, 0x7C, 0x7C, 0x7F, 
-0x80, 0x81, 0x81, 0x81, 0x80, 0x7F, 0x81, 0x81, 0x80, 0x80, 0x7F, 0x7F, 0x7E, 0x7F, 0x7F, 0x80, 
-0x7F, 0x7F, 0x7E, 0x7D, 0x80, 0x80, 0x81, 0x80, 0x7F, 0x7E, 0x7E, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 
-0x7F, 0x7E, 0x7F, 0x7E, 0x7F, 0x7F, 0x80, 0x80, 0x81, 0x82, 0x82, 0x82, 0x82, 0x83, 0x83, 0x80, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7E, 0x7D, 0x7E, 0x7F, 0x7F, 0x7E, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x80, 0x81, 0x80, 0x7F, 0x7E, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 
-0x7F, 0x80, 0x7F, 0x7D, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x81, 0x81, 0x81, 0x83, 0x84, 0x84, 0x81, 0x80, 0x80, 0x7F, 0x7F, 
-0x7E, 0x7F, 0x7D, 0x7D, 0x7E, 0x7D, 0x7D, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x81, 0x81, 
-0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x80, 0x81, 0x81, 0x80, 
-0x81, 0x81, 0x81, 0x81, 0x81, 0x81, 0x82, 0x82, 0x83, 0x80, 0x7E, 0x7F, 0x7E, 0x7F, 0x7F, 0x80, 
-0x7E, 0x7D, 0x7D, 0x7D, 0x7E, 0x80, 0x81, 0x7F, 0x7E, 0x80, 0x80, 0x81, 0x81, 0x81, 0x80, 0x80, 
-0x80, 0x7E, 0x7E, 0x7F, 0x80, 0x7E, 0x7E, 0x7E, 0x7E, 0x80, 0x81, 0x82, 0x80, 0x80, 0x80, 0x7E, 
-0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x81, 0x81, 0x82, 0x81, 
-0x81, 0x82, 0x81, 0x82, 0x81, 0x82, 0x83, 0x84, 0x82, 0x7C, 0x7C, 0x7B, 0x7C, 0x7D, 0x7F, 0x7E, 
-0x7B, 0x7E, 0x7F, 0x81, 0x82, 0x84, 0x82, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7E, 0x7C, 0x7D, 
-0x7D, 0x7E, 0x7F, 0x81, 0x80, 0x7F, 0x81, 0x80, 0x82, 0x83, 0x82, 0x7F, 0x7E, 0x7E, 0x7D, 0x7E, 
-0x7E, 0x7C, 0x7B, 0x7C, 0x7D, 0x7E, 0x81, 0x82, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x7F, 0x7F, 
-0x80, 0x82, 0x83, 0x82, 0x81, 0x83, 0x82, 0x84, 0x84, 0x84, 0x80, 0x79, 0x7A, 0x77, 0x7B, 0x7D, 
-0x81, 0x7F, 0x7D, 0x81, 0x81, 0x85, 0x85, 0x85, 0x7F, 0x7D, 0x7D, 0x7C, 0x7D, 0x7D, 0x7E, 0x7C, 
-0x7D, 0x80, 0x81, 0x84, 0x84, 0x84, 0x80, 0x7F, 0x7F, 0x7E, 0x7F, 0x7E, 0x7D, 0x7B, 0x7D, 0x7F, 
-0x80, 0x82, 0x82, 0x81, 0x7E, 0x7E, 0x7E, 0x7D, 0x7F, 0x7E, 0x7E, 0x7E, 0x80, 0x81, 0x81, 0x82, 
-0x7F, 0x7F, 0x7F, 0x81, 0x82, 0x82, 0x83, 0x83, 0x81, 0x82, 0x83, 0x84, 0x82, 0x80, 0x7D, 0x77, 
-0x79, 0x7A, 0x7E, 0x80, 0x82, 0x81, 0x80, 0x83, 0x83, 0x83, 0x81, 0x7F, 0x7B, 0x79, 0x7C, 0x7D, 
-0x7F, 0x80, 0x81, 0x80, 0x81, 0x84, 0x82, 0x82, 0x80, 0x7D, 0x7B, 0x7C, 0x7E, 0x7E, 0x80, 0x80, 
-0x80, 0x81, 0x83, 0x83, 0x81, 0x80, 0x7E, 0x7C, 0x7D, 0x7D, 0x7C, 0x7B, 0x7D, 0x7E, 0x82, 0x84, 
-0x84, 0x81, 0x80, 0x7F, 0x7D, 0x7D, 0x7D, 0x7D, 0x7E, 0x81, 0x83, 0x85, 0x85, 0x85, 0x82, 0x83, 
-0x82, 0x7F, 0x7D, 0x7B, 0x78, 0x78, 0x7D, 0x7F, 0x82, 0x84, 0x84, 0x82, 0x82, 0x82, 0x7F, 0x7E, 
-0x7C, 0x7A, 0x79, 0x7D, 0x80, 0x81, 0x84, 0x83, 0x82, 0x82, 0x82, 0x80, 0x7E, 0x7D, 0x7B, 0x7B, 
-0x7E, 0x80, 0x82, 0x83, 0x83, 0x82, 0x82, 0x81, 0x7F, 0x7D, 0x7C, 0x7B, 0x7B, 0x7C, 0x7D, 0x7F, 
-0x82, 0x84, 0x84, 0x83, 0x80, 0x7D, 0x7C, 0x7C, 0x7C, 0x7C, 0x7E, 0x80, 0x84, 0x86, 0x83, 0x85, 
-0x84, 0x85, 0x83, 0x86, 0x79, 0x70, 0x75, 0x75, 0x7C, 0x86, 0x8C, 0x83, 0x85, 0x86, 0x82, 0x82, 
-0x81, 0x77, 0x72, 0x76, 0x7A, 0x7F, 0x88, 0x88, 0x83, 0x85, 0x84, 0x81, 0x80, 0x7E, 0x75, 0x76, 
-0x79, 0x7D, 0x83, 0x88, 0x86, 0x84, 0x84, 0x82, 0x7F, 0x7F, 0x7A, 0x76, 0x78, 0x7C, 0x80, 0x85, 
-0x87, 0x83, 0x82, 0x82, 0x80, 0x7E, 0x7D, 0x7A, 0x78, 0x7C, 0x7F, 0x82, 0x84, 0x83, 0x81, 0x81, 
-0x81, 0x80, 0x7E, 0x7D, 0x7B, 0x7C, 0x7E, 0x81, 0x82, 0x82, 0x81, 0x81, 0x82, 0x82, 0x82, 0x81, 
-0x7F, 0x7E, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7D, 0x7E, 0x80, 0x81, 0x81, 0x81, 0x7F, 0x7E, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7E, 0x7E, 0x80, 0x81, 0x81, 0x81, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x81, 0x81, 0x81, 0x80, 0x80, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x80, 0x81, 
-0x82, 0x80, 0x7F, 0x7E, 0x7E, 0x7E, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x81, 0x80, 0x82, 0x7F, 0x7E, 
-0x7D, 0x80, 0x7E, 0x82, 0x7F, 0x7E, 0x81, 0x84, 0x85, 0x89, 0x89, 0x74, 0x75, 0x75, 0x77, 0x7E, 
-0x8B, 0x82, 0x81, 0x84, 0x83, 0x83, 0x86, 0x7E, 0x73, 0x77, 0x77, 0x7E, 0x86, 0x89, 0x81, 0x84, 
-0x81, 0x81, 0x82, 0x80, 0x77, 0x76, 0x7A, 0x7C, 0x84, 0x88, 0x85, 0x82, 0x82, 0x7F, 0x7F, 0x7F, 
-0x7B, 0x78, 0x7B, 0x7D, 0x82, 0x87, 0x86, 0x81, 0x7F, 0x7E, 0x7D, 0x7E, 0x7E, 0x7B, 0x7C, 0x7E, 
-0x81, 0x84, 0x85, 0x82, 0x7F, 0x7E, 0x7D, 0x7E, 0x7F, 0x7E, 0x7D, 0x7F, 0x81, 0x83, 0x84, 0x83, 
-0x7F, 0x7E, 0x7D, 0x7E, 0x7F, 0x7F, 0x7E, 0x7E, 0x80, 0x81, 0x83, 0x82, 0x80, 0x7E, 0x7E, 0x7E, 
-0x7E, 0x7F, 0x7F, 0x7E, 0x7E, 0x80, 0x81, 0x82, 0x81, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7D, 
-0x7E, 0x80, 0x80, 0x82, 0x82, 0x81, 0x80, 0x81, 0x80, 0x80, 0x7F, 0x7D, 0x7C, 0x7D, 0x7F, 0x80, 
-0x83, 0x82, 0x81, 0x81, 0x80, 0x7F, 0x7F, 0x7E, 0x7C, 0x7D, 0x7F, 0x80, 0x82, 0x83, 0x81, 0x80, 
-0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x81, 0x82, 0x82, 0x81, 0x80, 0x7F, 0x7F, 0x7F, 0x7E, 
-0x7E, 0x7E, 0x7F, 0x80, 0x81, 0x82, 0x81, 0x80, 0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 
-0x81, 0x81, 0x81, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x81, 0x80, 0x80, 
-0x7F, 0x7E, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7E, 0x7D, 0x80, 0x81, 0x81, 0x82, 0x81, 0x7E, 0x7F, 
-0x7F, 0x7E, 0x7F, 0x7E, 0x7D, 0x7F, 0x81, 0x81, 0x82, 0x81, 0x7F, 0x80, 0x81, 0x82, 0x83, 0x80, 
-0x7A, 0x79, 0x7B, 0x7E, 0x82, 0x85, 0x82, 0x81, 0x81, 0x80, 0x81, 0x80, 0x7D, 0x7A, 0x7B, 0x7E, 
-0x81, 0x84, 0x84, 0x81, 0x80, 0x7F, 0x7F, 0x80, 0x7E, 0x7C, 0x7C, 0x7E, 0x81, 0x84, 0x84, 0x82, 
-0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 0x7E, 0x7D, 0x7E, 0x80, 0x83, 0x84, 0x82, 0x7F, 0x7D, 0x7D, 0x7E, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x82, 0x82, 0x81, 0x7F, 0x7E, 0x7D, 0x7E, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x81, 0x81, 0x7F, 0x7E, 0x7E, 0x7E, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 
-0x80, 0x7F, 0x7E, 0x7E, 0x7F, 0x80, 0x81, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x81, 0x81, 
-0x80, 0x7E, 0x7F, 0x7F, 0x80, 0x81, 0x80, 0x7F, 0x7E, 0x7E, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 
-0x81, 0x82, 0x83, 0x82, 0x7F, 0x7C, 0x7B, 0x7C, 0x7E, 0x80, 0x81, 0x82, 0x81, 0x82, 0x81, 0x81, 
-0x80, 0x7D, 0x7C, 0x7C, 0x7D, 0x80, 0x82, 0x82, 0x82, 0x81, 0x80, 0x80, 0x7F, 0x7E, 0x7D, 0x7D, 
-0x7E, 0x80, 0x82, 0x83, 0x82, 0x81, 0x80, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x80, 0x82, 0x82, 
-0x82, 0x80, 0x7F, 0x7E, 0x7E, 0x7E, 0x7F, 0x7F, 0x80, 0x81, 0x81, 0x81, 0x81, 0x80, 0x7E, 0x7E, 
-0x7E, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x81, 0x80, 0x7F, 0x7F, 0x7E, 0x7E, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x81, 
-0x81, 0x80, 0x80, 0x7F, 0x7E, 0x7E, 0x7E, 0x7E, 0x80, 0x81, 0x81, 0x81, 0x81, 0x80, 0x80, 0x7F, 
-0x7E, 0x7E, 0x7E, 0x7E, 0x7F, 0x80, 0x81, 0x81, 0x81, 0x80, 0x7F, 0x7F, 0x7E, 0x7E, 0x7E, 0x7F, 
-0x7F, 0x80, 0x81, 0x81, 0x81, 0x80, 0x7F, 0x7F, 0x7E, 0x7E, 0x7F, 0x7F, 0x80, 0x80, 0x81, 0x81, 
-0x80, 0x80, 0x7F, 0x7F, 0x7E, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7E, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 
-0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 
-0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 
-0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 
-0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x7F, 0x80, 0x80, 
-0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 
-0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 
-0x7F, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 
-0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x7F, 0x80, 
-0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 
-0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x80, 0x7F, 0x80, 0x7F, 0x80, 0x7F, 0x80, 
-0x80, 0x7F
-];
\ No newline at end of file
@@ -0,0 +1,42 @@
+use core::fmt;
+
+/// Why a ringtone could not be read, and where.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Error {
+    pub kind: ErrorKind,
+    /// Byte offset in the ringtone text of the character at fault.
+    pub position: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The text has no `:` after the name, or none after the defaults.
+    MissingSection,
+    /// A default other than `d`, `o` or `b`, or one without `=`.
+    UnknownDefault,
+    /// A duration that is not 1, 2, 4, 8, 16, 32 or 64.
+    BadDuration,
+    /// An octave outside 0 to 8.
+    BadOctave,
+    /// A tempo of 0, or too big to be a number of beats per minute.
+    BadTempo,
+    /// Not a note letter (`a` to `h`) or `p` for a pause.
+    BadNote,
+    /// Characters left over after a note.
+    TrailingCharacters,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = match self.kind {
+            ErrorKind::MissingSection => "missing ':' section separator",
+            ErrorKind::UnknownDefault => "unknown default",
+            ErrorKind::BadDuration => "invalid duration",
+            ErrorKind::BadOctave => "invalid octave",
+            ErrorKind::BadTempo => "invalid tempo",
+            ErrorKind::BadNote => "invalid note",
+            ErrorKind::TrailingCharacters => "unexpected characters after the note",
+        };
+        write!(f, "{what} at byte {}", self.position)
+    }
+}
@@ -0,0 +1,461 @@
+//! RTTTL (Nokia ringtone) parser for the buzzer projects of this collection.
+//!
+//! A ringtone is one line of text: a name, the defaults, then the notes.
+//!
+//! ```text
+//! NokiaTune:d=4,o=5,b=225:8e6,8d6,f#,g#,8c#6,8b,d,e,8b,8a,c#,e,2a
+//! ```
+//!
+//! `d` is the duration of notes that give none (4 for a quarter note), `o` the
+//! octave of notes that give none, `b` the tempo in quarter notes per minute.
+//! Each note is an optional duration, a letter (`p` for a pause), an optional
+//! `#`, an optional octave and an optional `.` that makes it half as long
+//! again. [`parse`] checks the whole text once, so the [`Tone`]s come out of
+//! [`Ringtone::tones`] without errors, whatever the text came from (flash, an
+//! SD card, the serial port or the network).
+//!
+//! ```
+//! let ringtone = rtttl::parse("Beep:d=8,o=6,b=120:c,p,2c.").unwrap();
+//! let tones: Vec<_> = ringtone.tones().collect();
+//! assert_eq!(tones[0], rtttl::Tone { frequency: 1047, duration: 250 });
+//! assert_eq!(tones[1], rtttl::Tone { frequency: 0, duration: 250 });
+//! assert_eq!(tones[2], rtttl::Tone { frequency: 1047, duration: 1500 });
+//! ```
+
+#![no_std]
+
+mod error;
+
+pub use error::{Error, ErrorKind};
+
+/// Defaults of the RTTTL specification, for a ringtone that leaves one out.
+pub const DEFAULT_DURATION: u8 = 4;
+pub const DEFAULT_OCTAVE: u8 = 6;
+pub const DEFAULT_BPM: u16 = 63;
+
+/// Frequencies of the 8th octave, C to B, in mHz. Lower octaves halve them;
+/// rounded to the Hz, they are the `NOTE_*` constants of `esp-hal-buzzer`.
+const OCTAVE_8: [u32; 12] = [
+    4_186_009, 4_434_922, 4_698_636, 4_978_032, 5_274_041, 5_587_652, 5_919_911, 6_271_927,
+    6_644_875, 7_040_000, 7_458_620, 7_902_133,
+];
+
+/// A note as the buzzer plays it, with the fields of `esp_hal_buzzer::ToneValue`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Tone {
+    /// In Hz, 0 for a pause.
+    pub frequency: u32,
+    /// In milliseconds.
+    pub duration: u32,
+}
+
+/// The `d`, `o` and `b` section.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Defaults {
+    /// 1 for a whole note, 4 for a quarter note...
+    pub duration: u8,
+    pub octave: u8,
+    /// Quarter notes per minute.
+    pub bpm: u16,
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        Self {
+            duration: DEFAULT_DURATION,
+            octave: DEFAULT_OCTAVE,
+            bpm: DEFAULT_BPM,
+        }
+    }
+}
+
+/// A checked ringtone, borrowing its text.
+#[derive(Clone, Copy, Debug)]
+pub struct Ringtone<'a> {
+    pub name: &'a str,
+    pub defaults: Defaults,
+    notes: &'a str,
+    /// Where `notes` starts in the text, for error positions.
+    notes_at: usize,
+}
+
+impl<'a> Ringtone<'a> {
+    /// The notes in order, pauses included.
+    pub fn tones(&self) -> Tones<'a> {
+        Tones {
+            notes: self.notes,
+            at: self.notes_at,
+            defaults: self.defaults,
+        }
+    }
+
+    /// Number of notes, pauses included.
+    pub fn len(&self) -> usize {
+        self.tones().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tones().next().is_none()
+    }
+
+    /// How long the whole ringtone plays, in milliseconds.
+    pub fn duration_ms(&self) -> u32 {
+        self.tones().map(|tone| tone.duration).sum()
+    }
+}
+
+/// Iterator over the notes of a [`Ringtone`].
+#[derive(Clone, Debug)]
+pub struct Tones<'a> {
+    notes: &'a str,
+    at: usize,
+    defaults: Defaults,
+}
+
+impl Iterator for Tones<'_> {
+    type Item = Tone;
+
+    fn next(&mut self) -> Option<Tone> {
+        loop {
+            let (note, at) = next_item(&mut self.notes, &mut self.at)?;
+            // Checked by `parse`: only empty notes are skipped here
+            if let Ok(Some(tone)) = parse_note(note, at, &self.defaults) {
+                return Some(tone);
+            }
+        }
+    }
+}
+
+/// Reads the name and the defaults and checks every note.
+pub fn parse(text: &str) -> Result<Ringtone<'_>, Error> {
+    let missing = |position| Error {
+        kind: ErrorKind::MissingSection,
+        position,
+    };
+    let name_end = text.find(':').ok_or(missing(text.len()))?;
+    let defaults_at = name_end + 1;
+    let defaults_end = text[defaults_at..]
+        .find(':')
+        .map(|end| defaults_at + end)
+        .ok_or(missing(text.len()))?;
+
+    let defaults = parse_defaults(&text[defaults_at..defaults_end], defaults_at)?;
+    let ringtone = Ringtone {
+        name: text[..name_end].trim(),
+        defaults,
+        notes: &text[defaults_end + 1..],
+        notes_at: defaults_end + 1,
+    };
+
+    let (mut notes, mut at) = (ringtone.notes, ringtone.notes_at);
+    while let Some((note, note_at)) = next_item(&mut notes, &mut at) {
+        parse_note(note, note_at, &defaults)?;
+    }
+    Ok(ringtone)
+}
+
+/// The next comma-separated item of `list` and its position, moving past it.
+fn next_item<'a>(list: &mut &'a str, at: &mut usize) -> Option<(&'a str, usize)> {
+    if list.is_empty() {
+        return None;
+    }
+    let end = list.find(',').unwrap_or(list.len());
+    let item = (&list[..end], *at);
+    let next = (end + 1).min(list.len());
+    *list = &list[next..];
+    *at += next;
+    Some(item)
+}
+
+fn parse_defaults(section: &str, at: usize) -> Result<Defaults, Error> {
+    let mut defaults = Defaults::default();
+    let (mut section, mut at) = (section, at);
+    while let Some((item, item_at)) = next_item(&mut section, &mut at) {
+        let mut cursor = Cursor::new(item, item_at);
+        cursor.skip_spaces();
+        if cursor.is_empty() {
+            continue;
+        }
+        let key_at = cursor.position();
+        let key = cursor.next().map(|c| c.to_ascii_lowercase());
+        cursor.skip_spaces();
+        if cursor.next() != Some(b'=') {
+            return Err(Error {
+                kind: ErrorKind::UnknownDefault,
+                position: key_at,
+            });
+        }
+        cursor.skip_spaces();
+        let value_at = cursor.position();
+        let value = cursor.number();
+        cursor.skip_spaces();
+        let bad = |kind| Error {
+            kind,
+            position: value_at,
+        };
+        match key {
+            Some(b'd') => {
+                defaults.duration = value
+                    .and_then(valid_duration)
+                    .ok_or(bad(ErrorKind::BadDuration))?
+            }
+            Some(b'o') => {
+                defaults.octave = value
+                    .and_then(valid_octave)
+                    .ok_or(bad(ErrorKind::BadOctave))?
+            }
+            Some(b'b') => {
+                defaults.bpm = value
+                    .and_then(|bpm| u16::try_from(bpm).ok())
+                    .filter(|&bpm| bpm > 0)
+                    .ok_or(bad(ErrorKind::BadTempo))?
+            }
+            _ => {
+                return Err(Error {
+                    kind: ErrorKind::UnknownDefault,
+                    position: key_at,
+                })
+            }
+        }
+        cursor.expect_end()?;
+    }
+    Ok(defaults)
+}
+
+/// The tone of one note, or `None` for an empty item, such as after a
+/// trailing comma.
+fn parse_note(note: &str, at: usize, defaults: &Defaults) -> Result<Option<Tone>, Error> {
+    let mut cursor = Cursor::new(note, at);
+    cursor.skip_spaces();
+    if cursor.is_empty() {
+        return Ok(None);
+    }
+
+    let duration_at = cursor.position();
+    let duration = match cursor.number() {
+        Some(duration) => valid_duration(duration).ok_or(Error {
+            kind: ErrorKind::BadDuration,
+            position: duration_at,
+        })?,
+        None => defaults.duration,
+    };
+
+    let note_at = cursor.position();
+    let semitone = match cursor.next().map(|c| c.to_ascii_lowercase()) {
+        Some(b'c') => Some(0),
+        Some(b'd') => Some(2),
+        Some(b'e') => Some(4),
+        Some(b'f') => Some(5),
+        Some(b'g') => Some(7),
+        Some(b'a') => Some(9),
+        // `h` is the German name of B
+        Some(b'b' | b'h') => Some(11),
+        Some(b'p') => None,
+        _ => {
+            return Err(Error {
+                kind: ErrorKind::BadNote,
+                position: note_at,
+            })
+        }
+    };
+    let sharp = cursor.eat(b'#');
+    // The dot comes before or after the octave, depending on the tool
+    let mut dotted = cursor.eat(b'.');
+    let octave_at = cursor.position();
+    let octave = match cursor.number() {
+        Some(octave) => valid_octave(octave).ok_or(Error {
+            kind: ErrorKind::BadOctave,
+            position: octave_at,
+        })?,
+        None => defaults.octave,
+    };
+    dotted |= cursor.eat(b'.');
+    cursor.skip_spaces();
+    cursor.expect_end()?;
+
+    let frequency = match semitone {
+        Some(semitone) => frequency(semitone + sharp as usize, octave),
+        None => 0,
+    };
+    // A whole note is four beats
+    let (times, over) = if dotted { (3, 2) } else { (1, 1) };
+    let duration = 240_000 * times / (defaults.bpm as u32 * duration as u32 * over);
+    Ok(Some(Tone {
+        frequency,
+        duration,
+    }))
+}
+
+/// Equal-tempered frequency of `semitone` above C in `octave`, rounded to
+/// the Hz. B# is the C of the next octave.
+fn frequency(semitone: usize, octave: u8) -> u32 {
+    note_frequency((octave + 1) * 12 + semitone as u8)
+}
+
+/// Equal-tempered frequency of a MIDI note number (60 is C4, 69 is A4 at
+/// 440 Hz), rounded to the Hz, for melodies that come from elsewhere.
+pub fn note_frequency(note: u8) -> u32 {
+    let note = note.min(127);
+    let (semitone, octave) = ((note % 12) as usize, (note / 12) as u32);
+    // `octave` counts from the octave below C0: 9 is the 8th
+    let divisor = 1000 << 9u32.saturating_sub(octave);
+    let millihertz = OCTAVE_8[semitone] << octave.saturating_sub(9);
+    (millihertz + divisor / 2) / divisor
+}
+
+fn valid_duration(duration: u32) -> Option<u8> {
+    match duration {
+        1 | 2 | 4 | 8 | 16 | 32 | 64 => Some(duration as u8),
+        _ => None,
+    }
+}
+
+fn valid_octave(octave: u32) -> Option<u8> {
+    (octave <= 8).then_some(octave as u8)
+}
+
+/// Reads a note or a default byte by byte, keeping track of the position.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    at: usize,
+    /// Position of `bytes[0]` in the whole text.
+    base: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(text: &'a str, base: usize) -> Self {
+        Self {
+            bytes: text.as_bytes(),
+            at: 0,
+            base,
+        }
+    }
+
+    fn position(&self) -> usize {
+        self.base + self.at
+    }
+
+    fn is_empty(&self) -> bool {
+        self.at == self.bytes.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.at).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.at += 1;
+        Some(byte)
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let found = self.peek() == Some(byte);
+        if found {
+            self.at += 1;
+        }
+        found
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.at += 1;
+        }
+    }
+
+    /// The decimal number here, if there is one. Saturates instead of
+    /// overflowing, which makes it invalid anyway.
+    fn number(&mut self) -> Option<u32> {
+        let mut number: Option<u32> = None;
+        while let Some(digit) = self.peek().filter(u8::is_ascii_digit) {
+            self.at += 1;
+            let value = number.unwrap_or(0);
+            number = Some(
+                value
+                    .saturating_mul(10)
+                    .saturating_add((digit - b'0') as u32),
+            );
+        }
+        number
+    }
+
+    fn expect_end(&self) -> Result<(), Error> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(Error {
+                kind: ErrorKind::TrailingCharacters,
+                position: self.position(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frequencies_match_the_buzzer_note_table() {
+        // NOTE_B0, NOTE_CS1, NOTE_C4, NOTE_A4, NOTE_AS7, NOTE_C8 and NOTE_DS8
+        assert_eq!(frequency(11, 0), 31);
+        assert_eq!(frequency(1, 1), 35);
+        assert_eq!(frequency(0, 4), 262);
+        assert_eq!(frequency(9, 4), 440);
+        assert_eq!(frequency(10, 7), 3729);
+        assert_eq!(frequency(0, 8), 4186);
+        assert_eq!(frequency(3, 8), 4978);
+        // B#4 is C5
+        assert_eq!(frequency(12, 4), frequency(0, 5));
+        assert_eq!(note_frequency(69), 440);
+        assert_eq!(note_frequency(0), 8);
+        assert_eq!(note_frequency(127), 12544);
+    }
+
+    #[test]
+    fn notes_fall_back_on_the_defaults() {
+        let ringtone = parse("x:d=8,o=4,b=120:a,2a5,p,16p").unwrap();
+        let tones: [Tone; 4] = core::array::from_fn({
+            let mut tones = ringtone.tones();
+            move |_| tones.next().unwrap()
+        });
+        assert_eq!(
+            tones,
+            [
+                Tone {
+                    frequency: 440,
+                    duration: 250
+                },
+                Tone {
+                    frequency: 880,
+                    duration: 1000
+                },
+                Tone {
+                    frequency: 0,
+                    duration: 250
+                },
+                Tone {
+                    frequency: 0,
+                    duration: 125
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_defaults_use_the_specification_values() {
+        let ringtone = parse(":: c").unwrap();
+        assert_eq!(ringtone.name, "");
+        assert_eq!(ringtone.defaults, Defaults::default());
+        let tone = ringtone.tones().next().unwrap();
+        assert_eq!(tone.frequency, 1047);
+        assert_eq!(tone.duration, 240_000 / (63 * 4));
+    }
+
+    #[test]
+    fn a_dot_before_or_after_the_octave_lengthens_the_note() {
+        let ringtone = parse("x:b=60:4c.5,4c5.,4c5").unwrap();
+        let durations = ringtone.tones().map(|tone| tone.duration);
+        assert!(durations.eq([1500, 1500, 1000]));
+    }
+}
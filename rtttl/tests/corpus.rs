@@ -0,0 +1,206 @@
+//! Parses well-known ringtones as they circulate in RTTTL collections, and
+//! broken ones to check where the errors are reported.
+
+use rtttl::{parse, Defaults, Error, ErrorKind, Tone};
+
+const NOKIA_TUNE: &str = "NokiaTune:d=4,o=5,b=225:8e6,8d6,f#,g#,8c#6,8b,d,e,8b,8a,c#,e,2a";
+const SIMPSONS: &str = "The Simpsons:d=4,o=5,b=160:c.6,e6,f#6,8a6,g.6,e6,c6,8a,8f#,8f#,8f#,2g,\
+8p,8p,8f#,8f#,8f#,8g,a#.,8c6,8c6,8c6,c6";
+const TETRIS: &str = "Tetris:d=4,o=5,b=160:e6,8b,8c6,8d6,16e6,16d6,8c6,8b,a,8a,8c6,e6,8d6,8c6,\
+b,8b,8c6,d6,e6,c6,a,2a,8p,d6,8f6,a6,8g6,8f6,e6,8e6,8c6,e6,8d6,8c6,b,8b,8c6,d6,e6,c6,a,a";
+const STAR_WARS: &str = "StarWars:d=4,o=5,b=45:32p,32f#,32f#,32f#,8b.,8f#.6,32e6,32d#6,32c#6,\
+8b.6,16f#.6,32e6,32d#6,32c#6,8b.6,16f#.6,32e6,32d#6,32e6,8c#.6,32f#,32f#,32f#,8b.,8f#.6,32e6,\
+32d#6,32c#6,8b.6,16f#.6,32e6,32d#6,32c#6,8b.6,16f#.6,32e6,32d#6,32e6,8c#6";
+const MISSION_IMPOSSIBLE: &str = "MissionImp:d=16,o=6,b=95:32d,32d#,32d,32d#,32d,32d#,32d,32d#,\
+32d,32d,32d#,32e,32f,32f#,32g,g,8p,g,8p,a#,p,c7,p,g,8p,g,8p,f,p,f#,p,g,8p,g,8p,a#,p,c7,p,g,8p,\
+g,8p,f,p,f#,p,a#,g,2d,32p,a#,g,2c#,32p,a#,g,2c,a#5,8c,2p,32p,a#5,g5,2f#,32p,a#5,g5,2f,32p,a#5,\
+g5,2e,d#,8d";
+const INDIANA: &str = "Indiana:d=4,o=5,b=250:e,8p,8f,8g,8p,1c6,8p.,d,8p,8e,1f,p.,g,8p,8a,8b,8p,\
+1f6,p,a,8p,8b,2c6,2d6,2e6,e,8p,8f,8g,8p,1c6,p,d6,8p,8e6,1f.6,g,8p,8g,e.6,8p,d6,8p,8g,e.6,8p,d6,\
+8p,8g,f.6,8p,e6,8p,8d6,2c6";
+
+const CORPUS: [&str; 6] = [
+    NOKIA_TUNE,
+    SIMPSONS,
+    TETRIS,
+    STAR_WARS,
+    MISSION_IMPOSSIBLE,
+    INDIANA,
+];
+
+/// Frequencies of the `NOTE_*` constants of `esp-hal-buzzer`, octaves 1 to 8.
+fn buzzer_notes() -> Vec<u32> {
+    const C1_TO_B1: [u32; 12] = [33, 35, 37, 39, 41, 44, 46, 49, 52, 55, 58, 62];
+    const C8_TO_DS8: [u32; 4] = [4186, 4435, 4699, 4978];
+    let mut notes = vec![0, 31];
+    notes.extend(C1_TO_B1);
+    // Octaves 2 to 7 from the buzzer crate's table
+    notes.extend([
+        65, 69, 73, 78, 82, 87, 93, 98, 104, 110, 117, 123, 131, 139, 147, 156, 165, 175, 185, 196,
+        208, 220, 233, 247, 262, 277, 294, 311, 330, 349, 370, 392, 415, 440, 466, 494, 523, 554,
+        587, 622, 659, 698, 740, 784, 831, 880, 932, 988, 1047, 1109, 1175, 1245, 1319, 1397, 1480,
+        1568, 1661, 1760, 1865, 1976, 2093, 2217, 2349, 2489, 2637, 2794, 2960, 3136, 3322, 3520,
+        3729, 3951,
+    ]);
+    notes.extend(C8_TO_DS8);
+    notes
+}
+
+#[test]
+fn the_whole_corpus_parses_into_buzzer_notes() {
+    let notes = buzzer_notes();
+    for text in CORPUS {
+        let ringtone = parse(text).unwrap_or_else(|err| panic!("{text}: {err}"));
+        let (_, list) = text.rsplit_once(':').unwrap();
+        assert_eq!(ringtone.len(), list.split(',').count(), "{}", ringtone.name);
+        for tone in ringtone.tones() {
+            assert!(
+                notes.contains(&tone.frequency),
+                "{}: {tone:?}",
+                ringtone.name
+            );
+            assert!(tone.duration > 0);
+        }
+    }
+}
+
+#[test]
+fn nokia_tune() {
+    let ringtone = parse(NOKIA_TUNE).unwrap();
+    assert_eq!(ringtone.name, "NokiaTune");
+    assert_eq!(
+        ringtone.defaults,
+        Defaults {
+            duration: 4,
+            octave: 5,
+            bpm: 225
+        }
+    );
+    let tones: Vec<Tone> = ringtone.tones().collect();
+    // E6 and D6 eighths, then F#5 and G#5 quarters
+    assert_eq!(
+        tones[..4],
+        [
+            Tone {
+                frequency: 1319,
+                duration: 133
+            },
+            Tone {
+                frequency: 1175,
+                duration: 133
+            },
+            Tone {
+                frequency: 740,
+                duration: 266
+            },
+            Tone {
+                frequency: 831,
+                duration: 266
+            },
+        ]
+    );
+    // Ends on a half note A5
+    assert_eq!(
+        tones.last(),
+        Some(&Tone {
+            frequency: 880,
+            duration: 533
+        })
+    );
+    assert_eq!(ringtone.duration_ms(), 2927);
+}
+
+#[test]
+fn simpsons_dotted_notes_and_pauses() {
+    let tones: Vec<Tone> = parse(SIMPSONS).unwrap().tones().collect();
+    // c.6 at 160 BPM: a quarter note and a half
+    assert_eq!(
+        tones[0],
+        Tone {
+            frequency: 1047,
+            duration: 562
+        }
+    );
+    assert_eq!(
+        tones[12],
+        Tone {
+            frequency: 0,
+            duration: 187
+        }
+    );
+    // a#. in the default octave
+    assert_eq!(
+        tones[18],
+        Tone {
+            frequency: 932,
+            duration: 562
+        }
+    );
+}
+
+#[test]
+fn mission_impossible_uses_a_sixteenth_default() {
+    let ringtone = parse(MISSION_IMPOSSIBLE).unwrap();
+    assert_eq!(ringtone.defaults.duration, 16);
+    let g = ringtone.tones().nth(15).unwrap();
+    assert_eq!(
+        g,
+        Tone {
+            frequency: 1568,
+            duration: 157
+        }
+    );
+}
+
+#[test]
+fn spaces_case_and_a_trailing_comma_are_accepted() {
+    let ringtone = parse(" Beep : D = 8 , O = 6 , B = 120 : C , 4P , 8a#5. ,").unwrap();
+    assert_eq!(ringtone.name, "Beep");
+    assert!(ringtone.tones().eq([
+        Tone {
+            frequency: 1047,
+            duration: 250
+        },
+        Tone {
+            frequency: 0,
+            duration: 500
+        },
+        Tone {
+            frequency: 932,
+            duration: 375
+        },
+    ]));
+}
+
+#[test]
+fn errors_point_at_the_offending_character() {
+    let error = |kind, position| Err::<(), _>(Error { kind, position });
+    let check = |text| parse(text).map(|_| ());
+
+    assert_eq!(check("no sections"), error(ErrorKind::MissingSection, 11));
+    assert_eq!(check("x:d=4"), error(ErrorKind::MissingSection, 5));
+    assert_eq!(check("x:d=4,q=5:c"), error(ErrorKind::UnknownDefault, 6));
+    assert_eq!(check("x:o:c"), error(ErrorKind::UnknownDefault, 2));
+    assert_eq!(check("x:d=3:c"), error(ErrorKind::BadDuration, 4));
+    assert_eq!(check("x:o=9:c"), error(ErrorKind::BadOctave, 4));
+    assert_eq!(check("x:b=0:c"), error(ErrorKind::BadTempo, 4));
+    assert_eq!(check("x:b=70000:c"), error(ErrorKind::BadTempo, 4));
+    assert_eq!(
+        check("x:b=100 x:c"),
+        error(ErrorKind::TrailingCharacters, 8)
+    );
+    assert_eq!(check("x::c,8e,12d"), error(ErrorKind::BadDuration, 8));
+    assert_eq!(check("x::c,8e,8x"), error(ErrorKind::BadNote, 9));
+    assert_eq!(check("x::c,8e,8d9"), error(ErrorKind::BadOctave, 10));
+    assert_eq!(check("x::c,8e#6#"), error(ErrorKind::TrailingCharacters, 9));
+    // The position counts bytes of the whole text
+    let text = "Tetris:d=4,o=5,b=160:e6,8b,8c6,8d6,16e6,16d6,8k6";
+    assert_eq!(
+        check(text),
+        error(ErrorKind::BadNote, text.rfind('k').unwrap())
+    );
+    assert_eq!(
+        parse("x::c,y").unwrap_err().to_string(),
+        "invalid note at byte 5"
+    );
+}
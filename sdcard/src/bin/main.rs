@@ -8,17 +8,28 @@
 
 use defmt::info;
 use embassy_executor::Spawner;
-use embassy_time::{Delay, Duration, Timer};
+use audio_pipeline::WavRecorder;
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_sync::channel::Channel;
+use embassy_time::{Delay, Duration, Ticker, Timer};
 use embedded_hal_bus::spi::ExclusiveDevice;
 use embedded_sdmmc::{SdCard, TimeSource, Timestamp, VolumeIdx, VolumeManager};
+use esp_hal::analog::adc::{Adc, AdcConfig, AdcPin, Attenuation};
 use esp_hal::clock::CpuClock;
 use esp_hal::gpio::{Level, Output, OutputConfig};
+use esp_hal::interrupt::software::SoftwareInterruptControl;
+use esp_hal::interrupt::Priority;
+use esp_hal::peripherals::{ADC1, GPIO1};
 use esp_hal::spi::master::{Config, Spi};
 use esp_hal::spi::Mode;
 use esp_hal::time::Rate;
 use esp_hal::timer::systimer::SystemTimer;
+use esp_hal::Blocking;
+use esp_hal_embassy::InterruptExecutor;
 use esp_println::println;
 use esp_println::{self as _, print};
+use static_cell::StaticCell;
+use wav_parser::Format;
 
 #[panic_handler]
 fn panic(_: &core::panic::PanicInfo) -> ! {
@@ -29,6 +40,39 @@ fn panic(_: &core::panic::PanicInfo) -> ! {
 // For more information see: <https://docs.espressif.com/projects/esp-idf/en/stable/esp32/api-reference/system/app_image_format.html#application-description>
 esp_bootloader_esp_idf::esp_app_desc!();
 
+/// Microphone samples per second: enough for speech.
+const RECORD_RATE: u32 = 8000;
+
+/// Length of the recording.
+const RECORD_SECONDS: u32 = 10;
+
+/// Chunks written to the card per second.
+const CHUNKS_PER_SECOND: usize = 16;
+
+/// Samples written to the card at a time.
+const CHUNK: usize = RECORD_RATE as usize / CHUNKS_PER_SECOND;
+
+/// Chunks of the whole recording.
+const CHUNKS: usize = RECORD_SECONDS as usize * CHUNKS_PER_SECOND;
+
+/// Chunks sampled but not written yet: half a second, longer than the card
+/// takes to sync.
+const CHUNKS_QUEUED: usize = 8;
+
+/// Samples on their way from the sampler to the card.
+static RECORDED: Channel<CriticalSectionRawMutex, Chunk, CHUNKS_QUEUED> = Channel::new();
+
+/// The executor of the sampler, which interrupts the SD card writes.
+static SAMPLER: StaticCell<InterruptExecutor<2>> = StaticCell::new();
+
+/// A chunk of 16-bit PCM.
+struct Chunk {
+    /// Chunks before this one the sampler dropped, because the card was too
+    /// far behind.
+    lost: usize,
+    samples: [u8; 2 * CHUNK],
+}
+
 /// Code from https://github.com/rp-rs/rp-hal-boards/blob/main/boards/rp-pico/examples/pico_spi_sd_card.rs
 /// A dummy timesource, which is mostly important for creating files.
 #[derive(Default)]
@@ -119,6 +163,52 @@ async fn main(spawner: Spawner) {
     my_other_file.flush().unwrap();
     my_other_file.close().unwrap();
     info!("FILE SAVED");
+
+    // A microphone module on the pin wav-hex-player reads the light sensor
+    // from: its output sits around the middle of the ADC range
+    let mut adc_config = AdcConfig::new();
+    let mic_pin = adc_config.enable_pin(peripherals.GPIO1, Attenuation::_11dB);
+    let adc = Adc::new(peripherals.ADC1, adc_config);
+
+    info!("RECORDING");
+    let rec_file = root_dir
+        .open_file_in_dir("REC.WAV", embedded_sdmmc::Mode::ReadWriteCreateOrTruncate)
+        .unwrap();
+    let mut recorder = WavRecorder::new(rec_file, Format::pcm(1, RECORD_RATE, 16)).unwrap();
+    // The card blocks this executor while it writes, so the sampler runs on
+    // one that interrupts it
+    let software_interrupts = SoftwareInterruptControl::new(peripherals.SW_INTERRUPT);
+    let sampler = SAMPLER.init(InterruptExecutor::new(
+        software_interrupts.software_interrupt2,
+    ));
+    sampler
+        .start(Priority::Priority3)
+        .must_spawn(sample(adc, mic_pin));
+    let mut written = 0;
+    while written < CHUNKS {
+        let chunk = RECORDED.receive().await;
+        // Silence in place of what was dropped keeps the length right
+        for _ in 0..chunk.lost {
+            recorder.write(&[0; 2 * CHUNK]).unwrap();
+        }
+        recorder.write(&chunk.samples).unwrap();
+        let before = written;
+        written += chunk.lost + 1;
+        if chunk.lost > 0 {
+            println!(
+                "Lost {} ms to the card",
+                chunk.lost * 1000 / CHUNKS_PER_SECOND
+            );
+        }
+        // A reset from here on still leaves a playable file
+        if written / CHUNKS_PER_SECOND > before / CHUNKS_PER_SECOND {
+            recorder.sync().unwrap();
+            println!("Recorded {} s", written / CHUNKS_PER_SECOND);
+        }
+    }
+    let data_len = recorder.finish().unwrap();
+    println!("REC.WAV saved: {} bytes of audio", data_len);
+
     // TODO: Spawn some tasks
     let _ = spawner;
 
@@ -128,3 +218,53 @@ async fn main(spawner: Spawner) {
 
     // for inspiration have a look at the examples at https://github.com/esp-rs/esp-hal/tree/esp-hal-v1.0.0-rc.0/examples/src/bin
 }
+
+/// Samples the microphone for the whole recording and queues it in chunks,
+/// never waiting on the card: a chunk that finds the queue full is dropped.
+#[embassy_executor::task]
+async fn sample(
+    mut adc: Adc<'static, ADC1<'static>, Blocking>,
+    mut mic_pin: AdcPin<GPIO1<'static>, ADC1<'static>>,
+) {
+    let mut dc_blocker = DcBlocker::default();
+    let mut samples = [0u8; 2 * CHUNK];
+    let mut lost = 0;
+    let mut ticker = Ticker::every(Duration::from_hz(RECORD_RATE as u64));
+    for index in 0..CHUNKS {
+        for sample in samples.chunks_exact_mut(2) {
+            ticker.next().await;
+            let raw: u16 = nb::block!(adc.read_oneshot(&mut mic_pin)).unwrap();
+            sample.copy_from_slice(&dc_blocker.pcm(raw).to_le_bytes());
+        }
+        let chunk = Chunk { lost, samples };
+        if index == CHUNKS - 1 {
+            // Nothing is left to sample, so the last one can wait
+            RECORDED.send(chunk).await;
+        } else if RECORDED.try_send(chunk).is_ok() {
+            lost = 0;
+        } else {
+            lost += 1;
+        }
+    }
+}
+
+/// Turns 12-bit ADC readings into 16-bit PCM centred on zero, by taking out
+/// their running average: the bias of the microphone is never exactly half
+/// the ADC range.
+#[derive(Default)]
+struct DcBlocker {
+    /// The average so far, in 1/256 of an ADC step.
+    average: i32,
+}
+
+impl DcBlocker {
+    fn pcm(&mut self, raw: u16) -> i16 {
+        let raw = i32::from(raw) << 8;
+        if self.average == 0 {
+            self.average = raw;
+        }
+        // Follows the bias over about 1024 samples, well below speech
+        self.average += (raw - self.average) >> 10;
+        ((raw - self.average) >> 4).clamp(i16::MIN as i32, i16::MAX as i32) as i16
+    }
+}
@@ -0,0 +1,88 @@
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+use crate::{Pick, Tone};
+
+/// `pub const {name}: [ToneValue; N] = [...];` for `tones`, ready for
+/// `include!` next to `use esp_hal_buzzer::ToneValue`, or any type with the
+/// same two fields.
+pub fn melody_source(name: &str, tones: impl IntoIterator<Item = Tone>) -> String {
+    let mut body = String::new();
+    let mut len = 0;
+    for tone in tones {
+        let _ = writeln!(
+            body,
+            "    ToneValue {{ frequency: {}, duration: {} }},",
+            tone.frequency, tone.duration
+        );
+        len += 1;
+    }
+    std::format!("pub const {name}: [ToneValue; {len}] = [\n{body}];\n")
+}
+
+/// [`melody_source`] for the melody track of every `.mid` file in `dir`, in
+/// file name order, then `MELODIES`: each file's name with its tones. A file
+/// named `ode_to_joy.mid` becomes `ODE_TO_JOY`. Files without notes are left
+/// out; a file that is not a valid MIDI file is an `InvalidData` error.
+pub fn melodies_source(dir: impl AsRef<Path>) -> io::Result<String> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("mid"))
+        {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut source = String::new();
+    let mut names = Vec::new();
+    for path in &paths {
+        let bytes = fs::read(path)?;
+        let smf = crate::parse(&bytes).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                std::format!("{}: {err}", path.display()),
+            )
+        })?;
+        let Some(melody) = smf
+            .melody_track()
+            .and_then(|track| smf.melody(track, Pick::Highest))
+        else {
+            continue;
+        };
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let name: String = stem
+            .chars()
+            .map(|c| match c {
+                'a'..='z' | 'A'..='Z' | '0'..='9' => c.to_ascii_uppercase(),
+                _ => '_',
+            })
+            .collect();
+        // Not a valid identifier otherwise
+        let name = if name.starts_with(|c: char| c.is_ascii_digit()) {
+            std::format!("SONG_{name}")
+        } else {
+            name
+        };
+        source += &melody_source(&name, melody);
+        names.push((stem.to_string(), name));
+    }
+
+    let _ = writeln!(
+        source,
+        "pub const MELODIES: [(&str, &[ToneValue]); {}] = [",
+        names.len()
+    );
+    for (file, name) in &names {
+        let _ = writeln!(source, "    ({file:?}, &{name}),");
+    }
+    source += "];\n";
+    Ok(source)
+}
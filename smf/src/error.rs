@@ -0,0 +1,29 @@
+use core::fmt;
+
+/// Everything that can go wrong while reading a MIDI file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The file does not start with an `MThd` chunk.
+    NotMidi,
+    /// The chunk starting at `offset` declares more bytes than the file holds.
+    TruncatedChunk { offset: usize },
+    /// Type 2 files hold independent patterns, not one song.
+    UnsupportedFormat(u16),
+    /// The header announces more tracks than the file has.
+    MissingTrack(u16),
+    /// An event of the track starting at `offset` is cut short or uses a
+    /// status byte that does not exist.
+    MalformedEvent { offset: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotMidi => f.write_str("not a Standard MIDI File"),
+            Error::TruncatedChunk { offset } => write!(f, "chunk at byte {offset} is truncated"),
+            Error::UnsupportedFormat(format) => write!(f, "unsupported MIDI file type {format}"),
+            Error::MissingTrack(track) => write!(f, "track {track} is missing"),
+            Error::MalformedEvent { offset } => write!(f, "malformed event at byte {offset}"),
+        }
+    }
+}
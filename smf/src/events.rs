@@ -0,0 +1,206 @@
+use crate::Error;
+
+/// The events a melody needs. Everything else is read past.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Event {
+    NoteOn {
+        channel: u8,
+        key: u8,
+    },
+    /// Also a note on with a velocity of 0.
+    NoteOff {
+        channel: u8,
+        key: u8,
+    },
+    /// Microseconds per quarter note from now on.
+    Tempo(u32),
+    Other,
+}
+
+/// Reads the events of one `MTrk` chunk in order, with their absolute tick.
+#[derive(Clone, Debug)]
+pub(crate) struct Events<'a> {
+    data: &'a [u8],
+    at: usize,
+    /// Offset of `data` in the file, for errors.
+    base: usize,
+    tick: u32,
+    /// The last channel status, which later events may leave out.
+    running: Option<u8>,
+    ended: bool,
+}
+
+impl<'a> Events<'a> {
+    pub(crate) fn new(data: &'a [u8], base: usize) -> Self {
+        Self {
+            data,
+            at: 0,
+            base,
+            tick: 0,
+            running: None,
+            ended: false,
+        }
+    }
+
+    /// The next event and its tick, `None` after the end of the track.
+    pub(crate) fn next(&mut self) -> Result<Option<(u32, Event)>, Error> {
+        if self.ended || self.at == self.data.len() {
+            return Ok(None);
+        }
+        let start = self.at;
+        let malformed = Error::MalformedEvent {
+            offset: self.base + start,
+        };
+        let result = self.read_event().ok_or(malformed);
+        if result.is_err() {
+            self.ended = true;
+        }
+        result.map(Some)
+    }
+
+    fn read_event(&mut self) -> Option<(u32, Event)> {
+        let delta = self.varint()?;
+        self.tick = self.tick.saturating_add(delta);
+
+        let mut status = *self.data.get(self.at)?;
+        if status < 0x80 {
+            // Running status: this byte is already the first data byte
+            status = self.running?;
+        } else {
+            self.at += 1;
+        }
+
+        let event = match status {
+            0x80..=0xEF => {
+                self.running = Some(status);
+                let channel = status & 0x0F;
+                let len = if matches!(status & 0xF0, 0xC0 | 0xD0) {
+                    1
+                } else {
+                    2
+                };
+                let data = self.take(len)?;
+                if data.iter().any(|&byte| byte >= 0x80) {
+                    return None;
+                }
+                match (status & 0xF0, data) {
+                    (0x90, &[key, velocity]) if velocity > 0 => Event::NoteOn { channel, key },
+                    (0x80 | 0x90, &[key, _]) => Event::NoteOff { channel, key },
+                    _ => Event::Other,
+                }
+            }
+            0xF0 | 0xF7 => {
+                self.running = None;
+                let len = self.varint()? as usize;
+                self.take(len)?;
+                Event::Other
+            }
+            0xFF => {
+                self.running = None;
+                let kind = *self.take(1)?.first()?;
+                let len = self.varint()? as usize;
+                let data = self.take(len)?;
+                match (kind, data) {
+                    (0x2F, _) => {
+                        self.ended = true;
+                        Event::Other
+                    }
+                    (0x51, &[a, b, c]) => Event::Tempo(u32::from_be_bytes([0, a, b, c])),
+                    _ => Event::Other,
+                }
+            }
+            // System common and real-time messages have no place in a file
+            _ => return None,
+        };
+        Some((self.tick, event))
+    }
+
+    /// A variable-length quantity: 7 bits per byte, at most 4 bytes.
+    fn varint(&mut self) -> Option<u32> {
+        let mut value = 0u32;
+        for _ in 0..4 {
+            let byte = *self.data.get(self.at)?;
+            self.at += 1;
+            value = (value << 7) | (byte & 0x7F) as u32;
+            if byte < 0x80 {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.at..self.at.checked_add(len)?)?;
+        self.at += len;
+        Some(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    fn all(data: &[u8]) -> Result<Vec<(u32, Event)>, Error> {
+        let mut events = Events::new(data, 100);
+        let mut all = Vec::new();
+        while let Some(event) = events.next()? {
+            all.push(event);
+        }
+        Ok(all)
+    }
+
+    #[test]
+    fn running_status_and_zero_velocity_note_offs() {
+        let data = [
+            0x00, 0x91, 60, 100, // note on
+            0x81, 0x00, 60, 0, // 128 ticks later, running status, velocity 0
+            0x00, 0xFF, 0x51, 3, 0x07, 0xA1, 0x20, // 500 000 µs per quarter
+            0x10, 0xC1, 5, // program change
+            0x00, 0xFF, 0x2F, 0x00, // end of track
+            0x00, 0x90, 61, 100, // after the end: ignored
+        ];
+        assert_eq!(
+            all(&data).unwrap(),
+            [
+                (
+                    0,
+                    Event::NoteOn {
+                        channel: 1,
+                        key: 60
+                    }
+                ),
+                (
+                    128,
+                    Event::NoteOff {
+                        channel: 1,
+                        key: 60
+                    }
+                ),
+                (128, Event::Tempo(500_000)),
+                (144, Event::Other),
+                (144, Event::Other),
+            ]
+        );
+    }
+
+    #[test]
+    fn sysex_is_skipped_and_breaks_running_status() {
+        let data = [0x00, 0x90, 60, 1, 0x00, 0xF0, 2, 0x7E, 0xF7, 0x00, 60, 0];
+        assert_eq!(all(&data), Err(Error::MalformedEvent { offset: 109 }));
+    }
+
+    #[test]
+    fn cut_events_are_malformed() {
+        assert_eq!(
+            all(&[0x00, 0x90, 60]),
+            Err(Error::MalformedEvent { offset: 100 })
+        );
+        assert_eq!(
+            all(&[0x00, 0x90, 60, 1, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F]),
+            Err(Error::MalformedEvent { offset: 104 })
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! Standard MIDI File reader that turns one track into a buzzer melody.
+//!
+//! A buzzer plays one note at a time, so [`Smf::melody`] follows a single
+//! track (type 0 and type 1 files) and keeps the highest or the lowest of the
+//! notes sounding together. Tempo changes are applied as they come, and the
+//! result is the [`Tone`] sequence `buzzer-music` plays: frequencies in Hz
+//! (0 for a rest) and durations in milliseconds.
+//!
+//! ```no_run
+//! let smf = smf::parse(include_bytes!("../tests/fixtures/ode_to_joy.mid")).unwrap();
+//! let track = smf.melody_track().unwrap();
+//! for tone in smf.melody(track, smf::Pick::Highest).unwrap() {
+//!     // play tone.frequency for tone.duration ms
+//! }
+//! ```
+//!
+//! With the `std` feature, [`melody_source`] writes a melody as Rust source,
+//! and [`melodies_source`] every file of a folder, so a `build.rs` can
+//! convert `.mid` files at build time.
+
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+mod codegen;
+mod error;
+mod events;
+mod melody;
+
+#[cfg(feature = "std")]
+pub use codegen::{melodies_source, melody_source};
+pub use error::Error;
+pub use melody::{Melody, Pick};
+pub use rtttl::Tone;
+
+use events::{Event, Events};
+
+/// The MIDI channel of drums (10, counted from 1): not notes a buzzer can
+/// play.
+pub const DRUM_CHANNEL: u8 = 9;
+
+/// How ticks map to time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Division {
+    /// Ticks per quarter note: the time of a tick follows the tempo.
+    TicksPerQuarter(u16),
+    /// Frames per second (29 for 29.97 drop-frame) and ticks per frame, no
+    /// matter the tempo.
+    Smpte { fps: u8, ticks_per_frame: u8 },
+}
+
+/// A checked MIDI file, borrowing its bytes.
+#[derive(Clone, Copy, Debug)]
+pub struct Smf<'a> {
+    /// 0 for a single track, 1 for tracks played together.
+    pub format: u16,
+    pub division: Division,
+    track_count: u16,
+    /// The chunks after the header.
+    chunks: &'a [u8],
+    /// Offset of `chunks` in the file, for errors.
+    chunks_at: usize,
+}
+
+impl<'a> Smf<'a> {
+    pub fn track_count(&self) -> u16 {
+        self.track_count
+    }
+
+    /// Notes started in `track`, drums left out.
+    pub fn note_count(&self, track: u16) -> usize {
+        let Some(mut events) = self.events(track) else {
+            return 0;
+        };
+        let mut count = 0;
+        while let Ok(Some((_, event))) = events.next() {
+            if matches!(event, Event::NoteOn { channel, .. } if channel != DRUM_CHANNEL) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// The track with the most notes: the tune, more often than not.
+    pub fn melody_track(&self) -> Option<u16> {
+        (0..self.track_count)
+            .map(|track| (self.note_count(track), track))
+            .filter(|&(count, _)| count > 0)
+            // The first of the busiest
+            .max_by_key(|&(count, track)| (count, core::cmp::Reverse(track)))
+            .map(|(_, track)| track)
+    }
+
+    /// The tones of `track`, one note at a time. In type 1 files the tempo
+    /// changes are read from the first track, where the standard puts them.
+    pub fn melody(&self, track: u16, pick: Pick) -> Option<Melody<'a>> {
+        let notes = self.events(track)?;
+        let tempo = if self.format == 1 && track != 0 {
+            self.events(0)
+        } else {
+            None
+        };
+        Some(Melody::new(notes, tempo, self.division, pick))
+    }
+
+    fn events(&self, track: u16) -> Option<Events<'a>> {
+        let mut chunks = Chunks {
+            data: self.chunks,
+            at: self.chunks_at,
+        };
+        let mut index = 0;
+        while let Ok(Some((id, body, at))) = chunks.next() {
+            if &id == b"MTrk" {
+                if index == track {
+                    return Some(Events::new(body, at));
+                }
+                index += 1;
+            }
+        }
+        None
+    }
+}
+
+/// Reads the header and checks every chunk and every event of the file.
+pub fn parse(bytes: &[u8]) -> Result<Smf<'_>, Error> {
+    let mut chunks = Chunks { data: bytes, at: 0 };
+    let header = match chunks.next() {
+        Ok(Some((id, body, _))) if &id == b"MThd" && body.len() >= 6 => body,
+        _ => return Err(Error::NotMidi),
+    };
+    let field = |at: usize| u16::from_be_bytes([header[at], header[at + 1]]);
+    let (format, track_count, division) = (field(0), field(2), field(4));
+    if format > 1 {
+        return Err(Error::UnsupportedFormat(format));
+    }
+    let division = if division & 0x8000 == 0 {
+        Division::TicksPerQuarter(division)
+    } else {
+        Division::Smpte {
+            // The frame rate is stored negated
+            fps: ((division >> 8) as u8 as i8).unsigned_abs(),
+            ticks_per_frame: division as u8,
+        }
+    };
+    if matches!(
+        division,
+        Division::TicksPerQuarter(0)
+            | Division::Smpte { fps: 0, .. }
+            | Division::Smpte {
+                ticks_per_frame: 0,
+                ..
+            }
+    ) {
+        return Err(Error::NotMidi);
+    }
+
+    let smf = Smf {
+        format,
+        division,
+        track_count,
+        chunks: chunks.data,
+        chunks_at: chunks.at,
+    };
+    let mut found = 0;
+    while let Some((id, body, at)) = chunks.next()? {
+        if &id != b"MTrk" {
+            continue;
+        }
+        let mut events = Events::new(body, at);
+        while events.next()?.is_some() {}
+        found += 1;
+    }
+    if found < track_count {
+        return Err(Error::MissingTrack(found));
+    }
+    Ok(smf)
+}
+
+/// A chunk's id, body and the offset of the body in the file.
+type Chunk<'a> = ([u8; 4], &'a [u8], usize);
+
+/// Walks the chunks of the file: an id, a big-endian length and the body.
+struct Chunks<'a> {
+    data: &'a [u8],
+    /// Offset of `data` in the file.
+    at: usize,
+}
+
+impl<'a> Chunks<'a> {
+    fn next(&mut self) -> Result<Option<Chunk<'a>>, Error> {
+        if self.data.is_empty() {
+            return Ok(None);
+        }
+        let truncated = Error::TruncatedChunk { offset: self.at };
+        let header = self.data.get(..8).ok_or(truncated)?;
+        let id = [header[0], header[1], header[2], header[3]];
+        let len = u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        let end = len.checked_add(8).ok_or(truncated)?;
+        let body = self.data.get(8..end).ok_or(truncated)?;
+        let body_at = self.at + 8;
+        self.data = &self.data[end..];
+        self.at += end;
+        Ok(Some((id, body, body_at)))
+    }
+}
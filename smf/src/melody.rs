@@ -0,0 +1,196 @@
+use rtttl::{note_frequency, Tone};
+
+use crate::events::{Event, Events};
+use crate::{Division, DRUM_CHANNEL};
+
+/// Tempo until the file sets one: 120 quarter notes per minute.
+const DEFAULT_TEMPO: u32 = 500_000;
+
+/// Which note to keep when several sound at once.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Pick {
+    /// The tune is usually on top.
+    #[default]
+    Highest,
+    /// For a bass line.
+    Lowest,
+}
+
+/// The tones of one track, one note at a time: a tone lasts until the note
+/// kept changes, is struck again or stops. Silence before the first note and
+/// after the last one is left out; rests in between come out as tones of
+/// 0 Hz.
+#[derive(Clone, Debug)]
+pub struct Melody<'a> {
+    notes: Events<'a>,
+    /// The first track of a type 1 file, for its tempo changes.
+    tempo_track: Option<Events<'a>>,
+    /// The next event of each, read ahead to merge them in tick order.
+    next_note: Option<(u32, Event)>,
+    next_tempo: Option<(u32, Event)>,
+    division: Division,
+    pick: Pick,
+    /// Microseconds per quarter note.
+    tempo: u32,
+    tick: u32,
+    /// Time since the start, in units of 1 / `scale()` µs: exact across
+    /// tempo changes.
+    elapsed: u64,
+    /// How many times each key is held down, over all channels.
+    sounding: [u8; 128],
+    /// The note playing, and when it started (or the rest).
+    current: Option<u8>,
+    start: u64,
+    started: bool,
+    done: bool,
+}
+
+impl<'a> Melody<'a> {
+    pub(crate) fn new(
+        mut notes: Events<'a>,
+        mut tempo_track: Option<Events<'a>>,
+        division: Division,
+        pick: Pick,
+    ) -> Self {
+        let next_note = notes.next().ok().flatten();
+        let next_tempo = tempo_track
+            .as_mut()
+            .and_then(|track| track.next().ok().flatten());
+        Self {
+            notes,
+            tempo_track,
+            next_note,
+            next_tempo,
+            division,
+            pick,
+            tempo: DEFAULT_TEMPO,
+            tick: 0,
+            elapsed: 0,
+            sounding: [0; 128],
+            current: None,
+            start: 0,
+            started: false,
+            done: false,
+        }
+    }
+
+    /// The next event of either track, the earlier one first.
+    fn pull(&mut self) -> Option<(u32, Event)> {
+        let note_tick = self.next_note?.0;
+        let tempo_first = self.next_tempo.is_some_and(|(tick, _)| tick <= note_tick);
+        if tempo_first {
+            let event = self.next_tempo.take();
+            self.next_tempo = self
+                .tempo_track
+                .as_mut()
+                .and_then(|track| track.next().ok().flatten());
+            event
+        } else {
+            let event = self.next_note.take();
+            self.next_note = self.notes.next().ok().flatten();
+            event
+        }
+    }
+
+    /// Moves the clock to `tick` at the tempo in force.
+    fn advance_to(&mut self, tick: u32) {
+        let ticks = tick.saturating_sub(self.tick) as u64;
+        self.tick = tick;
+        self.elapsed += ticks
+            * match self.division {
+                Division::TicksPerQuarter(_) => self.tempo as u64,
+                // 29 means 29.97 frames per second
+                Division::Smpte { fps: 29, .. } => 100_000_000,
+                Division::Smpte { .. } => 1_000_000,
+            };
+    }
+
+    /// Units of `elapsed` per millisecond.
+    fn scale(&self) -> u64 {
+        match self.division {
+            Division::TicksPerQuarter(ticks) => ticks as u64 * 1000,
+            Division::Smpte {
+                fps: 29,
+                ticks_per_frame,
+            } => 2997 * ticks_per_frame as u64 * 1000,
+            Division::Smpte {
+                fps,
+                ticks_per_frame,
+            } => fps as u64 * ticks_per_frame as u64 * 1000,
+        }
+    }
+
+    fn picked(&self) -> Option<u8> {
+        let mut held = (0..128u8).filter(|&key| self.sounding[key as usize] > 0);
+        match self.pick {
+            Pick::Highest => held.next_back(),
+            Pick::Lowest => held.next(),
+        }
+    }
+
+    /// Ends the tone playing now and starts `next`. Returns the tone ended,
+    /// unless it took no time or is the silence before the first note.
+    fn cut(&mut self, next: Option<u8>) -> Option<Tone> {
+        let scale = self.scale();
+        let ms = |at: u64| (at + scale / 2) / scale;
+        let duration = (ms(self.elapsed) - ms(self.start)) as u32;
+        let tone = Tone {
+            frequency: self.current.map_or(0, note_frequency),
+            duration,
+        };
+        let audible = self.started && duration > 0;
+
+        self.started |= next.is_some();
+        self.current = next;
+        self.start = self.elapsed;
+        audible.then_some(tone)
+    }
+}
+
+impl Iterator for Melody<'_> {
+    type Item = Tone;
+
+    fn next(&mut self) -> Option<Tone> {
+        while !self.done {
+            let Some((tick, event)) = self.pull() else {
+                self.done = true;
+                // A note still held at the end of the track stops there
+                return self.current.and_then(|_| self.cut(None));
+            };
+            self.advance_to(tick);
+
+            let tone = match event {
+                Event::Tempo(tempo) => {
+                    self.tempo = tempo;
+                    None
+                }
+                Event::NoteOn { channel, key } if channel != DRUM_CHANNEL => {
+                    let held = &mut self.sounding[key as usize];
+                    *held = held.saturating_add(1);
+                    let picked = self.picked();
+                    // The note kept changed, or was struck again
+                    if picked != self.current || picked == Some(key) {
+                        self.cut(picked)
+                    } else {
+                        None
+                    }
+                }
+                Event::NoteOff { channel, key } if channel != DRUM_CHANNEL => {
+                    let held = &mut self.sounding[key as usize];
+                    *held = held.saturating_sub(1);
+                    let picked = self.picked();
+                    if picked != self.current {
+                        self.cut(picked)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            };
+            if tone.is_some() {
+                return tone;
+            }
+        }
+        None
+    }
+}
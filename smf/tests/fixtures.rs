@@ -0,0 +1,193 @@
+//! Reads `tests/fixtures/ode_to_joy.mid` and small files assembled event by
+//! event.
+//!
+//! `ode_to_joy.mid` is a type 1 file written for these tests: a tempo track
+//! that slows from 120 to 100 BPM at bar 3, the tune over a held bass note on
+//! a second channel, and a hi-hat track on the drum channel.
+
+use smf::{melodies_source, melody_source, parse, Division, Error, Pick, Tone};
+
+const ODE_TO_JOY: &[u8] = include_bytes!("fixtures/ode_to_joy.mid");
+
+fn tone(key: u8, duration: u32) -> Tone {
+    Tone {
+        frequency: rtttl::note_frequency(key),
+        duration,
+    }
+}
+
+fn rest(duration: u32) -> Tone {
+    Tone {
+        frequency: 0,
+        duration,
+    }
+}
+
+/// A type 0 file at `division` holding `events` (delta-time and event
+/// bytes, end of track included).
+fn type_0(division: u16, events: &[u8]) -> Vec<u8> {
+    let mut file = b"MThd\0\0\0\x06\0\0\0\x01".to_vec();
+    file.extend(division.to_be_bytes());
+    file.extend(b"MTrk");
+    file.extend((events.len() as u32).to_be_bytes());
+    file.extend(events);
+    file
+}
+
+const END: [u8; 4] = [0x00, 0xFF, 0x2F, 0x00];
+
+#[test]
+fn ode_to_joy_header_and_tracks() {
+    let smf = parse(ODE_TO_JOY).unwrap();
+    assert_eq!(smf.format, 1);
+    assert_eq!(smf.division, Division::TicksPerQuarter(480));
+    assert_eq!(smf.track_count(), 3);
+    assert_eq!(smf.note_count(0), 0);
+    assert_eq!(smf.note_count(1), 17);
+    // Drums are not notes
+    assert_eq!(smf.note_count(2), 0);
+    assert_eq!(smf.melody_track(), Some(1));
+    assert!(smf.melody(3, Pick::Highest).is_none());
+}
+
+#[test]
+fn ode_to_joy_tune_follows_the_tempo_track() {
+    let smf = parse(ODE_TO_JOY).unwrap();
+    let tones: Vec<Tone> = smf.melody(1, Pick::Highest).unwrap().collect();
+    let (e, f, g, d, c) = (64, 65, 67, 62, 60);
+    let mut expected: Vec<Tone> = [e, e, f, g, g, f, e, d]
+        .into_iter()
+        .map(|key| tone(key, 500))
+        .collect();
+    // 100 BPM from bar 3
+    expected.extend([c, c, d, e].map(|key| tone(key, 600)));
+    expected.extend([tone(e, 900), tone(d, 300), tone(d, 1200)]);
+    assert_eq!(tones, expected);
+}
+
+#[test]
+fn lowest_pick_follows_the_bass() {
+    let smf = parse(ODE_TO_JOY).unwrap();
+    let tones: Vec<Tone> = smf.melody(1, Pick::Lowest).unwrap().collect();
+    assert_eq!(tones, [tone(48, 4000), tone(43, 4800)]);
+}
+
+#[test]
+fn rests_are_kept_between_notes_only() {
+    let events = [
+        0x83, 0x60, 0x90, 60, 100, // after a silent quarter note
+        0x83, 0x60, 60, 0, // running status note off
+        0x83, 0x60, 62, 100, // a quarter note of rest before
+        0x83, 0x60, 0x80, 62, 0,
+    ];
+    let file = type_0(480, &[&events[..], &[0x87, 0x40], &END[1..]].concat());
+    let smf = parse(&file).unwrap();
+    let tones: Vec<Tone> = smf.melody(0, Pick::Highest).unwrap().collect();
+    assert_eq!(tones, [tone(60, 500), rest(500), tone(62, 500)]);
+}
+
+#[test]
+fn overlapping_notes_keep_the_highest_and_restrikes_split() {
+    let events = [
+        0x00, 0x90, 60, 100, // C
+        0x83, 0x60, 0x90, 67, 100, // G over it
+        0x83, 0x60, 0x90, 67, 100, // G struck again, the first one still held
+        0x83, 0x60, 0x80, 67, 0, // one G off: the other still sounds
+        0x83, 0x60, 0x80, 67, 0, // back to C
+        0x83, 0x60, 0x80, 60, 0,
+    ];
+    let file = type_0(480, &[&events[..], &END].concat());
+    let smf = parse(&file).unwrap();
+    let tones: Vec<Tone> = smf.melody(0, Pick::Highest).unwrap().collect();
+    assert_eq!(
+        tones,
+        [tone(60, 500), tone(67, 500), tone(67, 1000), tone(60, 500)]
+    );
+}
+
+#[test]
+fn a_note_still_held_stops_at_the_end_of_the_track() {
+    // Tempo change in a type 0 file, then a note never released
+    let events = [
+        0x00, 0xFF, 0x51, 0x03, 0x0F, 0x42, 0x40, // 1 s per quarter note
+        0x00, 0x90, 69, 100, 0x81, 0x70, 0xFF, 0x2F, 0x00,
+    ];
+    let file = type_0(96, &events);
+    let tones: Vec<Tone> = parse(&file)
+        .unwrap()
+        .melody(0, Pick::Highest)
+        .unwrap()
+        .collect();
+    assert_eq!(tones, [tone(69, 2500)]);
+}
+
+#[test]
+fn smpte_time_ignores_the_tempo() {
+    // 25 frames per second, 40 ticks per frame: a millisecond per tick
+    let division = u16::from_be_bytes([(-25i8) as u8, 40]);
+    let events = [
+        0x00, 0xFF, 0x51, 0x03, 0x0F, 0x42, 0x40, 0x00, 0x90, 72, 100, 0x87, 0x68, 0x80, 72, 0,
+    ];
+    let file = type_0(division, &[&events[..], &END].concat());
+    let smf = parse(&file).unwrap();
+    assert_eq!(
+        smf.division,
+        Division::Smpte {
+            fps: 25,
+            ticks_per_frame: 40
+        }
+    );
+    let tones: Vec<Tone> = smf.melody(0, Pick::Highest).unwrap().collect();
+    assert_eq!(tones, [tone(72, 1000)]);
+}
+
+#[test]
+fn broken_files_are_reported() {
+    assert_eq!(parse(b"RIFF\0\0\0\x04WAVE").unwrap_err(), Error::NotMidi);
+    let mut type_2 = type_0(480, &END);
+    type_2[9] = 2;
+    assert_eq!(parse(&type_2).unwrap_err(), Error::UnsupportedFormat(2));
+    let mut two_tracks = type_0(480, &END);
+    two_tracks[11] = 2;
+    assert_eq!(parse(&two_tracks).unwrap_err(), Error::MissingTrack(1));
+    let truncated = &ODE_TO_JOY[..ODE_TO_JOY.len() - 10];
+    assert_eq!(
+        parse(truncated).unwrap_err(),
+        Error::TruncatedChunk { offset: 218 }
+    );
+    // A data byte where a status byte has to be, with no running status
+    let file = type_0(480, &[0x00, 60, 100]);
+    assert_eq!(
+        parse(&file).unwrap_err(),
+        Error::MalformedEvent { offset: 22 }
+    );
+    assert_eq!(
+        Error::MalformedEvent { offset: 22 }.to_string(),
+        "malformed event at byte 22"
+    );
+}
+
+#[test]
+fn melodies_become_buzzer_source() {
+    let source = melody_source("JOY", [tone(64, 500), rest(250)]);
+    assert_eq!(
+        source,
+        "pub const JOY: [ToneValue; 2] = [\n    \
+         ToneValue { frequency: 330, duration: 500 },\n    \
+         ToneValue { frequency: 0, duration: 250 },\n];\n"
+    );
+}
+
+#[test]
+fn a_folder_of_files_becomes_one_source() {
+    let source = melodies_source(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures")).unwrap();
+    assert!(source.starts_with(
+        "pub const ODE_TO_JOY: [ToneValue; 15] = [\n    \
+         ToneValue { frequency: 330, duration: 500 },\n"
+    ));
+    assert!(source.ends_with(
+        "pub const MELODIES: [(&str, &[ToneValue]); 1] = [\n    \
+         (\"ode_to_joy\", &ODE_TO_JOY),\n];\n"
+    ));
+    assert!(melodies_source("tests/missing").is_err());
+}
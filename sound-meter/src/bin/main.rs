@@ -0,0 +1,99 @@
+#![no_std]
+#![no_main]
+#![deny(
+    clippy::mem_forget,
+    reason = "mem::forget is generally not safe to do with esp_hal types, especially those \
+    holding buffers for the duration of a data transfer."
+)]
+
+use audio_pipeline::SoundEvent;
+use defmt::info;
+use embassy_executor::Spawner;
+use esp_hal::clock::CpuClock;
+use esp_hal::gpio::{Level, Output, OutputConfig};
+use esp_hal::timer::systimer::SystemTimer;
+use sound_meter::meter_task::sound_meter;
+use sound_meter::EVENTS;
+use {esp_backtrace as _, esp_println as _};
+
+// This creates a default app-descriptor required by the esp-idf bootloader.
+// For more information see: <https://docs.espressif.com/projects/esp-idf/en/stable/esp32/api-reference/system/app_image_format.html#application-description>
+esp_bootloader_esp_idf::esp_app_desc!();
+
+#[esp_hal_embassy::main]
+async fn main(spawner: Spawner) {
+    // generator version: 0.5.0
+
+    let config = esp_hal::Config::default().with_cpu_clock(CpuClock::max());
+    let peripherals = esp_hal::init(config);
+
+    let timer0 = SystemTimer::new(peripherals.SYSTIMER);
+    esp_hal_embassy::init(timer0.alarm0);
+
+    info!("Embassy initialized!");
+
+    #[cfg(not(feature = "adc"))]
+    {
+        use esp_hal::dma_buffers;
+        use esp_hal::i2s::master::{DataFormat, I2s, Standard};
+        use esp_hal::time::Rate;
+        use sound_meter::capture::{i2s_capture, DMA_BUFFER_SIZE};
+        use sound_meter::SAMPLE_RATE;
+
+        // INMP441: SCK on GPIO4, WS on GPIO5, SD on GPIO6, L/R to GND
+        let (rx_buffer, rx_descriptors, _, _) = dma_buffers!(DMA_BUFFER_SIZE, 0);
+        let i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data32Channel32,
+            Rate::from_hz(SAMPLE_RATE),
+            peripherals.DMA_CH0,
+        )
+        .into_async();
+        let i2s_rx = i2s
+            .i2s_rx
+            .with_bclk(peripherals.GPIO4)
+            .with_ws(peripherals.GPIO5)
+            .with_din(peripherals.GPIO6)
+            .build(rx_descriptors);
+
+        // The I2S records into the ring from now on; the capture task owns
+        // the transfer and keeps emptying it
+        let mic = i2s_rx.read_dma_circular_async(rx_buffer).unwrap();
+        spawner.spawn(i2s_capture(mic)).unwrap();
+    }
+
+    #[cfg(feature = "adc")]
+    {
+        use esp_hal::analog::adc::{Adc, AdcCalBasic, AdcConfig, Attenuation};
+        use sound_meter::capture::adc_capture;
+
+        // Analog module: OUT on GPIO2, biased at mid-supply
+        let mut adc_config = AdcConfig::new();
+        let pin = adc_config
+            .enable_pin_with_cal::<_, AdcCalBasic<_>>(peripherals.GPIO2, Attenuation::_11dB);
+        let adc = Adc::new(peripherals.ADC1, adc_config).into_async();
+        spawner.spawn(adc_capture(adc, pin)).unwrap();
+    }
+
+    spawner.spawn(sound_meter()).unwrap();
+
+    // A clap toggles the LED on GPIO7
+    let mut led = Output::new(peripherals.GPIO7, Level::Low, OutputConfig::default());
+    let mut events = EVENTS.subscriber().unwrap();
+
+    loop {
+        let event = events.next_message_pure().await;
+        match event {
+            SoundEvent::Leq { window_ms, db } => {
+                info!("Leq {} ms: {}.{} dB(A)", window_ms, db / 10, db % 10)
+            }
+            SoundEvent::Loud { db } => info!("Loud: {}.{} dB(A)", db / 10, db % 10),
+            SoundEvent::Quiet { ms } => info!("Quiet again after {} ms", ms),
+            SoundEvent::Clap { db } => {
+                info!("Clap: {}.{} dB(A)", db / 10, db % 10);
+                led.toggle();
+            }
+        }
+    }
+}
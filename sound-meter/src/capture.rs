@@ -0,0 +1,100 @@
+//! Microphone capture: an INMP441 on the I2S RX, or an analog module on the
+//! ADC with the `adc` feature. Both send full-scale `i32` samples to `BLOCKS`.
+
+use crate::{Block, BLOCKS, BLOCK_LEN};
+use esp_hal::i2s::master::asynch::I2sReadDmaTransferAsync;
+use esp_println::println;
+
+/// One I2S frame of `Data32Channel32`: the left slot, where an INMP441 with
+/// L/R tied to GND talks, then the right one.
+const FRAME: usize = 8;
+
+/// The DMA ring: 4 descriptors of 4092 bytes, about 64 ms at 16 kHz.
+pub const DMA_BUFFER_SIZE: usize = 4 * 4092;
+
+/// The circular DMA transfer the I2S records into without stopping.
+pub type I2sMic = I2sReadDmaTransferAsync<'static, &'static mut [u8; DMA_BUFFER_SIZE]>;
+
+/// Gathers samples into blocks for the meter task.
+struct Blocks {
+    block: Block,
+    len: usize,
+}
+
+impl Blocks {
+    const fn new() -> Self {
+        Self {
+            block: [0; BLOCK_LEN],
+            len: 0,
+        }
+    }
+
+    async fn push(&mut self, sample: i32) {
+        self.block[self.len] = sample;
+        self.len += 1;
+        if self.len == BLOCK_LEN {
+            self.len = 0;
+            BLOCKS.send(self.block).await;
+        }
+    }
+}
+
+/// Reads the INMP441's left slot out of the DMA ring. The 24-bit samples are
+/// left-aligned in 32-bit words, so they come out at full scale as they are.
+#[embassy_executor::task]
+pub async fn i2s_capture(mut mic: I2sMic) {
+    let mut bytes = [0u8; BLOCK_LEN * FRAME];
+    // Descriptors are 4092 bytes long, so a pop can end inside a frame
+    let mut frame = [0u8; FRAME];
+    let mut frame_len = 0;
+    let mut blocks = Blocks::new();
+
+    loop {
+        let read = match mic.pop(&mut bytes).await {
+            Ok(read) => read,
+            Err(err) => {
+                println!("I2S error: {:?}", err);
+                continue;
+            }
+        };
+        for &byte in &bytes[..read] {
+            frame[frame_len] = byte;
+            frame_len += 1;
+            if frame_len == FRAME {
+                frame_len = 0;
+                let left = i32::from_le_bytes([frame[0], frame[1], frame[2], frame[3]]);
+                blocks.push(left).await;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "adc")]
+pub use adc::{adc_capture, AdcMic};
+
+#[cfg(feature = "adc")]
+mod adc {
+    use super::Blocks;
+    use crate::SAMPLE_RATE;
+    use audio_pipeline::adc_sample;
+    use embassy_time::{Duration, Ticker};
+    use esp_hal::analog::adc::{Adc, AdcCalBasic, AdcPin};
+    use esp_hal::peripherals::{ADC1, GPIO2};
+    use esp_hal::Async;
+
+    /// The module's output on GPIO2, read with the basic calibration.
+    pub type AdcMic = AdcPin<GPIO2<'static>, ADC1<'static>, AdcCalBasic<ADC1<'static>>>;
+
+    /// Reads the ADC at `SAMPLE_RATE`. The module is biased at mid-supply;
+    /// the A-weighting of the meter removes the bias.
+    #[embassy_executor::task]
+    pub async fn adc_capture(mut adc: Adc<'static, ADC1<'static>, Async>, mut pin: AdcMic) {
+        let mut ticker = Ticker::every(Duration::from_hz(SAMPLE_RATE as u64));
+        let mut blocks = Blocks::new();
+        loop {
+            ticker.next().await;
+            let raw = adc.read_oneshot(&mut pin).await;
+            blocks.push(adc_sample(raw)).await;
+        }
+    }
+}
@@ -0,0 +1,29 @@
+#![no_std]
+
+use audio_pipeline::SoundEvent;
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_sync::channel::Channel;
+use embassy_sync::pubsub::PubSubChannel;
+
+pub mod capture;
+pub mod meter_task;
+
+/// Rate the microphone is sampled at. The ADC is read one sample at a time,
+/// so it gets half the rate of the I2S.
+pub const SAMPLE_RATE: u32 = if cfg!(feature = "adc") { 8000 } else { 16_000 };
+
+/// Samples handed from the capture task to the meter task at a time: 16 ms
+/// at 16 kHz.
+pub const BLOCK_LEN: usize = 256;
+
+/// Microphone samples at full scale, oldest first.
+pub type Block = [i32; BLOCK_LEN];
+
+/// Blocks on their way to the meter task. The capture task waits when both
+/// are taken, and the DMA ring keeps recording meanwhile.
+pub static BLOCKS: Channel<CriticalSectionRawMutex, Block, 2> = Channel::new();
+
+/// What the meter task heard, for any task that subscribes. When nobody
+/// reads, the oldest events are dropped.
+pub static EVENTS: PubSubChannel<CriticalSectionRawMutex, SoundEvent, 8, 2, 1> =
+    PubSubChannel::new();
@@ -0,0 +1,24 @@
+use crate::{BLOCKS, EVENTS, SAMPLE_RATE};
+use audio_pipeline::{SoundMeter, INMP441_SENSITIVITY};
+
+/// Sensitivity of the microphone: the INMP441's datasheet value, or for an
+/// analog module a starting point to calibrate against a reference meter.
+const SENSITIVITY: i16 = if cfg!(feature = "adc") {
+    -200
+} else {
+    INMP441_SENSITIVITY
+};
+
+/// Leq windows: a reading every second and one every minute.
+const LEQ_WINDOWS_MS: [u32; 2] = [1000, 60_000];
+
+/// Measures the blocks of `BLOCKS` and publishes what it hears on `EVENTS`.
+#[embassy_executor::task]
+pub async fn sound_meter() {
+    let mut meter = SoundMeter::new(SAMPLE_RATE, SENSITIVITY, LEQ_WINDOWS_MS);
+    let events = EVENTS.immediate_publisher();
+    loop {
+        let block = BLOCKS.receive().await;
+        meter.process(&block, &mut |event| events.publish_immediate(event));
+    }
+}
@@ -1,4 +1,46 @@
+include!("src/sample_rate.rs");
+
 fn main() {
+    // Every file in src/audios becomes an `AudioClip`. WAV clips are resampled
+    // to the I2S rate (`SAMPLE_RATE`) and stored as IMA ADPCM, a
+    // quarter of the flash of 16-bit PCM; MP3 clips are kept as they are.
+    // Ambient clips loop between the markers of their `smpl` chunk, or
+    // between points set here: `.loop_ms("rain", 500, 4500)`
+    let clips = audio_assets::Assets::new("src/audios")
+        .transcode(audio_assets::Transcode {
+            sample_rate: SAMPLE_RATE,
+            mono: true,
+            encoding: audio_assets::Encoding::ImaAdpcm { block_align: 256 },
+        })
+        .generate()
+        .unwrap_or_else(|err| panic!("{err}"));
+
+    // Spoken prompts need a WAV clip for every word they can say
+    if std::env::var_os("CARGO_FEATURE_SPEECH").is_some() {
+        let missing: Vec<String> = audio_pipeline::Word::ALL
+            .iter()
+            .map(|word| format!("speech_{}", word.name()))
+            .filter(|name| {
+                !clips.iter().any(|clip| {
+                    clip.name == *name && clip.info.format == audio_assets::ClipFormat::Wav
+                })
+            })
+            .collect();
+        if !missing.is_empty() {
+            panic!(
+                "the `speech` feature needs these WAV clips in src/audios: {}",
+                missing.join(", ")
+            );
+        }
+    }
+
+    // The buzzer songs, to play them on the speaker as well
+    let songs = "../buzzer-music/songs";
+    println!("cargo:rerun-if-changed={songs}");
+    let melodies = smf::melodies_source(songs).unwrap_or_else(|err| panic!("{songs}: {err}"));
+    let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("melodies.rs");
+    std::fs::write(out, melodies).unwrap();
+
     linker_be_nice();
     println!("cargo:rustc-link-arg=-Tdefmt.x");
     // make sure linkall.x is the last linker script (otherwise might cause problems with flip-link)
@@ -4,88 +4,585 @@
     holding buffers for the duration of a data transfer."
 )]
 
-use crate::audios::{AudioClip, FAIRY_CAUTION, FAIRY_SONG_1, FAIRY_SONG_2, FAIRY_SONG_3, MP3_DATA, TEST_16BITS_8000HZ_MONO, WAV_DATA};
-use crate::CURRENT_AUDIO;
-use defmt::info;
-use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
-use embassy_sync::mutex::Mutex;
-use esp_hal::i2s::master::I2sTx;
-use esp_hal::Blocking;
+use crate::audios::ClipFormat;
+#[cfg(feature = "speech")]
+use crate::SPEECH;
+use crate::{
+    Ambience, AudioClip, PlayerEvent, AMBIENCE, COMMANDS, DMA_BUFFER_SIZE, EVENTS, LEVELS,
+    PROGRESS_INTERVAL_MS, QUEUE_LEN, TUNES,
+};
+#[cfg(feature = "speech")]
+use embassy_futures::select::{select, Either};
+use embassy_futures::select::{select3, select4, Either3, Either4};
+use esp_hal::i2s::master::asynch::I2sWriteDmaTransferAsync;
 use esp_println::{self as _, println};
 
-use crate::AUDIO_TRIGGER;
-use crate::{DMA_BUFFER_SIZE, HEADER_SIZE};
+use crate::SAMPLE_RATE;
+use audio_pipeline::{
+    ClipStream, Command, Event, FilterChain, Gain, LevelMeter, Levels, Limiter, LoopRegion, Mixer,
+    Mp3Stream, OutputFormat, PcmSource, Player, Position, Priority, Quality, RingWriter, State,
+    Tune, VoiceId, MAX_VOLUME,
+};
+#[cfg(feature = "speech")]
+use audio_pipeline::{Sentence, Speech, Word};
+use mp3_decoder::{find_frame, Decoder};
+use static_cell::StaticCell;
 
-// Or whatever size dma_buffers! creates 4 * 4092 * 4
-// static AUDIO_TRIGGER: Signal<CriticalSectionRawMutex, ()> = Signal::new(); // Replace AUDIO_ENABLED
+/// The MP3 decoder state is too big for the task's stack.
+static MP3_DECODER: StaticCell<Decoder> = StaticCell::new();
 
+/// Clips played at once: the player's clip, the one it replaces while that
+/// fades out, an alert, a buzzer tune and a spoken prompt over them, and an
+/// ambient loop under them.
+const VOICES: usize = 6;
+
+/// Smoothing of the levels published on `LEVELS`: a VU meter's quick rise
+/// and slow fall.
+const METER_ATTACK_MS: u32 = 10;
+const METER_RELEASE_MS: u32 = 300;
+
+/// Speaker protection: the lows a small speaker cannot play are cut before
+/// they make it distort, and the peaks are held under -1 dBFS.
+const HIGH_PASS_HZ: u32 = 150;
+const LIMITER_CEILING_DB: i8 = -1;
+const LIMITER_LOOKAHEAD_MS: u32 = 5;
+const LIMITER_RELEASE_MS: u32 = 80;
+
+/// The filters between the mixer and the master volume. Shelves can be
+/// added to suit the speaker, e.g. `.high_shelf(4000, -3)` to tame a
+/// harsh tweeter.
+fn speaker_filters() -> FilterChain {
+    FilterChain::new(SAMPLE_RATE)
+        .dc_blocker()
+        .high_pass(HIGH_PASS_HZ)
+}
+
+/// The circular DMA transfer the I2S plays without stopping.
+pub type I2sRing = I2sWriteDmaTransferAsync<'static, &'static mut [u8; DMA_BUFFER_SIZE]>;
+
+/// Plays what `COMMANDS` asks for and publishes every change on `EVENTS`.
 #[embassy_executor::task]
-pub async fn audio(
-    audio_machine: &'static Mutex<CriticalSectionRawMutex, Option<I2sTx<'static, Blocking>>>,
-    tx_buffer: &'static mut [u8],
-) {
-    // Get current audio selection
+pub async fn audio(ring: I2sRing) {
+    // Lent to the voice playing an MP3 clip, one at a time
+    let mut mp3_decoder = Some(MP3_DECODER.init(Decoder::new()));
+    let mut writer = RingWriter::new(ring, OutputFormat::STEREO_16.frame_size());
+    let mut player: Player<AudioClip, QUEUE_LEN> = Player::new();
+    player.set_progress_interval(PROGRESS_INTERVAL_MS);
+    // The master volume the player keeps, applied after mixing
+    let mut master = Gain::new(SAMPLE_RATE, player.volume());
+    master.fade_in();
+    let mut output = Output {
+        mixer: Mixer::new(OutputFormat::STEREO_16, SAMPLE_RATE),
+        master,
+        filters: speaker_filters(),
+        limiter: Limiter::new(
+            SAMPLE_RATE,
+            LIMITER_CEILING_DB,
+            LIMITER_LOOKAHEAD_MS,
+            LIMITER_RELEASE_MS,
+        ),
+        meter: LevelMeter::new(SAMPLE_RATE, METER_ATTACK_MS, METER_RELEASE_MS),
+    };
+    // The voice of the player's clip, and a jump waiting for its fade-out
+    let mut current: Option<VoiceId> = None;
+    let mut seek: Option<u32> = None;
+    let mut ambient: Option<VoiceId> = None;
+
     loop {
-        AUDIO_TRIGGER.wait().await;
-        let current_audio = {
-            let guard = CURRENT_AUDIO.lock().await;
-            *guard
-        };
+        // Voices that played to their end, or faded out after a stop
+        while let Some((id, source)) = output.mixer.take_finished() {
+            let (clip, alert) = (source.clip, source.alert);
+            if let Some(decoder) = source.into_decoder() {
+                mp3_decoder = Some(decoder);
+            }
+            if ambient == Some(id) {
+                ambient = None;
+            } else if alert {
+                publish(Event::AlertFinished(clip));
+            } else if current == Some(id) {
+                current = None;
+                player.finished(&mut publish);
+            }
+        }
+
+        // Tunes and prompts only need a free voice
+        while let Ok(tune) = TUNES.try_receive() {
+            add_tune(&mut output.mixer, tune);
+        }
+        #[cfg(feature = "speech")]
+        while let Ok(sentence) = SPEECH.try_receive() {
+            add_speech(&mut output.mixer, sentence);
+        }
+        while let Ok(ambience) = AMBIENCE.try_receive() {
+            set_ambience(&mut output.mixer, &mut ambient, ambience, &mut mp3_decoder);
+        }
+
+        if let (Some(id), Some(ms)) = (current, seek) {
+            if output.mixer.is_silent(id) {
+                seek = None;
+                if let Some(source) = output.mixer.source_mut(id) {
+                    source.seek(ms);
+                }
+                if let State::Playing(_) = player.state() {
+                    output.mixer.resume(id);
+                }
+            }
+        }
+
+        // Start the player's clip. An MP3 waits for the decoder to come back
+        // from the clip it replaces.
+        if let (None, State::Playing(clip)) = (current, player.state()) {
+            match open(clip, false, &mut mp3_decoder) {
+                Opened::Ready(source) => {
+                    match output.mixer.add(source, MAX_VOLUME, Priority::Normal) {
+                        Ok(id) => current = Some(id),
+                        Err(source) => {
+                            println!("No voice left for {:?}", clip);
+                            if let Some(decoder) = source.into_decoder() {
+                                mp3_decoder = Some(decoder);
+                            }
+                            player.finished(&mut publish);
+                        }
+                    }
+                }
+                Opened::Busy => {}
+                // Go on with the queue
+                Opened::Failed => player.finished(&mut publish),
+            }
+        }
 
-        println!("{:?}", current_audio);
-        // Select audio clip based on current selection
-        let pcm_data = match current_audio {
-            AudioClip::FairyCaution => &FAIRY_CAUTION[HEADER_SIZE..],
-            AudioClip::WavAudio => &WAV_DATA[HEADER_SIZE..],
-            AudioClip::FairySong1 => &FAIRY_SONG_1[HEADER_SIZE..],
-            AudioClip::FairySong2 => &FAIRY_SONG_2[HEADER_SIZE..],
-            AudioClip::FairySong3 => &FAIRY_SONG_3[HEADER_SIZE..],
-            AudioClip::Test16Bits8000HzMono => &TEST_16BITS_8000HZ_MONO[HEADER_SIZE..],
-            AudioClip::Mp3Data => &MP3_DATA,
-            AudioClip::None => &[],
+        // Commands are checked between pushes, so they take effect within one
+        // DMA descriptor of audio
+        let command = if output.mixer.is_active() {
+            match COMMANDS.try_receive() {
+                Ok(command) => command,
+                Err(_) => {
+                    if let Err(err) = writer.push(&mut output).await {
+                        println!("I2S error: {:?}", err);
+                    }
+                    // The player reports the progress of its clip
+                    let position = current
+                        .and_then(|id| output.mixer.source_mut(id))
+                        .and_then(|source| source.position());
+                    if let Some(position) = position {
+                        player.progress(position, &mut publish);
+                    }
+                    continue;
+                }
+            }
+        } else {
+            // Nothing to play or everything paused: the end of the fade still
+            // in the limiter goes out, then the ring plays silence
+            while !output.limiter.is_drained() {
+                if let Err(err) = writer.push(&mut output).await {
+                    println!("I2S error: {:?}", err);
+                    break;
+                }
+            }
+            output.filters.reset();
+            output.limiter.reset();
+            output.meter.reset();
+            LEVELS
+                .immediate_publisher()
+                .publish_immediate(Levels::SILENT);
+            match next_input(&mut writer).await {
+                Input::Command(command) => command,
+                Input::Tune(tune) => {
+                    add_tune(&mut output.mixer, tune);
+                    continue;
+                }
+                #[cfg(feature = "speech")]
+                Input::Speech(sentence) => {
+                    add_speech(&mut output.mixer, sentence);
+                    continue;
+                }
+                Input::Ambience(ambience) => {
+                    set_ambience(&mut output.mixer, &mut ambient, ambience, &mut mp3_decoder);
+                    continue;
+                }
+            }
         };
 
-        let pcm_len = pcm_data.len();
-        println!("PCM Length: {}", pcm_len);
-        info!("STARTING LOOP FROM AUDIO TASK");
-        // Check if audio playback is enabled based on temperature
+        player.handle(command, &mut |event| {
+            publish(event);
+            // Changes that cut the audio fade it out first
+            match event {
+                Event::Started(_) | Event::Stopped(_) => {
+                    if let Some(id) = current.take() {
+                        // A looping clip plays its release after a stop
+                        let released = matches!(event, Event::Stopped(_))
+                            && output
+                                .mixer
+                                .source_mut(id)
+                                .is_some_and(|source| source.release());
+                        if !released {
+                            output.mixer.stop(id);
+                        }
+                    }
+                    seek = None;
+                }
+                Event::Paused(_) => {
+                    if let Some(id) = current {
+                        output.mixer.pause(id);
+                    }
+                }
+                Event::Resumed(_) => {
+                    // A pending seek resumes once it is done
+                    if let (Some(id), None) = (current, seek) {
+                        output.mixer.resume(id);
+                    }
+                }
+                Event::Seeked(_, ms) => {
+                    if let Some(id) = current {
+                        seek = Some(ms);
+                        output.mixer.pause(id);
+                    }
+                }
+                Event::VolumeChanged(volume) => output.master.set_volume(volume),
+                Event::MuteChanged(muted) => output.master.set_muted(muted),
+                Event::Alert(clip) => {
+                    let added = match open(clip, true, &mut mp3_decoder) {
+                        Opened::Ready(source) => output
+                            .mixer
+                            .add(source, MAX_VOLUME, Priority::Alert)
+                            .map_err(|source| {
+                                if let Some(decoder) = source.into_decoder() {
+                                    mp3_decoder = Some(decoder);
+                                }
+                            })
+                            .is_ok(),
+                        Opened::Busy | Opened::Failed => false,
+                    };
+                    if !added {
+                        println!("Skipping alert {:?}", clip);
+                        publish(Event::AlertFinished(clip));
+                    }
+                }
+                _ => {}
+            }
+        });
+    }
+}
+
+/// What wakes the task up while nothing plays.
+enum Input {
+    Command(Command<AudioClip>),
+    Tune(Tune<'static>),
+    #[cfg(feature = "speech")]
+    Speech(Sentence),
+    Ambience(Ambience),
+}
+
+/// Waits for the next command, tune, prompt or ambience while the ring plays
+/// silence, or it would replay the end of the last clip.
+async fn next_input(writer: &mut RingWriter<I2sRing>) -> Input {
+    let idle = writer.idle();
+    match select4(COMMANDS.receive(), TUNES.receive(), background(), idle).await {
+        Either4::First(command) => Input::Command(command),
+        Either4::Second(tune) => Input::Tune(tune),
+        Either4::Third(input) => input,
+        Either4::Fourth(err) => {
+            println!("I2S error: {:?}", err);
+            match select3(COMMANDS.receive(), TUNES.receive(), background()).await {
+                Either3::First(command) => Input::Command(command),
+                Either3::Second(tune) => Input::Tune(tune),
+                Either3::Third(input) => input,
+            }
+        }
+    }
+}
+
+/// The next prompt or ambience.
+#[cfg(feature = "speech")]
+async fn background() -> Input {
+    match select(SPEECH.receive(), AMBIENCE.receive()).await {
+        Either::First(sentence) => Input::Speech(sentence),
+        Either::Second(ambience) => Input::Ambience(ambience),
+    }
+}
+
+/// The next ambience.
+#[cfg(not(feature = "speech"))]
+async fn background() -> Input {
+    Input::Ambience(AMBIENCE.receive().await)
+}
+
+/// Replaces the ambient loop: the one playing is released, or fades out if
+/// it does not loop, and the new one starts under the other voices.
+fn set_ambience(
+    mixer: &mut Mixer<Source, VOICES>,
+    ambient: &mut Option<VoiceId>,
+    ambience: Ambience,
+    mp3_decoder: &mut Option<&'static mut Decoder>,
+) {
+    if let Some(id) = ambient.take() {
+        if !mixer.source_mut(id).is_some_and(|source| source.release()) {
+            mixer.stop(id);
+        }
+    }
+    let Ambience::Play { clip, for_ms } = ambience else {
+        return;
+    };
+    let mut source = match open(clip, false, mp3_decoder) {
+        Opened::Ready(source) => source,
+        Opened::Busy | Opened::Failed => {
+            println!("Skipping ambience {:?}", clip);
+            return;
+        }
+    };
+    if let Some(ms) = for_ms {
+        source.release_after(ms);
+    }
+    match mixer.add(source, MAX_VOLUME, Priority::Normal) {
+        Ok(id) => *ambient = Some(id),
+        Err(source) => {
+            println!("No voice left for ambience {:?}", clip);
+            if let Some(decoder) = source.into_decoder() {
+                *mp3_decoder = Some(decoder);
+            }
+        }
+    }
+}
+
+/// Mixes `tune` over whatever plays, if a voice is free.
+fn add_tune(mixer: &mut Mixer<Source, VOICES>, tune: Tune<'static>) {
+    let source = Source {
+        stream: Stream::Tune(tune),
+        clip: AudioClip::None,
+        alert: false,
+    };
+    if mixer.add(source, MAX_VOLUME, Priority::Normal).is_err() {
+        println!("No voice left for a tune");
+    }
+}
+
+/// Speaks `sentence` over whatever plays, which is ducked like under an
+/// alert, if a voice is free.
+#[cfg(feature = "speech")]
+fn add_speech(mixer: &mut Mixer<Source, VOICES>, sentence: Sentence) {
+    let source = Source {
+        stream: Stream::Speech(Speech::new(sentence, word_clip, SAMPLE_RATE)),
+        clip: AudioClip::None,
+        alert: false,
+    };
+    if mixer.add(source, MAX_VOLUME, Priority::Alert).is_err() {
+        println!("No voice left for {:?}", sentence.words());
+    }
+}
+
+/// The recording of `word`: the WAV clip `speech_<word>` of `src/audios`,
+/// which `build.rs` checks is there.
+#[cfg(feature = "speech")]
+fn word_clip(word: Word) -> Option<ClipStream<'static>> {
+    let clip = AudioClip::ALL
+        .iter()
+        .find(|clip| clip.name().strip_prefix("speech_") == Some(word.name()))?;
+    if !matches!(clip.info()?.format, ClipFormat::Wav) {
+        println!("Skipping {:?}: words are WAV clips", clip);
+        return None;
+    }
+    wav_parser::parse(clip.data())
+        .and_then(|wav| ClipStream::new(&wav, OutputFormat::STEREO_16, SAMPLE_RATE, Quality::Sinc))
+        .map_err(|err| println!("Skipping {:?}: {}", clip, err))
+        .ok()
+}
 
-        println!("Temperature condition met. Starting audio playback...");
+fn publish(event: PlayerEvent) {
+    println!("{:?}", event);
+    EVENTS.immediate_publisher().publish_immediate(event);
+}
 
-        let mut offset = 0;
-        // Play the entire audio clip in chunks
-        while offset < pcm_len {
-            let chunk_size = core::cmp::min(DMA_BUFFER_SIZE, pcm_len - offset);
-            println!("offset: {offset}");
+/// What [`open`] made of a clip.
+enum Opened {
+    Ready(Source),
+    /// An MP3 clip while the decoder is still lent to another voice.
+    Busy,
+    Failed,
+}
 
-            // Copy PCM data to the DMA buffer
-            tx_buffer[..chunk_size].copy_from_slice(&pcm_data[offset..offset + chunk_size]);
+/// Parses `clip` and sets up its decoder, or says why it can't be played.
+fn open(clip: AudioClip, alert: bool, mp3_decoder: &mut Option<&'static mut Decoder>) -> Opened {
+    // The build script checked every clip and recorded how it is stored
+    let Some(clip_info) = clip.info() else {
+        return Opened::Failed;
+    };
+    let clip_data = clip.data();
+    println!("Clip: {:?}", clip_info);
 
-            // Zero-pad the rest of the buffer if necessary
-            if chunk_size < DMA_BUFFER_SIZE {
-                tx_buffer[chunk_size..].fill(0);
+    // The WAV header is not always 44 bytes: play exactly the data chunk,
+    // converted to the 16-bit stereo frames the I2S was configured for and
+    // resampled to its clock, so every clip plays at its own pitch
+    let stream = match clip_info.format {
+        // Decoded frame by frame to 16-bit stereo, then resampled the same way
+        ClipFormat::Mp3 => {
+            // Checked before lending the decoder, which a failed start would keep
+            if let Err(err) = find_frame(clip_data) {
+                println!("Skipping {:?}: {}", clip, err);
+                return Opened::Failed;
             }
+            let Some(decoder) = mp3_decoder.take() else {
+                return Opened::Busy;
+            };
+            match Mp3Stream::new(
+                clip_data,
+                decoder,
+                OutputFormat::STEREO_16,
+                SAMPLE_RATE,
+                Quality::Sinc,
+            ) {
+                Ok(stream) => Stream::Mp3(stream),
+                Err(_) => unreachable!("the first frame was found above"),
+            }
+        }
+        ClipFormat::Wav => match wav_parser::parse(clip_data).and_then(|wav| {
+            ClipStream::new(&wav, OutputFormat::STEREO_16, SAMPLE_RATE, Quality::Sinc)
+        }) {
+            Ok(mut stream) => {
+                // Alerts play once, whatever the clip
+                if let (Some(region), false) = (clip_info.loop_region, alert) {
+                    stream.set_loop(LoopRegion {
+                        start: region.start,
+                        end: region.end,
+                    });
+                }
+                Stream::Clip(stream)
+            }
+            Err(err) => {
+                println!("Skipping {:?}: {}", clip, err);
+                return Opened::Failed;
+            }
+        },
+    };
+    Opened::Ready(Source {
+        stream,
+        clip,
+        alert,
+    })
+}
 
-            // Perform the DMA transfer
-            let mut transfer_guard = audio_machine.lock().await;
-            if let Some(i2s_tx) = transfer_guard.as_mut() {
-                // Start transfer and wait for completion
-                i2s_tx.write_dma(&tx_buffer).unwrap().is_done();
+/// A clip or a tune playing in a voice of the mixer.
+struct Source {
+    stream: Stream,
+    /// `AudioClip::None` for a tune or a prompt.
+    clip: AudioClip,
+    /// Played over the player's clip, outside its state machine.
+    alert: bool,
+}
+
+enum Stream {
+    /// A WAV clip, converted and resampled to the I2S format.
+    Clip(ClipStream<'static>),
+    /// An MP3 clip, decoded, then converted and resampled like a WAV clip.
+    Mp3(Mp3Stream<'static>),
+    /// A buzzer song, rendered at the I2S rate.
+    Tune(Tune<'static>),
+    /// A spoken prompt, one WAV clip per word.
+    #[cfg(feature = "speech")]
+    Speech(Speech<ClipStream<'static>>),
+}
+
+impl Source {
+    fn seek(&mut self, ms: u32) {
+        match &mut self.stream {
+            Stream::Clip(stream) => stream.seek(ms),
+            Stream::Mp3(stream) => stream.seek(ms),
+            // Never the player's clip
+            Stream::Tune(_) => {}
+            #[cfg(feature = "speech")]
+            Stream::Speech(_) => {}
+        }
+    }
+
+    /// Where the clip is, from its frames and sample rate. An MP3 clip takes
+    /// its length from the build script.
+    fn position(&self) -> Option<Position> {
+        match &self.stream {
+            Stream::Clip(stream) => Some(Position {
+                ms: stream.position_ms(),
+                duration_ms: stream.duration_ms(),
+            }),
+            Stream::Mp3(stream) => Some(Position {
+                ms: stream.position_ms(),
+                duration_ms: self.clip.info().map_or(0, |info| info.duration_ms),
+            }),
+            // Never the player's clip
+            Stream::Tune(_) => None,
+            #[cfg(feature = "speech")]
+            Stream::Speech(_) => None,
+        }
+    }
+
+    /// Lets a looping clip play on to its end. `false` if it does not loop.
+    fn release(&mut self) -> bool {
+        match &mut self.stream {
+            Stream::Clip(stream) if stream.is_looping() => {
+                stream.release();
+                true
             }
-            // Release the lock as soon as possible
-            drop(transfer_guard);
+            _ => false,
+        }
+    }
 
-            offset += chunk_size;
+    /// Releases a looping clip once it has played for `ms`.
+    fn release_after(&mut self, ms: u32) {
+        if let Stream::Clip(stream) = &mut self.stream {
+            stream.release_after(ms);
+        }
+    }
 
-            // Optional: Small delay between chunks if needed
-            // Timer::after_micros(10).await;
+    /// The MP3 decoder this clip borrowed, if it did.
+    fn into_decoder(self) -> Option<&'static mut Decoder> {
+        match self.stream {
+            Stream::Clip(_) | Stream::Tune(_) => None,
+            #[cfg(feature = "speech")]
+            Stream::Speech(_) => None,
+            Stream::Mp3(stream) => Some(stream.into_decoder()),
         }
-        println!("Audio playback finished for this loop.");
-        // Optional: Add a small delay before checking the condition again
-        // to avoid playing back-to-back immediately if the clip is short.
-        // Timer::after_millis(100).await;
+    }
+}
+
+impl PcmSource for Source {
+    fn fill(&mut self, tx_buffer: &mut [u8]) -> usize {
+        match &mut self.stream {
+            Stream::Clip(stream) => stream.fill(tx_buffer),
+            Stream::Mp3(stream) => stream.fill(tx_buffer),
+            Stream::Tune(tune) => tune.fill(tx_buffer),
+            #[cfg(feature = "speech")]
+            Stream::Speech(speech) => speech.fill(tx_buffer),
+        }
+    }
+}
+
+/// What goes into the DMA ring: every voice mixed, filtered for the speaker,
+/// then the master volume and the limiter. Its levels are published on
+/// `LEVELS` as it goes.
+struct Output {
+    mixer: Mixer<Source, VOICES>,
+    filters: FilterChain,
+    master: Gain,
+    limiter: Limiter,
+    meter: LevelMeter,
+}
 
-        // Small delay at the end of the loop to prevent excessive checking
-        // Timer::after_millis(100).await;
+impl PcmSource for Output {
+    fn fill(&mut self, tx_buffer: &mut [u8]) -> usize {
+        let written = self.mixer.fill(tx_buffer);
+        self.filters
+            .apply(&mut tx_buffer[..written], OutputFormat::STEREO_16);
+        self.master
+            .apply(&mut tx_buffer[..written], OutputFormat::STEREO_16);
+        self.limiter
+            .apply(&mut tx_buffer[..written], OutputFormat::STEREO_16);
+        // Once the voices stop, the end of their audio is still in the
+        // limiter's delay and follows them out
+        let written = written
+            + self
+                .limiter
+                .flush(&mut tx_buffer[written..], OutputFormat::STEREO_16);
+        let levels = self
+            .meter
+            .measure(&tx_buffer[..written], OutputFormat::STEREO_16);
+        LEVELS.immediate_publisher().publish_immediate(levels);
+        written
     }
 }
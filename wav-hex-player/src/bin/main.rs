@@ -8,20 +8,27 @@
 
 use defmt::info;
 use embassy_executor::Spawner;
-use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
-use embassy_sync::mutex::Mutex;
 use embassy_time::Timer;
+#[cfg(feature = "speech")]
+use embassy_time::{with_timeout, Duration};
 use esp_hal::analog::adc::{Adc, AdcCalBasic, AdcCalLine, AdcConfig, Attenuation};
 use esp_hal::clock::CpuClock;
 use esp_hal::dma_buffers;
-use esp_hal::i2s::master::I2sTx;
+use esp_hal::gpio::{DriveStrength, Level, Output, OutputConfig};
 use esp_hal::i2s::master::{DataFormat, I2s, Standard};
 use esp_hal::rng::Rng;
 use esp_hal::time::Rate;
 use esp_hal::timer::systimer::SystemTimer;
-use esp_hal::Blocking;
+use portable_atomic::{AtomicU8, Ordering};
 use wav_hex_player::audio_task::audio;
-use wav_hex_player::{AudioClip, AUDIO_TRIGGER, CURRENT_AUDIO, DMA_BUFFER_SIZE};
+use audio_pipeline::{Command, Event, Mode, Playlist, Timbre, Tune, SAVED_HEADER};
+#[cfg(feature = "speech")]
+use audio_pipeline::Sentence;
+use wav_hex_player::melodies::MELODIES;
+use wav_hex_player::vu_meter::vu_meter;
+use wav_hex_player::{AudioClip, COMMANDS, DMA_BUFFER_SIZE, EVENTS, SAMPLE_RATE, TUNES};
+#[cfg(feature = "speech")]
+use wav_hex_player::{MOISTURE_PHRASE, SPEECH};
 
 #[panic_handler]
 fn panic(_: &core::panic::PanicInfo) -> ! {
@@ -32,10 +39,40 @@ fn panic(_: &core::panic::PanicInfo) -> ! {
 // For more information see: <https://docs.espressif.com/projects/esp-idf/en/stable/esp32/api-reference/system/app_image_format.html#application-description>
 esp_bootloader_esp_idf::esp_app_desc!();
 
-/// Code from https://github.com/rp-rs/rp-hal-boards/blob/main/boards/rp-pico/examples/pico_spi_sd_card.rs
+/// Songs the playlist can hold; the others are never played.
+const MAX_SONGS: usize = 16;
+
+/// Played when the soil is dry. A named clip, so the build fails without
+/// `src/audios/fairy_caution.wav`.
+const CAUTION: AudioClip = AudioClip::FairyCaution;
+
+const SAVED_LEN: usize = SAVED_HEADER + MAX_SONGS;
+
+/// The playlist, kept in RTC memory so a reset resumes the round where it was.
+/// Atomics, so it is shared without `unsafe` and any bit pattern is valid.
+#[esp_hal::ram(rtc_fast, persistent)]
+static SAVED_PLAYLIST: [AtomicU8; SAVED_LEN] = [const { AtomicU8::new(0) }; SAVED_LEN];
+
+fn save_playlist(playlist: &Playlist<MAX_SONGS>) {
+    let mut saved = [0; SAVED_LEN];
+    playlist.save(&mut saved);
+    for (byte, value) in SAVED_PLAYLIST.iter().zip(saved) {
+        byte.store(value, Ordering::Relaxed);
+    }
+}
 
-static AUDIO_MACHINE: Mutex<CriticalSectionRawMutex, Option<I2sTx<'static, Blocking>>> =
-    Mutex::new(None);
+fn saved_playlist() -> [u8; SAVED_LEN] {
+    SAVED_PLAYLIST.each_ref().map(|byte| byte.load(Ordering::Relaxed))
+}
+
+/// The moisture reading as a percentage: the sensor reads higher the drier
+/// the soil.
+#[cfg(feature = "speech")]
+fn moisture_percent(reading: u16) -> i32 {
+    (4095 - reading.min(4095) as i32) * 100 / 4095
+}
+
+/// Code from https://github.com/rp-rs/rp-hal-boards/blob/main/boards/rp-pico/examples/pico_spi_sd_card.rs
 
 #[esp_hal_embassy::main]
 async fn main(spawner: Spawner) {
@@ -60,20 +97,21 @@ async fn main(spawner: Spawner) {
         peripherals.I2S0,
         Standard::Philips,
         DataFormat::Data16Channel16,
-        Rate::from_hz(11025),
+        Rate::from_hz(SAMPLE_RATE),
         dma_channel,
-    );
+    )
+    .into_async();
     let i2s = i2s.with_mclk(peripherals.GPIO5); // MCLK not used but required by driver
-    let i2s_tx: esp_hal::i2s::master::I2sTx<'_, esp_hal::Blocking> = i2s
+    let i2s_tx = i2s
         .i2s_tx
         .with_bclk(peripherals.GPIO21)
         .with_ws(peripherals.GPIO20)
         .with_dout(peripherals.GPIO10)
         .build(tx_descriptors);
 
-    {
-        *(AUDIO_MACHINE.lock().await) = Some(i2s_tx);
-    }
+    // The I2S loops over the (zeroed) buffer from now on; the audio task
+    // owns the transfer and keeps refilling it
+    let ring = i2s_tx.write_dma_circular_async(tx_buffer).unwrap();
 
     let mut adc_config = AdcConfig::new();
     let mut light_pin =
@@ -82,15 +120,35 @@ async fn main(spawner: Spawner) {
         adc_config.enable_pin_with_cal::<_, AdcCalBasic<_>>(peripherals.GPIO0, Attenuation::_11dB);
     let mut adc = Adc::new(peripherals.ADC1, adc_config);
 
-    spawner.spawn(audio(&AUDIO_MACHINE, tx_buffer)).unwrap();
+    spawner.spawn(audio(ring)).unwrap();
+
+    // VU meter of the speaker, wired like simple-voltmeter's bar: green on
+    // GPIO2, 3 and 4, yellow on GPIO6 and 7, red on GPIO8
+    let led_config = OutputConfig::default().with_drive_strength(DriveStrength::_5mA);
+    let leds = [
+        Output::new(peripherals.GPIO2, Level::Low, led_config),
+        Output::new(peripherals.GPIO3, Level::Low, led_config),
+        Output::new(peripherals.GPIO4, Level::Low, led_config),
+        Output::new(peripherals.GPIO6, Level::Low, led_config),
+        Output::new(peripherals.GPIO7, Level::Low, led_config),
+        Output::new(peripherals.GPIO8, Level::Low, led_config),
+    ];
+    spawner.spawn(vu_meter(leds)).unwrap();
 
     let mut prev_moisture: Option<u16> = None;
      // Track previous state
-    let songs = [
-        AudioClip::FairySong1,
-        AudioClip::FairySong2,
-        AudioClip::FairySong3
-    ];
+    // Clips come from the files in src/audios: all but the caution and the
+    // spoken words are songs
+    let songs = AudioClip::ALL
+        .iter()
+        .filter(|&&clip| clip != CAUTION && !clip.name().starts_with("speech_"));
+    let song_count = songs.clone().count().min(MAX_SONGS);
+    let song = |track: usize| *songs.clone().nth(track).unwrap();
+    // Shuffled, so a song does not come back before the others have played
+    let mut playlist = Playlist::<MAX_SONGS>::restore(&saved_playlist(), song_count, rng.random())
+        .unwrap_or_else(|| Playlist::new(song_count, Mode::Shuffle, rng.random()));
+    let mut events = EVENTS.subscriber().unwrap();
+    let mut playing = false;
 
     loop {
         info!("READING LIGHT DATA");
@@ -104,18 +162,38 @@ async fn main(spawner: Spawner) {
 
         let is_dry = moisture_data > 3200;
 
+        // Watered: a buzzer song on the speaker, over whatever plays
+        if !is_dry && prev_moisture.is_some_and(|prev| prev > 3200) {
+            if let Some(&(name, song)) = MELODIES.first() {
+                TUNES.send(Tune::new(song, Timbre::Bell, SAMPLE_RATE)).await;
+                info!("THANKS FOR THE WATER: {}", name);
+            }
+        }
+
         // Handle dry condition only when there's light
         if light_data < 2800 && is_dry {
             // Check state transition to dry
             if prev_moisture.map(|prev| prev <= 3200).unwrap_or(true) {
-                // Set audio to fairy caution for dry condition
+                // Play fairy caution for dry condition over the music, which is ducked
+                #[cfg(feature = "speech")]
+                let mut alert_events = EVENTS.subscriber().unwrap();
+                COMMANDS.send(Command::Alert(CAUTION)).await;
+                info!("COMMAND SENT");
+                info!("Plant needs water (value: {})", moisture_data);
+
+                // Then say how dry the soil is, once the caution is over
+                #[cfg(feature = "speech")]
                 {
-                    let mut guard = CURRENT_AUDIO.lock().await;
-                    *guard = AudioClip::FairyCaution;
+                    let caution_over = async {
+                        while alert_events.next_message_pure().await
+                            != Event::AlertFinished(CAUTION)
+                        {}
+                    };
+                    if with_timeout(Duration::from_secs(30), caution_over).await.is_ok() {
+                        let percent = moisture_percent(moisture_data);
+                        SPEECH.send(Sentence::build(&MOISTURE_PHRASE, percent)).await;
+                    }
                 }
-                AUDIO_TRIGGER.signal(());
-                info!("SIGNAL SENT");
-                info!("Plant needs water (value: {})", moisture_data);
             }
 
             // Continue dry-condition handling while light is present and still dry
@@ -137,29 +215,34 @@ async fn main(spawner: Spawner) {
                     continue_dry_loop = false;
                 } else {
                     // Continue playing dry audio
-                    {
-                        let mut guard = CURRENT_AUDIO.lock().await;
-                        *guard = AudioClip::FairyCaution;
-                    }
-                    AUDIO_TRIGGER.signal(());
-                    info!("SIGNAL SENT");
+                    COMMANDS.send(Command::Alert(CAUTION)).await;
+                    info!("COMMAND SENT");
                     info!("Plant needs water (value: {})", moisture_data);
                 }
             }
         }
 
-        if light_data < 2800 {
-            let random_index = (rng.random() as usize) % songs.len();
-            let selected_song = songs[random_index];
-            
-            {
-                let mut guard = CURRENT_AUDIO.lock().await;
-                *guard = selected_song;
+        // Follow the player, so a song is not cut by the next one
+        while let Some(event) = events.try_next_message_pure() {
+            match event {
+                Event::Started(_) => playing = true,
+                Event::Finished(clip) => {
+                    playing = false;
+                    if playlist.current().map(song) == Some(clip) {
+                        playlist.finished();
+                        save_playlist(&playlist);
+                    }
+                }
+                Event::Stopped(_) => playing = false,
+                _ => {}
             }
-            AUDIO_TRIGGER.signal(());
-            info!("FAIRY IS SINGING RANDOM SONG: {}", random_index);
+        }
 
-            
+        if light_data < 2800 && !playing {
+            if let Some(track) = playlist.current() {
+                COMMANDS.send(Command::Enqueue(song(track))).await;
+                info!("FAIRY IS SINGING SONG: {}", track);
+            }
         }
 
         prev_moisture = Some(moisture_data); // Update state
@@ -1,20 +1,91 @@
 #![no_std]
 
+use audio_pipeline::{Command, Event, Levels, Tune};
+#[cfg(feature = "speech")]
+use audio_pipeline::{Part, Sentence, Word};
 use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
-use embassy_sync::mutex::Mutex;
-use embassy_sync::signal::Signal;
+use embassy_sync::channel::Channel;
+use embassy_sync::pubsub::PubSubChannel;
 
 pub mod audio_task;
 pub mod audios;
+pub mod melodies;
+pub mod vu_meter;
 
 pub use audios::AudioClip;
 
-pub static CURRENT_AUDIO: Mutex<CriticalSectionRawMutex, AudioClip> = Mutex::new(AudioClip::None); // Default to fairy audio
-
-pub const HEADER_SIZE: usize = 44;
-pub const DMA_BUFFER_SIZE: usize = 65472;
-
-pub static AUDIO_TRIGGER: Signal<CriticalSectionRawMutex, ()> = Signal::new();
+/// The DMA ring: 8 descriptors of 4092 bytes, about 0.74 s of 16-bit stereo
+/// at `SAMPLE_RATE`. A new clip starts after the silence already queued in it.
+pub const DMA_BUFFER_SIZE: usize = 8 * 4092;
+
+include!("sample_rate.rs");
+
+pub type PlayerCommand = Command<AudioClip>;
+pub type PlayerEvent = Event<AudioClip>;
+
+/// Clips the audio task can queue behind the one playing.
+pub const QUEUE_LEN: usize = 4;
+
+/// How often the audio task publishes `Event::Progress` for the playing
+/// clip, in milliseconds of it. 0 turns the reports off.
+pub const PROGRESS_INTERVAL_MS: u32 = 1000;
+
+/// Commands for the audio task. Unlike a `Signal`, a command sent during
+/// playback is neither merged with another one nor lost: it waits its turn.
+pub static COMMANDS: Channel<CriticalSectionRawMutex, PlayerCommand, 4> = Channel::new();
+
+/// Buzzer songs for the audio task to render and mix over the clips. They
+/// play outside the player's state machine, like alerts without the ducking.
+pub static TUNES: Channel<CriticalSectionRawMutex, Tune<'static>, 2> = Channel::new();
+
+/// Spoken prompts for the audio task, played over the clips like alerts.
+/// Each word is the clip `speech_<word>` of `src/audios`, which the `speech`
+/// feature does not build without.
+#[cfg(feature = "speech")]
+pub static SPEECH: Channel<CriticalSectionRawMutex, Sentence, 2> = Channel::new();
+
+/// Background loops for the audio task, mixed under everything else. A clip
+/// with a loop region (its `smpl` chunk, or `loop_ms` in `build.rs`) loops
+/// until it is stopped or for `for_ms`, then plays the rest of the clip.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ambience {
+    Play {
+        clip: AudioClip,
+        for_ms: Option<u32>,
+    },
+    Stop,
+}
+
+pub static AMBIENCE: Channel<CriticalSectionRawMutex, Ambience, 2> = Channel::new();
+
+/// "soil moisture is forty two percent"
+#[cfg(feature = "speech")]
+pub const MOISTURE_PHRASE: [Part; 5] = [
+    Part::Word(Word::Soil),
+    Part::Word(Word::Moisture),
+    Part::Word(Word::Is),
+    Part::Number,
+    Part::Word(Word::Percent),
+];
+
+/// "temperature is twenty three point five degrees", for a reading of
+/// `ntn-resistor-temp`'s thermistor with `Number::from_f64(celsius, 1)`.
+#[cfg(feature = "speech")]
+pub const TEMPERATURE_PHRASE: [Part; 4] = [
+    Part::Word(Word::Temperature),
+    Part::Word(Word::Is),
+    Part::Number,
+    Part::Word(Word::Degrees),
+];
+
+/// What the audio task did, for any task that subscribes. When nobody reads,
+/// the oldest events are dropped.
+pub static EVENTS: PubSubChannel<CriticalSectionRawMutex, PlayerEvent, 8, 2, 1> =
+    PubSubChannel::new();
+
+/// Levels of what the audio task sends to the I2S, after every push, and
+/// `Levels::SILENT` when it goes idle. Only the latest ones matter.
+pub static LEVELS: PubSubChannel<CriticalSectionRawMutex, Levels, 2, 1, 1> = PubSubChannel::new();
 
 // // Fill DMA buffer with a stereo square wave at a given frequency
 // fn fill_square_wave(buffer: &mut [u8], freq_hz: u32, sample_rate: u32) {
@@ -38,96 +109,3 @@ pub static AUDIO_TRIGGER: Signal<CriticalSectionRawMutex, ()> = Signal::new();
 //         i += 1;
 //     }
 // }
-
-// // fn fill_from_wav(buffer: &mut [u8]) {
-// //     const HEADER_SIZE: usize = 44; // Standard PCM header size
-// //     let pcm_data = &WAV_DATA[HEADER_SIZE..];
-
-// //     // Copy WAV data into DMA buffer (wrap if buffer bigger)
-// //     for (i, byte) in buffer.iter_mut().enumerate() {
-// //         *byte = pcm_data[i % pcm_data.len()];
-// //     }
-// // }
-
-// fn fill_from_wav(buffer: &mut [u8]) {
-//     const HEADER_SIZE: usize = 44; // Standard PCM header size for simple WAVs
-//     // Ensure WAV_DATA is large enough to contain a header
-//     if WAV_DATA.len() < HEADER_SIZE {
-//         // Handle error or fill with silence
-//         buffer.fill(0);
-//         return;
-//     }
-
-//     // Get the raw sample data (assuming 16-bit stereo PCM)
-//     let raw_pcm_data: &[u8] = &WAV_DATA[HEADER_SIZE..];
-
-//     // Check if buffer and data lengths are compatible (ideally, buffer should be a multiple or vice-versa)
-//     let data_len = raw_pcm_data.len();
-//     if data_len == 0 {
-//          buffer.fill(0);
-//         return;
-//     }
-
-//     // Copy PCM data, wrapping if buffer is larger, truncating if data is larger
-//     // This assumes raw_pcm_data contains interleaved L/R 16-bit samples as bytes
-//     // e.g., [L0_low, L0_high, R0_low, R0_high, L1_low, L1_high, ...]
-//     // And buffer expects the same format.
-//     // for (i, byte) in buffer.iter_mut().enumerate() {
-//     //     *byte = raw_pcm_data[i % data_len];
-//     // }
-
-//     // Optional: If the DMA buffer is significantly larger and you want true looping
-//     // without byte-level wrapping artifacts, calculate how many full loops fit:
-
-//     let full_copies = buffer.len() / data_len;
-//     let remainder = buffer.len() % data_len;
-
-//     for i in 0..full_copies {
-//         let start_idx = i * data_len;
-//         let end_idx = start_idx + data_len;
-//         buffer[start_idx..end_idx].copy_from_slice(raw_pcm_data);
-//     }
-//     // Copy the remainder
-//     buffer[full_copies * data_len..].copy_from_slice(&raw_pcm_data[..remainder]);
-
-// }
-
-// // Example for 16-bit Mono -> Stereo (simplified logic)
-// fn fill_from_wav_mono_to_stereo(buffer: &mut [u8]) {
-//     const HEADER_SIZE: usize = 44;
-//     if WAV_DATA.len() < HEADER_SIZE {
-//         buffer.fill(0);
-//         return;
-//     }
-
-//     let raw_mono_data: &[u8] = &WAV_DATA[HEADER_SIZE..];
-//     let mono_sample_count = raw_mono_data.len() / 2; // Each sample is 2 bytes
-
-//     if mono_sample_count == 0 {
-//         buffer.fill(0);
-//         return;
-//     }
-
-//     let mut buffer_idx = 0;
-//     let mut mono_idx = 0;
-//     while buffer_idx < buffer.len().saturating_sub(3) && mono_idx < raw_mono_data.len().saturating_sub(1) {
-//         let sample_byte_0 = raw_mono_data[mono_idx];
-//         let sample_byte_1 = raw_mono_data[mono_idx + 1];
-
-//         // Write sample to Left channel (L)
-//         buffer[buffer_idx] = sample_byte_0;
-//         buffer[buffer_idx + 1] = sample_byte_1;
-//         // Write same sample to Right channel (R)
-//         buffer[buffer_idx + 2] = sample_byte_0;
-//         buffer[buffer_idx + 3] = sample_byte_1;
-
-//         buffer_idx += 4; // Move to next stereo frame in buffer
-//         mono_idx += 2;   // Move to next mono sample in data
-//     }
-
-//     // Fill remaining bytes with silence if any
-//     while buffer_idx < buffer.len() {
-//         buffer[buffer_idx] = 0;
-//         buffer_idx += 1;
-//     }
-// }
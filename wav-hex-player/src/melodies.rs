@@ -0,0 +1,6 @@
+//! The buzzer songs of `buzzer-music/songs`, converted by `build.rs`: one
+//! `const` per file and `MELODIES`, ready for a [`Tune`](audio_pipeline::Tune).
+
+use audio_pipeline::Tone as ToneValue;
+
+include!(concat!(env!("OUT_DIR"), "/melodies.rs"));
@@ -0,0 +1,5 @@
+// Included by both `build.rs`, which transcodes the clips to this rate, and
+// `lib.rs`, which runs the I2S at it, so the two cannot drift apart.
+
+/// I2S sample rate. Clips recorded at other rates are resampled to it.
+pub const SAMPLE_RATE: u32 = 11025;
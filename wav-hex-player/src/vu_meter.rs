@@ -0,0 +1,35 @@
+use crate::LEVELS;
+use audio_pipeline::{LedBar, Levels, VU_THRESHOLDS};
+use embassy_futures::select::{select, Either};
+use embassy_time::{Duration, Instant, Timer};
+use esp_hal::gpio::{Level, Output};
+
+/// How long the LED of the highest peak stays lit.
+const PEAK_HOLD_MS: u32 = 1000;
+
+/// Refresh when no levels come, so a held peak still goes out.
+const REFRESH: Duration = Duration::from_millis(50);
+
+/// Shows the levels of `LEVELS` on a bar of six LEDs, lowest first: three
+/// green, two yellow and a red one for a clipping output.
+#[embassy_executor::task]
+pub async fn vu_meter(mut leds: [Output<'static>; 6]) {
+    let mut levels_rx = LEVELS.subscriber().unwrap();
+    let mut bar = LedBar::new(VU_THRESHOLDS, PEAK_HOLD_MS);
+    let mut levels = Levels::SILENT;
+    let mut last = Instant::now();
+
+    loop {
+        if let Either::First(new) =
+            select(levels_rx.next_message_pure(), Timer::after(REFRESH)).await
+        {
+            levels = new;
+        }
+        let now = Instant::now();
+        let lit = bar.update(levels, (now - last).as_millis() as u32);
+        last = now;
+        for (led, on) in leds.iter_mut().zip(lit) {
+            led.set_level(if on { Level::High } else { Level::Low });
+        }
+    }
+}
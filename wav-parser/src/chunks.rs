@@ -0,0 +1,189 @@
+use crate::{le_u32, ChunkId, Error};
+
+/// One chunk of a RIFF file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Chunk<'a> {
+    /// Four-character chunk identifier, e.g. `*b"fmt "`.
+    pub id: ChunkId,
+    /// Offset of the chunk body from the start of the walked buffer.
+    pub offset: usize,
+    /// Body size as declared in the chunk header.
+    pub size: u32,
+    /// The body bytes that are actually present in the buffer.
+    pub body: &'a [u8],
+}
+
+impl Chunk<'_> {
+    /// `false` when the buffer ends before the declared end of the body.
+    pub fn is_complete(&self) -> bool {
+        self.body.len() as u64 == self.size as u64
+    }
+}
+
+/// Iterator over the chunks of a RIFF file (or over the sub-chunks of a `LIST`).
+///
+/// The walk honours the RIFF word alignment (odd-sized bodies are followed by a
+/// pad byte) and stops after the first chunk that runs past the end of the
+/// buffer, yielding that chunk with a short body so callers that only have the
+/// start of a file can still find where the audio data begins.
+#[derive(Clone, Debug)]
+pub struct Chunks<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    end: usize,
+    done: bool,
+}
+
+impl<'a> Chunks<'a> {
+    /// Checks the 12-byte `RIFF....WAVE` header and walks the chunks after it.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, Error> {
+        if bytes.get(0..4) != Some(b"RIFF") {
+            return Err(Error::NotRiff);
+        }
+        if bytes.len() < 12 {
+            return Err(Error::TruncatedHeader { offset: 0 });
+        }
+        if &bytes[8..12] != b"WAVE" {
+            return Err(Error::NotWave);
+        }
+
+        // Streaming writers leave the RIFF size at 0 or 0xFFFFFFFF, and a header
+        // prefix is naturally shorter than the size it declares: in both cases
+        // walk whatever we were given.
+        let riff_size = le_u32(bytes, 4) as usize;
+        let end = match riff_size.checked_add(8) {
+            Some(end) if riff_size >= 4 && end <= bytes.len() => end,
+            _ => bytes.len(),
+        };
+        Ok(Self::within(bytes, 12, end))
+    }
+
+    /// Walks the chunks stored in `bytes[start..end]`.
+    pub(crate) fn within(bytes: &'a [u8], start: usize, end: usize) -> Self {
+        Self {
+            bytes,
+            pos: start,
+            end,
+            done: false,
+        }
+    }
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = Result<Chunk<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.pos >= self.end {
+            return None;
+        }
+        if self.end - self.pos < 8 {
+            self.done = true;
+            return Some(Err(Error::TruncatedHeader { offset: self.pos }));
+        }
+
+        let mut id = [0u8; 4];
+        id.copy_from_slice(&self.bytes[self.pos..self.pos + 4]);
+        let size = le_u32(self.bytes, self.pos + 4);
+        let offset = self.pos + 8;
+
+        let body_end = match offset.checked_add(size as usize) {
+            Some(body_end) if body_end <= self.end => body_end,
+            _ => {
+                self.done = true;
+                return Some(Ok(Chunk {
+                    id,
+                    offset,
+                    size,
+                    body: &self.bytes[offset..self.end],
+                }));
+            }
+        };
+
+        // Chunks are word aligned; a missing final pad byte is tolerated.
+        self.pos = (body_end + (size as usize & 1)).min(self.end);
+        Some(Ok(Chunk {
+            id,
+            offset,
+            size,
+            body: &self.bytes[offset..body_end],
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    fn riff(chunks: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+        let mut body = Vec::from(*b"WAVE");
+        for (id, data) in chunks {
+            body.extend_from_slice(*id);
+            body.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            body.extend_from_slice(data);
+            if data.len() % 2 == 1 {
+                body.push(0);
+            }
+        }
+        let mut out = Vec::from(*b"RIFF");
+        out.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        out.extend_from_slice(&body);
+        out
+    }
+
+    #[test]
+    fn walks_padded_chunks() {
+        let bytes = riff(&[(b"odd ", b"abc"), (b"even", b"abcd")]);
+        let chunks: Vec<_> = Chunks::new(&bytes).unwrap().map(Result::unwrap).collect();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].id, *b"odd ");
+        assert_eq!(chunks[0].body, b"abc");
+        assert_eq!(chunks[1].offset, 12 + 8 + 4 + 8);
+        assert_eq!(chunks[1].body, b"abcd");
+    }
+
+    #[test]
+    fn rejects_foreign_files() {
+        assert_eq!(Chunks::new(b"ID3\x04").unwrap_err(), Error::NotRiff);
+        assert_eq!(
+            Chunks::new(b"RIFF\x04\0\0\0AVI ").unwrap_err(),
+            Error::NotWave
+        );
+        assert_eq!(
+            Chunks::new(b"RIFF\x04\0\0").unwrap_err(),
+            Error::TruncatedHeader { offset: 0 }
+        );
+    }
+
+    #[test]
+    fn yields_partial_last_chunk_and_stops() {
+        let bytes = riff(&[(b"data", &[1, 2, 3, 4, 5, 6])]);
+        let prefix = &bytes[..bytes.len() - 2];
+        let mut chunks = Chunks::new(prefix).unwrap();
+        let data = chunks.next().unwrap().unwrap();
+        assert_eq!(data.size, 6);
+        assert_eq!(data.body, &[1, 2, 3, 4]);
+        assert!(!data.is_complete());
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn reports_cut_chunk_header() {
+        let bytes = riff(&[(b"fmt ", &[0; 4]), (b"data", &[0; 4])]);
+        let mut chunks = Chunks::new(&bytes[..24 + 3]).unwrap();
+        assert!(chunks.next().unwrap().is_ok());
+        assert_eq!(
+            chunks.next().unwrap().unwrap_err(),
+            Error::TruncatedHeader { offset: 24 }
+        );
+    }
+
+    #[test]
+    fn ignores_bytes_past_the_riff_size() {
+        let mut bytes = riff(&[(b"data", &[0; 2])]);
+        bytes.extend_from_slice(b"trailing garbage");
+        assert_eq!(Chunks::new(&bytes).unwrap().count(), 1);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{le_u32, ChunkId, Error};
+
+const CUE_POINT_SIZE: usize = 24;
+
+/// A marker from the `cue ` chunk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CuePoint {
+    /// Identifier referenced by `smpl` loops and `adtl` labels.
+    pub id: u32,
+    /// Play order position.
+    pub position: u32,
+    /// Chunk the marker points into, normally `*b"data"`.
+    pub chunk: ChunkId,
+    pub chunk_start: u32,
+    pub block_start: u32,
+    /// Frame offset of the marker inside the audio data.
+    pub sample_offset: u32,
+}
+
+/// The markers of a `cue ` chunk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cues<'a> {
+    points: &'a [u8],
+}
+
+impl<'a> Cues<'a> {
+    pub(crate) fn parse(body: &'a [u8]) -> Result<Self, Error> {
+        if body.len() < 4 {
+            return Err(malformed("shorter than 4 bytes"));
+        }
+        let count = le_u32(body, 0) as usize;
+        let len = count
+            .checked_mul(CUE_POINT_SIZE)
+            .filter(|&len| len <= body.len() - 4)
+            .ok_or(malformed("fewer cue points than declared"))?;
+        Ok(Self {
+            points: &body[4..4 + len],
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len() / CUE_POINT_SIZE
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = CuePoint> + 'a {
+        self.points.chunks_exact(CUE_POINT_SIZE).map(|p| CuePoint {
+            id: le_u32(p, 0),
+            position: le_u32(p, 4),
+            chunk: [p[8], p[9], p[10], p[11]],
+            chunk_start: le_u32(p, 12),
+            block_start: le_u32(p, 16),
+            sample_offset: le_u32(p, 20),
+        })
+    }
+
+    /// The marker with identifier `id`.
+    pub fn get(&self, id: u32) -> Option<CuePoint> {
+        self.iter().find(|p| p.id == id)
+    }
+}
+
+fn malformed(reason: &'static str) -> Error {
+    Error::MalformedChunk {
+        id: *b"cue ",
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_declared_points_only() {
+        let mut body = [0u8; 4 + 2 * CUE_POINT_SIZE];
+        body[0] = 1;
+        body[4] = 7;
+        body[12..16].copy_from_slice(b"data");
+        body[24..28].copy_from_slice(&1234u32.to_le_bytes());
+
+        let cues = Cues::parse(&body).unwrap();
+        assert_eq!(cues.len(), 1);
+        let point = cues.get(7).unwrap();
+        assert_eq!(point.chunk, *b"data");
+        assert_eq!(point.sample_offset, 1234);
+        assert!(cues.get(8).is_none());
+    }
+
+    #[test]
+    fn rejects_short_bodies() {
+        assert!(Cues::parse(&[2, 0, 0, 0]).is_err());
+        assert!(Cues::parse(&[0, 0]).is_err());
+        assert!(Cues::parse(&[0, 0, 0, 0]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,68 @@
+use core::fmt;
+
+use crate::ChunkId;
+
+/// Everything that can go wrong while reading a WAV file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The buffer does not start with a `RIFF` header.
+    NotRiff,
+    /// The RIFF form type is not `WAVE`.
+    NotWave,
+    /// The buffer ends inside the 8-byte header of the chunk starting at `offset`.
+    TruncatedHeader { offset: usize },
+    /// The chunk `id` starting at `offset` declares more bytes than the buffer holds.
+    TruncatedChunk { id: ChunkId, offset: usize },
+    /// A chunk body is too short or contradicts itself.
+    MalformedChunk { id: ChunkId, reason: &'static str },
+    /// The format tag (or the extensible sub-format) is not PCM, IEEE float or
+    /// IMA ADPCM.
+    UnsupportedCodec(u16),
+    /// The codec is known but the sample layout is not one we can play.
+    UnsupportedFormat(&'static str),
+    /// There is no `fmt ` chunk. [`parse`](crate::parse) takes it anywhere in
+    /// the file; [`parse_header`](crate::parse_header) stops at the `data`
+    /// chunk, so it needs it before.
+    MissingFormat,
+    /// There is no `data` chunk at all.
+    MissingData,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotRiff => f.write_str("not a RIFF file"),
+            Error::NotWave => f.write_str("RIFF form type is not WAVE"),
+            Error::TruncatedHeader { offset } => {
+                write!(f, "chunk header at byte {offset} is truncated")
+            }
+            Error::TruncatedChunk { id, offset } => {
+                write!(f, "chunk '{}' at byte {offset} is truncated", Fourcc(id))
+            }
+            Error::MalformedChunk { id, reason } => {
+                write!(f, "malformed '{}' chunk: {reason}", Fourcc(id))
+            }
+            Error::UnsupportedCodec(tag) => write!(f, "unsupported codec 0x{tag:04X}"),
+            Error::UnsupportedFormat(reason) => write!(f, "unsupported format: {reason}"),
+            Error::MissingFormat => f.write_str("no 'fmt ' chunk"),
+            Error::MissingData => f.write_str("no 'data' chunk"),
+        }
+    }
+}
+
+/// Prints a chunk id as text, replacing anything that is not printable ASCII.
+struct Fourcc<'a>(&'a ChunkId);
+
+impl fmt::Display for Fourcc<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &b in self.0 {
+            let c = if b.is_ascii_graphic() || b == b' ' {
+                b as char
+            } else {
+                '?'
+            };
+            write!(f, "{c}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,390 @@
+use crate::{le_u16, le_u32, Error};
+
+const WAVE_FORMAT_PCM: u16 = 0x0001;
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 0x0003;
+const WAVE_FORMAT_IMA_ADPCM: u16 = 0x0011;
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// Bytes 2..16 shared by every `KSDATAFORMAT_SUBTYPE_*` GUID; the first two
+/// bytes of the sub-format GUID hold the plain format tag.
+const SUBTYPE_GUID_TAIL: [u8; 14] = [
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71,
+];
+
+/// How the samples in the `data` chunk are encoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Codec {
+    /// Linear PCM: unsigned 8-bit, or signed little-endian 16/24/32-bit.
+    Pcm,
+    /// IEEE 754 floating point, 32 or 64 bits.
+    IeeeFloat,
+    /// IMA/DVI ADPCM, 4 bits per sample. The data is cut into blocks of
+    /// `block_align` bytes that each start with the decoder state of every
+    /// channel, so any block can be decoded on its own.
+    ImaAdpcm {
+        /// Frames in a complete block.
+        samples_per_block: u16,
+    },
+}
+
+impl Codec {
+    fn from_tag(tag: u16) -> Result<Self, Error> {
+        match tag {
+            WAVE_FORMAT_PCM => Ok(Codec::Pcm),
+            WAVE_FORMAT_IEEE_FLOAT => Ok(Codec::IeeeFloat),
+            // The block size is only known once the rest of `fmt ` is read
+            WAVE_FORMAT_IMA_ADPCM => Ok(Codec::ImaAdpcm {
+                samples_per_block: 0,
+            }),
+            other => Err(Error::UnsupportedCodec(other)),
+        }
+    }
+
+    /// The `wFormatTag` of the codec.
+    pub fn tag(self) -> u16 {
+        match self {
+            Codec::Pcm => WAVE_FORMAT_PCM,
+            Codec::IeeeFloat => WAVE_FORMAT_IEEE_FLOAT,
+            Codec::ImaAdpcm { .. } => WAVE_FORMAT_IMA_ADPCM,
+        }
+    }
+}
+
+/// Contents of the `fmt ` chunk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Format {
+    pub codec: Codec,
+    /// 1 = mono, 2 = stereo, ...
+    pub channels: u16,
+    /// Frames per second.
+    pub sample_rate: u32,
+    /// Average bytes per second, as declared by the writer.
+    pub byte_rate: u32,
+    /// Bytes per frame (all channels of one sample instant).
+    pub block_align: u16,
+    /// Size of each sample container in bits.
+    pub bits_per_sample: u16,
+    /// Meaningful bits inside the container (less than `bits_per_sample` only
+    /// for extensible files such as 20-bit audio in 24-bit slots).
+    pub valid_bits: u16,
+    /// Speaker position bits from `WAVE_FORMAT_EXTENSIBLE`, 0 when not given.
+    pub channel_mask: u32,
+}
+
+impl Format {
+    /// Reads and validates the body of a `fmt ` chunk.
+    pub(crate) fn parse(body: &[u8]) -> Result<Self, Error> {
+        if body.len() < 16 {
+            return Err(malformed("shorter than 16 bytes"));
+        }
+
+        let mut tag = le_u16(body, 0);
+        let channels = le_u16(body, 2);
+        let sample_rate = le_u32(body, 4);
+        let byte_rate = le_u32(body, 8);
+        let block_align = le_u16(body, 12);
+        let bits_per_sample = le_u16(body, 14);
+        let mut valid_bits = bits_per_sample;
+        let mut channel_mask = 0;
+
+        if tag == WAVE_FORMAT_EXTENSIBLE {
+            if body.len() < 40 || le_u16(body, 16) < 22 {
+                return Err(malformed("extensible format shorter than 40 bytes"));
+            }
+            valid_bits = le_u16(body, 18);
+            channel_mask = le_u32(body, 20);
+            if body[26..40] != SUBTYPE_GUID_TAIL {
+                return Err(Error::UnsupportedCodec(WAVE_FORMAT_EXTENSIBLE));
+            }
+            tag = le_u16(body, 24);
+            if valid_bits == 0 {
+                valid_bits = bits_per_sample;
+            }
+        }
+
+        let mut codec = Codec::from_tag(tag)?;
+        if channels == 0 {
+            return Err(malformed("zero channels"));
+        }
+        if sample_rate == 0 {
+            return Err(malformed("zero sample rate"));
+        }
+        if let Codec::ImaAdpcm { samples_per_block } = &mut codec {
+            *samples_per_block =
+                ima_samples_per_block(body, channels, block_align, bits_per_sample)?;
+            return Ok(Self {
+                codec,
+                channels,
+                sample_rate,
+                byte_rate,
+                block_align,
+                bits_per_sample,
+                valid_bits,
+                channel_mask,
+            });
+        }
+        match (codec, bits_per_sample) {
+            (Codec::Pcm, 8 | 16 | 24 | 32) | (Codec::IeeeFloat, 32 | 64) => {}
+            (Codec::Pcm, _) => {
+                return Err(Error::UnsupportedFormat("PCM must be 8, 16, 24 or 32 bits"))
+            }
+            (Codec::IeeeFloat, _) => {
+                return Err(Error::UnsupportedFormat("float must be 32 or 64 bits"))
+            }
+            (Codec::ImaAdpcm { .. }, _) => unreachable!("returned above"),
+        }
+        if valid_bits > bits_per_sample {
+            return Err(malformed("valid bits exceed the container size"));
+        }
+        if block_align as u32 != channels as u32 * (bits_per_sample as u32 / 8) {
+            return Err(malformed(
+                "block align does not match channels and bit depth",
+            ));
+        }
+
+        Ok(Self {
+            codec,
+            channels,
+            sample_rate,
+            byte_rate,
+            block_align,
+            bits_per_sample,
+            valid_bits,
+            channel_mask,
+        })
+    }
+
+    /// Linear PCM with the usual derived fields, e.g. for a file being written.
+    pub fn pcm(channels: u16, sample_rate: u32, bits_per_sample: u16) -> Self {
+        let block_align = channels * (bits_per_sample / 8);
+        Self {
+            codec: Codec::Pcm,
+            channels,
+            sample_rate,
+            byte_rate: sample_rate * block_align as u32,
+            block_align,
+            bits_per_sample,
+            valid_bits: bits_per_sample,
+            channel_mask: 0,
+        }
+    }
+
+    /// IMA ADPCM in blocks of `block_align` bytes, e.g. for a file being
+    /// written. `block_align` must be a multiple of `4 * channels`.
+    pub fn ima_adpcm(channels: u16, sample_rate: u32, block_align: u16) -> Self {
+        let samples_per_block = ima_block_frames(block_align as usize, channels as usize) as u16;
+        Self {
+            codec: Codec::ImaAdpcm { samples_per_block },
+            channels,
+            sample_rate,
+            byte_rate: (sample_rate as u64 * block_align as u64 / samples_per_block as u64) as u32,
+            block_align,
+            bits_per_sample: 4,
+            valid_bits: 4,
+            channel_mask: 0,
+        }
+    }
+
+    /// Bytes per sample of a single channel (0 for ADPCM, which packs two
+    /// samples in a byte).
+    pub fn bytes_per_sample(&self) -> usize {
+        self.bits_per_sample as usize / 8
+    }
+
+    /// Bytes per frame (one sample for every channel); bytes per block for
+    /// ADPCM.
+    pub fn frame_size(&self) -> usize {
+        self.block_align as usize
+    }
+
+    /// Number of whole frames in `data_len` bytes of audio.
+    pub fn frames(&self, data_len: usize) -> u32 {
+        match self.codec {
+            Codec::ImaAdpcm { samples_per_block } => {
+                let blocks = data_len / self.frame_size();
+                // A short last block still holds whole frames
+                let last = ima_block_frames(data_len % self.frame_size(), self.channels as usize);
+                (blocks * samples_per_block as usize + last) as u32
+            }
+            _ => (data_len / self.frame_size()) as u32,
+        }
+    }
+
+    /// Playback time of `data_len` bytes of audio, in milliseconds.
+    pub fn duration_ms(&self, data_len: usize) -> u32 {
+        (self.frames(data_len) as u64 * 1000 / self.sample_rate as u64) as u32
+    }
+}
+
+/// Frames in an IMA ADPCM block of `len` bytes: one in the header of each
+/// channel, then two per byte of every channel.
+fn ima_block_frames(len: usize, channels: usize) -> usize {
+    let header = 4 * channels;
+    if len < header {
+        0
+    } else {
+        (len - header) * 2 / channels + 1
+    }
+}
+
+/// Checks the block layout of an IMA ADPCM `fmt ` chunk and returns its
+/// frames per block, taken from the extra bytes when the writer filled them.
+fn ima_samples_per_block(
+    body: &[u8],
+    channels: u16,
+    block_align: u16,
+    bits_per_sample: u16,
+) -> Result<u16, Error> {
+    if bits_per_sample != 4 {
+        return Err(Error::UnsupportedFormat("IMA ADPCM must be 4 bits"));
+    }
+    let (channels, block_align) = (channels as usize, block_align as usize);
+    if block_align <= 4 * channels || block_align % (4 * channels) != 0 {
+        return Err(malformed("block align does not fit whole ADPCM blocks"));
+    }
+    let frames = ima_block_frames(block_align, channels);
+    if frames > u16::MAX as usize {
+        return Err(malformed("ADPCM blocks too large"));
+    }
+    match body.get(16..20) {
+        Some(extra) if le_u16(extra, 0) >= 2 && le_u16(extra, 2) != 0 => {
+            let declared = le_u16(extra, 2);
+            if declared as usize > frames {
+                return Err(malformed("more samples per block than the block holds"));
+            }
+            Ok(declared)
+        }
+        _ => Ok(frames as u16),
+    }
+}
+
+fn malformed(reason: &'static str) -> Error {
+    Error::MalformedChunk {
+        id: *b"fmt ",
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fmt(tag: u16, channels: u16, rate: u32, bits: u16) -> [u8; 16] {
+        let align = channels * bits / 8;
+        let mut body = [0u8; 16];
+        body[0..2].copy_from_slice(&tag.to_le_bytes());
+        body[2..4].copy_from_slice(&channels.to_le_bytes());
+        body[4..8].copy_from_slice(&rate.to_le_bytes());
+        body[8..12].copy_from_slice(&(rate * align as u32).to_le_bytes());
+        body[12..14].copy_from_slice(&align.to_le_bytes());
+        body[14..16].copy_from_slice(&bits.to_le_bytes());
+        body
+    }
+
+    #[test]
+    fn parses_plain_pcm() {
+        let format = Format::parse(&fmt(1, 2, 44100, 16)).unwrap();
+        assert_eq!(format.codec, Codec::Pcm);
+        assert_eq!(format.channels, 2);
+        assert_eq!(format.sample_rate, 44100);
+        assert_eq!(format.frame_size(), 4);
+        assert_eq!(format.valid_bits, 16);
+        assert_eq!(format.frames(4 * 44100 + 3), 44100);
+        assert_eq!(format.duration_ms(4 * 22050), 500);
+    }
+
+    #[test]
+    fn parses_extensible_sub_format() {
+        let mut body = [0u8; 40];
+        body[..16].copy_from_slice(&fmt(0xFFFE, 2, 48000, 24));
+        body[16..18].copy_from_slice(&22u16.to_le_bytes());
+        body[18..20].copy_from_slice(&20u16.to_le_bytes());
+        body[20..24].copy_from_slice(&3u32.to_le_bytes());
+        body[24..26].copy_from_slice(&3u16.to_le_bytes());
+        body[26..40].copy_from_slice(&SUBTYPE_GUID_TAIL);
+
+        assert_eq!(
+            Format::parse(&body).unwrap_err(),
+            Error::UnsupportedFormat("float must be 32 or 64 bits")
+        );
+
+        body[24..26].copy_from_slice(&1u16.to_le_bytes());
+        let format = Format::parse(&body).unwrap();
+        assert_eq!(format.codec, Codec::Pcm);
+        assert_eq!(format.bits_per_sample, 24);
+        assert_eq!(format.valid_bits, 20);
+        assert_eq!(format.channel_mask, 3);
+
+        body[30] = 0xFF;
+        assert_eq!(
+            Format::parse(&body).unwrap_err(),
+            Error::UnsupportedCodec(0xFFFE)
+        );
+    }
+
+    #[test]
+    fn parses_ima_adpcm_blocks() {
+        // Stereo, 512-byte blocks: 8 header bytes, then 504 bytes of nibbles
+        let mut body = [0u8; 20];
+        body[..16].copy_from_slice(&fmt(0x11, 2, 22050, 4));
+        body[12..14].copy_from_slice(&512u16.to_le_bytes());
+        let format = Format::parse(&body[..16]).unwrap();
+        assert_eq!(
+            format.codec,
+            Codec::ImaAdpcm {
+                samples_per_block: 505
+            }
+        );
+        // Two whole blocks and a short one with 8 bytes of nibbles
+        assert_eq!(format.frames(2 * 512 + 16), 2 * 505 + 9);
+
+        body[16..18].copy_from_slice(&2u16.to_le_bytes());
+        body[18..20].copy_from_slice(&500u16.to_le_bytes());
+        let format = Format::parse(&body).unwrap();
+        assert_eq!(
+            format.codec,
+            Codec::ImaAdpcm {
+                samples_per_block: 500
+            }
+        );
+        assert_eq!(Format::ima_adpcm(2, 22050, 512).codec.tag(), 0x11);
+
+        body[18..20].copy_from_slice(&506u16.to_le_bytes());
+        assert!(matches!(
+            Format::parse(&body).unwrap_err(),
+            Error::MalformedChunk { .. }
+        ));
+        body[12..14].copy_from_slice(&510u16.to_le_bytes());
+        assert!(matches!(
+            Format::parse(&body[..16]).unwrap_err(),
+            Error::MalformedChunk { .. }
+        ));
+    }
+
+    #[test]
+    fn rejects_unsupported_codecs_and_layouts() {
+        assert_eq!(
+            Format::parse(&fmt(0x55, 1, 8000, 16)).unwrap_err(),
+            Error::UnsupportedCodec(0x55)
+        );
+        assert!(matches!(
+            Format::parse(&fmt(1, 1, 8000, 12)).unwrap_err(),
+            Error::UnsupportedFormat(_)
+        ));
+        assert!(matches!(
+            Format::parse(&fmt(1, 0, 8000, 16)).unwrap_err(),
+            Error::MalformedChunk { .. }
+        ));
+        assert!(matches!(
+            Format::parse(&fmt(1, 1, 8000, 16)[..14]).unwrap_err(),
+            Error::MalformedChunk { .. }
+        ));
+
+        let mut bad_align = fmt(1, 2, 8000, 16);
+        bad_align[12] = 2;
+        assert!(matches!(
+            Format::parse(&bad_align).unwrap_err(),
+            Error::MalformedChunk { .. }
+        ));
+    }
+}
@@ -0,0 +1,110 @@
+use crate::chunks::Chunks;
+use crate::ChunkId;
+
+/// The text tags of a `LIST`/`INFO` chunk (title, artist, comment, ...).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Info<'a> {
+    /// The list body without the leading `INFO` form type.
+    body: &'a [u8],
+}
+
+impl<'a> Info<'a> {
+    /// Wraps a `LIST` body; `None` for lists of any other form type (`adtl`, ...).
+    pub(crate) fn from_list(body: &'a [u8]) -> Option<Self> {
+        match body.split_first_chunk::<4>() {
+            Some((b"INFO", rest)) => Some(Self { body: rest }),
+            _ => None,
+        }
+    }
+
+    /// All tags, in file order.
+    pub fn entries(&self) -> InfoEntries<'a> {
+        InfoEntries {
+            chunks: Chunks::within(self.body, 0, self.body.len()),
+        }
+    }
+
+    /// The text of the first tag called `id`.
+    pub fn get(&self, id: ChunkId) -> Option<&'a str> {
+        self.entries().find(|e| e.id == id).and_then(|e| e.text())
+    }
+
+    /// `INAM`: the title of the clip.
+    pub fn title(&self) -> Option<&'a str> {
+        self.get(*b"INAM")
+    }
+
+    /// `IART`: the artist.
+    pub fn artist(&self) -> Option<&'a str> {
+        self.get(*b"IART")
+    }
+
+    /// `ICMT`: a free-form comment.
+    pub fn comment(&self) -> Option<&'a str> {
+        self.get(*b"ICMT")
+    }
+}
+
+/// A single `INFO` tag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InfoEntry<'a> {
+    pub id: ChunkId,
+    /// Raw value, usually NUL terminated.
+    pub value: &'a [u8],
+}
+
+impl<'a> InfoEntry<'a> {
+    /// The value up to the first NUL, if it is valid UTF-8.
+    pub fn text(&self) -> Option<&'a str> {
+        let end = self
+            .value
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(self.value.len());
+        core::str::from_utf8(&self.value[..end]).ok()
+    }
+}
+
+/// Iterator over the tags of an [`Info`] list. Stops at the first damaged tag
+/// instead of failing: metadata is never worth refusing to play a clip.
+#[derive(Clone, Debug)]
+pub struct InfoEntries<'a> {
+    chunks: Chunks<'a>,
+}
+
+impl<'a> Iterator for InfoEntries<'a> {
+    type Item = InfoEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.chunks.next()? {
+            Ok(chunk) if chunk.is_complete() => Some(InfoEntry {
+                id: chunk.id,
+                value: chunk.body,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_tags_and_trims_terminators() {
+        let body = b"INFOINAM\x06\0\0\0Fairy\0IART\x03\0\0\0Bob\0";
+        let info = Info::from_list(body).unwrap();
+        assert_eq!(info.title(), Some("Fairy"));
+        assert_eq!(info.artist(), Some("Bob"));
+        assert_eq!(info.comment(), None);
+        assert_eq!(info.entries().count(), 2);
+    }
+
+    #[test]
+    fn ignores_other_list_types_and_damaged_tags() {
+        assert!(Info::from_list(b"adtlnote\0\0\0\0").is_none());
+
+        let info = Info::from_list(b"INFOINAM\x06\0\0\0Fai").unwrap();
+        assert_eq!(info.entries().count(), 0);
+    }
+}
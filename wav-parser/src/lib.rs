@@ -0,0 +1,199 @@
+//! RIFF/WAVE parser shared by the audio projects of this collection.
+//!
+//! Clips are not always the "classic" 44-byte header followed by samples:
+//! encoders add `LIST`, `fact`, `cue ` or `smpl` chunks, and some write
+//! `WAVE_FORMAT_EXTENSIBLE` headers. This crate walks every chunk and reports
+//! the exact position of the audio data, so the players never send header
+//! bytes to the speaker.
+//!
+//! * [`parse`] reads a complete file held in memory (clips stored in flash).
+//! * [`parse_header`] reads just the start of a file and stops at the `data`
+//!   chunk (files streamed from an SD card).
+//! * [`write_header`] builds the header for audio you record or render.
+//!
+//! ```
+//! let bytes = include_bytes!("../tests/fixtures/pcm16_mono_8000.wav");
+//! let wav = wav_parser::parse(bytes).unwrap();
+//! assert_eq!(wav.format.sample_rate, 8000);
+//! assert_eq!(wav.data_offset, 44);
+//! ```
+
+#![no_std]
+
+mod chunks;
+mod cue;
+mod error;
+mod format;
+mod info;
+mod sampler;
+mod write;
+
+pub use chunks::{Chunk, Chunks};
+pub use cue::{CuePoint, Cues};
+pub use error::Error;
+pub use format::{Codec, Format};
+pub use info::{Info, InfoEntries, InfoEntry};
+pub use sampler::{LoopKind, SampleLoop, Sampler};
+pub use write::{write_header, HEADER_LEN};
+
+/// A four-character RIFF chunk identifier, e.g. `*b"data"`.
+pub type ChunkId = [u8; 4];
+
+/// Where the audio lives in a file and how to interpret it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub format: Format,
+    /// Offset of the first audio byte from the start of the file.
+    pub data_offset: usize,
+    /// Length of the audio data in bytes.
+    pub data_len: usize,
+    /// Frame count from the `fact` chunk, when the file has one.
+    pub fact_samples: Option<u32>,
+}
+
+impl Header {
+    /// Number of whole frames in the audio data.
+    pub fn frames(&self) -> u32 {
+        self.format.frames(self.data_len)
+    }
+
+    /// Playback time in milliseconds.
+    pub fn duration_ms(&self) -> u32 {
+        self.format.duration_ms(self.data_len)
+    }
+}
+
+/// A complete WAV file held in memory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Wav<'a> {
+    pub format: Format,
+    /// Offset of [`Wav::data`] from the start of the file.
+    pub data_offset: usize,
+    /// The audio samples, exactly as declared by the `data` chunk.
+    pub data: &'a [u8],
+    /// Frame count from the `fact` chunk, when the file has one.
+    pub fact_samples: Option<u32>,
+    /// `LIST`/`INFO` text tags.
+    pub info: Option<Info<'a>>,
+    /// Markers from the `cue ` chunk.
+    pub cues: Option<Cues<'a>>,
+    /// Loop points from the `smpl` chunk.
+    pub sampler: Option<Sampler<'a>>,
+}
+
+impl Wav<'_> {
+    pub fn header(&self) -> Header {
+        Header {
+            format: self.format,
+            data_offset: self.data_offset,
+            data_len: self.data.len(),
+            fact_samples: self.fact_samples,
+        }
+    }
+
+    /// Number of whole frames in the audio data.
+    pub fn frames(&self) -> u32 {
+        self.format.frames(self.data.len())
+    }
+
+    /// Playback time in milliseconds.
+    pub fn duration_ms(&self) -> u32 {
+        self.format.duration_ms(self.data.len())
+    }
+}
+
+/// Parses a complete WAV file, walking every chunk.
+///
+/// Every chunk must fit in `bytes`; the first chunk of each kind wins and
+/// unknown chunks are skipped.
+pub fn parse(bytes: &[u8]) -> Result<Wav<'_>, Error> {
+    let mut format = None;
+    let mut data = None;
+    let mut fact_samples = None;
+    let mut info = None;
+    let mut cues = None;
+    let mut sampler = None;
+
+    for chunk in Chunks::new(bytes)? {
+        let chunk = chunk?;
+        if !chunk.is_complete() {
+            return Err(Error::TruncatedChunk {
+                id: chunk.id,
+                offset: chunk.offset - 8,
+            });
+        }
+        match &chunk.id {
+            b"fmt " if format.is_none() => format = Some(Format::parse(chunk.body)?),
+            b"data" if data.is_none() => data = Some(chunk),
+            b"fact" if fact_samples.is_none() => fact_samples = Some(parse_fact(chunk.body)?),
+            b"LIST" if info.is_none() => info = Info::from_list(chunk.body),
+            b"cue " if cues.is_none() => cues = Some(Cues::parse(chunk.body)?),
+            b"smpl" if sampler.is_none() => sampler = Some(Sampler::parse(chunk.body)?),
+            _ => {}
+        }
+    }
+
+    let format = format.ok_or(Error::MissingFormat)?;
+    let data = data.ok_or(Error::MissingData)?;
+    Ok(Wav {
+        format,
+        data_offset: data.offset,
+        data: data.body,
+        fact_samples,
+        info,
+        cues,
+        sampler,
+    })
+}
+
+/// Parses the start of a WAV file up to the header of the `data` chunk.
+///
+/// `prefix` only needs to reach the first audio byte; the `data` chunk itself
+/// may extend past it. Chunks stored after the audio are not visited, so the
+/// `fmt ` chunk has to come before it.
+pub fn parse_header(prefix: &[u8]) -> Result<Header, Error> {
+    let mut format = None;
+    let mut fact_samples = None;
+
+    for chunk in Chunks::new(prefix)? {
+        let chunk = chunk?;
+        if &chunk.id == b"data" {
+            return Ok(Header {
+                format: format.ok_or(Error::MissingFormat)?,
+                data_offset: chunk.offset,
+                data_len: chunk.size as usize,
+                fact_samples,
+            });
+        }
+        if !chunk.is_complete() {
+            return Err(Error::TruncatedChunk {
+                id: chunk.id,
+                offset: chunk.offset - 8,
+            });
+        }
+        match &chunk.id {
+            b"fmt " if format.is_none() => format = Some(Format::parse(chunk.body)?),
+            b"fact" if fact_samples.is_none() => fact_samples = Some(parse_fact(chunk.body)?),
+            _ => {}
+        }
+    }
+    Err(Error::MissingData)
+}
+
+fn parse_fact(body: &[u8]) -> Result<u32, Error> {
+    if body.len() < 4 {
+        return Err(Error::MalformedChunk {
+            id: *b"fact",
+            reason: "shorter than 4 bytes",
+        });
+    }
+    Ok(le_u32(body, 0))
+}
+
+pub(crate) fn le_u16(bytes: &[u8], at: usize) -> u16 {
+    u16::from_le_bytes([bytes[at], bytes[at + 1]])
+}
+
+pub(crate) fn le_u32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
@@ -0,0 +1,115 @@
+use crate::{le_u32, Error};
+
+const HEADER_SIZE: usize = 36;
+const LOOP_SIZE: usize = 24;
+
+/// Direction of a sampler loop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoopKind {
+    Forward,
+    PingPong,
+    Backward,
+    /// Manufacturer specific loop type.
+    Other(u32),
+}
+
+/// A loop region from the `smpl` chunk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SampleLoop {
+    /// Identifier of the matching `cue ` point, if any.
+    pub cue_id: u32,
+    pub kind: LoopKind,
+    /// First frame of the loop.
+    pub start: u32,
+    /// Last frame of the loop (inclusive).
+    pub end: u32,
+    /// Fraction of a frame to add to `end`, in units of 1/2^32.
+    pub fraction: u32,
+    /// How many times to play the loop, 0 = forever.
+    pub play_count: u32,
+}
+
+/// Contents of the `smpl` chunk: tuning and loop points for samplers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sampler<'a> {
+    /// Duration of one frame in nanoseconds.
+    pub sample_period: u32,
+    /// MIDI note at which the clip plays back unpitched.
+    pub unity_note: u32,
+    /// Fine tuning above `unity_note`, in units of 1/2^32 semitone.
+    pub pitch_fraction: u32,
+    loops: &'a [u8],
+}
+
+impl<'a> Sampler<'a> {
+    pub(crate) fn parse(body: &'a [u8]) -> Result<Self, Error> {
+        if body.len() < HEADER_SIZE {
+            return Err(malformed("shorter than 36 bytes"));
+        }
+        let count = le_u32(body, 28) as usize;
+        let len = count
+            .checked_mul(LOOP_SIZE)
+            .filter(|&len| len <= body.len() - HEADER_SIZE)
+            .ok_or(malformed("fewer loops than declared"))?;
+        Ok(Self {
+            sample_period: le_u32(body, 8),
+            unity_note: le_u32(body, 12),
+            pitch_fraction: le_u32(body, 16),
+            loops: &body[HEADER_SIZE..HEADER_SIZE + len],
+        })
+    }
+
+    pub fn loops(&self) -> impl Iterator<Item = SampleLoop> + 'a {
+        self.loops.chunks_exact(LOOP_SIZE).map(|l| SampleLoop {
+            cue_id: le_u32(l, 0),
+            kind: match le_u32(l, 4) {
+                0 => LoopKind::Forward,
+                1 => LoopKind::PingPong,
+                2 => LoopKind::Backward,
+                other => LoopKind::Other(other),
+            },
+            start: le_u32(l, 8),
+            end: le_u32(l, 12),
+            fraction: le_u32(l, 16),
+            play_count: le_u32(l, 20),
+        })
+    }
+}
+
+fn malformed(reason: &'static str) -> Error {
+    Error::MalformedChunk {
+        id: *b"smpl",
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_loops() {
+        let mut body = [0u8; HEADER_SIZE + LOOP_SIZE];
+        body[12] = 60;
+        body[28] = 1;
+        body[HEADER_SIZE + 4] = 1;
+        body[HEADER_SIZE + 8..HEADER_SIZE + 12].copy_from_slice(&10u32.to_le_bytes());
+        body[HEADER_SIZE + 12..HEADER_SIZE + 16].copy_from_slice(&99u32.to_le_bytes());
+
+        let sampler = Sampler::parse(&body).unwrap();
+        assert_eq!(sampler.unity_note, 60);
+        let mut loops = sampler.loops();
+        let first = loops.next().unwrap();
+        assert_eq!(first.kind, LoopKind::PingPong);
+        assert_eq!((first.start, first.end, first.play_count), (10, 99, 0));
+        assert!(loops.next().is_none());
+    }
+
+    #[test]
+    fn rejects_missing_loops() {
+        let mut body = [0u8; HEADER_SIZE + LOOP_SIZE];
+        body[28] = 2;
+        assert!(Sampler::parse(&body).is_err());
+        assert!(Sampler::parse(&body[..20]).is_err());
+    }
+}
@@ -0,0 +1,63 @@
+use crate::Format;
+
+/// Size of the header written by [`write_header`].
+pub const HEADER_LEN: usize = 44;
+
+/// Builds the canonical 44-byte header (`RIFF`, `fmt `, `data`) for
+/// `data_len` bytes of audio in `format`.
+///
+/// Recorders write it with `data_len = 0` first and write it again once the
+/// length is known. Extensible details (`valid_bits`, `channel_mask`) are not
+/// kept: the plain `fmt ` chunk has no room for them. Neither is the ADPCM
+/// samples per block, which readers work out from `block_align`.
+pub fn write_header(format: &Format, data_len: u32) -> [u8; HEADER_LEN] {
+    let tag = format.codec.tag();
+    let fields: [&[u8]; 12] = [
+        b"RIFF",
+        &(36u32.saturating_add(data_len)).to_le_bytes(),
+        b"WAVEfmt ",
+        &16u32.to_le_bytes(),
+        &tag.to_le_bytes(),
+        &format.channels.to_le_bytes(),
+        &format.sample_rate.to_le_bytes(),
+        &format.byte_rate.to_le_bytes(),
+        &format.block_align.to_le_bytes(),
+        &format.bits_per_sample.to_le_bytes(),
+        b"data",
+        &data_len.to_le_bytes(),
+    ];
+
+    let mut header = [0u8; HEADER_LEN];
+    let mut at = 0;
+    for field in fields {
+        header[at..at + field.len()].copy_from_slice(field);
+        at += field.len();
+    }
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Codec;
+
+    #[test]
+    fn written_header_parses_back() {
+        let format = Format::pcm(2, 11025, 16);
+        let header = write_header(&format, 4000);
+        let parsed = crate::parse_header(&header).unwrap();
+        assert_eq!(parsed.format, format);
+        assert_eq!(parsed.data_offset, HEADER_LEN);
+        assert_eq!(parsed.data_len, 4000);
+        assert_eq!(&header[4..8], &4036u32.to_le_bytes());
+    }
+
+    #[test]
+    fn float_format_keeps_its_tag() {
+        let mut format = Format::pcm(1, 48000, 32);
+        format.codec = Codec::IeeeFloat;
+        let header = write_header(&format, 0);
+        assert_eq!(&header[20..22], &3u16.to_le_bytes());
+        assert_eq!(crate::parse_header(&header).unwrap().format, format);
+    }
+}
@@ -0,0 +1,172 @@
+//! Parses the WAV files in `tests/fixtures`.
+//!
+//! `pcm8_mono_8000.wav` is the clip embedded in `mp3-player`; the others were
+//! written by Python's `wave` module or assembled chunk by chunk to cover the
+//! layouts encoders produce in the wild.
+
+use wav_parser::{parse, parse_header, Codec, Error, LoopKind};
+
+const PCM8_MONO: &[u8] = include_bytes!("fixtures/pcm8_mono_8000.wav");
+const PCM16_MONO: &[u8] = include_bytes!("fixtures/pcm16_mono_8000.wav");
+const PCM16_STEREO_LIST: &[u8] = include_bytes!("fixtures/pcm16_stereo_44100_list.wav");
+const PCM24_EXTENSIBLE: &[u8] = include_bytes!("fixtures/pcm24_mono_48000_extensible.wav");
+const FLOAT32_STEREO: &[u8] = include_bytes!("fixtures/float32_stereo_22050.wav");
+const PCM16_LOOP: &[u8] = include_bytes!("fixtures/pcm16_mono_11025_loop.wav");
+const MULAW: &[u8] = include_bytes!("fixtures/mulaw_mono_8000.wav");
+
+#[test]
+fn mp3_player_clip() {
+    let wav = parse(PCM8_MONO).unwrap();
+    assert_eq!(wav.format.codec, Codec::Pcm);
+    assert_eq!(wav.format.channels, 1);
+    assert_eq!(wav.format.sample_rate, 8000);
+    assert_eq!(wav.format.bits_per_sample, 8);
+    assert_eq!(wav.data_offset, 44);
+    assert_eq!(wav.data.len(), 18854);
+    assert_eq!(wav.duration_ms(), 2356);
+}
+
+#[test]
+fn classic_44_byte_header() {
+    let wav = parse(PCM16_MONO).unwrap();
+    assert_eq!(wav.data_offset, 44);
+    assert_eq!(wav.frames(), 800);
+    assert_eq!(wav.duration_ms(), 100);
+    assert!(wav.info.is_none());
+}
+
+#[test]
+fn list_chunk_before_data_moves_the_audio() {
+    let wav = parse(PCM16_STEREO_LIST).unwrap();
+    assert_eq!(wav.format.channels, 2);
+    assert_eq!(wav.format.sample_rate, 44100);
+    assert_ne!(wav.data_offset, 44);
+    assert_eq!(
+        &PCM16_STEREO_LIST[wav.data_offset - 8..wav.data_offset - 4],
+        b"data"
+    );
+    assert_eq!(wav.frames(), 441);
+
+    let info = wav.info.unwrap();
+    assert_eq!(info.title(), Some("Fairy Song"));
+    assert_eq!(info.artist(), Some("Plant Fairy"));
+    assert_eq!(info.get(*b"ISFT"), Some("Lavf58.76.100"));
+}
+
+#[test]
+fn extensible_24_bit() {
+    let wav = parse(PCM24_EXTENSIBLE).unwrap();
+    assert_eq!(wav.format.codec, Codec::Pcm);
+    assert_eq!(wav.format.bits_per_sample, 24);
+    assert_eq!(wav.format.valid_bits, 24);
+    assert_eq!(wav.format.channel_mask, 0x4);
+    assert_eq!(wav.format.frame_size(), 3);
+    assert_eq!(wav.fact_samples, Some(480));
+    assert_eq!(wav.frames(), 480);
+    assert_eq!(wav.duration_ms(), 10);
+}
+
+#[test]
+fn float_with_padding_and_trailing_list() {
+    let wav = parse(FLOAT32_STEREO).unwrap();
+    assert_eq!(wav.format.codec, Codec::IeeeFloat);
+    assert_eq!(wav.format.frame_size(), 8);
+    assert_eq!(wav.fact_samples, Some(221));
+    assert_eq!(wav.frames(), 221);
+    assert_eq!(wav.info.unwrap().comment(), Some("odd"));
+}
+
+#[test]
+fn cue_and_sampler_loops() {
+    let wav = parse(PCM16_LOOP).unwrap();
+    assert_eq!(wav.frames(), 1100);
+
+    let cues = wav.cues.unwrap();
+    assert_eq!(cues.len(), 2);
+    assert_eq!(cues.get(2).unwrap().sample_offset, 1000);
+
+    let sampler = wav.sampler.unwrap();
+    assert_eq!(sampler.unity_note, 69);
+    let first = sampler.loops().next().unwrap();
+    assert_eq!(first.kind, LoopKind::Forward);
+    assert_eq!((first.cue_id, first.start, first.end), (1, 100, 999));
+    assert_eq!(first.play_count, 0);
+}
+
+#[test]
+fn header_prefix_matches_full_parse() {
+    for bytes in [
+        PCM8_MONO,
+        PCM16_MONO,
+        PCM16_STEREO_LIST,
+        PCM24_EXTENSIBLE,
+        FLOAT32_STEREO,
+        PCM16_LOOP,
+    ] {
+        let full = parse(bytes).unwrap().header();
+        // Just the header, as read from the start of a file on an SD card.
+        let prefix = &bytes[..full.data_offset + 2];
+        assert_eq!(parse_header(prefix).unwrap(), full);
+        assert_eq!(parse_header(bytes).unwrap(), full);
+    }
+}
+
+#[test]
+fn truncated_data_is_reported() {
+    let cut = &PCM16_MONO[..PCM16_MONO.len() - 10];
+    assert_eq!(
+        parse(cut).unwrap_err(),
+        Error::TruncatedChunk {
+            id: *b"data",
+            offset: 36
+        }
+    );
+
+    let cut = &PCM16_STEREO_LIST[..60];
+    assert!(matches!(
+        parse_header(cut).unwrap_err(),
+        Error::TruncatedChunk { id, .. } if id == *b"LIST"
+    ));
+}
+
+#[test]
+fn unsupported_codec_is_reported() {
+    assert_eq!(parse(MULAW).unwrap_err(), Error::UnsupportedCodec(0x0007));
+    assert_eq!(
+        parse_header(MULAW).unwrap_err(),
+        Error::UnsupportedCodec(0x0007)
+    );
+}
+
+#[test]
+fn missing_chunks_are_reported() {
+    // Only the RIFF header and the fmt chunk of a 44-byte-header file.
+    let mut no_data = PCM16_MONO[..36].to_vec();
+    no_data[4..8].copy_from_slice(&28u32.to_le_bytes());
+    assert_eq!(parse(&no_data).unwrap_err(), Error::MissingData);
+    assert_eq!(parse_header(&no_data).unwrap_err(), Error::MissingData);
+
+    let mut no_fmt = PCM16_MONO.to_vec();
+    no_fmt[12..16].copy_from_slice(b"junk");
+    assert_eq!(parse(&no_fmt).unwrap_err(), Error::MissingFormat);
+    assert_eq!(parse_header(&no_fmt).unwrap_err(), Error::MissingFormat);
+
+    assert_eq!(parse(b"ID3\x03\0\0\0\0").unwrap_err(), Error::NotRiff);
+}
+
+#[test]
+fn format_after_data_needs_the_whole_file() {
+    // The 44-byte-header file with its fmt chunk moved behind the audio.
+    let mut moved = PCM16_MONO[..12].to_vec();
+    moved.extend_from_slice(&PCM16_MONO[36..]);
+    moved.extend_from_slice(&PCM16_MONO[12..36]);
+
+    let expected = parse(PCM16_MONO).unwrap();
+    let wav = parse(&moved).unwrap();
+    assert_eq!(wav.format, expected.format);
+    assert_eq!(wav.data, expected.data);
+    assert_eq!(wav.data_offset, 20);
+
+    // Streaming stops at the audio, before the format.
+    assert_eq!(parse_header(&moved).unwrap_err(), Error::MissingFormat);
+}